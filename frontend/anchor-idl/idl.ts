@@ -14,22 +14,53 @@ export type Txguard = {
   },
   "instructions": [
     {
-      "name": "initialize",
+      "name": "archiveRegistry",
+      "docs": [
+        "Moves the registry's all-time counters into a new ArchiveRegistry PDA and restarts",
+        "them from zero, so old reports stop weighing on current metrics. Every counted",
+        "report must predate `archive_before_slot`. The last-100 window is kept, but its",
+        "entries can no longer be disputed."
+      ],
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        30,
+        176,
+        247,
+        117,
+        44,
+        47,
+        40,
+        3
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "authority",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
         },
         {
           "name": "registry",
@@ -48,6 +79,10 @@ export type Txguard = {
                   114,
                   121
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
@@ -68,27 +103,39 @@ export type Txguard = {
                   111,
                   103
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "priorityFeeStats",
+          "name": "archive",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  105,
-                  111,
+                  97,
                   114,
+                  99,
+                  104,
                   105,
-                  116,
-                  121
+                  118,
+                  101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "registry"
+              },
+              {
+                "kind": "account",
+                "path": "registry.session_id",
+                "account": "transactionRegistry"
               }
             ]
           }
@@ -98,42 +145,113 @@ export type Txguard = {
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "archiveBeforeSlot",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "recordFailure",
+      "name": "calibrateTiers",
+      "docs": [
+        "Respaces the tier lamport boundaries within [lamport_min, lamport_max] so each tier",
+        "would have seen an equal share of past usage, see PriorityFeeStats::calibrated_boundaries."
+      ],
       "discriminator": [
-        86,
-        94,
-        231,
-        2,
-        95,
-        43,
-        53,
-        161
+        142,
+        175,
+        174,
+        213,
+        164,
+        250,
+        87,
+        25
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "failureCatalog",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   99,
-                  97,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
                   116,
-                  97,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierLamportConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114,
+                  95,
                   108,
+                  97,
+                  109,
+                  112,
                   111,
-                  103
+                  114,
+                  116,
+                  115
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
@@ -141,32 +259,34 @@ export type Txguard = {
       ],
       "args": [
         {
-          "name": "failureType",
-          "type": "u8"
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "lamportMin",
+          "type": "u64"
+        },
+        {
+          "name": "lamportMax",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "registerTxOutcome",
+      "name": "checkHealth",
       "discriminator": [
-        230,
-        86,
-        70,
-        24,
-        111,
-        33,
+        71,
+        59,
+        207,
+        58,
         136,
-        196
+        156,
+        153,
+        7
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "registry",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -181,102 +301,212 @@ export type Txguard = {
                   114,
                   121
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
         },
         {
-          "name": "failureCatalog",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   99,
-                  97,
-                  116,
-                  97,
-                  108,
                   111,
+                  110,
+                  102,
+                  105,
                   103
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "healthStatus"
+        }
+      }
+    },
+    {
+      "name": "claimRewards",
+      "discriminator": [
+        4,
+        144,
+        132,
+        71,
+        116,
+        23,
+        151,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "reporter",
+          "signer": true
         },
         {
-          "name": "priorityFeeStats",
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporterRecord",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  114,
+                  101,
                   112,
+                  111,
                   114,
-                  105,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "reporter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardMint",
+          "writable": true
+        },
+        {
+          "name": "reporterTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
                   111,
                   114,
                   105,
                   116,
                   121
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
               }
             ]
           }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": [
         {
-          "name": "success",
-          "type": "bool"
-        },
-        {
-          "name": "failureType",
-          "type": "u8"
-        },
-        {
-          "name": "priorityFeeTier",
-          "type": "u8"
+          "name": "namespace",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "updatePriorityFee",
+      "name": "closeReporterRecord",
       "discriminator": [
-        29,
-        149,
-        238,
-        80,
-        148,
-        208,
-        73,
-        66
+        195,
+        234,
+        57,
+        84,
+        99,
+        187,
+        52,
+        14
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "reporter",
           "writable": true,
           "signer": true
         },
         {
-          "name": "priorityFeeStats",
+          "name": "reporterRecord",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
                   114,
-                  105,
+                  101,
+                  112,
                   111,
                   114,
-                  105,
                   116,
-                  121
+                  101,
+                  114
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "reporter"
               }
             ]
           }
@@ -284,32 +514,8317 @@ export type Txguard = {
       ],
       "args": [
         {
-          "name": "tier",
-          "type": "u8"
+          "name": "namespace",
+          "type": "string"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "failureCatalog",
-      "discriminator": [
-        103,
-        75,
-        222,
-        15,
-        18,
-        86,
-        199,
-        97
-      ]
     },
     {
-      "name": "priorityFeeStats",
+      "name": "compareToSnapshot",
+      "docs": [
+        "Before/after result for a protocol upgrade: failures per core type since `snapshot`,",
+        "and which types now fail more or less often per report than they did up to it."
+      ],
       "discriminator": [
         52,
-        190,
-        74,
+        49,
+        177,
+        44,
+        145,
+        146,
+        135,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "snapshot",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  110,
+                  97,
+                  112,
+                  115,
+                  104,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "snapshot.epoch",
+                "account": "epochSnapshot"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "catalogDiff"
+        }
+      }
+    },
+    {
+      "name": "computeDominantFailure",
+      "discriminator": [
+        32,
+        149,
+        155,
+        66,
+        241,
+        179,
+        224,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minTxCount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u8"
+    },
+    {
+      "name": "computeFeeRoi",
+      "docs": [
+        "What each step up a tier buys: the success rate gained over the tier below and the",
+        "extra lamports it costs under the configured tier mapping."
+      ],
+      "discriminator": [
+        146,
+        26,
+        142,
+        234,
+        115,
+        221,
+        196,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierLamportConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114,
+                  95,
+                  108,
+                  97,
+                  109,
+                  112,
+                  111,
+                  114,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": {
+            "name": "feeRoi"
+          }
+        }
+      }
+    },
+    {
+      "name": "computeSuccessRate",
+      "discriminator": [
+        27,
+        75,
+        210,
+        152,
+        249,
+        83,
+        104,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minTxCount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "successRate"
+        }
+      }
+    },
+    {
+      "name": "computeWastedFees",
+      "discriminator": [
+        21,
+        189,
+        177,
+        15,
+        124,
+        152,
+        80,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "tierLamports",
+          "type": {
+            "array": [
+              "u64",
+              5
+            ]
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crankAggregate",
+      "docs": [
+        "Permissionless maintenance, callable once per `crank_interval_slots`. Pays the",
+        "caller `crank_reward_lamports` from the treasury; fails without side effects if",
+        "called too early or if the treasury can't cover the bounty."
+      ],
+      "discriminator": [
+        0,
+        118,
+        142,
+        250,
+        105,
+        31,
+        28,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "cranker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dailyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  105,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "detectAnomaly",
+      "docs": [
+        "Flags the failure type that has grown furthest out of line with the baseline",
+        "snapshot's failure mix, see FailureCatalog::detect_anomaly."
+      ],
+      "discriminator": [
+        201,
+        147,
+        231,
+        94,
+        15,
+        167,
+        139,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baselineSnapshot",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  110,
+                  97,
+                  112,
+                  115,
+                  104,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "baseline_snapshot.epoch",
+                "account": "epochSnapshot"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "zThresholdBps",
+          "type": "u16"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "anomalyReport"
+        }
+      }
+    },
+    {
+      "name": "detectSandwichPatterns",
+      "docs": [
+        "Success, MEV failure, success runs in the last-100 window. `sandwich_pattern_count`",
+        "on the registry keeps the all-time total."
+      ],
+      "discriminator": [
+        131,
+        75,
+        226,
+        3,
+        123,
+        92,
+        36,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": "u32"
+    },
+    {
+      "name": "exportState",
+      "docs": [
+        "One page of the registry, catalog or priority fee stats encoding; the accounts outgrew",
+        "return data long ago, so callers page through them. See EXPORT_SCHEMA_VERSION."
+      ],
+      "discriminator": [
+        124,
+        158,
+        168,
+        224,
+        58,
+        18,
+        64,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "section",
+          "type": "u8"
+        },
+        {
+          "name": "offset",
+          "type": "u32"
+        }
+      ],
+      "returns": "bytes"
+    },
+    {
+      "name": "flagReport",
+      "discriminator": [
+        75,
+        7,
+        158,
+        134,
+        88,
+        57,
+        54,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporterRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "arg",
+                "path": "reporter"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reporter",
+          "type": "pubkey"
+        },
+        {
+          "name": "windowIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "formatFailurePie",
+      "discriminator": [
+        200,
+        182,
+        194,
+        212,
+        203,
+        49,
+        177,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": "string"
+    },
+    {
+      "name": "getBestDay",
+      "docs": [
+        "Weekday (Monday = 0, UTC) with the best all-time success rate, for scheduling work",
+        "that can wait for a historically reliable day."
+      ],
+      "discriminator": [
+        103,
+        84,
+        235,
+        251,
+        9,
+        228,
+        152,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "bestDay"
+        }
+      }
+    },
+    {
+      "name": "getBufferUtilization",
+      "docs": [
+        "How much of the last-100 window is filled, so callers can tell how much of it is",
+        "still pending before trusting windowed statistics."
+      ],
+      "discriminator": [
+        98,
+        201,
+        254,
+        145,
+        230,
+        87,
+        218,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "bufferUtilization"
+        }
+      }
+    },
+    {
+      "name": "getInstructionStats",
+      "docs": [
+        "Success and failure totals for one protocol instruction type, as declared through",
+        "`TxOutcomeParams::instruction_type`. Types never reported return zeros."
+      ],
+      "discriminator": [
+        16,
+        235,
+        209,
+        51,
+        168,
+        14,
+        207,
+        165
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "instructionType",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "instructionTypeTotals"
+        }
+      }
+    },
+    {
+      "name": "getPeakHourOfWeek",
+      "discriminator": [
+        4,
+        113,
+        139,
+        82,
+        99,
+        250,
+        184,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "peakHour"
+        }
+      }
+    },
+    {
+      "name": "getRecentRate",
+      "discriminator": [
+        64,
+        179,
+        47,
+        203,
+        156,
+        104,
+        81,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxAgeSlots",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "recentRate"
+        }
+      }
+    },
+    {
+      "name": "getReporterStats",
+      "discriminator": [
+        245,
+        92,
+        88,
+        15,
+        35,
+        211,
+        110,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporterRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "arg",
+                "path": "reporter"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reporter",
+          "type": "pubkey"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "reporterStats"
+        }
+      }
+    },
+    {
+      "name": "getStats",
+      "discriminator": [
+        241,
+        65,
+        112,
+        185,
+        230,
+        140,
+        139,
+        177
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hourlyStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  117,
+                  114,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "computeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "txSizeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  120,
+                  95,
+                  115,
+                  105,
+                  122,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  116,
+                  114,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "blockhashAgeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  108,
+                  111,
+                  99,
+                  107,
+                  104,
+                  97,
+                  115,
+                  104,
+                  95,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "routeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "statsReport"
+        }
+      }
+    },
+    {
+      "name": "getSummary",
+      "discriminator": [
+        159,
+        2,
+        226,
+        186,
+        90,
+        59,
+        255,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "registrySummary"
+        }
+      }
+    },
+    {
+      "name": "getTagStats",
+      "docs": [
+        "Success rate in bps for each `TxOutcomeParams::tags` bit, indexed by bit; bits",
+        "never reported come back as 0. What each bit means is up to the deployer."
+      ],
+      "discriminator": [
+        62,
+        245,
+        249,
+        78,
+        85,
+        189,
+        35,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "tagStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "array": [
+          "u16",
+          16
+        ]
+      }
+    },
+    {
+      "name": "getTierSparkline",
+      "docs": [
+        "Per-bucket report counts for `tier` over the last-100 window, oldest bucket first, for",
+        "drawing a sparkline. See TransactionRegistry::tier_sparkline for how entries are bucketed."
+      ],
+      "discriminator": [
+        53,
+        222,
+        93,
+        0,
+        27,
+        217,
+        79,
+        112
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "tier",
+          "type": "u8"
+        },
+        {
+          "name": "buckets",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "vec": "u16"
+      }
+    },
+    {
+      "name": "getTierStats",
+      "discriminator": [
+        25,
+        203,
+        6,
+        20,
+        215,
+        173,
+        221,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": {
+            "name": "tierInfo"
+          }
+        }
+      }
+    },
+    {
+      "name": "getTierTrend",
+      "docs": [
+        "Daily counts for `tier` over the last `days` days, oldest first and ending today.",
+        "Pass the TierDailySnapshot PDAs as remaining accounts; days without one read as 0."
+      ],
+      "discriminator": [
+        7,
+        139,
+        89,
+        188,
+        165,
+        14,
+        58,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "tier",
+          "type": "u8"
+        },
+        {
+          "name": "days",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "vec": "u64"
+      }
+    },
+    {
+      "name": "initialize",
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hourlyStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  117,
+                  114,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dailyStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  105,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seasonalityStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "computeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "latencyStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  116,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "txSizeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  120,
+                  95,
+                  115,
+                  105,
+                  122,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  116,
+                  114,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "blockhashAgeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  108,
+                  111,
+                  99,
+                  107,
+                  104,
+                  97,
+                  115,
+                  104,
+                  95,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "routeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tagStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "mergeIntoParent",
+      "docs": [
+        "Folds a child namespace's failure catalog into a parent's, e.g. one TxGuard",
+        "namespace per pool rolled up into a protocol-wide summary. Both namespaces must",
+        "share the signing authority, which may be a PDA of the calling program."
+      ],
+      "discriminator": [
+        175,
+        249,
+        37,
+        86,
+        70,
+        80,
+        233,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "childConfig",
+            "parentConfig"
+          ]
+        },
+        {
+          "name": "childConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "childNamespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "parentNamespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "childCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "childNamespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "parentNamespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "childNamespace",
+          "type": "string"
+        },
+        {
+          "name": "parentNamespace",
+          "type": "string"
+        },
+        {
+          "name": "zeroChild",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "mergeRegistries",
+      "discriminator": [
+        197,
+        200,
+        33,
+        60,
+        7,
+        116,
+        218,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "sourceConfig",
+            "destinationConfig"
+          ]
+        },
+        {
+          "name": "sourceConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sourceNamespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destinationConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "destinationNamespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "source",
+          "writable": true
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "sourceCatalog",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "sourceNamespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destinationCatalog",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "destinationNamespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentRecipient",
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sourceNamespace",
+          "type": "string"
+        },
+        {
+          "name": "destinationNamespace",
+          "type": "string"
+        },
+        {
+          "name": "sourceUser",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "destinationUser",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "closeSource",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "migrateRegistry",
+      "docs": [
+        "Bring a namespace's accounts written by an earlier version of the program up to",
+        "the current layout, topping up their rent from `payer`. Pass any of the",
+        "namespace's stats PDAs (hourly, daily, ...) as writable remaining accounts to",
+        "migrate them too. Deployments from before GuardConfig get one, with `payer` as",
+        "authority as `initialize` would; only the program's upgrade authority may claim",
+        "one that way, passing `program_data`. Safe to repeat; accounts more than 10 KiB",
+        "short need one call per 10 KiB."
+      ],
+      "discriminator": [
+        216,
+        135,
+        36,
+        181,
+        124,
+        116,
+        222,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programData",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  222,
+                  61,
+                  221,
+                  224,
+                  68,
+                  213,
+                  179,
+                  108,
+                  224,
+                  229,
+                  90,
+                  45,
+                  65,
+                  119,
+                  206,
+                  72,
+                  24,
+                  5,
+                  154,
+                  90,
+                  86,
+                  209,
+                  88,
+                  90,
+                  69,
+                  104,
+                  249,
+                  117,
+                  23,
+                  181,
+                  83,
+                  182
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                2,
+                168,
+                246,
+                145,
+                78,
+                136,
+                161,
+                176,
+                226,
+                16,
+                21,
+                62,
+                247,
+                99,
+                174,
+                43,
+                0,
+                194,
+                185,
+                61,
+                22,
+                193,
+                36,
+                210,
+                192,
+                83,
+                122,
+                16,
+                4,
+                128,
+                0,
+                0
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "openDispute",
+      "discriminator": [
+        137,
+        25,
+        99,
+        119,
+        23,
+        223,
+        161,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "disputer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "windowIndex",
+          "type": "u8"
+        },
+        {
+          "name": "claimedSuccess",
+          "type": "bool"
+        },
+        {
+          "name": "bondLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "projectNextFailure",
+      "docs": [
+        "Baseline guess at the next failure type: the one with the largest share of past",
+        "failures. `recent_context` (RECENT_CONTEXT_*) is validated but doesn't bias the",
+        "guess yet, since the catalog keeps no per-context counts."
+      ],
+      "discriminator": [
+        150,
+        25,
+        33,
+        62,
+        155,
+        17,
+        38,
+        2
+      ],
+      "accounts": [
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "recentContext",
+          "type": "u8"
+        }
+      ],
+      "returns": "u8"
+    },
+    {
+      "name": "recommendFeeLamports",
+      "discriminator": [
+        179,
+        67,
+        103,
+        138,
+        172,
+        251,
+        239,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierLamportConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114,
+                  95,
+                  108,
+                  97,
+                  109,
+                  112,
+                  111,
+                  114,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "targetSuccessRateBps",
+          "type": "u16"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "recommendTierForDeadline",
+      "docs": [
+        "Cheapest tier whose average confirmation latency fits within `deadline_slots`, or",
+        "the fastest tier on average when none does, with the share of that tier's reports",
+        "confirmed within the deadline. Only reports that declare submitted_slot and",
+        "confirmed_slot and pass the latency account count."
+      ],
+      "discriminator": [
+        158,
+        41,
+        235,
+        98,
+        151,
+        165,
+        21,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "priorityFeeStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "latencyStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  116,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "deadlineSlots",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "deadlineRecommendation"
+        }
+      }
+    },
+    {
+      "name": "recordFailure",
+      "discriminator": [
+        86,
+        94,
+        231,
+        2,
+        95,
+        43,
+        53,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "failureType",
+          "type": {
+            "defined": {
+              "name": "failureType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordInlineSuccess",
+      "docs": [
+        "Records a success from inside the transaction it describes. Append this after the",
+        "instruction(s) being tracked: if any of them fails the whole transaction reverts,",
+        "so only successes can be captured this way. Failures still need to be reported",
+        "off-chain through `register_tx_outcome_v2`."
+      ],
+      "discriminator": [
+        200,
+        252,
+        57,
+        123,
+        121,
+        25,
+        191,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "base",
+          "accounts": [
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "registry",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      103,
+                      105,
+                      115,
+                      116,
+                      114,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "failureCatalog",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      99,
+                      97,
+                      116,
+                      97,
+                      108,
+                      111,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "priorityFeeStats",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      105,
+                      111,
+                      114,
+                      105,
+                      116,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "config",
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "registryConfig",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      103,
+                      105,
+                      115,
+                      116,
+                      114,
+                      121,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "registry"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "epochRegistry",
+              "docs": [
+                "`load_epoch_registry`, which also creates it on first use."
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "reporterRecord",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      112,
+                      111,
+                      114,
+                      116,
+                      101,
+                      114
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "payer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "treasury",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      114,
+                      101,
+                      97,
+                      115,
+                      117,
+                      114,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "hourlyStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      104,
+                      111,
+                      117,
+                      114,
+                      108,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "dailyStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      100,
+                      97,
+                      105,
+                      108,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "seasonalityStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      115,
+                      101,
+                      97,
+                      115,
+                      111,
+                      110,
+                      97,
+                      108,
+                      105,
+                      116,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "tierDaily",
+              "docs": [
+                "are verified in `record_tier_daily`, which also creates it on first use."
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "computeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      99,
+                      111,
+                      109,
+                      112,
+                      117,
+                      116,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "latencyStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      108,
+                      97,
+                      116,
+                      101,
+                      110,
+                      99,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "txSizeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      120,
+                      95,
+                      115,
+                      105,
+                      122,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "instructionStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      115,
+                      116,
+                      114,
+                      117,
+                      99,
+                      116,
+                      105,
+                      111,
+                      110,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "blockhashAgeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      98,
+                      108,
+                      111,
+                      99,
+                      107,
+                      104,
+                      97,
+                      115,
+                      104,
+                      95,
+                      97,
+                      103,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "routeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      111,
+                      117,
+                      116,
+                      101,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "tagStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      97,
+                      103,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "programStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "base.config.namespace",
+                "account": "registerTxOutcome"
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "priorityFeeTier",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "registerTxOutcome",
+      "docs": [
+        "Deprecated: prefer `register_tx_outcome_v2`, which takes a `TxOutcomeParams` struct",
+        "and can grow new fields without breaking callers."
+      ],
+      "discriminator": [
+        230,
+        86,
+        70,
+        24,
+        111,
+        33,
+        136,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registryConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "registry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "epochRegistry",
+          "docs": [
+            "`load_epoch_registry`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "reporterRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hourlyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  117,
+                  114,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dailyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  105,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seasonalityStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierDaily",
+          "docs": [
+            "are verified in `record_tier_daily`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "computeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "latencyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  116,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "txSizeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  120,
+                  95,
+                  115,
+                  105,
+                  122,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  116,
+                  114,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "blockhashAgeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  108,
+                  111,
+                  99,
+                  107,
+                  104,
+                  97,
+                  115,
+                  104,
+                  95,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "routeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tagStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "outcome",
+          "type": {
+            "defined": {
+              "name": "outcome"
+            }
+          }
+        },
+        {
+          "name": "failureType",
+          "type": {
+            "defined": {
+              "name": "failureType"
+            }
+          }
+        },
+        {
+          "name": "priorityFeeTier",
+          "type": "u8"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "registerTxOutcomeDual",
+      "discriminator": [
+        25,
+        1,
+        91,
+        52,
+        156,
+        117,
+        9,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "base",
+          "accounts": [
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "registry",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      103,
+                      105,
+                      115,
+                      116,
+                      114,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "failureCatalog",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      99,
+                      97,
+                      116,
+                      97,
+                      108,
+                      111,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "priorityFeeStats",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      105,
+                      111,
+                      114,
+                      105,
+                      116,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "config",
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "registryConfig",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      103,
+                      105,
+                      115,
+                      116,
+                      114,
+                      121,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "registry"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "epochRegistry",
+              "docs": [
+                "`load_epoch_registry`, which also creates it on first use."
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "reporterRecord",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      112,
+                      111,
+                      114,
+                      116,
+                      101,
+                      114
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "payer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "treasury",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      114,
+                      101,
+                      97,
+                      115,
+                      117,
+                      114,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "hourlyStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      104,
+                      111,
+                      117,
+                      114,
+                      108,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "dailyStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      100,
+                      97,
+                      105,
+                      108,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "seasonalityStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      115,
+                      101,
+                      97,
+                      115,
+                      111,
+                      110,
+                      97,
+                      108,
+                      105,
+                      116,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "tierDaily",
+              "docs": [
+                "are verified in `record_tier_daily`, which also creates it on first use."
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "computeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      99,
+                      111,
+                      109,
+                      112,
+                      117,
+                      116,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "latencyStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      108,
+                      97,
+                      116,
+                      101,
+                      110,
+                      99,
+                      121
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "txSizeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      120,
+                      95,
+                      115,
+                      105,
+                      122,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "instructionStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      115,
+                      116,
+                      114,
+                      117,
+                      99,
+                      116,
+                      105,
+                      111,
+                      110,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "blockhashAgeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      98,
+                      108,
+                      111,
+                      99,
+                      107,
+                      104,
+                      97,
+                      115,
+                      104,
+                      95,
+                      97,
+                      103,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "routeStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      111,
+                      117,
+                      116,
+                      101,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "tagStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      97,
+                      103,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "arg",
+                    "path": "namespace"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        },
+        {
+          "name": "userRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "base.config.namespace",
+                "account": "registerTxOutcome"
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "txOutcomeParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerTxOutcomeV2",
+      "docs": [
+        "Returns the new `tx_count`, which callers can keep as a sequential reference ID",
+        "for the report (read it via return data, including after a CPI)."
+      ],
+      "discriminator": [
+        11,
+        66,
+        13,
+        22,
+        0,
+        162,
+        255,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registryConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "registry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "epochRegistry",
+          "docs": [
+            "`load_epoch_registry`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "reporterRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hourlyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  117,
+                  114,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dailyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  105,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seasonalityStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierDaily",
+          "docs": [
+            "are verified in `record_tier_daily`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "computeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "latencyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  116,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "txSizeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  120,
+                  95,
+                  115,
+                  105,
+                  122,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  116,
+                  114,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "blockhashAgeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  108,
+                  111,
+                  99,
+                  107,
+                  104,
+                  97,
+                  115,
+                  104,
+                  95,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "routeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tagStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "txOutcomeParams"
+            }
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "registerTxOutcomeWithMetadata",
+      "docs": [
+        "`register_tx_outcome_v2` plus a `TxMetadata` (defined in txguard-types) describing",
+        "the transaction itself. Metadata fields left at their sentinel are ignored; a",
+        "signature hash or tx_slot given in the metadata takes precedence over `params`."
+      ],
+      "discriminator": [
+        171,
+        143,
+        251,
+        97,
+        230,
+        223,
+        4,
+        152
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registryConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "registry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "epochRegistry",
+          "docs": [
+            "`load_epoch_registry`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "reporterRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hourlyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  117,
+                  114,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dailyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  105,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seasonalityStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierDaily",
+          "docs": [
+            "are verified in `record_tier_daily`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "computeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "latencyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  116,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "txSizeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  120,
+                  95,
+                  115,
+                  105,
+                  122,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  116,
+                  114,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "blockhashAgeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  108,
+                  111,
+                  99,
+                  107,
+                  104,
+                  97,
+                  115,
+                  104,
+                  95,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "routeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tagStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "txOutcomeParams"
+            }
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": {
+              "name": "txMetadata"
+            }
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "registerTxOutcomesBatch",
+      "docs": [
+        "Several `register_tx_outcome_v2` reports in one instruction, recorded in order.",
+        "The reporter's cooldown and the signature dedup are checked once for the whole",
+        "batch, before anything is recorded. Returns the `tx_count` after the last report."
+      ],
+      "discriminator": [
+        192,
+        220,
+        16,
+        155,
+        21,
+        223,
+        8,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registryConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "registry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "epochRegistry",
+          "docs": [
+            "`load_epoch_registry`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "reporterRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hourlyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  117,
+                  114,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dailyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  105,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seasonalityStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierDaily",
+          "docs": [
+            "are verified in `record_tier_daily`, which also creates it on first use."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "computeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "latencyStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  116,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "txSizeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  120,
+                  95,
+                  115,
+                  105,
+                  122,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  116,
+                  114,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "blockhashAgeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  108,
+                  111,
+                  99,
+                  107,
+                  104,
+                  97,
+                  115,
+                  104,
+                  95,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "routeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tagStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reports",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "txOutcomeParams"
+              }
+            }
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "resetFailureTypes",
+      "docs": [
+        "Zeroes only the listed failure types (counters, occurrence timestamps and any",
+        "per-type detail), e.g. MEV after shipping a MEV-protection fix."
+      ],
+      "discriminator": [
+        8,
+        87,
+        65,
+        242,
+        234,
+        48,
+        88,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failureCatalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "typesToReset",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "resetSeasonality",
+      "discriminator": [
+        171,
+        73,
+        238,
+        98,
+        229,
+        79,
+        245,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seasonalityStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "discriminator": [
+        231,
+        6,
+        202,
+        6,
+        96,
+        103,
+        12,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "disputer",
+          "writable": true,
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "windowIndex",
+          "type": "u8"
+        },
+        {
+          "name": "accept",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setBackfillReporter",
+      "discriminator": [
+        34,
+        25,
+        28,
+        209,
+        101,
+        59,
+        7,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporterRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "arg",
+                "path": "reporter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reporter",
+          "type": "pubkey"
+        },
+        {
+          "name": "backfillReporter",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setBlockhashValidityWindow",
+      "discriminator": [
+        3,
+        5,
+        194,
+        153,
+        58,
+        10,
+        32,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "blockhashValidityWindow",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setBulkReporter",
+      "discriminator": [
+        109,
+        216,
+        153,
+        234,
+        53,
+        77,
+        11,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reporterRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  111,
+                  114,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "arg",
+                "path": "reporter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reporter",
+          "type": "pubkey"
+        },
+        {
+          "name": "bulkReporter",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setCrankConfig",
+      "discriminator": [
+        40,
+        32,
+        168,
+        165,
+        48,
+        100,
+        102,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "crankIntervalSlots",
+          "type": "u64"
+        },
+        {
+          "name": "crankRewardLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setEpochMode",
+      "discriminator": [
+        8,
+        47,
+        39,
+        228,
+        234,
+        36,
+        185,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "epochScoped",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setFreshnessBound",
+      "discriminator": [
+        35,
+        238,
+        191,
+        74,
+        197,
+        204,
+        210,
+        93
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxReportStalenessSlots",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setHealthThreshold",
+      "discriminator": [
+        213,
+        102,
+        175,
+        30,
+        115,
+        252,
+        204,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minSuccessRateBps",
+          "type": "u16"
+        },
+        {
+          "name": "useLowerBound",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setMaxReportAge",
+      "discriminator": [
+        9,
+        245,
+        216,
+        222,
+        120,
+        26,
+        90,
+        114
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxReportAgeSlots",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPointsMultiplier",
+      "discriminator": [
+        64,
+        216,
+        228,
+        155,
+        253,
+        77,
+        7,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "pointsMultiplier",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setPrecompileUsage",
+      "docs": [
+        "Declares whether this namespace's transactions use the ed25519 or secp256k1",
+        "verify precompiles, i.e. whether a zero signature_verify_failure count means anything."
+      ],
+      "discriminator": [
+        23,
+        147,
+        64,
+        76,
+        169,
+        245,
+        147,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "usedEd25519Precompile",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setReportCooldown",
+      "discriminator": [
+        131,
+        157,
+        206,
+        59,
+        161,
+        150,
+        22,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "minSlotsBetweenReports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setReportFee",
+      "discriminator": [
+        141,
+        251,
+        243,
+        185,
+        77,
+        114,
+        166,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "reportFeeLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setReputationParams",
+      "discriminator": [
+        74,
+        172,
+        248,
+        104,
+        160,
+        161,
+        6,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "increment",
+          "type": "u16"
+        },
+        {
+          "name": "penalty",
+          "type": "u16"
+        },
+        {
+          "name": "halfLifeSlots",
+          "type": "u64"
+        },
+        {
+          "name": "minReputation",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setRewardMint",
+      "discriminator": [
+        163,
+        98,
+        152,
+        110,
+        111,
+        9,
+        56,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardMint"
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "mint",
+          "type": "pubkey"
+        },
+        {
+          "name": "claimIntervalSlots",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setRewardPoints",
+      "discriminator": [
+        219,
+        217,
+        81,
+        129,
+        77,
+        206,
+        146,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "successReportPoints",
+          "type": "u64"
+        },
+        {
+          "name": "failureReportPoints",
+          "type": "u64"
+        },
+        {
+          "name": "maxPointsPerSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setSlotQuota",
+      "discriminator": [
+        17,
+        34,
+        7,
+        254,
+        121,
+        217,
+        15,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxReportsPerSlot",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setTierLamports",
+      "discriminator": [
+        56,
+        134,
+        245,
+        95,
+        132,
+        68,
+        6,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tierLamportConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114,
+                  95,
+                  108,
+                  97,
+                  109,
+                  112,
+                  111,
+                  114,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "tierMinLamports",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTierNames",
+      "discriminator": [
+        219,
+        35,
+        238,
+        223,
+        216,
+        253,
+        48,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "names",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferUserRegistry",
+      "discriminator": [
+        13,
+        207,
+        152,
+        81,
+        167,
+        255,
+        92,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "newUserRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              },
+              {
+                "kind": "arg",
+                "path": "newOwner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "newOwner",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updatePriorityFee",
+      "discriminator": [
+        29,
+        149,
+        238,
+        80,
+        148,
+        208,
+        73,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "priorityFeeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateRegistryConfig",
+      "docs": [
+        "Per-registry settings, kept apart from the namespace-wide GuardConfig; see RegistryConfig"
+      ],
+      "discriminator": [
+        205,
+        108,
+        204,
+        178,
+        107,
+        143,
+        150,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registryConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "registry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "maxTxCount",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "bufferSize",
+          "type": "u16"
+        },
+        {
+          "name": "strictFailureTypes",
+          "type": "bool"
+        },
+        {
+          "name": "observer",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawTreasury",
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "writeCatalogSnapshotToAccount",
+      "docs": [
+        "Copy the catalog's counters into `dest` for a program later in the same transaction",
+        "to read. `dest` is an account the caller controls and signs for: a fresh one is",
+        "allocated and handed to TxGuard on the first write, which claims it for `writer`;",
+        "only they can overwrite it."
+      ],
+      "discriminator": [
+        88,
+        149,
+        254,
+        35,
+        196,
+        241,
+        132,
+        153
+      ],
+      "accounts": [
+        {
+          "name": "writer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "failureCatalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "namespace"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dest",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "namespace",
+          "type": "string"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "archiveRegistry",
+      "discriminator": [
+        34,
+        83,
+        42,
+        22,
+        181,
+        161,
+        190,
+        101
+      ]
+    },
+    {
+      "name": "blockhashAgeStats",
+      "discriminator": [
+        46,
+        98,
+        143,
+        25,
+        89,
+        20,
+        72,
+        106
+      ]
+    },
+    {
+      "name": "computeStats",
+      "discriminator": [
+        203,
+        183,
+        243,
+        141,
+        95,
+        234,
+        10,
+        202
+      ]
+    },
+    {
+      "name": "dailyStats",
+      "discriminator": [
+        59,
+        80,
+        154,
+        206,
+        133,
+        223,
+        161,
+        59
+      ]
+    },
+    {
+      "name": "dispute",
+      "discriminator": [
+        36,
+        49,
+        241,
+        67,
+        40,
+        36,
+        241,
+        74
+      ]
+    },
+    {
+      "name": "epochSnapshot",
+      "discriminator": [
+        45,
+        169,
+        145,
+        50,
+        103,
+        29,
+        220,
+        244
+      ]
+    },
+    {
+      "name": "failureCatalog",
+      "discriminator": [
+        103,
+        75,
+        222,
+        15,
+        18,
+        86,
+        199,
+        97
+      ]
+    },
+    {
+      "name": "guardConfig",
+      "discriminator": [
+        95,
+        175,
+        118,
+        50,
+        193,
+        113,
+        37,
+        250
+      ]
+    },
+    {
+      "name": "hourlyStats",
+      "discriminator": [
+        5,
+        251,
+        145,
+        73,
+        186,
+        88,
+        182,
+        186
+      ]
+    },
+    {
+      "name": "instructionStats",
+      "discriminator": [
+        37,
+        87,
+        7,
+        220,
+        224,
+        38,
+        95,
+        100
+      ]
+    },
+    {
+      "name": "latencyStats",
+      "discriminator": [
+        175,
+        33,
+        96,
+        227,
+        202,
+        43,
+        181,
+        119
+      ]
+    },
+    {
+      "name": "priorityFeeStats",
+      "discriminator": [
+        52,
+        190,
+        74,
         129,
         208,
         215,
@@ -318,105 +8833,3146 @@ export type Txguard = {
       ]
     },
     {
-      "name": "transactionRegistry",
-      "discriminator": [
-        190,
-        146,
-        237,
-        4,
-        19,
-        150,
-        13,
-        217
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidPriorityFeeTier",
-      "msg": "Invalid priority fee tier"
+      "name": "programStats",
+      "discriminator": [
+        60,
+        184,
+        22,
+        7,
+        19,
+        10,
+        106,
+        111
+      ]
+    },
+    {
+      "name": "registryConfig",
+      "discriminator": [
+        23,
+        118,
+        10,
+        246,
+        173,
+        231,
+        243,
+        156
+      ]
+    },
+    {
+      "name": "reporterRecord",
+      "discriminator": [
+        125,
+        48,
+        5,
+        46,
+        34,
+        82,
+        0,
+        173
+      ]
+    },
+    {
+      "name": "routeStats",
+      "discriminator": [
+        227,
+        155,
+        237,
+        55,
+        246,
+        69,
+        177,
+        99
+      ]
+    },
+    {
+      "name": "seasonalityStats",
+      "discriminator": [
+        113,
+        174,
+        116,
+        61,
+        244,
+        182,
+        238,
+        114
+      ]
+    },
+    {
+      "name": "tagStats",
+      "discriminator": [
+        158,
+        126,
+        144,
+        229,
+        132,
+        86,
+        203,
+        65
+      ]
+    },
+    {
+      "name": "tierLamportConfig",
+      "discriminator": [
+        238,
+        79,
+        160,
+        92,
+        213,
+        99,
+        94,
+        136
+      ]
+    },
+    {
+      "name": "transactionRegistry",
+      "discriminator": [
+        190,
+        146,
+        237,
+        4,
+        19,
+        150,
+        13,
+        217
+      ]
+    },
+    {
+      "name": "treasury",
+      "discriminator": [
+        238,
+        239,
+        123,
+        238,
+        89,
+        1,
+        168,
+        253
+      ]
+    },
+    {
+      "name": "txSizeStats",
+      "discriminator": [
+        41,
+        52,
+        89,
+        76,
+        201,
+        254,
+        64,
+        183
+      ]
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [
+        239,
+        222,
+        102,
+        235,
+        193,
+        85,
+        1,
+        214
+      ],
+      "name": "disputeOpened"
+    },
+    {
+      "discriminator": [
+        121,
+        64,
+        249,
+        153,
+        139,
+        128,
+        236,
+        187
+      ],
+      "name": "disputeResolved"
+    },
+    {
+      "discriminator": [
+        157,
+        241,
+        5,
+        166,
+        135,
+        117,
+        61,
+        15
+      ],
+      "name": "reportFlagged"
+    },
+    {
+      "discriminator": [
+        233,
+        208,
+        20,
+        206,
+        179,
+        94,
+        255,
+        197
+      ],
+      "name": "reporterRewarded"
+    },
+    {
+      "discriminator": [
+        75,
+        98,
+        88,
+        18,
+        219,
+        112,
+        88,
+        121
+      ],
+      "name": "rewardsClaimed"
+    },
+    {
+      "discriminator": [
+        216,
+        219,
+        114,
+        239,
+        109,
+        90,
+        109,
+        7
+      ],
+      "name": "selectiveReset"
+    },
+    {
+      "discriminator": [
+        74,
+        53,
+        21,
+        142,
+        123,
+        177,
+        10,
+        75
+      ],
+      "name": "snapshotMissed"
+    },
+    {
+      "discriminator": [
+        109,
+        156,
+        106,
+        136,
+        217,
+        66,
+        148,
+        20
+      ],
+      "name": "tiersCalibrated"
+    },
+    {
+      "discriminator": [
+        9,
+        248,
+        90,
+        163,
+        93,
+        167,
+        118,
+        94
+      ],
+      "name": "userRegistryTransferred"
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidPriorityFeeTier",
+      "msg": "Invalid priority fee tier"
+    },
+    {
+      "code": 6001,
+      "name": "countOverflow",
+      "msg": "Count overflow"
+    },
+    {
+      "code": 6002,
+      "name": "unauthorized",
+      "msg": "Signer is not the config authority"
+    },
+    {
+      "code": 6003,
+      "name": "missingEpochRegistry",
+      "msg": "Epoch mode requires the epoch registry account"
+    },
+    {
+      "code": 6004,
+      "name": "invalidEpochRegistry",
+      "msg": "Epoch registry does not match the current epoch PDA"
+    },
+    {
+      "code": 6005,
+      "name": "tooManyTierNames",
+      "msg": "At most MAX_TIERS tier names can be set"
+    },
+    {
+      "code": 6006,
+      "name": "invalidTierName",
+      "msg": "Tier name is not valid UTF-8"
+    },
+    {
+      "code": 6007,
+      "name": "namespaceTooLong",
+      "msg": "Namespace is longer than 32 bytes"
+    },
+    {
+      "code": 6008,
+      "name": "namespaceExists",
+      "msg": "Namespace has already been initialized"
+    },
+    {
+      "code": 6009,
+      "name": "invalidPartialFill",
+      "msg": "A partial fill must be reported with success = true"
+    },
+    {
+      "code": 6010,
+      "name": "exportTooLarge",
+      "msg": "Exported state does not fit in return data"
+    },
+    {
+      "code": 6011,
+      "name": "selfMerge",
+      "msg": "Cannot merge a registry into itself"
+    },
+    {
+      "code": 6012,
+      "name": "invalidRegistry",
+      "msg": "Registry does not match the given namespace and user"
+    },
+    {
+      "code": 6013,
+      "name": "invalidRentRecipient",
+      "msg": "Rent recipient must be the owner of the closed account"
+    },
+    {
+      "code": 6014,
+      "name": "invalidNewOwner",
+      "msg": "New owner must differ from the current owner"
+    },
+    {
+      "code": 6015,
+      "name": "destinationRegistryExists",
+      "msg": "Destination registry already exists; use merge_registries instead"
+    },
+    {
+      "code": 6016,
+      "name": "duplicateReport",
+      "msg": "This transaction signature was already reported"
+    },
+    {
+      "code": 6017,
+      "name": "invalidTierLamports",
+      "msg": "Tier lamports must list MAX_TIERS non-decreasing values"
+    },
+    {
+      "code": 6018,
+      "name": "invalidTargetProgram",
+      "msg": "Target program cannot be TxGuard itself"
+    },
+    {
+      "code": 6019,
+      "name": "missingTargetInstruction",
+      "msg": "No earlier instruction in this transaction targets the given program"
+    },
+    {
+      "code": 6020,
+      "name": "rateLimited",
+      "msg": "Reporter must wait min_slots_between_reports before reporting again"
+    },
+    {
+      "code": 6021,
+      "name": "missingTreasury",
+      "msg": "Namespace charges a report fee; pass the treasury account"
+    },
+    {
+      "code": 6022,
+      "name": "insufficientTreasury",
+      "msg": "Withdrawal would leave the treasury below its rent-exempt reserve"
+    },
+    {
+      "code": 6023,
+      "name": "reentrancyDetected",
+      "msg": "Registry is locked by an in-progress TxGuard instruction"
+    },
+    {
+      "code": 6024,
+      "name": "crankTooEarly",
+      "msg": "crank_interval_slots has not elapsed since the last crank"
+    },
+    {
+      "code": 6025,
+      "name": "slotQuotaExceeded",
+      "msg": "Namespace has reached max_reports_per_slot for this slot"
+    },
+    {
+      "code": 6026,
+      "name": "deadlineNotExceeded",
+      "msg": "Deadline failure reported before the blockhash validity window elapsed"
+    },
+    {
+      "code": 6027,
+      "name": "invalidValidityWindow",
+      "msg": "Blockhash validity window must be at least 1 slot"
+    },
+    {
+      "code": 6028,
+      "name": "invalidTierSnapshot",
+      "msg": "Tier daily snapshot does not match today's PDA"
+    },
+    {
+      "code": 6029,
+      "name": "invalidTrendWindow",
+      "msg": "Trend window must be between 1 and 7 days"
+    },
+    {
+      "code": 6030,
+      "name": "invalidTxSlot",
+      "msg": "Declared tx_slot is ahead of the current slot"
+    },
+    {
+      "code": 6031,
+      "name": "insufficientData",
+      "msg": "Registry has fewer reports than the requested min_tx_count"
+    },
+    {
+      "code": 6032,
+      "name": "staleReport",
+      "msg": "Declared tx_slot is older than max_report_age_slots"
+    },
+    {
+      "code": 6033,
+      "name": "invalidConfirmationSlots",
+      "msg": "confirmed_slot is before submitted_slot or more than 10,000 slots after it"
+    },
+    {
+      "code": 6034,
+      "name": "invalidTxSize",
+      "msg": "tx_size_bytes exceeds the 1232-byte transaction limit"
+    },
+    {
+      "code": 6035,
+      "name": "invalidLookupUsage",
+      "msg": "Lookup table counts are inconsistent with the transaction version"
+    },
+    {
+      "code": 6036,
+      "name": "bufferEmpty",
+      "msg": "No outcomes recorded in the window yet"
+    },
+    {
+      "code": 6037,
+      "name": "invalidFailureType",
+      "msg": "Unknown failure type code, or no failure types given"
+    },
+    {
+      "code": 6038,
+      "name": "invalidInstructionCount",
+      "msg": "A transaction has at least one instruction"
+    },
+    {
+      "code": 6039,
+      "name": "invalidSignerCount",
+      "msg": "num_signers must be between 1 and 12"
+    },
+    {
+      "code": 6040,
+      "name": "contradictoryReport",
+      "msg": "Landing status contradicts the reported success"
+    },
+    {
+      "code": 6041,
+      "name": "invalidLanding",
+      "msg": "landing must be 0 (never landed), 1 (landed and errored), 2 (landed and succeeded) or 255 (unknown)"
+    },
+    {
+      "code": 6042,
+      "name": "invalidRecentContext",
+      "msg": "recent_context must be 0 (normal), 1 (high congestion) or 2 (volatile market)"
+    },
+    {
+      "code": 6043,
+      "name": "insufficientPoints",
+      "msg": "Reporter does not have enough points"
+    },
+    {
+      "code": 6044,
+      "name": "rewardsDisabled",
+      "msg": "No reward mint is configured for this namespace"
+    },
+    {
+      "code": 6045,
+      "name": "invalidRewardMint",
+      "msg": "Reward mint must be an SPL Token mint whose authority is the reward_authority PDA"
+    },
+    {
+      "code": 6046,
+      "name": "invalidRewardAccount",
+      "msg": "Reward tokens go to the reporter's associated token account for the reward mint"
+    },
+    {
+      "code": 6047,
+      "name": "claimTooEarly",
+      "msg": "claim_interval_slots has not elapsed since the reporter's last claim"
+    },
+    {
+      "code": 6048,
+      "name": "invalidCpiDepth",
+      "msg": "cpi_depth cannot exceed the runtime's maximum of 4"
+    },
+    {
+      "code": 6049,
+      "name": "invalidReputation",
+      "msg": "Reputation values cannot exceed 10,000"
+    },
+    {
+      "code": 6050,
+      "name": "invalidWindowIndex",
+      "msg": "window_index must point at a filled entry of the outcome window"
+    },
+    {
+      "code": 6051,
+      "name": "reportAlreadyFlagged",
+      "msg": "This report has already been flagged"
+    },
+    {
+      "code": 6052,
+      "name": "disputeAlreadyOpen",
+      "msg": "A dispute is already open on this window entry"
+    },
+    {
+      "code": 6053,
+      "name": "invalidDispute",
+      "msg": "Only success or failure entries can be disputed, and only to the opposite outcome"
+    },
+    {
+      "code": 6054,
+      "name": "disputeEntryOverwritten",
+      "msg": "The disputed entry has been overwritten by later reports"
+    },
+    {
+      "code": 6055,
+      "name": "invalidSnapshotAccount",
+      "msg": "Snapshot destination must be fresh, or large enough and empty or last written by the writer"
+    },
+    {
+      "code": 6056,
+      "name": "invalidSparklineBuckets",
+      "msg": "Sparkline bucket count must be between 1 and 100"
+    },
+    {
+      "code": 6057,
+      "name": "invalidBufferSize",
+      "msg": "buffer_size must be between 1 and 100"
+    },
+    {
+      "code": 6058,
+      "name": "registryFull",
+      "msg": "The registry has reached its configured max_tx_count"
+    },
+    {
+      "code": 6059,
+      "name": "notObserver",
+      "msg": "Only the registry's observer or its authority may report"
+    },
+    {
+      "code": 6060,
+      "name": "invalidOutcome",
+      "msg": "Reported outcomes are failure, success or partial; pending only marks empty window entries"
+    },
+    {
+      "code": 6061,
+      "name": "invalidArchiveSlot",
+      "msg": "archive_before_slot must be after the last report and the last archive, and not in the future"
+    },
+    {
+      "code": 6062,
+      "name": "disputeEntryArchived",
+      "msg": "The disputed entry was archived; its outcome no longer counts towards the registry"
+    },
+    {
+      "code": 6063,
+      "name": "archiveExists",
+      "msg": "This session's archive account already holds an archive"
+    },
+    {
+      "code": 6064,
+      "name": "invalidSuccessRateThreshold",
+      "msg": "min_success_rate_bps cannot exceed 10,000"
+    },
+    {
+      "code": 6065,
+      "name": "invalidMigrationAccount",
+      "msg": "Accounts to migrate must be writable TxGuard stats PDAs"
+    },
+    {
+      "code": 6066,
+      "name": "emptyBatch",
+      "msg": "A batch must hold at least one report"
+    },
+    {
+      "code": 6067,
+      "name": "invalidExportPage",
+      "msg": "Unknown export section, or an offset past the end of its account"
+    }
+  ],
+  "types": [
+    {
+      "name": "anomalyReport",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "anomalyDetected",
+            "type": "bool"
+          },
+          {
+            "name": "anomalousType",
+            "type": "u8"
+          },
+          {
+            "name": "zScoreBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "archiveRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registry",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionId",
+            "type": "u64"
+          },
+          {
+            "name": "sinceSlot",
+            "type": "u64"
+          },
+          {
+            "name": "archiveBeforeSlot",
+            "type": "u64"
+          },
+          {
+            "name": "archivedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "txCount",
+            "type": "u64"
+          },
+          {
+            "name": "successCount",
+            "type": "u64"
+          },
+          {
+            "name": "failureCount",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "totalValueLamports",
+            "type": "u64"
+          },
+          {
+            "name": "landingCounts",
+            "type": {
+              "array": [
+                "u32",
+                3
+              ]
+            }
+          },
+          {
+            "name": "sandwichPatternCount",
+            "type": "u32"
+          },
+          {
+            "name": "dayOfWeekSuccess",
+            "type": {
+              "array": [
+                "u32",
+                7
+              ]
+            }
+          },
+          {
+            "name": "dayOfWeekFailure",
+            "type": {
+              "array": [
+                "u32",
+                7
+              ]
+            }
+          },
+          {
+            "name": "failuresByType",
+            "type": {
+              "array": [
+                "u32",
+                20
+              ]
+            }
+          },
+          {
+            "name": "partialFill",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bestDay",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dayOfWeek",
+            "type": "u8"
+          },
+          {
+            "name": "successRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "sampleCount",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "blockhashAgeStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successesByAge",
+            "type": {
+              "array": [
+                "u32",
+                5
+              ]
+            }
+          },
+          {
+            "name": "failuresByAge",
+            "type": {
+              "array": [
+                "u32",
+                5
+              ]
+            }
+          },
+          {
+            "name": "staleFailures",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bufferUtilization",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonPendingCount",
+            "type": "u8"
+          },
+          {
+            "name": "pendingCount",
+            "type": "u8"
+          },
+          {
+            "name": "bufferLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "catalogDiff",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deltaSlippage",
+            "type": "i64"
+          },
+          {
+            "name": "deltaLiquidity",
+            "type": "i64"
+          },
+          {
+            "name": "deltaMev",
+            "type": "i64"
+          },
+          {
+            "name": "deltaDropped",
+            "type": "i64"
+          },
+          {
+            "name": "deltaFunds",
+            "type": "i64"
+          },
+          {
+            "name": "deltaOther",
+            "type": "i64"
+          },
+          {
+            "name": "improvedTypes",
+            "type": "u8"
+          },
+          {
+            "name": "regressedTypes",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chainFailureCount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "count",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "computeStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successCompute",
+            "type": {
+              "defined": {
+                "name": "computeTally"
+              }
+            }
+          },
+          {
+            "name": "failureCompute",
+            "type": {
+              "defined": {
+                "name": "computeTally"
+              }
+            }
+          },
+          {
+            "name": "nearCuLimitCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "computeTally",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "type": "u64"
+          },
+          {
+            "name": "sum",
+            "type": "u64"
+          },
+          {
+            "name": "min",
+            "type": "u32"
+          },
+          {
+            "name": "max",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dailyStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "days",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "dayBucket"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "dayBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dayIndex",
+            "type": "u64"
+          },
+          {
+            "name": "txCount",
+            "type": "u32"
+          },
+          {
+            "name": "successCount",
+            "type": "u32"
+          },
+          {
+            "name": "failureCount",
+            "type": "u32"
+          },
+          {
+            "name": "failuresByType",
+            "type": {
+              "array": [
+                "u16",
+                20
+              ]
+            }
+          },
+          {
+            "name": "topFailureType",
+            "type": "u8"
+          },
+          {
+            "name": "tierSum",
+            "type": "u64"
+          },
+          {
+            "name": "avgTier",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "deadlineRecommendation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tier",
+            "type": "u8"
+          },
+          {
+            "name": "confidenceBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dispute",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "disputer",
+            "type": "pubkey"
+          },
+          {
+            "name": "windowIndex",
+            "type": "u8"
+          },
+          {
+            "name": "claimedSuccess",
+            "type": "bool"
+          },
+          {
+            "name": "bondLamports",
+            "type": "u64"
+          },
+          {
+            "name": "outcomeSlot",
+            "type": "u64"
+          },
+          {
+            "name": "overwriteTxCount",
+            "type": "u64"
+          },
+          {
+            "name": "openedSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeOpened",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "disputer",
+            "type": "pubkey"
+          },
+          {
+            "name": "windowIndex",
+            "type": "u8"
+          },
+          {
+            "name": "claimedSuccess",
+            "type": "bool"
+          },
+          {
+            "name": "bondLamports",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "disputeResolved",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "disputer",
+            "type": "pubkey"
+          },
+          {
+            "name": "windowIndex",
+            "type": "u8"
+          },
+          {
+            "name": "accepted",
+            "type": "bool"
+          },
+          {
+            "name": "bondLamports",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "epochSnapshot",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "txCount",
+            "type": "u64"
+          },
+          {
+            "name": "successCount",
+            "type": "u64"
+          },
+          {
+            "name": "failureCount",
+            "type": "u64"
+          },
+          {
+            "name": "failuresByType",
+            "type": {
+              "array": [
+                "u32",
+                20
+              ]
+            }
+          },
+          {
+            "name": "capturedSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "failureCatalog",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slippageExceeded",
+            "type": "u32"
+          },
+          {
+            "name": "insufficientLiquidity",
+            "type": "u32"
+          },
+          {
+            "name": "mevDetected",
+            "type": "u32"
+          },
+          {
+            "name": "droppedTx",
+            "type": "u32"
+          },
+          {
+            "name": "insufficientFunds",
+            "type": "u32"
+          },
+          {
+            "name": "other",
+            "type": "u32"
+          },
+          {
+            "name": "bridgeFailure",
+            "type": "u32"
+          },
+          {
+            "name": "bridgeChainId",
+            "type": "u16"
+          },
+          {
+            "name": "bridgeFailureByChain",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "chainFailureCount"
+                }
+              }
+            }
+          },
+          {
+            "name": "partialFill",
+            "type": "u32"
+          },
+          {
+            "name": "partialFillRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "firstOccurrence",
+            "type": {
+              "vec": "i64"
+            }
+          },
+          {
+            "name": "lastOccurrence",
+            "type": {
+              "vec": "i64"
+            }
+          },
+          {
+            "name": "deadlineExceeded",
+            "type": "u32"
+          },
+          {
+            "name": "lastFailureSlot",
+            "type": "u64"
+          },
+          {
+            "name": "failureGapHistogram",
+            "type": {
+              "array": [
+                "u32",
+                5
+              ]
+            }
+          },
+          {
+            "name": "childCount",
+            "type": "u8"
+          },
+          {
+            "name": "wrappedSolError",
+            "type": "u32"
+          },
+          {
+            "name": "wsolErrorCode",
+            "type": "u16"
+          },
+          {
+            "name": "tokenFrozen",
+            "type": "u32"
+          },
+          {
+            "name": "frozenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "staleOracleUpdate",
+            "type": "u32"
+          },
+          {
+            "name": "signatureVerifyFailure",
+            "type": "u32"
+          },
+          {
+            "name": "versionMismatch",
+            "type": "u32"
+          },
+          {
+            "name": "expectedVersion",
+            "type": "u8"
+          },
+          {
+            "name": "actualVersion",
+            "type": "u8"
+          },
+          {
+            "name": "blockhashNotFound",
+            "type": "u32"
+          },
+          {
+            "name": "cpiDepthExceeded",
+            "type": "u32"
+          },
+          {
+            "name": "maxObservedCpiDepth",
+            "type": "u8"
+          },
+          {
+            "name": "nonceAccountExpired",
+            "type": "u32"
+          },
+          {
+            "name": "lastNonceFailureSlot",
+            "type": "u64"
+          },
+          {
+            "name": "gasPriceSpike",
+            "type": "u32"
+          },
+          {
+            "name": "lastSpikeFeeTier",
+            "type": "u8"
+          },
+          {
+            "name": "accountDataTooSmall",
+            "type": "u32"
+          },
+          {
+            "name": "lastRequiredSpace",
+            "type": "u32"
+          },
+          {
+            "name": "lastAvailableSpace",
+            "type": "u32"
+          },
+          {
+            "name": "programNotExecutable",
+            "type": "u32"
+          },
+          {
+            "name": "wrongTokenProgram",
+            "type": "u32"
+          },
+          {
+            "name": "lastExpectedProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "lastActualProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "catalogResetCount",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "failureType",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "slippage"
+          },
+          {
+            "name": "liquidity"
+          },
+          {
+            "name": "mev"
+          },
+          {
+            "name": "dropped"
+          },
+          {
+            "name": "insufficientFunds"
+          },
+          {
+            "name": "other"
+          },
+          {
+            "name": "unassigned6"
+          },
+          {
+            "name": "unassigned7"
+          },
+          {
+            "name": "unassigned8"
+          },
+          {
+            "name": "unassigned9"
+          },
+          {
+            "name": "unassigned10"
+          },
+          {
+            "name": "unassigned11"
+          },
+          {
+            "name": "unassigned12"
+          },
+          {
+            "name": "bridge"
+          },
+          {
+            "name": "deadline"
+          },
+          {
+            "name": "wsol"
+          },
+          {
+            "name": "tokenFrozen"
+          },
+          {
+            "name": "staleOracleUpdate"
+          },
+          {
+            "name": "signatureVerifyFailure"
+          },
+          {
+            "name": "versionMismatch"
+          },
+          {
+            "name": "blockhashNotFound"
+          },
+          {
+            "name": "cpiDepthExceeded"
+          },
+          {
+            "name": "nonceAccountExpired"
+          },
+          {
+            "name": "gasPriceSpike"
+          },
+          {
+            "name": "accountDataTooSmall"
+          },
+          {
+            "name": "programNotExecutable"
+          },
+          {
+            "name": "wrongTokenProgram"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeRoi",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tier",
+            "type": "u8"
+          },
+          {
+            "name": "marginalSuccessGainBps",
+            "type": "i16"
+          },
+          {
+            "name": "marginalLamportCost",
+            "type": "u64"
+          },
+          {
+            "name": "gainBpsPerMillionLamports",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "guardConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "epochScoped",
+            "type": "bool"
+          },
+          {
+            "name": "minSlotsBetweenReports",
+            "type": "u64"
+          },
+          {
+            "name": "maxReportsPerSlot",
+            "type": "u32"
+          },
+          {
+            "name": "reportFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "crankIntervalSlots",
+            "type": "u64"
+          },
+          {
+            "name": "crankRewardLamports",
+            "type": "u64"
+          },
+          {
+            "name": "lastCrankSlot",
+            "type": "u64"
+          },
+          {
+            "name": "maxReportStalenessSlots",
+            "type": "u64"
+          },
+          {
+            "name": "maxReportAgeSlots",
+            "type": "u64"
+          },
+          {
+            "name": "successReportPoints",
+            "type": "u64"
+          },
+          {
+            "name": "failureReportPoints",
+            "type": "u64"
+          },
+          {
+            "name": "pointsMultiplier",
+            "type": "u16"
+          },
+          {
+            "name": "maxPointsPerSlot",
+            "type": "u64"
+          },
+          {
+            "name": "rewardMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "claimIntervalSlots",
+            "type": "u64"
+          },
+          {
+            "name": "reputationIncrement",
+            "type": "u16"
+          },
+          {
+            "name": "reputationPenalty",
+            "type": "u16"
+          },
+          {
+            "name": "reputationHalfLifeSlots",
+            "type": "u64"
+          },
+          {
+            "name": "minReputation",
+            "type": "u16"
+          },
+          {
+            "name": "minSuccessRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "healthUsesLowerBound",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "healthStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "txCount",
+            "type": "u64"
+          },
+          {
+            "name": "slotsSinceLastReport",
+            "type": "u64"
+          },
+          {
+            "name": "stale",
+            "type": "bool"
+          },
+          {
+            "name": "landedErrorRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "successRateLowerBoundBps",
+            "type": "u16"
+          },
+          {
+            "name": "belowMinSuccessRate",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "hourBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "hour",
+            "type": "u64"
+          },
+          {
+            "name": "successCount",
+            "type": "u32"
+          },
+          {
+            "name": "failureCount",
+            "type": "u32"
+          },
+          {
+            "name": "failuresByType",
+            "type": {
+              "array": [
+                "u16",
+                20
+              ]
+            }
+          },
+          {
+            "name": "dominantFailureType",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "hourlyBucketStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "hour",
+            "type": "u64"
+          },
+          {
+            "name": "successCount",
+            "type": "u32"
+          },
+          {
+            "name": "failureCount",
+            "type": "u32"
+          },
+          {
+            "name": "dominantFailureType",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "hourlyStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buckets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "hourBucket"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "instructionStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successesByCount",
+            "type": {
+              "array": [
+                "u32",
+                5
+              ]
+            }
+          },
+          {
+            "name": "failuresByCount",
+            "type": {
+              "array": [
+                "u32",
+                5
+              ]
+            }
+          },
+          {
+            "name": "maxInstructions",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "instructionTypeStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "instructionType",
+            "type": "u8"
+          },
+          {
+            "name": "success",
+            "type": "u32"
+          },
+          {
+            "name": "failure",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "instructionTypeTotals",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "success",
+            "type": "u32"
+          },
+          {
+            "name": "failure",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "latencyStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "histogram",
+            "type": {
+              "array": [
+                "u32",
+                6
+              ]
+            }
+          },
+          {
+            "name": "histogramByTier",
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u32",
+                    6
+                  ]
+                },
+                5
+              ]
+            }
+          },
+          {
+            "name": "latencySum",
+            "type": "u64"
+          },
+          {
+            "name": "latencyCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outcome",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "failure"
+          },
+          {
+            "name": "success"
+          },
+          {
+            "name": "pending"
+          },
+          {
+            "name": "partial"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outcomeBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successCount",
+            "type": "u32"
+          },
+          {
+            "name": "failureCount",
+            "type": "u32"
+          },
+          {
+            "name": "successRateBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "peakHour",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "hourOfWeek",
+            "type": "u8"
+          },
+          {
+            "name": "count",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priorityFeeStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tiers",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "tierNames",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  16
+                ]
+              }
+            }
+          },
+          {
+            "name": "tierSuccesses",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "directUpdateCounts",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "tierAvgLatencySlots",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "tierLatencyCounts",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "feeSampleCount",
+            "type": "u64"
+          },
+          {
+            "name": "feeMeanFp",
+            "type": "u128"
+          },
+          {
+            "name": "feeM2",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "pubkey"
+          },
+          {
+            "name": "successCount",
+            "type": "u64"
+          },
+          {
+            "name": "lastSuccessSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recentRate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "sampleCount",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registryConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "maxTxCount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "bufferSize",
+            "type": "u16"
+          },
+          {
+            "name": "strictFailureTypes",
+            "type": "bool"
+          },
+          {
+            "name": "observer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registrySummary",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "txCount",
+            "type": "u64"
+          },
+          {
+            "name": "successCount",
+            "type": "u64"
+          },
+          {
+            "name": "failureCount",
+            "type": "u64"
+          },
+          {
+            "name": "cursor",
+            "type": "u8"
+          },
+          {
+            "name": "successRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "dominantFailureType",
+            "type": "u8"
+          },
+          {
+            "name": "dominantFailureCount",
+            "type": "u32"
+          },
+          {
+            "name": "optimalTier",
+            "type": "u8"
+          },
+          {
+            "name": "totalTierActivity",
+            "type": "u64"
+          },
+          {
+            "name": "uniqueCallerCount",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reportFlagged",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "windowIndex",
+            "type": "u8"
+          },
+          {
+            "name": "reputation",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "reporterRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "successesReported",
+            "type": "u64"
+          },
+          {
+            "name": "failuresReported",
+            "type": "u64"
+          },
+          {
+            "name": "recentOutcomes",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "cursor",
+            "type": "u8"
+          },
+          {
+            "name": "lastReportSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bulkReporter",
+            "type": "bool"
+          },
+          {
+            "name": "backfillReporter",
+            "type": "bool"
+          },
+          {
+            "name": "points",
+            "type": "u64"
+          },
+          {
+            "name": "pointsSlot",
+            "type": "u64"
+          },
+          {
+            "name": "pointsThisSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lastClaimSlot",
+            "type": "u64"
+          },
+          {
+            "name": "reputation",
+            "type": "u16"
+          },
+          {
+            "name": "reputationSlot",
+            "type": "u64"
+          },
+          {
+            "name": "flaggedOutcomes",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reporterRewarded",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "pointsAwarded",
+            "type": "u64"
+          },
+          {
+            "name": "totalPoints",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "reporterStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "successesReported",
+            "type": "u64"
+          },
+          {
+            "name": "failuresReported",
+            "type": "u64"
+          },
+          {
+            "name": "successRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "recentOutcomes",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "cursor",
+            "type": "u8"
+          },
+          {
+            "name": "lastReportSlot",
+            "type": "u64"
+          },
+          {
+            "name": "points",
+            "type": "u64"
+          },
+          {
+            "name": "reputation",
+            "type": "u16"
+          },
+          {
+            "name": "trusted",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardsClaimed",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "reporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "routeStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successesByHops",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          },
+          {
+            "name": "failuresByHops",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          },
+          {
+            "name": "slippageFailuresByHops",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "seasonalityStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successesByHour",
+            "type": {
+              "array": [
+                "u32",
+                24
+              ]
+            }
+          },
+          {
+            "name": "failuresByHour",
+            "type": {
+              "array": [
+                "u32",
+                24
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "selectiveReset",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "typesReset",
+            "type": "bytes"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sendMethodStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successCount",
+            "type": "u32"
+          },
+          {
+            "name": "failureCount",
+            "type": "u32"
+          },
+          {
+            "name": "failuresByType",
+            "type": {
+              "array": [
+                "u16",
+                20
+              ]
+            }
+          },
+          {
+            "name": "dominantFailureType",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "snapshotMissed",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "txCount",
+            "type": "u64"
+          },
+          {
+            "name": "successCount",
+            "type": "u64"
+          },
+          {
+            "name": "failureCount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "statsReport",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "hours",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "hourlyBucketStats"
+                }
+              }
+            }
+          },
+          {
+            "name": "failureGapHistogram",
+            "type": {
+              "array": [
+                "u32",
+                5
+              ]
+            }
+          },
+          {
+            "name": "successCompute",
+            "type": {
+              "defined": {
+                "name": "computeTally"
+              }
+            }
+          },
+          {
+            "name": "failureCompute",
+            "type": {
+              "defined": {
+                "name": "computeTally"
+              }
+            }
+          },
+          {
+            "name": "nearCuLimitCount",
+            "type": "u64"
+          },
+          {
+            "name": "txSizeBuckets",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "outcomeBucket"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "instructionBuckets",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "outcomeBucket"
+                  }
+                },
+                5
+              ]
+            }
+          },
+          {
+            "name": "maxInstructions",
+            "type": "u8"
+          },
+          {
+            "name": "signerBuckets",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "outcomeBucket"
+                  }
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "blockhashAgeBuckets",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "outcomeBucket"
+                  }
+                },
+                5
+              ]
+            }
+          },
+          {
+            "name": "staleBlockhashFailures",
+            "type": "u32"
+          },
+          {
+            "name": "simulatedSuccesses",
+            "type": "u32"
+          },
+          {
+            "name": "simulatedFailures",
+            "type": "u32"
+          },
+          {
+            "name": "unsimulatedSuccesses",
+            "type": "u32"
+          },
+          {
+            "name": "unsimulatedFailures",
+            "type": "u32"
+          },
+          {
+            "name": "sendMethodBuckets",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "outcomeBucket"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "sendMethodDominantFailures",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "routeHopBuckets",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "outcomeBucket"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "routeSlippageFailures",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeSampleCount",
+            "type": "u64"
+          },
+          {
+            "name": "feeMeanLamports",
+            "type": "u64"
+          },
+          {
+            "name": "feeVariance",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "successRate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "lowerBoundBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tagStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "successesByTag",
+            "type": {
+              "array": [
+                "u64",
+                16
+              ]
+            }
+          },
+          {
+            "name": "failuresByTag",
+            "type": {
+              "array": [
+                "u64",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "tierInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tier",
+            "type": "u8"
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tierLamportConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tierMinLamports",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "tiersCalibrated",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
+          },
+          {
+            "name": "oldBoundaries",
+            "type": {
+              "array": [
+                "u64",
+                5
+              ]
+            }
+          },
+          {
+            "name": "newBoundaries",
+            "type": {
+              "array": [
+                "u64",
+                5
+              ]
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "transactionRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "txCount",
+            "type": "u64"
+          },
+          {
+            "name": "successCount",
+            "type": "u64"
+          },
+          {
+            "name": "failureCount",
+            "type": "u64"
+          },
+          {
+            "name": "last100Outcomes",
+            "type": "bytes"
+          },
+          {
+            "name": "cursor",
+            "type": "u8"
+          },
+          {
+            "name": "totalFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "totalValueLamports",
+            "type": "u64"
+          },
+          {
+            "name": "recentSigHashes",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "sigCursor",
+            "type": "u8"
+          },
+          {
+            "name": "blockhashValidityWindow",
+            "type": "u8"
+          },
+          {
+            "name": "currentSlot",
+            "type": "u64"
+          },
+          {
+            "name": "reportsThisSlot",
+            "type": "u32"
+          },
+          {
+            "name": "maxTxPerSlot",
+            "type": "u32"
+          },
+          {
+            "name": "locked",
+            "type": "bool"
+          },
+          {
+            "name": "outcomeSlots",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "hourlyBuckets",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "lastSeenEpoch",
+            "type": "u64"
+          },
+          {
+            "name": "lastReportSlot",
+            "type": "u64"
+          },
+          {
+            "name": "maxReportGapSlots",
+            "type": "u64"
+          },
+          {
+            "name": "reportGapHistogram",
+            "type": {
+              "array": [
+                "u32",
+                5
+              ]
+            }
+          },
+          {
+            "name": "reportDelays",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "sumReportDelaySlots",
+            "type": "u64"
+          },
+          {
+            "name": "maxReportDelaySlots",
+            "type": "u64"
+          },
+          {
+            "name": "versionSuccesses",
+            "type": {
+              "array": [
+                "u32",
+                3
+              ]
+            }
+          },
+          {
+            "name": "versionFailures",
+            "type": {
+              "array": [
+                "u32",
+                3
+              ]
+            }
+          },
+          {
+            "name": "lookupReports",
+            "type": {
+              "array": [
+                "u32",
+                2
+              ]
+            }
+          },
+          {
+            "name": "lookupFailures",
+            "type": {
+              "array": [
+                "u32",
+                2
+              ]
+            }
+          },
+          {
+            "name": "maxLookedUpAccounts",
+            "type": "u8"
+          },
+          {
+            "name": "signerSuccesses",
+            "type": {
+              "array": [
+                "u32",
+                3
+              ]
+            }
+          },
+          {
+            "name": "signerFailures",
+            "type": {
+              "array": [
+                "u32",
+                3
+              ]
+            }
+          },
+          {
+            "name": "instructionTypes",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "instructionTypeStats"
+                }
+              }
+            }
+          },
+          {
+            "name": "landingCounts",
+            "type": {
+              "array": [
+                "u32",
+                3
+              ]
+            }
+          },
+          {
+            "name": "usedEd25519Precompile",
+            "type": "bool"
+          },
+          {
+            "name": "simulationSuccesses",
+            "type": {
+              "array": [
+                "u32",
+                2
+              ]
+            }
+          },
+          {
+            "name": "simulationFailures",
+            "type": {
+              "array": [
+                "u32",
+                2
+              ]
+            }
+          },
+          {
+            "name": "sendMethods",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "sendMethodStats"
+                }
+              }
+            }
+          },
+          {
+            "name": "uniqueCallerCount",
+            "type": "u32"
+          },
+          {
+            "name": "seenCallers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "callerFilter",
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          },
+          {
+            "name": "outcomeTiers",
+            "type": "bytes"
+          },
+          {
+            "name": "outcomeFailureTypes",
+            "type": "bytes"
+          },
+          {
+            "name": "sandwichPatternCount",
+            "type": "u32"
+          },
+          {
+            "name": "dayOfWeekSuccess",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "dayOfWeekFailure",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "sessionId",
+            "type": "u64"
+          },
+          {
+            "name": "archivedBeforeSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "totalCrankRewards",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "countOverflow",
-      "msg": "Count overflow"
-    }
-  ],
-  "types": [
-    {
-      "name": "failureCatalog",
+      "name": "txMetadata",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "slippageExceeded",
-            "type": "u32"
+            "name": "txSigHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "insufficientLiquidity",
-            "type": "u32"
+            "name": "txSlot",
+            "type": "u64"
           },
           {
-            "name": "mevDetected",
-            "type": "u32"
+            "name": "priorityFeeMicroLamports",
+            "type": "u64"
           },
           {
-            "name": "droppedTx",
+            "name": "computeUnitsConsumed",
             "type": "u32"
           },
           {
-            "name": "insufficientFunds",
+            "name": "cuLimit",
             "type": "u32"
           },
           {
-            "name": "other",
-            "type": "u32"
+            "name": "txSizeBytes",
+            "type": "u16"
+          },
+          {
+            "name": "numRetries",
+            "type": "u8"
+          },
+          {
+            "name": "txVersion",
+            "type": "u8"
+          },
+          {
+            "name": "numLookupTables",
+            "type": "u8"
+          },
+          {
+            "name": "numLookedUpAccounts",
+            "type": "u8"
+          },
+          {
+            "name": "numInstructions",
+            "type": "u8"
+          },
+          {
+            "name": "numSigners",
+            "type": "u8"
+          },
+          {
+            "name": "blockhashAgeSlots",
+            "type": "u8"
+          },
+          {
+            "name": "wasSimulated",
+            "type": "bool"
+          },
+          {
+            "name": "sendMethod",
+            "type": "u8"
+          },
+          {
+            "name": "routeHops",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "priorityFeeStats",
+      "name": "txOutcomeParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tiers",
+            "name": "success",
+            "type": "bool"
+          },
+          {
+            "name": "failureType",
             "type": {
-              "vec": "u64"
+              "defined": {
+                "name": "failureType"
+              }
+            }
+          },
+          {
+            "name": "priorityFeeTier",
+            "type": "u8"
+          },
+          {
+            "name": "feeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "valueLamports",
+            "type": "u64"
+          },
+          {
+            "name": "mevScore",
+            "type": "u8"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeChainId",
+            "type": "u16"
+          },
+          {
+            "name": "partialFill",
+            "type": "bool"
+          },
+          {
+            "name": "txSigHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
             }
+          },
+          {
+            "name": "slotsInFlight",
+            "type": "u64"
+          },
+          {
+            "name": "wsolErrorCode",
+            "type": "u16"
+          },
+          {
+            "name": "txSlot",
+            "type": "u64"
+          },
+          {
+            "name": "frozenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "submittedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "confirmedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "instructionType",
+            "type": "u8"
+          },
+          {
+            "name": "landing",
+            "type": "u8"
+          },
+          {
+            "name": "expectedVersion",
+            "type": "u8"
+          },
+          {
+            "name": "actualVersion",
+            "type": "u8"
+          },
+          {
+            "name": "tags",
+            "type": "u16"
+          },
+          {
+            "name": "cpiDepth",
+            "type": "u8"
+          },
+          {
+            "name": "requiredSpace",
+            "type": "u32"
+          },
+          {
+            "name": "availableSpace",
+            "type": "u32"
+          },
+          {
+            "name": "expectedProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "actualProgram",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "transactionRegistry",
+      "name": "txSizeStats",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "txCount",
-            "type": "u64"
+            "name": "successesBySize",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
           },
           {
-            "name": "successCount",
-            "type": "u64"
+            "name": "failuresBySize",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "userRegistryTransferred",
+      "type": {
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "string"
           },
           {
-            "name": "failureCount",
-            "type": "u64"
+            "name": "oldOwner",
+            "type": "pubkey"
           },
           {
-            "name": "last100Outcomes",
-            "type": "bytes"
+            "name": "newOwner",
+            "type": "pubkey"
           },
           {
-            "name": "cursor",
-            "type": "u8"
+            "name": "registry",
+            "type": "pubkey"
           }
-        ]
+        ],
+        "kind": "struct"
       }
     }
+  ],
+  "constants": [
+    {
+      "name": "archiveSeed",
+      "type": "bytes",
+      "value": "[97, 114, 99, 104, 105, 118, 101]"
+    },
+    {
+      "name": "catalogSeed",
+      "type": "bytes",
+      "value": "[99, 97, 116, 97, 108, 111, 103]"
+    },
+    {
+      "name": "maxTiers",
+      "type": "u8",
+      "value": "5"
+    },
+    {
+      "name": "prioritySeed",
+      "type": "bytes",
+      "value": "[112, 114, 105, 111, 114, 105, 116, 121]"
+    },
+    {
+      "name": "registrySeed",
+      "type": "bytes",
+      "value": "[114, 101, 103, 105, 115, 116, 114, 121]"
+    },
+    {
+      "name": "windowSize",
+      "type": "u16",
+      "value": "100"
+    }
   ]
 };
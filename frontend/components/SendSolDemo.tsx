@@ -6,11 +6,11 @@ import { Button } from "@/components/ui/button";
 import { getProgram, getPDAs } from "@/lib/anchor-client";
 
 const FAILURE_OPTIONS = [
-  { key: "slippage_exceeded", label: "Slippage Exceeded", value: 0 },
-  { key: "insufficient_liquidity", label: "Insufficient Liquidity", value: 1 },
-  { key: "mev_detected", label: "MEV Detected", value: 2 },
-  { key: "dropped_tx", label: "Dropped Transaction", value: 3 },
-  { key: "insufficient_funds", label: "Insufficient Funds", value: 4 },
+  { key: "slippage", label: "Slippage Exceeded", value: 0 },
+  { key: "liquidity", label: "Insufficient Liquidity", value: 1 },
+  { key: "mev", label: "MEV Detected", value: 2 },
+  { key: "dropped", label: "Dropped Transaction", value: 3 },
+  { key: "insufficientFunds", label: "Insufficient Funds", value: 4 },
   { key: "other", label: "Other", value: 5 },
 ];
 
@@ -46,15 +46,15 @@ export default function SendSolDemo() {
       const program = await getProgram();
       const pda = getPDAs(program.programId);
 
-      const args: [boolean, number, number] = [
-        Boolean(isSuccess),
-        Number(failureDisabled ? FAILURE_OPTIONS[FAILURE_OPTIONS.length - 1].value : failureType),
-        Number(priorityTier),
-      ];
+      // Outcome and FailureType are Anchor enums, passed as { variant: {} }
+      const failure = failureDisabled
+        ? FAILURE_OPTIONS[FAILURE_OPTIONS.length - 1]
+        : FAILURE_OPTIONS.find((opt) => opt.value === failureType) ?? FAILURE_OPTIONS[0];
+      const outcome = isSuccess ? { success: {} } : { failure: {} };
 
       const tx = await program.methods
-        .registerTxOutcome(...args)
-        .accounts({
+        .registerTxOutcome("", outcome as any, { [failure.key]: {} } as any, Number(priorityTier))
+        .accountsPartial({
           payer: publicKey,
           registry: pda.registry,
           failureCatalog: pda.catalog,
@@ -48,21 +48,21 @@ export async function getProgram(): Promise<Program<Txguard>> {
 }
 
 /**
- * Derive PDA addresses
+ * Derive PDA addresses (the empty namespace is the original deployment)
  */
-export function getPDAs(programId: PublicKey) {
+export function getPDAs(programId: PublicKey, namespace: string = '') {
   const [registry] = PublicKey.findProgramAddressSync(
-    [Buffer.from('registry')],
+    [Buffer.from('registry'), Buffer.from(namespace)],
     programId
   );
 
   const [catalog] = PublicKey.findProgramAddressSync(
-    [Buffer.from('catalog')],
+    [Buffer.from('catalog'), Buffer.from(namespace)],
     programId
   );
 
   const [priorityStats] = PublicKey.findProgramAddressSync(
-    [Buffer.from('priority')],
+    [Buffer.from('priority'), Buffer.from(namespace)],
     programId
   );
 
@@ -158,6 +158,49 @@ export async function getPriorityFeeStats(): Promise<{
   }
 }
 
+/**
+ * Derive the epoch-scoped registry PDA for a given epoch
+ */
+export function getEpochRegistryPDA(programId: PublicKey, epoch: number, namespace: string = ''): PublicKey {
+  const epochBytes = Buffer.alloc(8);
+  epochBytes.writeBigUInt64LE(BigInt(epoch));
+
+  const [registry] = PublicKey.findProgramAddressSync(
+    [Buffer.from('registry'), Buffer.from(namespace), epochBytes],
+    programId
+  );
+
+  return registry;
+}
+
+/**
+ * Iterate over the epoch registries of the last `count` epochs, newest first.
+ * Epochs without any recorded outcome yield `null` counts.
+ */
+export async function* iterateEpochRegistries(count: number, namespace: string = ''): AsyncGenerator<{
+  epoch: number;
+  registry: PublicKey;
+  txCount: number | null;
+  successCount: number | null;
+  failureCount: number | null;
+}> {
+  const program = await getProgram();
+  const { epoch: currentEpoch } = await program.provider.connection.getEpochInfo();
+
+  for (let epoch = currentEpoch; epoch > currentEpoch - count && epoch >= 0; epoch--) {
+    const registry = getEpochRegistryPDA(program.programId, epoch, namespace);
+    const account = await program.account.transactionRegistry.fetchNullable(registry);
+
+    yield {
+      epoch,
+      registry,
+      txCount: account ? account.txCount.toNumber() : null,
+      successCount: account ? account.successCount.toNumber() : null,
+      failureCount: account ? account.failureCount.toNumber() : null
+    };
+  }
+}
+
 /**
  * Calculate success rate from registry data
  */
@@ -171,3 +214,113 @@ export function calculateSuccessRate(registry: {
   }
   return (registry.successCount / registry.txCount) * 100;
 }
+
+/**
+ * Derive the 30-day DailyStats PDA
+ */
+export function getDailyStatsPDA(programId: PublicKey, namespace: string = ''): PublicKey {
+  const [dailyStats] = PublicKey.findProgramAddressSync(
+    [Buffer.from('daily'), Buffer.from(namespace)],
+    programId
+  );
+
+  return dailyStats;
+}
+
+export interface DailyPoint {
+  dayIndex: number;
+  txCount: number;
+  successCount: number;
+  failureCount: number;
+  topFailureType: number;
+  avgTier: number; // mean priority fee tier, e.g. 2.33
+}
+
+/**
+ * Rebuild an ordered 30-day series (oldest first, ending at `today`) from the
+ * DailyStats ring. Slots holding a day outside the window, or a day that was
+ * never written, come back as zero-activity days.
+ */
+export function buildDailySeries(
+  days: Array<{
+    dayIndex: number | { toNumber(): number };
+    txCount: number;
+    successCount: number;
+    failureCount: number;
+    topFailureType: number;
+    avgTier: number;
+  }>,
+  today: number = Math.floor(Date.now() / 86_400_000)
+): DailyPoint[] {
+  const series: DailyPoint[] = [];
+
+  for (let day = today - 29; day <= today; day++) {
+    const slot = days[((day % 30) + 30) % 30];
+    const dayIndex = slot
+      ? typeof slot.dayIndex === 'number' ? slot.dayIndex : slot.dayIndex.toNumber()
+      : -1;
+
+    if (slot && dayIndex === day) {
+      series.push({
+        dayIndex: day,
+        txCount: slot.txCount,
+        successCount: slot.successCount,
+        failureCount: slot.failureCount,
+        topFailureType: slot.topFailureType,
+        avgTier: slot.avgTier / 100
+      });
+    } else {
+      series.push({
+        dayIndex: day,
+        txCount: 0,
+        successCount: 0,
+        failureCount: 0,
+        topFailureType: 0,
+        avgTier: 0
+      });
+    }
+  }
+
+  return series;
+}
+
+/**
+ * Derive the all-time SeasonalityStats PDA
+ */
+export function getSeasonalityStatsPDA(programId: PublicKey, namespace: string = ''): PublicKey {
+  const [seasonalityStats] = PublicKey.findProgramAddressSync(
+    [Buffer.from('seasonality'), Buffer.from(namespace)],
+    programId
+  );
+
+  return seasonalityStats;
+}
+
+export interface HourOfDayRate {
+  hour: number; // UTC hour of day, 0-23
+  total: number;
+  successRate: number; // percent, 0 when the hour has no reports
+  failureRate: number;
+}
+
+/**
+ * Turn SeasonalityStats' per-hour success and failure counts into rates, one
+ * entry per UTC hour
+ */
+export function buildSeasonalityRates(stats: {
+  successesByHour: number[];
+  failuresByHour: number[];
+}): HourOfDayRate[] {
+  return Array.from({ length: 24 }, (_, hour) => {
+    const successes = stats.successesByHour[hour] ?? 0;
+    const failures = stats.failuresByHour[hour] ?? 0;
+    const total = successes + failures;
+
+    return {
+      hour,
+      total,
+      successRate: total === 0 ? 0 : (successes / total) * 100,
+      failureRate: total === 0 ? 0 : (failures / total) * 100
+    };
+  });
+}
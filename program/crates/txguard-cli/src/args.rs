@@ -0,0 +1,174 @@
+use std::env;
+
+use txguard::FailureType;
+
+pub const USAGE: &str = "\
+Usage: txguard-cli [OPTIONS] <COMMAND>
+
+Commands:
+  init                                          Create the namespace's accounts; on \"\" this also resets them
+  report (--success | --failure-type <N>) --tier <N>
+                                                Record one transaction outcome
+  stats                                         Print the registry, failure catalog and priority fee tiers
+  watch [--interval <SECS>] [--stale-slots <N>]
+                                                Live dashboard, refreshed as the accounts change (or every
+                                                SECS seconds without websockets); warns once the newest
+                                                report is N slots old [default: 2, 750]
+  reset (--failure-types <N,...> | --seasonality)
+                                                Zero failure type counters or seasonality stats (authority only)
+
+Options:
+  -u, --url <URL>           RPC URL or moniker: localhost, devnet, testnet, mainnet-beta [default: localhost]
+  -k, --keypair <PATH>      Signer keypair [default: ~/.config/solana/id.json]
+  -n, --namespace <NAME>    TxGuard namespace [default: \"\"]
+  -h, --help                Print this help";
+
+pub struct Args {
+    pub url: String,
+    pub keypair: String,
+    pub namespace: String,
+    pub command: Command,
+}
+
+pub enum Command {
+    Help,
+    Init,
+    Report {
+        success: bool,
+        failure_type: FailureType, // Ignored by the program when success is set
+        tier: u8,
+    },
+    Stats,
+    Watch {
+        interval_secs: u64,
+        stale_slots: u64, // Warn once the newest report is this many slots old
+    },
+    Reset(ResetTarget),
+}
+
+pub enum ResetTarget {
+    FailureTypes(Vec<u8>),
+    Seasonality,
+}
+
+// Options may appear before or after the command, like the solana CLI's
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut url = "localhost".to_string();
+    let mut keypair = "~/.config/solana/id.json".to_string();
+    let mut namespace = String::new();
+    let mut command = None;
+    let mut success = false;
+    let mut failure_type = None;
+    let mut tier = None;
+    let mut failure_types = None;
+    let mut seasonality = false;
+    let mut interval_secs = 2;
+    let mut stale_slots = 750;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "-h" | "--help" => command = Some("help".to_string()),
+            "-u" | "--url" => url = value()?,
+            "-k" | "--keypair" => keypair = value()?,
+            "-n" | "--namespace" => namespace = value()?,
+            "--success" => success = true,
+            "--failure-type" => {
+                let code = parse_u8("--failure-type", &value()?)?;
+                let parsed = FailureType::try_from(code)
+                    .map_err(|code| format!("--failure-type {} is not a failure type code", code))?;
+                failure_type = Some(parsed);
+            }
+            "--tier" => tier = Some(parse_u8("--tier", &value()?)?),
+            "--failure-types" => {
+                let list = value()?;
+                let codes = list
+                    .split(',')
+                    .map(|code| parse_u8("--failure-types", code.trim()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                failure_types = Some(codes);
+            }
+            "--seasonality" => seasonality = true,
+            "--interval" => interval_secs = parse_u64("--interval", &value()?)?,
+            "--stale-slots" => stale_slots = parse_u64("--stale-slots", &value()?)?,
+            flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+            name if command.is_none() => command = Some(name.to_string()),
+            extra => return Err(format!("unexpected argument {}", extra)),
+        }
+    }
+
+    let command = match command.as_deref() {
+        Some("help") => Command::Help,
+        Some("init") => Command::Init,
+        Some("report") => {
+            let tier = tier.ok_or("report needs --tier")?;
+            match (success, failure_type) {
+                (true, None) => Command::Report {
+                    success: true,
+                    failure_type: FailureType::Slippage,
+                    tier,
+                },
+                (false, Some(failure_type)) => Command::Report {
+                    success: false,
+                    failure_type,
+                    tier,
+                },
+                _ => return Err("report needs either --success or --failure-type".to_string()),
+            }
+        }
+        Some("stats") => Command::Stats,
+        Some("watch") if interval_secs == 0 => {
+            return Err("--interval must be at least 1".to_string())
+        }
+        Some("watch") => Command::Watch {
+            interval_secs,
+            stale_slots,
+        },
+        Some("reset") => match (failure_types, seasonality) {
+            (Some(codes), false) => Command::Reset(ResetTarget::FailureTypes(codes)),
+            (None, true) => Command::Reset(ResetTarget::Seasonality),
+            _ => return Err("reset needs either --failure-types or --seasonality".to_string()),
+        },
+        Some(other) => return Err(format!("unknown command {}", other)),
+        None => return Err("no command given".to_string()),
+    };
+
+    Ok(Args {
+        url: resolve_url(&url),
+        keypair: expand_home(&keypair),
+        namespace,
+        command,
+    })
+}
+
+fn parse_u8(flag: &str, value: &str) -> Result<u8, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number from 0 to 255, got {:?}", flag, value))
+}
+
+fn parse_u64(flag: &str, value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a whole number, got {:?}", flag, value))
+}
+
+// Same monikers as `solana --url`
+fn resolve_url(url: &str) -> String {
+    match url {
+        "l" | "localhost" => "http://127.0.0.1:8899",
+        "d" | "devnet" => "https://api.devnet.solana.com",
+        "t" | "testnet" => "https://api.testnet.solana.com",
+        "m" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{}/{}", home, rest),
+        _ => path.to_string(),
+    }
+}
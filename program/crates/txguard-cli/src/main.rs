@@ -0,0 +1,201 @@
+//! Operator CLI for TxGuard: create a namespace, report outcomes, read stats and run the
+//! authority's resets without writing a client.
+
+mod args;
+mod stats;
+mod watch;
+
+use std::fmt;
+use std::process::ExitCode;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use txguard::{TxOutcomeParams, CATALOG_SEED, PRIORITY_SEED, REGISTRY_SEED};
+use txguard_client::{
+    blocking, error_name, program_error, report_instruction, HealthCheckError, ProgramErrorLog,
+};
+
+use args::{Args, Command, ResetTarget, USAGE};
+
+enum CliError {
+    Io(std::io::Error),
+    Keypair(String),
+    Rpc(Box<ClientError>),
+    Program(ProgramErrorLog),
+    Fetch(HealthCheckError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{}", err),
+            Self::Keypair(err) => write!(f, "could not read keypair: {}", err),
+            // No preflight logs, so the name comes from the code alone
+            Self::Rpc(err) => match err.get_transaction_error() {
+                Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) => {
+                    match error_name(code) {
+                        Some(name) => write!(f, "transaction failed with {} ({})", name, code),
+                        None => write!(f, "transaction failed with program error {}", code),
+                    }
+                }
+                _ => write!(f, "RPC request failed: {}", err),
+            },
+            Self::Program(log) => write!(f, "{} ({}): {}", log.name, log.number, log.message),
+            Self::Fetch(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), CliError> {
+    let namespace = args.namespace.clone();
+    let rpc = RpcClient::new_with_commitment(args.url.clone(), CommitmentConfig::confirmed());
+    let address = |seed: &[u8]| pda(&[seed, namespace.as_bytes()]);
+
+    let (payer, ix) = match args.command {
+        Command::Help => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        Command::Stats => {
+            let client = blocking::TxGuardClient::for_namespace(args.url, &namespace);
+            let accounts = client.fetch_all().map_err(CliError::Fetch)?;
+            print!("{}", stats::render(&namespace, &accounts));
+            return Ok(());
+        }
+        Command::Watch {
+            interval_secs,
+            stale_slots,
+        } => {
+            let options = watch::WatchOptions {
+                interval_secs,
+                stale_slots,
+            };
+            return watch::watch(args.url, namespace, options);
+        }
+        Command::Init => {
+            let payer = signer(&args.keypair)?;
+            let accounts = txguard::accounts::Initialize {
+                payer: payer.pubkey(),
+                registry: address(REGISTRY_SEED),
+                failure_catalog: address(CATALOG_SEED),
+                priority_fee_stats: address(PRIORITY_SEED),
+                config: address(b"config"),
+                hourly_stats: address(b"hourly"),
+                daily_stats: address(b"daily"),
+                seasonality_stats: address(b"seasonality"),
+                compute_stats: address(b"compute"),
+                latency_stats: address(b"latency"),
+                tx_size_stats: address(b"tx_size"),
+                instruction_stats: address(b"instructions"),
+                blockhash_age_stats: address(b"blockhash_age"),
+                route_stats: address(b"routes"),
+                tag_stats: address(b"tags"),
+                system_program: system_program::ID,
+            };
+            let data = txguard::instruction::Initialize {
+                namespace: namespace.clone(),
+            };
+            (payer, instruction(accounts, data))
+        }
+        Command::Report {
+            success,
+            failure_type,
+            tier,
+        } => {
+            let payer = signer(&args.keypair)?;
+            let params = TxOutcomeParams {
+                success,
+                failure_type,
+                priority_fee_tier: tier,
+                ..Default::default()
+            };
+            let ix = report_instruction(&namespace, &payer.pubkey(), params);
+            (payer, ix)
+        }
+        Command::Reset(target) => {
+            let authority = signer(&args.keypair)?;
+            let ix = match target {
+                ResetTarget::FailureTypes(types_to_reset) => {
+                    let accounts = txguard::accounts::ResetFailureTypes {
+                        authority: authority.pubkey(),
+                        config: address(b"config"),
+                        failure_catalog: address(CATALOG_SEED),
+                    };
+                    let data = txguard::instruction::ResetFailureTypes {
+                        namespace: namespace.clone(),
+                        types_to_reset,
+                    };
+                    instruction(accounts, data)
+                }
+                ResetTarget::Seasonality => {
+                    let accounts = txguard::accounts::ResetSeasonality {
+                        authority: authority.pubkey(),
+                        config: address(b"config"),
+                        seasonality_stats: address(b"seasonality"),
+                    };
+                    let data = txguard::instruction::ResetSeasonality {
+                        _namespace: namespace.clone(),
+                    };
+                    instruction(accounts, data)
+                }
+            };
+            (authority, ix)
+        }
+    };
+
+    let signature = send(&rpc, &payer, ix)?;
+    println!("Signature: {}", signature);
+    Ok(())
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &txguard::ID).0
+}
+
+fn signer(path: &str) -> Result<Keypair, CliError> {
+    read_keypair_file(path).map_err(|err| CliError::Keypair(format!("{}: {}", path, err)))
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: txguard::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// Preflight stays on so a program error comes back with its logs, and with them its name
+fn send(rpc: &RpcClient, payer: &Keypair, ix: Instruction) -> Result<Signature, CliError> {
+    let blockhash = rpc
+        .get_latest_blockhash()
+        .map_err(|err| CliError::Rpc(Box::new(err)))?;
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], blockhash);
+    rpc.send_and_confirm_transaction(&tx)
+        .map_err(|err| match program_error(&err) {
+            Some(log) => CliError::Program(log),
+            None => CliError::Rpc(Box::new(err)),
+        })
+}
@@ -0,0 +1,81 @@
+use std::fmt::Write;
+
+use txguard_client::{TxGuardAccounts, FAILURE_TYPE_NAMES};
+
+// Plain-text tables of the three accounts a report writes
+pub fn render(namespace: &str, accounts: &TxGuardAccounts) -> String {
+    let registry = &accounts.registry;
+    let stats = &accounts.priority_stats;
+    let metrics = accounts.metrics();
+    let mut text = String::new();
+
+    let _ = writeln!(text, "Registry (namespace {:?})", namespace);
+    for (label, value) in [
+        ("transactions", registry.tx_count.to_string()),
+        ("successes", registry.success_count.to_string()),
+        ("failures", registry.failure_count.to_string()),
+        ("success rate", percent(metrics.success_rate_bps)),
+        (
+            "last-100 rate",
+            metrics
+                .window_success_rate_bps
+                .map_or("-".to_string(), percent),
+        ),
+        ("unique callers", registry.unique_caller_count.to_string()),
+    ] {
+        let _ = writeln!(text, "  {:<16}{:>12}", label, value);
+    }
+
+    let _ = writeln!(text, "\nFailures by type");
+    let _ = writeln!(text, "  {:<26}{:>6}{:>10}", "TYPE", "CODE", "COUNT");
+    let counts = accounts.catalog.counts_by_type();
+    for (((code, count), (_, share)), name) in counts
+        .iter()
+        .zip(metrics.failure_breakdown_bps)
+        .zip(FAILURE_TYPE_NAMES)
+    {
+        let _ = writeln!(
+            text,
+            "  {:<26}{:>6}{:>10}  {}",
+            name,
+            code,
+            count,
+            if *count > 0 {
+                percent(share)
+            } else {
+                String::new()
+            }
+        );
+    }
+    let dominant = metrics.dominant_failure_type.and_then(|code| {
+        counts
+            .iter()
+            .position(|(candidate, _)| *candidate == code)
+            .map(|index| FAILURE_TYPE_NAMES[index])
+    });
+    let _ = writeln!(text, "  dominant: {}", dominant.unwrap_or("-"));
+
+    let _ = writeln!(text, "\nPriority fee tiers");
+    let _ = writeln!(
+        text,
+        "  {:<6}{:<18}{:>10}{:>14}",
+        "TIER", "NAME", "REPORTS", "SUCCESS RATE"
+    );
+    for (tier, reports) in stats.tiers.iter().enumerate() {
+        let name = stats
+            .tier_names
+            .get(tier)
+            .map(|name| String::from_utf8_lossy(name).trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "-".to_string());
+        let rate = stats
+            .tier_success_rate_bps(tier)
+            .map_or("-".to_string(), percent);
+        let _ = writeln!(text, "  {:<6}{:<18}{:>10}{:>14}", tier, name, reports, rate);
+    }
+    text
+}
+
+pub fn percent(bps: u16) -> String {
+    format!("{}.{:02}%", bps / 100, bps % 100)
+}
@@ -0,0 +1,250 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use futures_util::stream::{select_all, BoxStream, StreamExt};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::RpcAccountInfoConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use txguard::OUTCOME_SUCCESS;
+use txguard_client::{websocket_url, TxGuardAccounts, TxGuardClient, FAILURE_TYPE_NAMES};
+
+use crate::stats::percent;
+use crate::CliError;
+
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const REPORTS_PER_POINT: usize = 10; // Window entries behind each sparkline point
+const BAR_WIDTH: usize = 30;
+
+pub struct WatchOptions {
+    pub interval_secs: u64,
+    pub stale_slots: u64,
+}
+
+// What one redraw shows; accounts stay from the last good fetch when a later one fails
+struct Frame {
+    accounts: TxGuardAccounts,
+    slot: u64,
+    updates: &'static str, // "websocket" or why it fell back to polling
+    error: Option<String>,
+}
+
+pub fn watch(url: String, namespace: String, options: WatchOptions) -> Result<(), CliError> {
+    let runtime = tokio::runtime::Runtime::new().map_err(CliError::Io)?;
+    runtime.block_on(run(url, namespace, options))
+}
+
+async fn run(url: String, namespace: String, options: WatchOptions) -> Result<(), CliError> {
+    let client = TxGuardClient::for_namespace(url.clone(), &namespace);
+    let rpc = RpcClient::new_with_commitment(url.clone(), CommitmentConfig::confirmed());
+    let current_slot = || async {
+        rpc.get_slot()
+            .await
+            .map_err(|err| CliError::Rpc(Box::new(err)))
+    };
+
+    // The first fetch fails fast on a wrong URL or an uninitialized namespace
+    let mut frame = Frame {
+        accounts: client.fetch_all().await.map_err(CliError::Fetch)?,
+        slot: current_slot().await?,
+        updates: "websocket",
+        error: None,
+    };
+
+    let pubsub = PubsubClient::new(&websocket_url(&url)).await.ok();
+    let mut unsubscribes = Vec::new();
+    let mut streams: Vec<BoxStream<'_, _>> = Vec::new();
+    if let Some(pubsub) = &pubsub {
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        };
+        let addresses = client.addresses;
+        for address in [
+            addresses.registry,
+            addresses.catalog,
+            addresses.priority_stats,
+        ] {
+            match pubsub
+                .account_subscribe(&address, Some(config.clone()))
+                .await
+            {
+                Ok((stream, unsubscribe)) => {
+                    streams.push(stream);
+                    unsubscribes.push(unsubscribe);
+                }
+                Err(_) => frame.updates = "polling, accountSubscribe was refused",
+            }
+        }
+    } else {
+        frame.updates = "polling, no websocket endpoint";
+    }
+    let mut streaming = frame.updates == "websocket";
+    let mut changes = select_all(streams);
+
+    let mut ticks = tokio::time::interval(Duration::from_secs(options.interval_secs));
+    // Created once so a Ctrl-C pressed mid-refresh is still seen on the next select
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    loop {
+        draw(&render(&namespace, &url, &frame, options.stale_slots));
+        let refetch = tokio::select! {
+            _ = &mut ctrl_c => break,
+            change = changes.next(), if streaming => {
+                if change.is_none() {
+                    streaming = false;
+                    frame.updates = "polling, websocket closed";
+                }
+                true
+            }
+            // While streaming, ticks only move the data age along
+            _ = ticks.tick() => !streaming,
+        };
+
+        let fetched = if refetch {
+            client.fetch_all().await.map_err(CliError::Fetch)
+        } else {
+            Ok(frame.accounts.clone())
+        };
+        match (fetched, current_slot().await) {
+            (Ok(accounts), Ok(slot)) => {
+                frame.accounts = accounts;
+                frame.slot = slot;
+                frame.error = None;
+            }
+            (Err(err), _) | (_, Err(err)) => frame.error = Some(err.to_string()),
+        }
+    }
+
+    drop(changes);
+    for unsubscribe in unsubscribes {
+        unsubscribe().await;
+    }
+    if let Some(pubsub) = pubsub {
+        let _ = pubsub.shutdown().await;
+    }
+    println!();
+    Ok(())
+}
+
+fn draw(screen: &str) {
+    // Clear and home, then redraw from the top
+    print!("\x1b[2J\x1b[H{}", screen);
+}
+
+fn render(namespace: &str, url: &str, frame: &Frame, stale_slots: u64) -> String {
+    let registry = &frame.accounts.registry;
+    let stats = &frame.accounts.priority_stats;
+    let metrics = frame.accounts.metrics();
+    let mut text = String::new();
+
+    let _ = writeln!(
+        text,
+        "TxGuard {:?} on {} ({}), slot {}. Ctrl-C to exit.",
+        namespace, url, frame.updates, frame.slot
+    );
+    if let Some(error) = &frame.error {
+        let _ = writeln!(
+            text,
+            "! Refresh failed, showing the last good data: {}",
+            error
+        );
+    }
+    let age = frame.slot.saturating_sub(registry.last_report_slot);
+    if registry.last_report_slot == 0 {
+        let _ = writeln!(text, "! No reports yet");
+    } else if age >= stale_slots {
+        let _ = writeln!(
+            text,
+            "! Data is stale: the newest report is {} slots old (slot {})",
+            age, registry.last_report_slot
+        );
+    }
+
+    let _ = writeln!(
+        text,
+        "\nSuccess rate {} all time, {} over the window ({} reports)",
+        percent(metrics.success_rate_bps),
+        metrics
+            .window_success_rate_bps
+            .map_or("-".to_string(), percent),
+        registry.tx_count
+    );
+    let rates: Vec<u16> = registry
+        .chronological_outcomes()
+        .chunks(REPORTS_PER_POINT)
+        .map(|chunk| {
+            let successes = chunk.iter().filter(|o| **o == OUTCOME_SUCCESS).count();
+            (successes * 10_000 / chunk.len()) as u16
+        })
+        .collect();
+    let _ = writeln!(
+        text,
+        "  {}  (oldest to newest, {} reports per point)",
+        sparkline(&rates),
+        REPORTS_PER_POINT
+    );
+
+    let _ = writeln!(text, "\nFailures");
+    let counts = frame.accounts.catalog.counts_by_type();
+    let most = counts.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    for (((_, count), (_, share)), name) in counts
+        .iter()
+        .zip(metrics.failure_breakdown_bps)
+        .zip(FAILURE_TYPE_NAMES)
+    {
+        if *count > 0 {
+            let _ = writeln!(
+                text,
+                "  {:<26}{} {:>8} {:>8}",
+                name,
+                bar(*count as u64, most as u64),
+                count,
+                percent(share)
+            );
+        }
+    }
+    if most == 0 {
+        let _ = writeln!(text, "  none");
+    }
+
+    let _ = writeln!(text, "\nPriority fee tiers");
+    let busiest = stats.tiers.iter().copied().max().unwrap_or(0);
+    for (tier, reports) in stats.tiers.iter().enumerate() {
+        let name = stats
+            .tier_names
+            .get(tier)
+            .map(|name| String::from_utf8_lossy(name).trim().to_string())
+            .unwrap_or_default();
+        let _ = writeln!(
+            text,
+            "  {} {:<16}{} {:>8}",
+            tier,
+            name,
+            bar(*reports, busiest),
+            reports
+        );
+    }
+    text
+}
+
+fn sparkline(rates_bps: &[u16]) -> String {
+    if rates_bps.is_empty() {
+        return "-".to_string();
+    }
+    rates_bps
+        .iter()
+        .map(|rate| SPARK_LEVELS[(*rate as usize * (SPARK_LEVELS.len() - 1)).div_ceil(10_000)])
+        .collect()
+}
+
+fn bar(value: u64, max: u64) -> String {
+    let filled = if max == 0 {
+        0
+    } else {
+        (value * BAR_WIDTH as u64).div_ceil(max) as usize
+    };
+    format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+}
@@ -0,0 +1,91 @@
+use std::process::{Command, Output};
+
+fn txguard_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_txguard-cli"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn help_prints_usage() {
+    let output = txguard_cli(&["--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Usage: txguard-cli"));
+}
+
+#[test]
+fn invalid_arguments_exit_with_usage() {
+    for (args, message) in [
+        (&[][..], "no command given"),
+        (
+            &["report", "--tier", "1"][..],
+            "either --success or --failure-type",
+        ),
+        (
+            &["report", "--success", "--failure-type", "2", "--tier", "1"][..],
+            "either --success or --failure-type",
+        ),
+        (&["report", "--success"][..], "report needs --tier"),
+        (
+            &["report", "--failure-type", "27", "--tier", "1"][..],
+            "--failure-type 27 is not a failure type code",
+        ),
+        (
+            &["report", "--success", "--tier", "x"][..],
+            "--tier expects a number",
+        ),
+        (&["reset"][..], "either --failure-types or --seasonality"),
+        (&["stats", "--url"][..], "--url needs a value"),
+        (&["stats", "--verbose"][..], "unknown option --verbose"),
+        (&["deploy"][..], "unknown command deploy"),
+        (
+            &["watch", "--interval", "0"][..],
+            "--interval must be at least 1",
+        ),
+        (
+            &["watch", "--stale-slots", "-1"][..],
+            "--stale-slots expects a whole number",
+        ),
+    ] {
+        let output = txguard_cli(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        let stderr = stderr(&output);
+        assert!(stderr.contains(message), "{:?}: {}", args, stderr);
+        assert!(stderr.contains("Usage: txguard-cli"));
+    }
+}
+
+#[test]
+fn unreadable_keypair_fails_before_sending() {
+    let output = txguard_cli(&[
+        "report",
+        "--success",
+        "--tier",
+        "1",
+        "--keypair",
+        "/nonexistent/id.json",
+        "--url",
+        "http://127.0.0.1:1",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("could not read keypair: /nonexistent/id.json"));
+}
+
+#[test]
+fn stats_reports_an_unreachable_rpc() {
+    let output = txguard_cli(&["stats", "-u", "http://127.0.0.1:1"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("RPC request failed"));
+}
+
+#[test]
+fn watch_exits_when_the_first_fetch_fails() {
+    let output = txguard_cli(&["watch", "-u", "http://127.0.0.1:1"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("RPC request failed"));
+}
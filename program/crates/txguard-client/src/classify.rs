@@ -0,0 +1,303 @@
+use anchor_lang::prelude::{pubkey, Pubkey};
+use anchor_lang::solana_program::instruction::error::InstructionError;
+use solana_transaction_error::TransactionError;
+use txguard::{
+    FAILURE_BLOCKHASH_NOT_FOUND, FAILURE_BRIDGE, FAILURE_CODES, FAILURE_CPI_DEPTH,
+    FAILURE_DATA_TOO_SMALL, FAILURE_DEADLINE, FAILURE_FROZEN, FAILURE_GAS_SPIKE, FAILURE_MEV,
+    FAILURE_NONCE_EXPIRED, FAILURE_NOT_EXECUTABLE, FAILURE_ORACLE_CACHE, FAILURE_OTHER,
+    FAILURE_SIG_VERIFY, FAILURE_VERSION_MISMATCH, FAILURE_WRONG_TOKEN_PROGRAM, FAILURE_WSOL,
+};
+
+use crate::FAILURE_TYPE_NAMES;
+
+// The failure_type a bot passes to register_tx_outcome, one variant per FAILURE_CODES code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FailureType {
+    Slippage = 0,
+    Liquidity = 1,
+    Mev = FAILURE_MEV,
+    Dropped = 3,
+    InsufficientFunds = 4,
+    Other = FAILURE_OTHER,
+    Bridge = FAILURE_BRIDGE,
+    Deadline = FAILURE_DEADLINE,
+    Wsol = FAILURE_WSOL,
+    TokenFrozen = FAILURE_FROZEN,
+    StaleOracleUpdate = FAILURE_ORACLE_CACHE,
+    SignatureVerifyFailure = FAILURE_SIG_VERIFY,
+    VersionMismatch = FAILURE_VERSION_MISMATCH,
+    BlockhashNotFound = FAILURE_BLOCKHASH_NOT_FOUND,
+    CpiDepthExceeded = FAILURE_CPI_DEPTH,
+    NonceAccountExpired = FAILURE_NONCE_EXPIRED,
+    GasPriceSpike = FAILURE_GAS_SPIKE,
+    AccountDataTooSmall = FAILURE_DATA_TOO_SMALL,
+    ProgramNotExecutable = FAILURE_NOT_EXECUTABLE,
+    WrongTokenProgram = FAILURE_WRONG_TOKEN_PROGRAM,
+}
+
+impl FailureType {
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+
+    // FAILURE_TYPE_NAMES label, e.g. "insufficient_funds"
+    pub fn name(self) -> &'static str {
+        FAILURE_CODES
+            .iter()
+            .position(|code| *code == self.code())
+            .map_or("other", |index| FAILURE_TYPE_NAMES[index])
+    }
+}
+
+// The program's argument, which also has variants for the never-assigned codes 6-12
+impl From<FailureType> for txguard::FailureType {
+    fn from(failure_type: FailureType) -> Self {
+        // Every code here has a variant there
+        failure_type.code().try_into().unwrap_or(Self::Other)
+    }
+}
+
+// What a rule looks for in a failed transaction
+#[derive(Clone, Copy, Debug)]
+pub enum Matcher {
+    // The transaction error itself, e.g. AccountInUse
+    Error(fn(&TransactionError) -> bool),
+    // InstructionError::Custom(code) from a known program, named by its "Program <id> failed" log
+    ProgramError {
+        program: Pubkey,
+        code: u32,
+    },
+    // Any other instruction error, from one of the programs, named the same way
+    ProgramFailed {
+        programs: &'static [Pubkey],
+        error: fn(&InstructionError) -> bool,
+    },
+    // Any log line containing the text, ignoring ASCII case
+    LogContains(&'static str),
+}
+
+impl Matcher {
+    fn matches(&self, err: &TransactionError, logs: &[String]) -> bool {
+        match self {
+            Self::Error(matches) => matches(err),
+            Self::ProgramError { program, code } => {
+                let failed = format!("Program {} failed", program);
+                let custom = match err {
+                    TransactionError::InstructionError(_, InstructionError::Custom(custom)) => {
+                        custom
+                    }
+                    _ => return false,
+                };
+                custom == code && logs.iter().any(|line| line.starts_with(&failed))
+            }
+            Self::ProgramFailed { programs, error } => {
+                let TransactionError::InstructionError(_, err) = err else {
+                    return false;
+                };
+                error(err)
+                    && programs.iter().any(|program| {
+                        let failed = format!("Program {} failed", program);
+                        logs.iter().any(|line| line.starts_with(&failed))
+                    })
+            }
+            Self::LogContains(text) => {
+                let text = text.to_ascii_lowercase();
+                logs.iter()
+                    .any(|line| line.to_ascii_lowercase().contains(&text))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Rule {
+    pub matcher: Matcher,
+    pub failure_type: FailureType,
+}
+
+const JUPITER_V6: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+const RAYDIUM_AMM_V4: Pubkey = pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+const ORCA_WHIRLPOOL: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+const SPL_TOKEN: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+// First match wins, so exact errors and program codes come before log text
+pub const DEFAULT_RULES: &[Rule] = &[
+    // Rejected before execution
+    Rule {
+        matcher: Matcher::Error(|err| matches!(err, TransactionError::BlockhashNotFound)),
+        failure_type: FailureType::BlockhashNotFound,
+    },
+    Rule {
+        matcher: Matcher::Error(|err| matches!(err, TransactionError::AccountInUse)),
+        failure_type: FailureType::Dropped,
+    },
+    Rule {
+        matcher: Matcher::Error(|err| {
+            matches!(
+                err,
+                TransactionError::InsufficientFundsForFee
+                    | TransactionError::InsufficientFundsForRent { .. }
+                    | TransactionError::InstructionError(_, InstructionError::InsufficientFunds)
+            )
+        }),
+        failure_type: FailureType::InsufficientFunds,
+    },
+    // No compute bucket on chain; ComputeStats tracks how close successes run to the limit
+    Rule {
+        matcher: Matcher::Error(|err| {
+            matches!(
+                err,
+                TransactionError::InstructionError(
+                    _,
+                    InstructionError::ComputationalBudgetExceeded
+                )
+            )
+        }),
+        failure_type: FailureType::Other,
+    },
+    Rule {
+        matcher: Matcher::LogContains("exceeded CUs meter"),
+        failure_type: FailureType::Other,
+    },
+    Rule {
+        matcher: Matcher::Error(|err| {
+            matches!(
+                err,
+                TransactionError::InstructionError(_, InstructionError::CallDepth)
+            )
+        }),
+        failure_type: FailureType::CpiDepthExceeded,
+    },
+    Rule {
+        matcher: Matcher::Error(|err| {
+            matches!(
+                err,
+                TransactionError::InstructionError(_, InstructionError::AccountDataTooSmall)
+            )
+        }),
+        failure_type: FailureType::AccountDataTooSmall,
+    },
+    // Top-level instructions are rejected before execution; CPIs fail inside the caller
+    Rule {
+        matcher: Matcher::Error(|err| {
+            matches!(
+                err,
+                TransactionError::InvalidProgramForExecution
+                    | TransactionError::InstructionError(_, InstructionError::AccountNotExecutable)
+            )
+        }),
+        failure_type: FailureType::ProgramNotExecutable,
+    },
+    // SPL Token and Token-2022 each reject accounts the other owns with IncorrectProgramId;
+    // from any other program it means something else
+    Rule {
+        matcher: Matcher::ProgramFailed {
+            programs: &[SPL_TOKEN, TOKEN_2022],
+            error: |err| matches!(err, InstructionError::IncorrectProgramId),
+        },
+        failure_type: FailureType::WrongTokenProgram,
+    },
+    // AMM slippage codes: SlippageToleranceExceeded, ExceededSlippage, AmountOutBelowMinimum
+    Rule {
+        matcher: Matcher::ProgramError {
+            program: JUPITER_V6,
+            code: 6001,
+        },
+        failure_type: FailureType::Slippage,
+    },
+    Rule {
+        matcher: Matcher::ProgramError {
+            program: RAYDIUM_AMM_V4,
+            code: 30,
+        },
+        failure_type: FailureType::Slippage,
+    },
+    Rule {
+        matcher: Matcher::ProgramError {
+            program: ORCA_WHIRLPOOL,
+            code: 6036,
+        },
+        failure_type: FailureType::Slippage,
+    },
+    // SPL Token InsufficientFunds and AccountFrozen
+    Rule {
+        matcher: Matcher::ProgramError {
+            program: SPL_TOKEN,
+            code: 1,
+        },
+        failure_type: FailureType::InsufficientFunds,
+    },
+    Rule {
+        matcher: Matcher::ProgramError {
+            program: SPL_TOKEN,
+            code: 17,
+        },
+        failure_type: FailureType::TokenFrozen,
+    },
+    // Programs without a rule of their own, by what they log
+    Rule {
+        matcher: Matcher::LogContains("slippage"),
+        failure_type: FailureType::Slippage,
+    },
+    Rule {
+        matcher: Matcher::LogContains("AmountOutBelowMinimum"),
+        failure_type: FailureType::Slippage,
+    },
+    Rule {
+        matcher: Matcher::LogContains("TooLittleOutputReceived"),
+        failure_type: FailureType::Slippage,
+    },
+    Rule {
+        matcher: Matcher::LogContains("insufficient lamports"),
+        failure_type: FailureType::InsufficientFunds,
+    },
+    Rule {
+        matcher: Matcher::LogContains("Error: insufficient funds"),
+        failure_type: FailureType::InsufficientFunds,
+    },
+    Rule {
+        matcher: Matcher::LogContains("Error: Account is frozen"),
+        failure_type: FailureType::TokenFrozen,
+    },
+];
+
+// DEFAULT_RULES plus a bot's own, e.g. its router's slippage code
+#[derive(Clone, Debug)]
+pub struct Classifier {
+    rules: Vec<Rule>,
+}
+
+impl Default for Classifier {
+    fn default() -> Self {
+        Self {
+            rules: DEFAULT_RULES.to_vec(),
+        }
+    }
+}
+
+impl Classifier {
+    // Added rules are checked before the defaults, latest first
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rules.insert(0, rule);
+        self
+    }
+
+    pub fn classify(&self, err: &TransactionError, logs: &[String]) -> FailureType {
+        classify_with(&self.rules, err, logs)
+    }
+}
+
+// Maps a failed transaction's error and logs to a failure type; anything no rule
+// recognizes is Other
+pub fn classify_error(err: &TransactionError, logs: &[String]) -> FailureType {
+    classify_with(DEFAULT_RULES, err, logs)
+}
+
+fn classify_with(rules: &[Rule], err: &TransactionError, logs: &[String]) -> FailureType {
+    rules
+        .iter()
+        .find(|rule| rule.matcher.matches(err, logs))
+        .map_or(FailureType::Other, |rule| rule.failure_type)
+}
@@ -0,0 +1,371 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, AccountDeserialize, InstructionData, ToAccountMetas};
+use solana_sdk::message::Message;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use txguard::{
+    FailureCatalog, PriorityFeeStats, TransactionRegistry, TxOutcomeParams, ARCHIVE_SEED,
+    CATALOG_SEED, FAILURE_KINDS, OUTCOME_SUCCESS, PRIORITY_SEED, REGISTRY_SEED,
+};
+
+use crate::health::HealthCheckError;
+
+// The three accounts every report writes, as of one fetch
+#[derive(Clone)]
+pub struct TxGuardAccounts {
+    pub registry: TransactionRegistry,
+    pub catalog: FailureCatalog,
+    pub priority_stats: PriorityFeeStats,
+}
+
+impl TxGuardAccounts {
+    pub fn metrics(&self) -> DerivedMetrics {
+        DerivedMetrics::from_accounts(&self.registry, &self.catalog)
+    }
+}
+
+// Rates worked out off-chain so consumers don't redo the window and catalog math
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivedMetrics {
+    pub success_rate_bps: u16, // All time, 0 before the first report
+    pub window_success_rate_bps: Option<u16>, // Over the last-100 window, None while it's empty
+    pub failure_breakdown_bps: [(u8, u16); FAILURE_KINDS], // Share of catalogued failures per code
+    pub dominant_failure_type: Option<u8>, // None until something has failed
+}
+
+impl DerivedMetrics {
+    pub fn from_accounts(registry: &TransactionRegistry, catalog: &FailureCatalog) -> Self {
+        let outcomes = registry.chronological_outcomes();
+        let window_success_rate_bps = (!outcomes.is_empty()).then(|| {
+            let successes = outcomes
+                .iter()
+                .filter(|outcome| **outcome == OUTCOME_SUCCESS)
+                .count();
+            (successes * 10_000 / outcomes.len()) as u16
+        });
+
+        // Against the catalog total rather than failure_count: an epoch-scoped or
+        // merged registry can disagree with the catalog it is read alongside
+        let counts = catalog.counts_by_type();
+        let total: u64 = counts.iter().map(|(_, count)| *count as u64).sum();
+        let failure_breakdown_bps = counts.map(|(code, count)| {
+            let share = if total == 0 {
+                0
+            } else {
+                (count as u64 * 10_000 / total) as u16
+            };
+            (code, share)
+        });
+
+        let (dominant_code, dominant_count) = catalog.dominant_failure();
+        Self {
+            success_rate_bps: registry.success_rate_bps(),
+            window_success_rate_bps,
+            failure_breakdown_bps,
+            dominant_failure_type: (dominant_count > 0).then_some(dominant_code),
+        }
+    }
+}
+
+// Registry, catalog and priority fee PDAs of a namespace
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamespaceAddresses {
+    pub registry: Pubkey,
+    pub catalog: Pubkey,
+    pub priority_stats: Pubkey,
+}
+
+impl NamespaceAddresses {
+    pub fn derive(namespace: &str) -> Self {
+        let address = |seed: &[u8]| {
+            Pubkey::find_program_address(&[seed, namespace.as_bytes()], &txguard::ID).0
+        };
+        Self {
+            registry: address(REGISTRY_SEED),
+            catalog: address(CATALOG_SEED),
+            priority_stats: address(PRIORITY_SEED),
+        }
+    }
+}
+
+// The ArchiveRegistry PDA archive_registry created for `registry` in that session
+pub fn archive_address(registry: &Pubkey, session_id: u64) -> Pubkey {
+    let seeds: &[&[u8]] = &[ARCHIVE_SEED, registry.as_ref(), &session_id.to_le_bytes()];
+    Pubkey::find_program_address(seeds, &txguard::ID).0
+}
+
+// Every ArchiveRegistry PDA of `registry`, oldest first. `session_id` is the registry's
+// own, which counts the archives taken so far
+pub fn list_archives(registry: &Pubkey, session_id: u64) -> Vec<Pubkey> {
+    (0..session_id)
+        .map(|session| archive_address(registry, session))
+        .collect()
+}
+
+// register_tx_outcome_v2 from payer, who must sign whichever transaction carries it
+pub fn report_instruction(namespace: &str, payer: &Pubkey, params: TxOutcomeParams) -> Instruction {
+    let data = txguard::instruction::RegisterTxOutcomeV2 {
+        _namespace: namespace.to_string(),
+        params,
+    };
+    Instruction {
+        program_id: txguard::ID,
+        accounts: report_accounts(namespace, payer).to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// register_tx_outcomes_batch from payer, as report_instruction
+pub fn batch_report_instruction(
+    namespace: &str,
+    payer: &Pubkey,
+    reports: Vec<TxOutcomeParams>,
+) -> Instruction {
+    let data = txguard::instruction::RegisterTxOutcomesBatch {
+        _namespace: namespace.to_string(),
+        reports,
+    };
+    Instruction {
+        program_id: txguard::ID,
+        accounts: report_accounts(namespace, payer).to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn report_accounts(namespace: &str, payer: &Pubkey) -> txguard::accounts::RegisterTxOutcome {
+    let address = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &txguard::ID).0;
+    let addresses = NamespaceAddresses::derive(namespace);
+    txguard::accounts::RegisterTxOutcome {
+        payer: *payer,
+        registry: addresses.registry,
+        failure_catalog: addresses.catalog,
+        priority_fee_stats: addresses.priority_stats,
+        config: address(&[b"config", namespace.as_bytes()]),
+        registry_config: address(&[b"registry_config", addresses.registry.as_ref()]),
+        epoch_registry: None,
+        reporter_record: address(&[b"reporter", namespace.as_bytes(), payer.as_ref()]),
+        treasury: None,
+        hourly_stats: None,
+        daily_stats: None,
+        seasonality_stats: None,
+        tier_daily: None,
+        compute_stats: None,
+        latency_stats: None,
+        tx_size_stats: None,
+        instruction_stats: None,
+        blockhash_age_stats: None,
+        route_stats: None,
+        tag_stats: None,
+        system_program: system_program::ID,
+    }
+}
+
+// Reports split into as few batches as fit in a packet, in their original order. Each
+// batch is one transaction's batch_report_instruction, with payer its only signer
+pub fn pack_reports(
+    namespace: &str,
+    payer: &Pubkey,
+    reports: Vec<TxOutcomeParams>,
+) -> Vec<Vec<TxOutcomeParams>> {
+    let mut packed: Vec<Vec<TxOutcomeParams>> = Vec::new();
+    for params in reports {
+        match packed.last_mut() {
+            Some(batch) if fits_with(namespace, payer, batch, &params) => batch.push(params),
+            _ => packed.push(vec![params]),
+        }
+    }
+    packed
+}
+
+fn fits_with(
+    namespace: &str,
+    payer: &Pubkey,
+    batch: &[TxOutcomeParams],
+    params: &TxOutcomeParams,
+) -> bool {
+    let mut reports = batch.to_vec();
+    reports.push(params.clone());
+    let ix = batch_report_instruction(namespace, payer, reports);
+    let message = Message::new(&[ix], Some(payer));
+    // The signature count's one-byte length prefix, then the payer's signature
+    1 + 64 + message.serialize().len() <= PACKET_DATA_SIZE
+}
+
+// Solana convention: the websocket listens one port above HTTP RPC
+pub fn websocket_url(url: &str) -> String {
+    let url = url
+        .replacen("https://", "wss://", 1)
+        .replacen("http://", "ws://", 1);
+    let Some(host_start) = url.find("://").map(|at| at + 3) else {
+        return url;
+    };
+    let host_end = url[host_start..]
+        .find('/')
+        .map_or(url.len(), |at| host_start + at);
+    match url[host_start..host_end].rsplit_once(':') {
+        Some((host, port)) => match port.parse::<u16>() {
+            Ok(port) => format!(
+                "{}{}:{}{}",
+                &url[..host_start],
+                host,
+                port.saturating_add(1),
+                &url[host_end..]
+            ),
+            Err(_) => url,
+        },
+        None => url,
+    }
+}
+
+pub(crate) fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T, HealthCheckError> {
+    T::try_deserialize(&mut &data[..]).map_err(HealthCheckError::Decode)
+}
+
+#[cfg(feature = "async")]
+mod nonblocking {
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::AccountDeserialize;
+    use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+    use txguard::{ArchiveRegistry, FailureCatalog, PriorityFeeStats, TransactionRegistry};
+
+    use super::{archive_address, decode, list_archives, NamespaceAddresses, TxGuardAccounts};
+    use crate::health::{AccountSource, HealthCheckError};
+
+    // Typed reads of a namespace's accounts; RpcClient in production, any
+    // AccountSource in tests
+    pub struct TxGuardClient<S = RpcClient> {
+        source: S,
+        pub addresses: NamespaceAddresses,
+    }
+
+    impl TxGuardClient {
+        // Reads the default namespace ""
+        pub fn new(rpc_url: String) -> Self {
+            Self::for_namespace(rpc_url, "")
+        }
+
+        pub fn for_namespace(rpc_url: String, namespace: &str) -> Self {
+            Self::with_source(RpcClient::new(rpc_url), namespace)
+        }
+    }
+
+    impl<S: AccountSource> TxGuardClient<S> {
+        pub fn with_source(source: S, namespace: &str) -> Self {
+            Self {
+                source,
+                addresses: NamespaceAddresses::derive(namespace),
+            }
+        }
+
+        pub async fn fetch_registry(&self) -> Result<TransactionRegistry, HealthCheckError> {
+            self.fetch(&self.addresses.registry).await
+        }
+
+        pub async fn fetch_catalog(&self) -> Result<FailureCatalog, HealthCheckError> {
+            self.fetch(&self.addresses.catalog).await
+        }
+
+        pub async fn fetch_priority_stats(&self) -> Result<PriorityFeeStats, HealthCheckError> {
+            self.fetch(&self.addresses.priority_stats).await
+        }
+
+        // One request per account, so a report can land between them
+        pub async fn fetch_all(&self) -> Result<TxGuardAccounts, HealthCheckError> {
+            Ok(TxGuardAccounts {
+                registry: self.fetch_registry().await?,
+                catalog: self.fetch_catalog().await?,
+                priority_stats: self.fetch_priority_stats().await?,
+            })
+        }
+
+        pub async fn list_archives(&self) -> Result<Vec<Pubkey>, HealthCheckError> {
+            let registry = self.fetch_registry().await?;
+            Ok(list_archives(&self.addresses.registry, registry.session_id))
+        }
+
+        pub async fn fetch_archive(
+            &self,
+            session_id: u64,
+        ) -> Result<ArchiveRegistry, HealthCheckError> {
+            self.fetch(&archive_address(&self.addresses.registry, session_id))
+                .await
+        }
+
+        async fn fetch<T: AccountDeserialize>(
+            &self,
+            address: &Pubkey,
+        ) -> Result<T, HealthCheckError> {
+            decode(&self.source.account_data(address).await?)
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use nonblocking::TxGuardClient;
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::AccountDeserialize;
+    use solana_rpc_client::rpc_client::RpcClient;
+    use txguard::{ArchiveRegistry, FailureCatalog, PriorityFeeStats, TransactionRegistry};
+
+    use super::{archive_address, decode, list_archives, NamespaceAddresses, TxGuardAccounts};
+    use crate::health::HealthCheckError;
+
+    // Same reads as the async TxGuardClient, for callers without a runtime
+    pub struct TxGuardClient {
+        rpc: RpcClient,
+        pub addresses: NamespaceAddresses,
+    }
+
+    impl TxGuardClient {
+        pub fn new(rpc_url: String) -> Self {
+            Self::for_namespace(rpc_url, "")
+        }
+
+        pub fn for_namespace(rpc_url: String, namespace: &str) -> Self {
+            Self {
+                rpc: RpcClient::new(rpc_url),
+                addresses: NamespaceAddresses::derive(namespace),
+            }
+        }
+
+        pub fn fetch_registry(&self) -> Result<TransactionRegistry, HealthCheckError> {
+            self.fetch(&self.addresses.registry)
+        }
+
+        pub fn fetch_catalog(&self) -> Result<FailureCatalog, HealthCheckError> {
+            self.fetch(&self.addresses.catalog)
+        }
+
+        pub fn fetch_priority_stats(&self) -> Result<PriorityFeeStats, HealthCheckError> {
+            self.fetch(&self.addresses.priority_stats)
+        }
+
+        pub fn fetch_all(&self) -> Result<TxGuardAccounts, HealthCheckError> {
+            Ok(TxGuardAccounts {
+                registry: self.fetch_registry()?,
+                catalog: self.fetch_catalog()?,
+                priority_stats: self.fetch_priority_stats()?,
+            })
+        }
+
+        pub fn list_archives(&self) -> Result<Vec<Pubkey>, HealthCheckError> {
+            let registry = self.fetch_registry()?;
+            Ok(list_archives(&self.addresses.registry, registry.session_id))
+        }
+
+        pub fn fetch_archive(&self, session_id: u64) -> Result<ArchiveRegistry, HealthCheckError> {
+            self.fetch(&archive_address(&self.addresses.registry, session_id))
+        }
+
+        fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T, HealthCheckError> {
+            let data = self
+                .rpc
+                .get_account_data(address)
+                .map_err(|err| HealthCheckError::Rpc(Box::new(err)))?;
+            decode(&data)
+        }
+    }
+}
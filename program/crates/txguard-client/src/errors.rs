@@ -0,0 +1,118 @@
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use solana_rpc_client_api::client_error::{Error as ClientError, ErrorKind};
+use solana_rpc_client_api::request::{RpcError, RpcResponseErrorData};
+
+// The error a failed instruction reported through Anchor, e.g. TxGuardError::Unauthorized
+// as ("Unauthorized", 6002, "Signer is not the config authority")
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramErrorLog {
+    pub name: String,
+    pub number: u32,
+    pub message: String,
+}
+
+// Anchor logs "AnchorError <origin>. Error Code: <name>. Error Number: <n>. Error Message: <msg>."
+// before failing; returns the last such line's error
+pub fn program_error_from_logs(logs: &[String]) -> Option<ProgramErrorLog> {
+    logs.iter().rev().find_map(|line| {
+        let (_, rest) = line.split_once("Error Code: ")?;
+        let (name, rest) = rest.split_once(". Error Number: ")?;
+        let (number, message) = rest.split_once(". Error Message: ")?;
+        Some(ProgramErrorLog {
+            name: name.to_string(),
+            number: number.parse().ok()?,
+            message: message.strip_suffix('.').unwrap_or(message).to_string(),
+        })
+    })
+}
+
+// Only preflight failures carry logs; a transaction sent with skip_preflight yields None
+pub fn program_error(err: &ClientError) -> Option<ProgramErrorLog> {
+    match err.kind() {
+        ErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+            ..
+        }) => program_error_from_logs(result.logs.as_deref().unwrap_or_default()),
+        _ => None,
+    }
+}
+
+// TxGuardError variant names in declaration order, so a custom error code minus
+// ERROR_CODE_OFFSET indexes them
+const ERROR_NAMES: &[&str] = &[
+    "InvalidPriorityFeeTier",
+    "CountOverflow",
+    "Unauthorized",
+    "MissingEpochRegistry",
+    "InvalidEpochRegistry",
+    "TooManyTierNames",
+    "InvalidTierName",
+    "NamespaceTooLong",
+    "NamespaceExists",
+    "InvalidPartialFill",
+    "ExportTooLarge",
+    "SelfMerge",
+    "InvalidRegistry",
+    "InvalidRentRecipient",
+    "InvalidNewOwner",
+    "DestinationRegistryExists",
+    "DuplicateReport",
+    "InvalidTierLamports",
+    "InvalidTargetProgram",
+    "MissingTargetInstruction",
+    "RateLimited",
+    "MissingTreasury",
+    "InsufficientTreasury",
+    "ReentrancyDetected",
+    "CrankTooEarly",
+    "SlotQuotaExceeded",
+    "DeadlineNotExceeded",
+    "InvalidValidityWindow",
+    "InvalidTierSnapshot",
+    "InvalidTrendWindow",
+    "InvalidTxSlot",
+    "InsufficientData",
+    "StaleReport",
+    "InvalidConfirmationSlots",
+    "InvalidTxSize",
+    "InvalidLookupUsage",
+    "BufferEmpty",
+    "InvalidFailureType",
+    "InvalidInstructionCount",
+    "InvalidSignerCount",
+    "ContradictoryReport",
+    "InvalidLanding",
+    "InvalidRecentContext",
+    "InsufficientPoints",
+    "RewardsDisabled",
+    "InvalidRewardMint",
+    "InvalidRewardAccount",
+    "ClaimTooEarly",
+    "InvalidCpiDepth",
+    "InvalidReputation",
+    "InvalidWindowIndex",
+    "ReportAlreadyFlagged",
+    "DisputeAlreadyOpen",
+    "InvalidDispute",
+    "DisputeEntryOverwritten",
+    "InvalidSnapshotAccount",
+    "InvalidSparklineBuckets",
+    "InvalidBufferSize",
+    "RegistryFull",
+    "NotObserver",
+    "InvalidOutcome",
+    "InvalidArchiveSlot",
+    "DisputeEntryArchived",
+    "ArchiveExists",
+    "InvalidSuccessRateThreshold",
+    "InvalidMigrationAccount",
+    "EmptyBatch",
+    "InvalidExportPage",
+];
+
+// Names a TxGuardError from its custom error code alone, for failures that came back
+// without logs; None for codes the program doesn't define
+pub fn error_name(code: u32) -> Option<&'static str> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)?;
+    ERROR_NAMES.get(index as usize).copied()
+}
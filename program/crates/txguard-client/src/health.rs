@@ -0,0 +1,200 @@
+use std::fmt;
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Error as ClientError;
+use txguard::{FailureCatalog, TransactionRegistry, OUTCOME_FAILURE, OUTCOME_SUCCESS};
+
+use crate::client::{decode, NamespaceAddresses};
+
+// Retry delays after a failed RPC call, doubling up to the cap
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+// Alert limits; a breach fires on every poll until the registry recovers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthThresholds {
+    pub min_success_rate_bps: u16,    // Over the registry's last-100 window
+    pub max_consecutive_failures: u8, // Most recent window entries that all failed
+    pub max_mev_rate_bps: u16,        // mev_detected / tx_count, all time
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HealthAlert {
+    LowSuccessRate {
+        success_rate_bps: u16,
+        min_success_rate_bps: u16,
+    },
+    ConsecutiveFailures {
+        count: u8,
+        max_consecutive_failures: u8,
+    },
+    HighMevRate {
+        mev_rate_bps: u16,
+        max_mev_rate_bps: u16,
+    },
+}
+
+#[derive(Debug)]
+pub enum HealthCheckError {
+    Rpc(Box<ClientError>),
+    MissingAccount(Pubkey),
+    Decode(anchor_lang::error::Error),
+}
+
+impl fmt::Display for HealthCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rpc(err) => write!(f, "RPC request failed: {}", err),
+            Self::MissingAccount(address) => write!(f, "account {} not found", address),
+            Self::Decode(err) => write!(f, "could not decode account: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for HealthCheckError {}
+
+// Where HealthChecker reads account data from; RpcClient in production, a map in tests
+#[allow(async_fn_in_trait)]
+pub trait AccountSource {
+    async fn account_data(&self, address: &Pubkey) -> Result<Vec<u8>, HealthCheckError>;
+}
+
+impl AccountSource for RpcClient {
+    async fn account_data(&self, address: &Pubkey) -> Result<Vec<u8>, HealthCheckError> {
+        self.get_account_data(address)
+            .await
+            .map_err(|err| HealthCheckError::Rpc(Box::new(err)))
+    }
+}
+
+// Polls a registry and its failure catalog and reports threshold breaches
+#[derive(Clone, Debug)]
+pub struct HealthChecker {
+    pub rpc_url: String,
+    pub registry_pubkey: Pubkey,
+    pub catalog_pubkey: Pubkey, // Source of the MEV count; the registry only keeps outcomes
+    pub thresholds: HealthThresholds,
+    pub poll_interval_secs: u64,
+}
+
+impl HealthChecker {
+    // Watches the namespace registry and catalog at [b"registry", namespace] and
+    // [b"catalog", namespace]
+    pub fn for_namespace(
+        rpc_url: String,
+        namespace: &str,
+        thresholds: HealthThresholds,
+        poll_interval_secs: u64,
+    ) -> Self {
+        let addresses = NamespaceAddresses::derive(namespace);
+        Self {
+            rpc_url,
+            registry_pubkey: addresses.registry,
+            catalog_pubkey: addresses.catalog,
+            thresholds,
+            poll_interval_secs,
+        }
+    }
+
+    // Polls forever. RPC failures are logged and retried with backoff; returns only when an
+    // account is missing or doesn't decode, which retrying won't fix
+    pub async fn run(&self, callback: impl Fn(HealthAlert)) -> Result<(), HealthCheckError> {
+        let rpc = RpcClient::new(self.rpc_url.clone());
+        self.run_with(&rpc, callback).await
+    }
+
+    pub async fn run_with(
+        &self,
+        source: &impl AccountSource,
+        callback: impl Fn(HealthAlert),
+    ) -> Result<(), HealthCheckError> {
+        let mut backoff = MIN_BACKOFF;
+        loop {
+            match self.check(source).await {
+                Ok(alerts) => {
+                    backoff = MIN_BACKOFF;
+                    for alert in alerts {
+                        callback(alert);
+                    }
+                }
+                Err(HealthCheckError::Rpc(err)) => {
+                    eprintln!("health check failed, retrying in {:?}: {}", backoff, err);
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    continue;
+                }
+                Err(err) => return Err(err),
+            }
+            tokio::time::sleep(Duration::from_secs(self.poll_interval_secs)).await;
+        }
+    }
+
+    // One poll: every threshold the accounts currently breach
+    pub async fn check(
+        &self,
+        source: &impl AccountSource,
+    ) -> Result<Vec<HealthAlert>, HealthCheckError> {
+        let registry: TransactionRegistry = fetch(source, &self.registry_pubkey).await?;
+        let catalog: FailureCatalog = fetch(source, &self.catalog_pubkey).await?;
+        Ok(evaluate(&self.thresholds, &registry, &catalog))
+    }
+}
+
+async fn fetch<T: AccountDeserialize>(
+    source: &impl AccountSource,
+    address: &Pubkey,
+) -> Result<T, HealthCheckError> {
+    decode(&source.account_data(address).await?)
+}
+
+pub fn evaluate(
+    thresholds: &HealthThresholds,
+    registry: &TransactionRegistry,
+    catalog: &FailureCatalog,
+) -> Vec<HealthAlert> {
+    let mut alerts = Vec::new();
+    let outcomes = registry.chronological_outcomes();
+
+    // An empty window has no rate to judge yet
+    if !outcomes.is_empty() {
+        let successes = outcomes
+            .iter()
+            .filter(|outcome| **outcome == OUTCOME_SUCCESS)
+            .count();
+        let success_rate_bps = (successes * 10_000 / outcomes.len()) as u16;
+        if success_rate_bps < thresholds.min_success_rate_bps {
+            alerts.push(HealthAlert::LowSuccessRate {
+                success_rate_bps,
+                min_success_rate_bps: thresholds.min_success_rate_bps,
+            });
+        }
+    }
+
+    let streak = outcomes
+        .iter()
+        .rev()
+        .take_while(|outcome| **outcome == OUTCOME_FAILURE)
+        .count();
+    let count = streak.min(u8::MAX as usize) as u8;
+    if count > thresholds.max_consecutive_failures {
+        alerts.push(HealthAlert::ConsecutiveFailures {
+            count,
+            max_consecutive_failures: thresholds.max_consecutive_failures,
+        });
+    }
+
+    if registry.tx_count > 0 {
+        let mev_rate_bps =
+            (catalog.mev_detected as u128 * 10_000 / registry.tx_count as u128).min(10_000) as u16;
+        if mev_rate_bps > thresholds.max_mev_rate_bps {
+            alerts.push(HealthAlert::HighMevRate {
+                mev_rate_bps,
+                max_mev_rate_bps: thresholds.max_mev_rate_bps,
+            });
+        }
+    }
+    alerts
+}
@@ -0,0 +1,151 @@
+//! Off-chain views over TxGuard accounts, for dashboards, metrics exporters and alerting.
+
+mod classify;
+mod client;
+mod errors;
+mod health;
+mod metrics;
+#[cfg(feature = "async")]
+mod sender;
+
+use std::fmt::Write;
+
+use anchor_lang::prelude::Pubkey;
+use txguard::{FailureCatalog, PriorityFeeStats, TransactionRegistry, FAILURE_KINDS};
+
+pub use classify::{classify_error, Classifier, FailureType, Matcher, Rule, DEFAULT_RULES};
+#[cfg(feature = "blocking")]
+pub use client::blocking;
+#[cfg(feature = "async")]
+pub use client::TxGuardClient;
+pub use client::{
+    archive_address, batch_report_instruction, list_archives, pack_reports, report_instruction,
+    websocket_url, DerivedMetrics, NamespaceAddresses, TxGuardAccounts,
+};
+pub use errors::{error_name, program_error, program_error_from_logs, ProgramErrorLog};
+pub use health::{
+    evaluate, AccountSource, HealthAlert, HealthCheckError, HealthChecker, HealthThresholds,
+};
+pub use metrics::{render_scrapes, NamespaceScrape};
+#[cfg(feature = "async")]
+pub use sender::{
+    outcome_params, GuardedSender, Landing, ReportDelivery, SendError, SenderConfig,
+    SentTransaction,
+};
+
+// Prometheus `type` label for each entry of FailureCatalog::counts_by_type
+pub const FAILURE_TYPE_NAMES: [&str; FAILURE_KINDS] = [
+    "slippage",
+    "liquidity",
+    "mev",
+    "dropped",
+    "insufficient_funds",
+    "other",
+    "bridge",
+    "deadline",
+    "wsol",
+    "token_frozen",
+    "stale_oracle_update",
+    "signature_verify_failure",
+    "version_mismatch",
+    "blockhash_not_found",
+    "cpi_depth_exceeded",
+    "nonce_account_expired",
+    "gas_price_spike",
+    "account_data_too_small",
+    "program_not_executable",
+    "wrong_token_program",
+];
+
+// The on-chain summary plus what get_summary leaves out: whose registry it is and
+// the full failure breakdown
+#[derive(Clone)]
+pub struct RegistrySummary {
+    pub wallet: Pubkey, // Registry owner: the user for user registries, else the namespace authority
+    pub summary: txguard::RegistrySummary,
+    pub failures_by_type: [(u8, u32); FAILURE_KINDS], // FailureCatalog::counts_by_type order
+}
+
+impl RegistrySummary {
+    pub fn from_accounts(
+        wallet: Pubkey,
+        registry: &TransactionRegistry,
+        catalog: &FailureCatalog,
+        stats: &PriorityFeeStats,
+    ) -> Self {
+        Self {
+            wallet,
+            summary: txguard::RegistrySummary::from_accounts(registry, catalog, stats),
+            failures_by_type: catalog.counts_by_type(),
+        }
+    }
+
+    // Prometheus text exposition format (version 0.0.4), one HELP/TYPE block per metric
+    pub fn to_prometheus_text(&self) -> String {
+        let wallet = format!("wallet=\"{}\"", self.wallet);
+        let summary = &self.summary;
+        let mut text = String::new();
+
+        for (name, kind, help, value) in [
+            (
+                "txguard_tx_total",
+                "counter",
+                "Outcomes recorded",
+                summary.tx_count,
+            ),
+            (
+                "txguard_success_total",
+                "counter",
+                "Successful outcomes recorded",
+                summary.success_count,
+            ),
+            (
+                "txguard_failure_total",
+                "counter",
+                "Failed outcomes recorded",
+                summary.failure_count,
+            ),
+            (
+                "txguard_success_rate_bps",
+                "gauge",
+                "All-time success rate, in basis points",
+                summary.success_rate_bps as u64,
+            ),
+            (
+                "txguard_optimal_tier",
+                "gauge",
+                "Priority fee tier with the best historical success rate",
+                summary.optimal_tier as u64,
+            ),
+            (
+                "txguard_unique_callers",
+                "gauge",
+                "Distinct wallets that reported, approximate past the first 50",
+                summary.unique_caller_count as u64,
+            ),
+        ] {
+            write_header(&mut text, name, kind, help);
+            let _ = writeln!(text, "{}{{{}}} {}", name, wallet, value);
+        }
+
+        write_header(
+            &mut text,
+            "txguard_failure_type",
+            "counter",
+            "Failures recorded by failure type",
+        );
+        for ((_, count), type_name) in self.failures_by_type.iter().zip(FAILURE_TYPE_NAMES) {
+            let _ = writeln!(
+                text,
+                "txguard_failure_type{{{},type=\"{}\"}} {}",
+                wallet, type_name, count
+            );
+        }
+        text
+    }
+}
+
+fn write_header(text: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(text, "# HELP {} {}", name, help);
+    let _ = writeln!(text, "# TYPE {} {}", name, kind);
+}
@@ -0,0 +1,154 @@
+use std::fmt::Write;
+
+use crate::client::TxGuardAccounts;
+use crate::{write_header, FAILURE_TYPE_NAMES};
+
+// A metric's value for one namespace's accounts and the current slot; None skips the sample
+type Sample = fn(&TxGuardAccounts, u64) -> Option<u64>;
+
+// One namespace as an exporter last read it
+#[derive(Clone)]
+pub struct NamespaceScrape {
+    pub deployment: String, // Operator-chosen name for the cluster or RPC it was read from
+    pub namespace: String,
+    pub read: Option<(TxGuardAccounts, u64)>, // Accounts and the RPC's slot; None when the read failed
+}
+
+// Prometheus text exposition for any number of namespaces. Each metric's samples are
+// grouped under a single HELP/TYPE block, as the format requires, and labelled with
+// deployment and namespace. A failed read only reports txguard_up 0: a stale value
+// would look current to alerting
+pub fn render_scrapes(scrapes: &[NamespaceScrape]) -> String {
+    let mut text = String::new();
+    write_header(
+        &mut text,
+        "txguard_up",
+        "gauge",
+        "Whether the last read of the namespace succeeded",
+    );
+    for scrape in scrapes {
+        let up = scrape.read.is_some() as u8;
+        let _ = writeln!(text, "txguard_up{{{}}} {}", labels(scrape), up);
+    }
+
+    let read: Vec<(String, &TxGuardAccounts, u64)> = scrapes
+        .iter()
+        .filter_map(|scrape| {
+            let (accounts, slot) = scrape.read.as_ref()?;
+            Some((labels(scrape), accounts, *slot))
+        })
+        .collect();
+
+    let families: [(&str, &str, &str, Sample); 7] = [
+        (
+            "txguard_tx_total",
+            "counter",
+            "Outcomes recorded",
+            |accounts, _| Some(accounts.registry.tx_count),
+        ),
+        (
+            "txguard_success_total",
+            "counter",
+            "Successful outcomes recorded",
+            |accounts, _| Some(accounts.registry.success_count),
+        ),
+        (
+            "txguard_failure_total",
+            "counter",
+            "Failed outcomes recorded",
+            |accounts, _| Some(accounts.registry.failure_count),
+        ),
+        (
+            "txguard_success_rate_bps",
+            "gauge",
+            "All-time success rate, in basis points",
+            |accounts, _| Some(accounts.registry.success_rate_bps() as u64),
+        ),
+        (
+            "txguard_success_rate_lower_bound_bps",
+            "gauge",
+            "Lower bound of the all-time success rate's 95% Wilson score interval, in basis points",
+            |accounts, _| Some(accounts.registry.success_rate_lower_bound_bps() as u64),
+        ),
+        (
+            "txguard_window_success_rate_bps",
+            "gauge",
+            "Success rate over the last-100 window, in basis points",
+            |accounts, _| {
+                let rate = accounts.metrics().window_success_rate_bps?;
+                Some(rate as u64)
+            },
+        ),
+        (
+            "txguard_data_age_slots",
+            "gauge",
+            "Slots since the newest report",
+            |accounts, slot| {
+                let last_report_slot = accounts.registry.last_report_slot;
+                (last_report_slot != 0).then(|| slot.saturating_sub(last_report_slot))
+            },
+        ),
+    ];
+    for (name, kind, help, value) in families {
+        write_header(&mut text, name, kind, help);
+        for (labels, accounts, slot) in &read {
+            if let Some(value) = value(accounts, *slot) {
+                let _ = writeln!(text, "{}{{{}}} {}", name, labels, value);
+            }
+        }
+    }
+
+    write_header(
+        &mut text,
+        "txguard_failure_type",
+        "counter",
+        "Failures recorded by failure type",
+    );
+    for (labels, accounts, _) in &read {
+        for ((_, count), type_name) in accounts
+            .catalog
+            .counts_by_type()
+            .iter()
+            .zip(FAILURE_TYPE_NAMES)
+        {
+            let _ = writeln!(
+                text,
+                "txguard_failure_type{{{},type=\"{}\"}} {}",
+                labels, type_name, count
+            );
+        }
+    }
+
+    write_header(
+        &mut text,
+        "txguard_tier_count",
+        "counter",
+        "Outcomes recorded by priority fee tier",
+    );
+    for (labels, accounts, _) in &read {
+        for (tier, count) in accounts.priority_stats.tiers.iter().enumerate() {
+            let _ = writeln!(
+                text,
+                "txguard_tier_count{{{},tier=\"{}\"}} {}",
+                labels, tier, count
+            );
+        }
+    }
+    text
+}
+
+fn labels(scrape: &NamespaceScrape) -> String {
+    format!(
+        "deployment=\"{}\",namespace=\"{}\"",
+        escape(&scrape.deployment),
+        escape(&scrape.namespace)
+    )
+}
+
+// Label values escape backslash, double quote and line feed
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,435 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anchor_lang::solana_program::instruction::error::InstructionError;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::{Error as ClientError, ErrorKind};
+use solana_rpc_client_api::config::{RpcSendTransactionConfig, RpcTransactionConfig};
+use solana_rpc_client_api::request::{RpcError, RpcResponseErrorData};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_transaction_status_client_types::option_serializer::OptionSerializer;
+use solana_transaction_status_client_types::{TransactionStatus, UiTransactionEncoding};
+use txguard::{TxGuardError, TxOutcomeParams, LANDING_ERRORED, LANDING_NEVER, LANDING_SUCCEEDED};
+
+use crate::classify::{Classifier, FailureType};
+use crate::client::report_instruction;
+
+// How a sent transaction ended up
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Landing {
+    Succeeded { slot: u64 },
+    Errored { slot: u64, err: TransactionError }, // Landed and failed; the fee was charged
+    Rejected(TransactionError),                   // Failed preflight, never reached a leader
+    Expired, // Its blockhash expired with no status for the signature
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReportDelivery {
+    Sent(Signature), // The report transaction's signature
+    AlreadyReported, // An earlier, interrupted attempt got the report in
+    Queued,          // Waiting in take_reports for the caller's next transaction
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SentTransaction {
+    pub signature: Signature,
+    pub landing: Landing,
+    pub failure_type: Option<FailureType>, // None when it succeeded
+    pub report: ReportDelivery,
+}
+
+#[derive(Clone, Debug)]
+pub struct SenderConfig {
+    pub commitment: CommitmentConfig, // What counts as landed, for both transactions
+    pub skip_preflight: bool,         // Preflight rejections are classified from their logs
+    pub piggyback: bool, // Queue reports for take_reports instead of sending them, saving a signature
+    pub poll_interval: Duration,
+    pub resend_interval: Duration, // Rebroadcast the unchanged transaction until it lands or expires
+}
+
+impl Default for SenderConfig {
+    fn default() -> Self {
+        Self {
+            commitment: CommitmentConfig::confirmed(),
+            skip_preflight: false,
+            piggyback: false,
+            poll_interval: Duration::from_millis(400),
+            resend_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SendError {
+    Rpc(Box<ClientError>), // The transaction stays in flight; flush picks it up again
+    Report(TransactionError), // The report transaction failed, e.g. on the report cooldown
+    ReportExpired,
+    InFlight(Signature), // Another send_and_report or flush is already driving this transaction
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rpc(err) => write!(f, "RPC request failed: {}", err),
+            Self::Report(err) => write!(f, "report transaction failed: {}", err),
+            Self::ReportExpired => write!(f, "report transaction expired before landing"),
+            Self::InFlight(signature) => write!(f, "{} is already being sent", signature),
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+impl From<ClientError> for SendError {
+    fn from(err: ClientError) -> Self {
+        Self::Rpc(Box::new(err))
+    }
+}
+
+// A transaction handed to send_and_report and not yet reported
+struct InFlight {
+    tx: Transaction,
+    tier: u8,
+    resolved: Option<(Landing, Option<FailureType>)>,
+    claimed: bool, // A live call is driving it; released by Claim even when that call is dropped
+}
+
+struct Claim<'a> {
+    sender: &'a GuardedSender,
+    signature: Signature,
+}
+
+impl Drop for Claim<'_> {
+    fn drop(&mut self) {
+        self.sender
+            .update(self.signature, |entry| entry.claimed = false);
+    }
+}
+
+// Sends a transaction, waits for it to land or expire, classifies the result and reports it.
+//
+// Cancel-safe: each transaction is tracked from before its first send until its report is
+// out. Dropping send_and_report part way leaves it in flight, and flush carries on from
+// the last finished step. Reports carry the signature's hash, so the program turns a
+// second report of the same transaction into AlreadyReported instead of a double count.
+// The tracking is in memory; it doesn't survive a restart.
+pub struct GuardedSender {
+    rpc: RpcClient,
+    reporter: Arc<Keypair>, // Pays for and signs reports; piggybacked ones name it as payer
+    namespace: String,
+    pub config: SenderConfig,
+    pub classifier: Classifier,
+    in_flight: Mutex<Vec<InFlight>>,
+    queued: Mutex<Vec<Instruction>>,
+}
+
+impl GuardedSender {
+    // Reports into the default namespace ""
+    pub fn new(rpc_url: String, reporter: Arc<Keypair>) -> Self {
+        Self::for_namespace(rpc_url, reporter, "", SenderConfig::default())
+    }
+
+    pub fn for_namespace(
+        rpc_url: String,
+        reporter: Arc<Keypair>,
+        namespace: &str,
+        config: SenderConfig,
+    ) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(rpc_url, config.commitment),
+            reporter,
+            namespace: namespace.to_string(),
+            config,
+            classifier: Classifier::default(),
+            in_flight: Mutex::new(Vec::new()),
+            queued: Mutex::new(Vec::new()),
+        }
+    }
+
+    // tx must be fully signed; its first signature identifies it from here on
+    pub async fn send_and_report(
+        &self,
+        tx: &Transaction,
+        tier: u8,
+    ) -> Result<SentTransaction, SendError> {
+        let signature = tx.signatures[0];
+        {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            if !in_flight
+                .iter()
+                .any(|entry| entry.tx.signatures[0] == signature)
+            {
+                in_flight.push(InFlight {
+                    tx: tx.clone(),
+                    tier,
+                    resolved: None,
+                    claimed: false,
+                });
+            }
+        }
+        let claim = self
+            .claim(signature)
+            .ok_or(SendError::InFlight(signature))?;
+        self.drive(claim).await
+    }
+
+    // Finishes whatever interrupted send_and_report calls left behind, oldest first
+    pub async fn flush(&self) -> Result<Vec<SentTransaction>, SendError> {
+        let mut finished = Vec::new();
+        for signature in self.in_flight() {
+            // Skips transactions a live call is still working on
+            if let Some(claim) = self.claim(signature) {
+                finished.push(self.drive(claim).await?);
+            }
+        }
+        Ok(finished)
+    }
+
+    pub fn in_flight(&self) -> Vec<Signature> {
+        let in_flight = self.in_flight.lock().unwrap();
+        in_flight
+            .iter()
+            .map(|entry| entry.tx.signatures[0])
+            .collect()
+    }
+
+    // Queued report instructions, for a transaction the reporter signs; empty unless piggybacking
+    pub fn take_reports(&self) -> Vec<Instruction> {
+        std::mem::take(&mut *self.queued.lock().unwrap())
+    }
+
+    fn claim(&self, signature: Signature) -> Option<Claim<'_>> {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        let entry = in_flight
+            .iter_mut()
+            .find(|entry| entry.tx.signatures[0] == signature && !entry.claimed)?;
+        entry.claimed = true;
+        Some(Claim {
+            sender: self,
+            signature,
+        })
+    }
+
+    async fn drive(&self, claim: Claim<'_>) -> Result<SentTransaction, SendError> {
+        let signature = claim.signature;
+        let (tx, tier, resolved) = {
+            let in_flight = self.in_flight.lock().unwrap();
+            let entry = in_flight
+                .iter()
+                .find(|entry| entry.tx.signatures[0] == signature)
+                .expect("claimed entries stay tracked until their claim finishes them");
+            (entry.tx.clone(), entry.tier, entry.resolved.clone())
+        };
+
+        let (landing, failure_type) = match resolved {
+            Some(resolved) => resolved,
+            None => {
+                let (landing, logs) = self.land(&tx).await?;
+                let failure_type = match &landing {
+                    Landing::Succeeded { .. } => None,
+                    Landing::Errored { err, .. } | Landing::Rejected(err) => {
+                        Some(self.classifier.classify(err, &logs))
+                    }
+                    Landing::Expired => Some(FailureType::Deadline),
+                };
+                self.update(signature, |entry| {
+                    entry.resolved = Some((landing.clone(), failure_type))
+                });
+                (landing, failure_type)
+            }
+        };
+
+        let params = outcome_params(&signature, &landing, failure_type, tier);
+        let ix = report_instruction(&self.namespace, &self.reporter.pubkey(), params);
+        let report = if self.config.piggyback {
+            self.queued.lock().unwrap().push(ix);
+            ReportDelivery::Queued
+        } else {
+            self.send_report(ix).await?
+        };
+        self.in_flight
+            .lock()
+            .unwrap()
+            .retain(|entry| entry.tx.signatures[0] != signature);
+
+        Ok(SentTransaction {
+            signature,
+            landing,
+            failure_type,
+            report,
+        })
+    }
+
+    async fn send_report(&self, ix: Instruction) -> Result<ReportDelivery, SendError> {
+        let (blockhash, _) = self
+            .rpc
+            .get_latest_blockhash_with_commitment(self.config.commitment)
+            .await?;
+        let payer = self.reporter.pubkey();
+        let tx =
+            Transaction::new_signed_with_payer(&[ix], Some(&payer), &[&*self.reporter], blockhash);
+        let duplicate = |err: &TransactionError| {
+            *err == TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(TxGuardError::DuplicateReport.into()),
+            )
+        };
+        match self.land(&tx).await?.0 {
+            Landing::Succeeded { .. } => Ok(ReportDelivery::Sent(tx.signatures[0])),
+            Landing::Errored { err, .. } | Landing::Rejected(err) if duplicate(&err) => {
+                Ok(ReportDelivery::AlreadyReported)
+            }
+            Landing::Errored { err, .. } | Landing::Rejected(err) => Err(SendError::Report(err)),
+            Landing::Expired => Err(SendError::ReportExpired),
+        }
+    }
+
+    // Sends tx and rebroadcasts it until it reaches the configured commitment or its
+    // blockhash expires; returns the failure's logs when there is one to classify
+    async fn land(&self, tx: &Transaction) -> Result<(Landing, Vec<String>), ClientError> {
+        let signature = tx.signatures[0];
+        let commitment = self.config.commitment;
+        let send = |skip_preflight: bool| {
+            let config = RpcSendTransactionConfig {
+                skip_preflight,
+                preflight_commitment: Some(commitment.commitment),
+                ..Default::default()
+            };
+            self.rpc.send_transaction_with_config(tx, config)
+        };
+
+        match send(self.config.skip_preflight).await {
+            Ok(_) => {}
+            Err(err) => match preflight_failure(&err) {
+                // Resent after an interruption; the status below says how it went
+                Some((TransactionError::AlreadyProcessed, _)) => {}
+                Some((err, logs)) => return Ok((Landing::Rejected(err), logs)),
+                // The transaction may still have reached a leader, so keep watching
+                None => {}
+            },
+        }
+
+        let mut last_send = Instant::now();
+        loop {
+            if let Some(status) = self.status(&signature).await? {
+                if status.satisfies_commitment(commitment) {
+                    return self.landed(&signature, status).await;
+                }
+            } else if !self
+                .rpc
+                .is_blockhash_valid(&tx.message.recent_blockhash, commitment)
+                .await?
+            {
+                // It may have landed between the two requests
+                return match self.status(&signature).await? {
+                    Some(status) if status.satisfies_commitment(commitment) => {
+                        self.landed(&signature, status).await
+                    }
+                    _ => Ok((Landing::Expired, Vec::new())),
+                };
+            }
+            if last_send.elapsed() >= self.config.resend_interval {
+                let _ = send(true).await;
+                last_send = Instant::now();
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    async fn status(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<TransactionStatus>, ClientError> {
+        let statuses = self.rpc.get_signature_statuses(&[*signature]).await?;
+        Ok(statuses.value.into_iter().next().flatten())
+    }
+
+    async fn landed(
+        &self,
+        signature: &Signature,
+        status: TransactionStatus,
+    ) -> Result<(Landing, Vec<String>), ClientError> {
+        let Some(err) = status.err else {
+            return Ok((Landing::Succeeded { slot: status.slot }, Vec::new()));
+        };
+        // getTransaction only serves confirmed or finalized transactions
+        let commitment = if self.config.commitment.is_at_least_confirmed() {
+            self.config.commitment
+        } else {
+            CommitmentConfig::confirmed()
+        };
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(commitment),
+            max_supported_transaction_version: Some(0),
+        };
+        // Without logs the transaction error alone still classifies most failures
+        let logs = match self
+            .rpc
+            .get_transaction_with_config(signature, config)
+            .await
+        {
+            Ok(fetched) => match fetched.transaction.meta.map(|meta| meta.log_messages) {
+                Some(OptionSerializer::Some(logs)) => logs,
+                _ => Vec::new(),
+            },
+            Err(_) => Vec::new(),
+        };
+        Ok((
+            Landing::Errored {
+                slot: status.slot,
+                err,
+            },
+            logs,
+        ))
+    }
+
+    fn update(&self, signature: Signature, change: impl FnOnce(&mut InFlight)) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if let Some(entry) = in_flight
+            .iter_mut()
+            .find(|entry| entry.tx.signatures[0] == signature)
+        {
+            change(entry);
+        }
+    }
+}
+
+// The report for one sent transaction. tx_sig_hash is the sha256 of the signature, so the
+// program rejects a second report of it while the hash is in its dedup ring.
+pub fn outcome_params(
+    signature: &Signature,
+    landing: &Landing,
+    failure_type: Option<FailureType>,
+    tier: u8,
+) -> TxOutcomeParams {
+    let (landing, tx_slot) = match landing {
+        Landing::Succeeded { slot } => (LANDING_SUCCEEDED, *slot),
+        Landing::Errored { slot, .. } => (LANDING_ERRORED, *slot),
+        Landing::Rejected(_) | Landing::Expired => (LANDING_NEVER, 0),
+    };
+    TxOutcomeParams {
+        success: landing == LANDING_SUCCEEDED,
+        failure_type: failure_type.map_or(txguard::FailureType::Slippage, Into::into),
+        priority_fee_tier: tier,
+        tx_sig_hash: hash(signature.as_ref()).to_bytes(),
+        tx_slot,
+        landing,
+        ..Default::default()
+    }
+}
+
+fn preflight_failure(err: &ClientError) -> Option<(TransactionError, Vec<String>)> {
+    match err.kind() {
+        ErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+            ..
+        }) => Some((result.err.clone()?, result.logs.clone().unwrap_or_default())),
+        _ => None,
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::Pubkey;
+use serde_json::Value;
+use solana_transaction_error::TransactionError;
+use txguard_client::{classify_error, Classifier, FailureType, Matcher, Rule, FAILURE_TYPE_NAMES};
+
+// Failed transactions as getTransaction and simulateTransaction return them: the err
+// field plus the log messages
+const FIXTURES: &str = include_str!("fixtures/tx_errors.json");
+
+fn fixtures() -> Vec<(String, TransactionError, Vec<String>, String)> {
+    let fixtures: Vec<Value> = serde_json::from_str(FIXTURES).unwrap();
+    fixtures
+        .into_iter()
+        .map(|fixture| {
+            (
+                fixture["name"].as_str().unwrap().to_string(),
+                serde_json::from_value(fixture["err"].clone()).unwrap(),
+                serde_json::from_value(fixture["logs"].clone()).unwrap(),
+                fixture["expected"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn fixtures_classify_to_their_expected_type() {
+    for (name, err, logs, expected) in fixtures() {
+        assert!(FAILURE_TYPE_NAMES.contains(&expected.as_str()), "{}", name);
+        assert_eq!(classify_error(&err, &logs).name(), expected, "{}", name);
+    }
+}
+
+#[test]
+fn added_rules_take_precedence() {
+    let router = Pubkey::new_unique();
+    let err: TransactionError =
+        serde_json::from_str(r#"{"InstructionError":[0,{"Custom":1}]}"#).unwrap();
+    let logs = [
+        format!("Program {} invoke [1]", router),
+        "Transfer: insufficient lamports 0, need 5000".to_string(),
+        format!("Program {} failed: custom program error: 0x1", router),
+    ];
+    assert_eq!(classify_error(&err, &logs), FailureType::InsufficientFunds);
+
+    let classifier = Classifier::default().with_rule(Rule {
+        matcher: Matcher::ProgramError {
+            program: router,
+            code: 1,
+        },
+        failure_type: FailureType::Liquidity,
+    });
+    assert_eq!(classifier.classify(&err, &logs), FailureType::Liquidity);
+    assert_eq!(FailureType::Liquidity.code(), 1);
+    // Same code from another program falls through to the defaults
+    assert_eq!(
+        classifier.classify(&err, &logs[1..2]),
+        FailureType::InsufficientFunds
+    );
+}
+
+#[test]
+fn failure_types_convert_to_the_program_argument() {
+    for (name, err, logs, _) in fixtures() {
+        let failure_type = classify_error(&err, &logs);
+        let argument = txguard::FailureType::from(failure_type);
+        assert_eq!(u8::from(argument), failure_type.code(), "{}", name);
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use solana_sdk::message::Message;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::transaction::Transaction;
+use txguard::{
+    FailureCatalog, PriorityFeeStats, TransactionRegistry, TxOutcomeParams, FAILURE_OTHER,
+    OUTCOME_FAILURE, OUTCOME_PENDING, OUTCOME_SUCCESS,
+};
+use txguard_client::{
+    archive_address, batch_report_instruction, blocking, list_archives, pack_reports,
+    websocket_url, AccountSource, DerivedMetrics, HealthCheckError, NamespaceAddresses,
+    TxGuardClient,
+};
+
+struct MockRpc(HashMap<Pubkey, Vec<u8>>);
+
+impl AccountSource for MockRpc {
+    async fn account_data(&self, address: &Pubkey) -> Result<Vec<u8>, HealthCheckError> {
+        self.0
+            .get(address)
+            .cloned()
+            .ok_or(HealthCheckError::MissingAccount(*address))
+    }
+}
+
+fn serialize(account: &impl AccountSerialize) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data
+}
+
+// 10 all-time reports, of which the window still holds the last 4
+fn accounts() -> (TransactionRegistry, FailureCatalog, PriorityFeeStats) {
+    let mut last_100_outcomes = vec![OUTCOME_PENDING; 100];
+    last_100_outcomes[..4].copy_from_slice(&[
+        OUTCOME_SUCCESS,
+        OUTCOME_FAILURE,
+        OUTCOME_FAILURE,
+        OUTCOME_SUCCESS,
+    ]);
+    let registry = TransactionRegistry {
+        tx_count: 10,
+        success_count: 7,
+        failure_count: 3,
+        last_100_outcomes,
+        cursor: 4,
+        ..Default::default()
+    };
+    let catalog = FailureCatalog {
+        slippage_exceeded: 2,
+        other: 1,
+        ..Default::default()
+    };
+    let stats = PriorityFeeStats {
+        tiers: vec![0, 4, 6, 0, 0],
+        tier_successes: vec![0, 3, 4, 0, 0],
+        ..Default::default()
+    };
+    (registry, catalog, stats)
+}
+
+#[test]
+fn derived_metrics_cover_rates_and_breakdown() {
+    let (registry, catalog, _) = accounts();
+    let metrics = DerivedMetrics::from_accounts(&registry, &catalog);
+
+    assert_eq!(metrics.success_rate_bps, 7_000);
+    assert_eq!(metrics.window_success_rate_bps, Some(5_000));
+    assert_eq!(metrics.failure_breakdown_bps[0], (0, 6_666));
+    assert_eq!(metrics.failure_breakdown_bps[5], (FAILURE_OTHER, 3_333));
+    assert_eq!(
+        metrics
+            .failure_breakdown_bps
+            .iter()
+            .filter(|(_, share)| *share > 0)
+            .count(),
+        2
+    );
+    assert_eq!(metrics.dominant_failure_type, Some(0));
+}
+
+#[test]
+fn derived_metrics_of_an_empty_namespace_have_no_rates() {
+    let metrics = DerivedMetrics::from_accounts(
+        &TransactionRegistry {
+            last_100_outcomes: vec![OUTCOME_PENDING; 100],
+            ..Default::default()
+        },
+        &FailureCatalog::default(),
+    );
+
+    assert_eq!(metrics.success_rate_bps, 0);
+    assert_eq!(metrics.window_success_rate_bps, None);
+    assert!(metrics
+        .failure_breakdown_bps
+        .iter()
+        .all(|(_, share)| *share == 0));
+    assert_eq!(metrics.dominant_failure_type, None);
+}
+
+#[tokio::test]
+async fn fetch_all_reads_the_namespace_pdas() {
+    let (registry, catalog, stats) = accounts();
+    let addresses = NamespaceAddresses::derive("team-a");
+    let rpc = MockRpc(HashMap::from([
+        (addresses.registry, serialize(&registry)),
+        (addresses.catalog, serialize(&catalog)),
+        (addresses.priority_stats, serialize(&stats)),
+    ]));
+    let client = TxGuardClient::with_source(rpc, "team-a");
+
+    let fetched = client.fetch_all().await.unwrap();
+    assert_eq!(fetched.registry.tx_count, 10);
+    assert_eq!(fetched.catalog.slippage_exceeded, 2);
+    assert_eq!(fetched.priority_stats.tiers, stats.tiers);
+    assert_eq!(
+        fetched.metrics(),
+        DerivedMetrics::from_accounts(&registry, &catalog)
+    );
+
+    // Another namespace's PDAs aren't in the mock
+    let other = TxGuardClient::with_source(MockRpc(HashMap::new()), "");
+    assert!(matches!(
+        other.fetch_registry().await,
+        Err(HealthCheckError::MissingAccount(address)) if address == NamespaceAddresses::derive("").registry
+    ));
+}
+
+#[tokio::test]
+async fn archives_are_listed_from_the_registry_session() {
+    let (mut registry, ..) = accounts();
+    registry.session_id = 2;
+    let addresses = NamespaceAddresses::derive("");
+    let archive = txguard::ArchiveRegistry {
+        registry: addresses.registry,
+        session_id: 1,
+        tx_count: 40,
+        success_count: 30,
+        failure_count: 10,
+        since_slot: 1_000,
+        archive_before_slot: 2_000,
+        archived_slot: 2_100,
+        total_fee_lamports: 0,
+        total_value_lamports: 0,
+        landing_counts: [0; 3],
+        sandwich_pattern_count: 0,
+        day_of_week_success: [0; 7],
+        day_of_week_failure: [0; 7],
+        failures_by_type: [0; txguard::FAILURE_KINDS],
+        partial_fill: 0,
+    };
+    let rpc = MockRpc(HashMap::from([
+        (addresses.registry, serialize(&registry)),
+        (archive_address(&addresses.registry, 1), serialize(&archive)),
+    ]));
+    let client = TxGuardClient::with_source(rpc, "");
+
+    let archives = client.list_archives().await.unwrap();
+    assert_eq!(archives, list_archives(&addresses.registry, 2));
+    let seeds = |session_id: u64| {
+        Pubkey::find_program_address(
+            &[
+                b"archive",
+                addresses.registry.as_ref(),
+                &session_id.to_le_bytes(),
+            ],
+            &txguard::ID,
+        )
+        .0
+    };
+    assert_eq!(archives, [seeds(0), seeds(1)]);
+    assert!(list_archives(&addresses.registry, 0).is_empty());
+
+    let fetched = client.fetch_archive(1).await.unwrap();
+    assert_eq!(
+        (fetched.session_id, fetched.tx_count, fetched.success_count),
+        (1, 40, 30)
+    );
+    assert!(matches!(
+        client.fetch_archive(0).await,
+        Err(HealthCheckError::MissingAccount(address)) if address == archives[0]
+    ));
+}
+
+#[test]
+fn pack_reports_fill_packets_in_order() {
+    let payer = Pubkey::new_unique();
+    let report = |nonce| TxOutcomeParams {
+        success: true,
+        nonce,
+        ..Default::default()
+    };
+    let packed = pack_reports("bots", &payer, (0..10).map(report).collect());
+    assert!(packed.len() > 1 && packed.len() < 10, "{}", packed.len());
+
+    // Every batch fits a packet, and would have been over it with the next report
+    let size = |reports: &[TxOutcomeParams]| {
+        let ix = batch_report_instruction("bots", &payer, reports.to_vec());
+        let message = Message::new(&[ix], Some(&payer));
+        Transaction::new_unsigned(message).message_data().len() + 1 + 64
+    };
+    for pair in packed.windows(2) {
+        assert!(size(&pair[0]) <= PACKET_DATA_SIZE);
+        let over = [pair[0].clone(), pair[1][..1].to_vec()].concat();
+        assert!(size(&over) > PACKET_DATA_SIZE);
+    }
+    let nonces: Vec<u64> = packed.concat().iter().map(|params| params.nonce).collect();
+    assert_eq!(nonces, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn websocket_url_is_one_port_above_rpc() {
+    for (rpc, websocket) in [
+        ("http://127.0.0.1:8899", "ws://127.0.0.1:8900"),
+        (
+            "https://rpc.example.com:443/key",
+            "wss://rpc.example.com:444/key",
+        ),
+        (
+            "https://api.devnet.solana.com",
+            "wss://api.devnet.solana.com",
+        ),
+    ] {
+        assert_eq!(websocket_url(rpc), websocket);
+    }
+}
+
+// Needs `anchor test` (or a validator with the program deployed and at least one
+// report in the default namespace): TXGUARD_RPC_URL=... cargo test -- --ignored
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn localnet_blocking_and_async_clients_agree() {
+    let rpc_url =
+        std::env::var("TXGUARD_RPC_URL").unwrap_or_else(|_| "http://127.0.0.1:8899".to_string());
+
+    let fetched = TxGuardClient::new(rpc_url.clone())
+        .fetch_all()
+        .await
+        .unwrap();
+    let blocking = tokio::task::spawn_blocking(move || {
+        blocking::TxGuardClient::new(rpc_url).fetch_all().unwrap()
+    })
+    .await
+    .unwrap();
+
+    let registry = &fetched.registry;
+    assert!(registry.tx_count > 0);
+    assert_eq!(fetched.priority_stats.tiers.len(), 5);
+    // Reports may land between the two fetches, but never roll the counters back
+    assert!(blocking.registry.tx_count >= registry.tx_count);
+    let metrics = fetched.metrics();
+    assert!(metrics.window_success_rate_bps.is_some());
+    assert!(metrics.success_rate_bps <= 10_000);
+}
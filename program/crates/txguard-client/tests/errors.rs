@@ -0,0 +1,41 @@
+use txguard::TxGuardError;
+use txguard_client::{error_name, program_error_from_logs, ProgramErrorLog};
+
+#[test]
+fn program_error_is_read_from_anchor_logs() {
+    let logs = [
+        "Program FxYDzyGPggfBeQsoLCJqmhAq9danG1qQJXaUjrWTwhp1 invoke [1]",
+        "Program log: Instruction: ResetFailureTypes",
+        "Program log: AnchorError caused by account: config. Error Code: Unauthorized. \
+         Error Number: 6002. Error Message: Signer is not the config authority.",
+        "Program FxYDzyGPggfBeQsoLCJqmhAq9danG1qQJXaUjrWTwhp1 failed: custom program error: 0x1772",
+    ]
+    .map(String::from);
+
+    assert_eq!(
+        program_error_from_logs(&logs),
+        Some(ProgramErrorLog {
+            name: TxGuardError::Unauthorized.name(),
+            number: TxGuardError::Unauthorized.into(),
+            message: TxGuardError::Unauthorized.to_string(),
+        })
+    );
+    assert_eq!(program_error_from_logs(&logs[..2]), None);
+}
+
+#[test]
+fn error_codes_map_to_variant_names() {
+    for error in [
+        TxGuardError::InvalidPriorityFeeTier,
+        TxGuardError::DuplicateReport,
+        TxGuardError::InvalidExportPage,
+    ] {
+        assert_eq!(
+            error_name(error.into()).map(String::from),
+            Some(error.name())
+        );
+    }
+    // One past the last variant, and codes below the Anchor offset
+    assert_eq!(error_name(u32::from(TxGuardError::InvalidExportPage) + 1), None);
+    assert_eq!(error_name(3012), None);
+}
@@ -0,0 +1,170 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use solana_rpc_client_api::client_error::{Error as ClientError, ErrorKind};
+use txguard::{
+    FailureCatalog, TransactionRegistry, OUTCOME_FAILURE, OUTCOME_PENDING, OUTCOME_SUCCESS,
+};
+use txguard_client::{
+    AccountSource, HealthAlert, HealthCheckError, HealthChecker, HealthThresholds,
+};
+
+// Serves fixed account data for a limited number of polls, then reports the registry
+// missing. The first `rpc_failures` polls fail like a dropped connection instead
+struct MockRpc {
+    accounts: HashMap<Pubkey, Vec<u8>>,
+    polls_left: Cell<u32>,
+    rpc_failures: Cell<u32>,
+}
+
+impl AccountSource for MockRpc {
+    async fn account_data(&self, address: &Pubkey) -> Result<Vec<u8>, HealthCheckError> {
+        // Each poll reads the registry first
+        if *address == checker().registry_pubkey {
+            if self.rpc_failures.get() > 0 {
+                self.rpc_failures.set(self.rpc_failures.get() - 1);
+                let err = ClientError::from(ErrorKind::Custom("connection reset".to_string()));
+                return Err(HealthCheckError::Rpc(Box::new(err)));
+            }
+            if self.polls_left.get() == 0 {
+                return Err(HealthCheckError::MissingAccount(*address));
+            }
+            self.polls_left.set(self.polls_left.get() - 1);
+        }
+        self.accounts
+            .get(address)
+            .cloned()
+            .ok_or(HealthCheckError::MissingAccount(*address))
+    }
+}
+
+fn checker() -> HealthChecker {
+    let thresholds = HealthThresholds {
+        min_success_rate_bps: 8_000,
+        max_consecutive_failures: 3,
+        max_mev_rate_bps: 1_000,
+    };
+    HealthChecker::for_namespace("http://localhost:8899".to_string(), "", thresholds, 0)
+}
+
+fn serialize(account: &impl AccountSerialize) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data
+}
+
+// Registry whose window holds `outcomes` oldest first, with a catalog of `mev_detected`
+fn mock(outcomes: &[u8], mev_detected: u32, polls: u32) -> MockRpc {
+    let mut last_100_outcomes = vec![OUTCOME_PENDING; 100];
+    last_100_outcomes[..outcomes.len()].copy_from_slice(outcomes);
+    let registry = TransactionRegistry {
+        tx_count: outcomes.len() as u64,
+        success_count: outcomes.iter().filter(|o| **o == OUTCOME_SUCCESS).count() as u64,
+        failure_count: outcomes.iter().filter(|o| **o == OUTCOME_FAILURE).count() as u64,
+        last_100_outcomes,
+        cursor: outcomes.len() as u8,
+        ..Default::default()
+    };
+    let catalog = FailureCatalog {
+        mev_detected,
+        ..Default::default()
+    };
+    let checker = checker();
+    MockRpc {
+        accounts: HashMap::from([
+            (checker.registry_pubkey, serialize(&registry)),
+            (checker.catalog_pubkey, serialize(&catalog)),
+        ]),
+        polls_left: Cell::new(polls),
+        rpc_failures: Cell::new(0),
+    }
+}
+
+#[tokio::test]
+async fn healthy_registry_raises_no_alerts() {
+    let outcomes = [
+        OUTCOME_SUCCESS,
+        OUTCOME_SUCCESS,
+        OUTCOME_FAILURE,
+        OUTCOME_SUCCESS,
+        OUTCOME_SUCCESS,
+    ];
+    let rpc = mock(&outcomes, 0, 1);
+    assert_eq!(checker().check(&rpc).await.unwrap(), vec![]);
+}
+
+#[tokio::test]
+async fn check_reports_every_breached_threshold() {
+    // 2 of 6 succeeded, the last 4 in a row failed, and 1 of 6 was MEV (1666 bps)
+    let outcomes = [
+        OUTCOME_SUCCESS,
+        OUTCOME_SUCCESS,
+        OUTCOME_FAILURE,
+        OUTCOME_FAILURE,
+        OUTCOME_FAILURE,
+        OUTCOME_FAILURE,
+    ];
+    let rpc = mock(&outcomes, 1, 1);
+    assert_eq!(
+        checker().check(&rpc).await.unwrap(),
+        vec![
+            HealthAlert::LowSuccessRate {
+                success_rate_bps: 3_333,
+                min_success_rate_bps: 8_000,
+            },
+            HealthAlert::ConsecutiveFailures {
+                count: 4,
+                max_consecutive_failures: 3,
+            },
+            HealthAlert::HighMevRate {
+                mev_rate_bps: 1_666,
+                max_mev_rate_bps: 1_000,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn run_invokes_the_callback_on_every_poll_until_an_account_is_missing() {
+    let outcomes = [
+        OUTCOME_SUCCESS,
+        OUTCOME_FAILURE,
+        OUTCOME_FAILURE,
+        OUTCOME_FAILURE,
+        OUTCOME_FAILURE,
+    ];
+    let rpc = mock(&outcomes, 0, 3);
+    let alerts = RefCell::new(Vec::new());
+
+    let result = checker()
+        .run_with(&rpc, |alert| alerts.borrow_mut().push(alert))
+        .await;
+
+    assert!(matches!(result, Err(HealthCheckError::MissingAccount(_))));
+    let alerts = alerts.into_inner();
+    // Low success rate and a 4-failure streak, once per successful poll
+    assert_eq!(alerts.len(), 6);
+    assert!(alerts
+        .iter()
+        .all(|alert| !matches!(alert, HealthAlert::HighMevRate { .. })));
+}
+
+#[tokio::test(start_paused = true)]
+async fn run_keeps_polling_after_an_rpc_failure() {
+    let outcomes = [OUTCOME_SUCCESS, OUTCOME_FAILURE, OUTCOME_FAILURE];
+    let rpc = mock(&outcomes, 0, 2);
+    rpc.rpc_failures.set(1);
+    let alerts = RefCell::new(Vec::new());
+
+    let result = checker()
+        .run_with(&rpc, |alert| alerts.borrow_mut().push(alert))
+        .await;
+
+    // Only the missing account ends the loop; the low success rate fired on both polls
+    // after the failed one
+    assert!(matches!(result, Err(HealthCheckError::MissingAccount(_))));
+    assert_eq!(rpc.rpc_failures.get(), 0);
+    assert_eq!(alerts.into_inner().len(), 2);
+}
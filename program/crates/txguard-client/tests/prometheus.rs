@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use anchor_lang::prelude::Pubkey;
+use txguard::{FailureCatalog, PriorityFeeStats, TransactionRegistry};
+use txguard_client::{render_scrapes, NamespaceScrape, RegistrySummary, TxGuardAccounts};
+
+fn summary() -> RegistrySummary {
+    let registry = TransactionRegistry {
+        tx_count: 1234,
+        success_count: 1172,
+        failure_count: 62,
+        unique_caller_count: 7,
+        ..Default::default()
+    };
+    let catalog = FailureCatalog {
+        slippage_exceeded: 20,
+        mev_detected: 42,
+        ..Default::default()
+    };
+    let mut stats = PriorityFeeStats::default();
+    stats.reset();
+    RegistrySummary::from_accounts(Pubkey::new_unique(), &registry, &catalog, &stats)
+}
+
+// Minimal exposition-format scanner: `name{labels} value` samples keyed by
+// "name{labels}", plus the declared TYPE of every metric name
+fn scan(text: &str) -> (HashMap<String, u64>, HashMap<String, String>) {
+    let mut samples = HashMap::new();
+    let mut types = HashMap::new();
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("# TYPE ") {
+            let (name, kind) = rest.split_once(' ').expect("TYPE line without a kind");
+            types.insert(name.to_string(), kind.to_string());
+        } else if line.starts_with("# HELP ") {
+            continue;
+        } else {
+            let (series, value) = line.rsplit_once(' ').expect("sample without a value");
+            let name = series.split('{').next().unwrap();
+            assert!(
+                types.contains_key(name),
+                "{} sampled before its TYPE line",
+                name
+            );
+            samples.insert(
+                series.to_string(),
+                value.parse().expect("non-integer value"),
+            );
+        }
+    }
+    (samples, types)
+}
+
+#[test]
+fn prometheus_text_exposes_totals_rate_and_failure_types() {
+    let summary = summary();
+    let text = summary.to_prometheus_text();
+    let (samples, types) = scan(&text);
+    let wallet = format!("wallet=\"{}\"", summary.wallet);
+
+    assert_eq!(samples[&format!("txguard_tx_total{{{}}}", wallet)], 1234);
+    assert_eq!(
+        samples[&format!("txguard_success_total{{{}}}", wallet)],
+        1172
+    );
+    assert_eq!(samples[&format!("txguard_failure_total{{{}}}", wallet)], 62);
+    assert_eq!(
+        samples[&format!("txguard_success_rate_bps{{{}}}", wallet)],
+        9497
+    );
+    assert_eq!(samples[&format!("txguard_unique_callers{{{}}}", wallet)], 7);
+    assert_eq!(
+        samples[&format!("txguard_failure_type{{{},type=\"mev\"}}", wallet)],
+        42
+    );
+    assert_eq!(
+        samples[&format!("txguard_failure_type{{{},type=\"slippage\"}}", wallet)],
+        20
+    );
+    assert_eq!(
+        samples[&format!("txguard_failure_type{{{},type=\"wsol\"}}", wallet)],
+        0
+    );
+
+    assert_eq!(types["txguard_tx_total"], "counter");
+    assert_eq!(types["txguard_success_rate_bps"], "gauge");
+    assert_eq!(types["txguard_failure_type"], "counter");
+    // Every metric gets exactly one HELP line, and the text ends with a newline
+    assert_eq!(text.matches("# HELP ").count(), types.len());
+    assert!(text.ends_with('\n'));
+}
+
+fn scrape(deployment: &str, namespace: &str, read: Option<u64>) -> NamespaceScrape {
+    let read = read.map(|slot| {
+        let registry = TransactionRegistry {
+            tx_count: 10,
+            success_count: 9,
+            failure_count: 1,
+            last_report_slot: 900,
+            ..Default::default()
+        };
+        let catalog = FailureCatalog {
+            slippage_exceeded: 1,
+            ..Default::default()
+        };
+        let mut priority_stats = PriorityFeeStats::default();
+        priority_stats.reset();
+        priority_stats.tiers[3] = 10;
+        let accounts = TxGuardAccounts {
+            registry,
+            catalog,
+            priority_stats,
+        };
+        (accounts, slot)
+    });
+    NamespaceScrape {
+        deployment: deployment.to_string(),
+        namespace: namespace.to_string(),
+        read,
+    }
+}
+
+#[test]
+fn scrapes_share_one_block_per_metric_across_namespaces() {
+    let text = render_scrapes(&[
+        scrape("mainnet", "", Some(1000)),
+        scrape("mainnet", "arb", Some(950)),
+        scrape("devnet", "", None),
+    ]);
+    let (samples, types) = scan(&text);
+    let main = "deployment=\"mainnet\",namespace=\"\"";
+    let arb = "deployment=\"mainnet\",namespace=\"arb\"";
+    let devnet = "deployment=\"devnet\",namespace=\"\"";
+
+    assert_eq!(samples[&format!("txguard_up{{{}}}", main)], 1);
+    assert_eq!(samples[&format!("txguard_up{{{}}}", devnet)], 0);
+    assert_eq!(samples[&format!("txguard_tx_total{{{}}}", arb)], 10);
+    assert_eq!(
+        samples[&format!("txguard_success_rate_bps{{{}}}", main)],
+        9000
+    );
+    // 9 in 10 is only reliably above 59.58%
+    assert_eq!(
+        samples[&format!("txguard_success_rate_lower_bound_bps{{{}}}", main)],
+        5958
+    );
+    assert_eq!(samples[&format!("txguard_data_age_slots{{{}}}", main)], 100);
+    assert_eq!(samples[&format!("txguard_data_age_slots{{{}}}", arb)], 50);
+    assert_eq!(
+        samples[&format!("txguard_failure_type{{{},type=\"slippage\"}}", arb)],
+        1
+    );
+    assert_eq!(
+        samples[&format!("txguard_tier_count{{{},tier=\"3\"}}", main)],
+        10
+    );
+    assert_eq!(
+        samples[&format!("txguard_tier_count{{{},tier=\"0\"}}", main)],
+        0
+    );
+    // A failed read only reports that it failed
+    let devnet_samples = samples.keys().filter(|series| series.contains(devnet));
+    assert_eq!(devnet_samples.count(), 1);
+
+    assert_eq!(types["txguard_up"], "gauge");
+    assert_eq!(types["txguard_data_age_slots"], "gauge");
+    assert_eq!(types["txguard_tier_count"], "counter");
+    assert_eq!(text.matches("# HELP ").count(), types.len());
+    assert_eq!(text.matches("# TYPE txguard_tx_total ").count(), 1);
+    assert!(text.ends_with('\n'));
+}
+
+#[test]
+fn scrapes_skip_data_age_before_the_first_report_and_escape_labels() {
+    let mut fresh = scrape("local \"test\"\\", "a\nb", Some(1000));
+    if let Some((accounts, _)) = &mut fresh.read {
+        accounts.registry.last_report_slot = 0;
+    }
+    let text = render_scrapes(&[fresh]);
+    let (samples, _) = scan(&text);
+    let labels = "deployment=\"local \\\"test\\\"\\\\\",namespace=\"a\\nb\"";
+
+    assert_eq!(samples[&format!("txguard_up{{{}}}", labels)], 1);
+    assert!(!text.contains("txguard_data_age_slots{"));
+    assert_eq!(
+        text.lines().filter(|line| line.contains("a\\nb")).count(),
+        samples.len()
+    );
+}
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::{hash, Hash};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_system_interface::instruction as system_instruction;
+use txguard::{LANDING_ERRORED, LANDING_NEVER, LANDING_SUCCEEDED};
+use txguard_client::{
+    outcome_params, FailureType, GuardedSender, Landing, ReportDelivery, SenderConfig,
+};
+
+#[test]
+fn outcome_params_follow_the_landing() {
+    let signature = Signature::from([7; 64]);
+    let landed = outcome_params(&signature, &Landing::Succeeded { slot: 90 }, None, 3);
+    assert!(landed.success);
+    assert_eq!(
+        (landed.landing, landed.tx_slot, landed.priority_fee_tier),
+        (LANDING_SUCCEEDED, 90, 3)
+    );
+    assert_eq!(landed.tx_sig_hash, hash(&[7; 64]).to_bytes());
+
+    let errored = outcome_params(
+        &signature,
+        &Landing::Errored {
+            slot: 91,
+            err: TransactionError::InstructionError(0, InstructionError::Custom(6001)),
+        },
+        Some(FailureType::Slippage),
+        1,
+    );
+    assert!(!errored.success);
+    assert_eq!(
+        (errored.landing, errored.tx_slot, errored.failure_type),
+        (LANDING_ERRORED, 91, txguard::FailureType::Slippage)
+    );
+
+    // Neither reached a block, so there is no slot to declare
+    for never in [
+        Landing::Rejected(TransactionError::InsufficientFundsForFee),
+        Landing::Expired,
+    ] {
+        let params = outcome_params(&signature, &never, Some(FailureType::Deadline), 0);
+        assert_eq!(
+            (params.success, params.landing, params.tx_slot),
+            (false, LANDING_NEVER, 0)
+        );
+        assert_eq!(params.failure_type, txguard::FailureType::Deadline);
+    }
+}
+
+// The tests below need a validator with TxGuard deployed and the default namespace
+// initialized: TXGUARD_RPC_URL=... cargo test -- --ignored
+fn rpc_url() -> String {
+    std::env::var("TXGUARD_RPC_URL").unwrap_or_else(|_| "http://127.0.0.1:8899".to_string())
+}
+
+async fn funded_keypair(rpc: &RpcClient) -> Arc<Keypair> {
+    let keypair = Keypair::new();
+    let signature = rpc
+        .request_airdrop(&keypair.pubkey(), 2 * LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+    while !rpc.confirm_transaction(&signature).await.unwrap() {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    Arc::new(keypair)
+}
+
+async fn transfer(rpc: &RpcClient, from: &Keypair, lamports: u64) -> Transaction {
+    let ix = system_instruction::transfer(&from.pubkey(), &Pubkey::new_unique(), lamports);
+    let blockhash = rpc.get_latest_blockhash().await.unwrap();
+    Transaction::new_signed_with_payer(&[ix], Some(&from.pubkey()), &[from], blockhash)
+}
+
+async fn setup(config: SenderConfig) -> (RpcClient, Arc<Keypair>, GuardedSender) {
+    let rpc = RpcClient::new_with_commitment(rpc_url(), CommitmentConfig::confirmed());
+    let reporter = funded_keypair(&rpc).await;
+    let sender = GuardedSender::for_namespace(rpc_url(), reporter.clone(), "", config);
+    (rpc, reporter, sender)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn localnet_reports_a_landed_transfer() {
+    let (rpc, reporter, sender) = setup(SenderConfig::default()).await;
+    let tx = transfer(&rpc, &reporter, LAMPORTS_PER_SOL / 100).await;
+
+    let sent = sender.send_and_report(&tx, 2).await.unwrap();
+    assert_eq!(sent.signature, tx.signatures[0]);
+    assert!(matches!(sent.landing, Landing::Succeeded { .. }));
+    assert_eq!(sent.failure_type, None);
+    assert!(matches!(sent.report, ReportDelivery::Sent(_)));
+    assert!(sender.in_flight().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn localnet_classifies_a_preflight_rejection() {
+    let (rpc, reporter, sender) = setup(SenderConfig::default()).await;
+    // More than the airdrop: the system program logs "insufficient lamports"
+    let tx = transfer(&rpc, &reporter, 10 * LAMPORTS_PER_SOL).await;
+
+    let sent = sender.send_and_report(&tx, 0).await.unwrap();
+    assert!(matches!(sent.landing, Landing::Rejected(_)));
+    assert_eq!(sent.failure_type, Some(FailureType::InsufficientFunds));
+    assert!(matches!(sent.report, ReportDelivery::Sent(_)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn localnet_reports_an_expired_blockhash_as_a_deadline_failure() {
+    let config = SenderConfig {
+        skip_preflight: true,
+        ..SenderConfig::default()
+    };
+    let (_, reporter, sender) = setup(config).await;
+    // A blockhash no leader knows: the transaction never lands and the hash is never valid
+    let ix = system_instruction::transfer(&reporter.pubkey(), &Pubkey::new_unique(), 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&reporter.pubkey()),
+        &[&*reporter],
+        Hash::new_unique(),
+    );
+
+    let sent = sender.send_and_report(&tx, 1).await.unwrap();
+    assert_eq!(sent.landing, Landing::Expired);
+    assert_eq!(sent.failure_type, Some(FailureType::Deadline));
+    assert!(matches!(sent.report, ReportDelivery::Sent(_)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn localnet_flush_finishes_a_cancelled_send() {
+    let (rpc, reporter, sender) = setup(SenderConfig::default()).await;
+    let tx = transfer(&rpc, &reporter, LAMPORTS_PER_SOL / 100).await;
+
+    // Dropped at its first RPC round trip
+    let cancelled = tokio::time::timeout(Duration::ZERO, sender.send_and_report(&tx, 4)).await;
+    assert!(cancelled.is_err());
+    assert_eq!(sender.in_flight(), vec![tx.signatures[0]]);
+
+    let finished = sender.flush().await.unwrap();
+    assert_eq!(finished.len(), 1);
+    assert!(matches!(finished[0].landing, Landing::Succeeded { .. }));
+    assert!(sender.in_flight().is_empty());
+    assert!(sender.flush().await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn localnet_piggybacked_report_rides_the_next_transaction() {
+    let config = SenderConfig {
+        piggyback: true,
+        ..SenderConfig::default()
+    };
+    let (rpc, reporter, sender) = setup(config).await;
+    let tx = transfer(&rpc, &reporter, LAMPORTS_PER_SOL / 100).await;
+
+    let sent = sender.send_and_report(&tx, 2).await.unwrap();
+    assert_eq!(sent.report, ReportDelivery::Queued);
+    let mut ixs = sender.take_reports();
+    assert_eq!(ixs.len(), 1);
+    assert!(sender.take_reports().is_empty());
+
+    ixs.push(system_instruction::transfer(
+        &reporter.pubkey(),
+        &Pubkey::new_unique(),
+        LAMPORTS_PER_SOL / 100,
+    ));
+    let blockhash = rpc.get_latest_blockhash().await.unwrap();
+    let next = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&reporter.pubkey()),
+        &[&*reporter],
+        blockhash,
+    );
+    rpc.send_and_confirm_transaction(&next).await.unwrap();
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use serde::Deserialize;
+use txguard::MAX_NAMESPACE_LEN;
+
+// txguard-exporter.toml
+//
+//   listen = "0.0.0.0:9464"
+//   interval_secs = 15
+//
+//   [[deployment]]
+//   name = "mainnet"
+//   url = "https://api.mainnet-beta.solana.com"
+//   namespaces = ["", "arb"]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default = "default_listen")]
+    pub listen: SocketAddr,
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64, // How often every namespace is read
+    #[serde(default, rename = "deployment")]
+    pub deployments: Vec<Deployment>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Deployment {
+    pub name: String, // The deployment label on every sample
+    pub url: String,
+    #[serde(default = "default_namespaces")]
+    pub namespaces: Vec<String>,
+}
+
+fn default_listen() -> SocketAddr {
+    ([0, 0, 0, 0], 9464).into()
+}
+
+fn default_interval_secs() -> u64 {
+    15
+}
+
+fn default_namespaces() -> Vec<String> {
+    vec![String::new()]
+}
+
+impl Config {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {}", path, err))?;
+        let config: Self = toml::from_str(&text).map_err(|err| format!("{}: {}", path, err))?;
+        config
+            .validate()
+            .map_err(|err| format!("{}: {}", path, err))?;
+        Ok(config)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.interval_secs == 0 {
+            return Err("interval_secs must be at least 1".to_string());
+        }
+        if self.deployments.is_empty() {
+            return Err("no [[deployment]] to export".to_string());
+        }
+        let mut names = HashSet::new();
+        for deployment in &self.deployments {
+            if !names.insert(&deployment.name) {
+                return Err(format!("deployment {:?} is listed twice", deployment.name));
+            }
+            if deployment.namespaces.is_empty() {
+                return Err(format!(
+                    "deployment {:?} has no namespaces",
+                    deployment.name
+                ));
+            }
+            let mut namespaces = HashSet::new();
+            for namespace in &deployment.namespaces {
+                if namespace.len() > MAX_NAMESPACE_LEN {
+                    return Err(format!(
+                        "namespace {:?} is longer than {} bytes",
+                        namespace, MAX_NAMESPACE_LEN
+                    ));
+                }
+                if !namespaces.insert(namespace) {
+                    return Err(format!(
+                        "namespace {:?} is listed twice in deployment {:?}",
+                        namespace, deployment.name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::future::join_all;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::RwLock;
+use txguard_client::{render_scrapes, NamespaceScrape, TxGuardClient};
+
+use crate::config::Config;
+
+const MAX_REQUEST_BYTES: usize = 8192;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+// One namespace of one deployment, and the clients it is read with
+struct Target {
+    deployment: String,
+    namespace: String,
+    rpc: Arc<RpcClient>, // Shared by the deployment's namespaces, for the current slot
+    client: TxGuardClient,
+}
+
+pub fn export(config: Config) -> io::Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(run(config))
+}
+
+async fn run(config: Config) -> io::Result<()> {
+    let listener = TcpListener::bind(config.listen).await?;
+    println!("Serving /metrics on {}", listener.local_addr()?);
+
+    let targets = targets(&config);
+    // Served until the first round of reads finishes
+    let metrics = Arc::new(RwLock::new(render_scrapes(&[])));
+    let poller = tokio::spawn(poll(targets, config.interval(), metrics.clone()));
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    tokio::spawn(serve(stream, metrics.clone()));
+                }
+                Err(err) => eprintln!("error: {}", err),
+            },
+        }
+    }
+    poller.abort();
+    Ok(())
+}
+
+fn targets(config: &Config) -> Vec<Target> {
+    let mut targets = Vec::new();
+    for deployment in &config.deployments {
+        let rpc = Arc::new(RpcClient::new(deployment.url.clone()));
+        for namespace in &deployment.namespaces {
+            targets.push(Target {
+                deployment: deployment.name.clone(),
+                namespace: namespace.clone(),
+                rpc: rpc.clone(),
+                client: TxGuardClient::for_namespace(deployment.url.clone(), namespace),
+            });
+        }
+    }
+    targets
+}
+
+// Reads every namespace at once each interval and swaps in the rendered text
+async fn poll(targets: Vec<Target>, interval: Duration, metrics: Arc<RwLock<String>>) {
+    let mut ticks = tokio::time::interval(interval);
+    ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticks.tick().await;
+        let scrapes = join_all(targets.iter().map(read)).await;
+        *metrics.write().await = render_scrapes(&scrapes);
+    }
+}
+
+async fn read(target: &Target) -> NamespaceScrape {
+    let accounts = target
+        .client
+        .fetch_all()
+        .await
+        .map_err(|err| err.to_string());
+    // The slot is read after the accounts so a report can't look newer than it
+    let read = match accounts {
+        Ok(accounts) => match target.rpc.get_slot().await {
+            Ok(slot) => Ok((accounts, slot)),
+            Err(err) => Err(format!("RPC request failed: {}", err)),
+        },
+        Err(err) => Err(err),
+    };
+    let read = read
+        .map_err(|err| {
+            eprintln!(
+                "error: {} namespace {:?}: {}",
+                target.deployment, target.namespace, err
+            )
+        })
+        .ok();
+    NamespaceScrape {
+        deployment: target.deployment.clone(),
+        namespace: target.namespace.clone(),
+        read,
+    }
+}
+
+// Just enough HTTP/1.1 for a Prometheus scrape: GET /metrics, one response per connection
+async fn serve(mut stream: TcpStream, metrics: Arc<RwLock<String>>) {
+    let Ok(Ok(request)) = tokio::time::timeout(REQUEST_TIMEOUT, read_head(&mut stream)).await
+    else {
+        return;
+    };
+    let mut request_line = request.lines().next().unwrap_or("").split(' ');
+    let (method, target) = (request_line.next(), request_line.next());
+    let path = target.map(|target| target.split('?').next().unwrap_or(target));
+    let response = match (method, path) {
+        (Some("GET"), Some("/metrics")) => {
+            let body = metrics.read().await.clone();
+            response("200 OK", "text/plain; version=0.0.4; charset=utf-8", &body)
+        }
+        (Some("GET"), _) => response("404 Not Found", "text/plain", "Not found; try /metrics\n"),
+        _ => response(
+            "405 Method Not Allowed",
+            "text/plain",
+            "Only GET is supported\n",
+        ),
+    };
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+// The request line and headers, up to the blank line that ends them
+async fn read_head(stream: &mut TcpStream) -> io::Result<String> {
+    let mut head = Vec::new();
+    let mut buf = [0; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut buf).await?;
+        if read == 0 || head.len() + read > MAX_REQUEST_BYTES {
+            break;
+        }
+        head.extend_from_slice(&buf[..read]);
+    }
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
+
+fn response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+}
@@ -0,0 +1,74 @@
+//! Prometheus exporter: reads the TxGuard accounts of every configured namespace on an
+//! interval and serves them on /metrics.
+
+mod config;
+mod exporter;
+
+use std::process::ExitCode;
+
+use config::Config;
+
+const USAGE: &str = "\
+Usage: txguard-exporter [OPTIONS]
+
+Serves the registry, failure catalog and priority fee stats of one or more TxGuard
+namespaces as Prometheus metrics on /metrics, labelled by deployment and namespace.
+
+Options:
+  -c, --config <PATH>  Config file listing the deployments and namespaces [default: txguard-exporter.toml]
+  -h, --help           Print this help";
+
+struct Args {
+    config: String,
+    help: bool,
+}
+
+fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        config: "txguard-exporter.toml".to_string(),
+        help: false,
+    };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => parsed.help = true,
+            "-c" | "--config" => {
+                parsed.config = args
+                    .next()
+                    .ok_or_else(|| format!("{} needs a value", arg))?
+            }
+            flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+            extra => return Err(format!("unexpected argument {}", extra)),
+        }
+    }
+    Ok(parsed)
+}
+
+fn main() -> ExitCode {
+    let args = match parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    if args.help {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let config = match Config::load(&args.config) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    match exporter::export(config) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
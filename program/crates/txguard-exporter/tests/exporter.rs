@@ -0,0 +1,149 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Output, Stdio};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+fn txguard_exporter(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_txguard-exporter"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+// A config file unique to the test, under the target directory
+fn write_config(test: &str, text: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.toml", test));
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+fn get(port: u16, path: &str) -> Option<String> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).ok()?;
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).ok()?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+    Some(response)
+}
+
+// Kills the exporter even when an assertion fails
+struct Exporter(Child);
+
+impl Drop for Exporter {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+#[test]
+fn help_prints_usage() {
+    let output = txguard_exporter(&["--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Usage: txguard-exporter"));
+}
+
+#[test]
+fn invalid_arguments_exit_with_usage() {
+    for (args, message) in [
+        (&["--config"][..], "--config needs a value"),
+        (&["--verbose"][..], "unknown option --verbose"),
+        (&["serve"][..], "unexpected argument serve"),
+    ] {
+        let output = txguard_exporter(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        let stderr = stderr(&output);
+        assert!(stderr.contains(message), "{:?}: {}", args, stderr);
+        assert!(stderr.contains("Usage: txguard-exporter"));
+    }
+}
+
+#[test]
+fn invalid_config_fails_before_serving() {
+    let deployment = "[[deployment]]\nname = \"local\"\nurl = \"http://127.0.0.1:1\"\n";
+    for (test, text, message) in [
+        (
+            "no_deployments",
+            "interval_secs = 5\n",
+            "no [[deployment]] to export",
+        ),
+        (
+            "zero_interval",
+            &format!("interval_secs = 0\n{}", deployment),
+            "interval_secs must be at least 1",
+        ),
+        (
+            "duplicate_deployment",
+            &format!("{}{}", deployment, deployment),
+            "deployment \"local\" is listed twice",
+        ),
+        (
+            "duplicate_namespace",
+            &format!("{}namespaces = [\"arb\", \"arb\"]\n", deployment),
+            "namespace \"arb\" is listed twice",
+        ),
+        (
+            "long_namespace",
+            &format!("{}namespaces = [\"{}\"]\n", deployment, "n".repeat(33)),
+            "is longer than 32 bytes",
+        ),
+        ("unknown_field", "port = 9464\n", "unknown field `port`"),
+    ] {
+        let path = write_config(test, text);
+        let output = txguard_exporter(&["--config", path.to_str().unwrap()]);
+        assert_eq!(output.status.code(), Some(1), "{}", test);
+        let stderr = stderr(&output);
+        assert!(stderr.contains(message), "{}: {}", test, stderr);
+    }
+
+    let output = txguard_exporter(&["--config", "/nonexistent/txguard-exporter.toml"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("could not read /nonexistent/txguard-exporter.toml"));
+}
+
+#[test]
+fn metrics_report_unreachable_namespaces_as_down() {
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let path = write_config(
+        "unreachable",
+        &format!(
+            "listen = \"127.0.0.1:{}\"\ninterval_secs = 1\n\n\
+             [[deployment]]\nname = \"local\"\nurl = \"http://127.0.0.1:1\"\nnamespaces = [\"\", \"arb\"]\n",
+            port
+        ),
+    );
+    let _exporter = Exporter(
+        Command::new(env!("CARGO_BIN_EXE_txguard-exporter"))
+            .args(["--config", path.to_str().unwrap()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap(),
+    );
+
+    let deadline = Instant::now() + Duration::from_secs(30);
+    let metrics = loop {
+        match get(port, "/metrics") {
+            Some(response) if response.contains("txguard_up{") => break response,
+            _ if Instant::now() > deadline => panic!("no metrics served on port {}", port),
+            _ => sleep(Duration::from_millis(100)),
+        }
+    };
+    assert!(metrics.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(metrics.contains("Content-Type: text/plain; version=0.0.4"));
+    assert!(metrics.contains("txguard_up{deployment=\"local\",namespace=\"\"} 0\n"));
+    assert!(metrics.contains("txguard_up{deployment=\"local\",namespace=\"arb\"} 0\n"));
+    assert!(!metrics.contains("txguard_tx_total{"));
+
+    let missing = get(port, "/").unwrap();
+    assert!(missing.starts_with("HTTP/1.1 404 Not Found\r\n"));
+}
@@ -0,0 +1,204 @@
+use std::env;
+use std::str::FromStr;
+use std::time::Duration;
+
+use txguard::MAX_NAMESPACE_LEN;
+use txguard_indexer::GroupBy;
+
+pub const USAGE: &str = "\
+Usage: txguard-indexer <COMMAND> [OPTIONS]
+
+Keeps the history TxGuard's accounts don't: every report sent to a namespace's registry,
+and the registry and fee tier counters on an interval, in a SQLite database.
+
+Commands:
+  run                      Index new reports and snapshot the counters every --interval seconds
+  query                    Summarize the indexed reports
+
+Options:
+      --db <PATH>          SQLite database, created if missing [default: txguard-index.sqlite]
+  -n, --namespace <NAME>   TxGuard namespace [default: \"\"]
+  -h, --help               Print this help
+
+Run options:
+  -u, --url <URL>          RPC URL or moniker: localhost, devnet, testnet, mainnet-beta [default: localhost]
+      --interval <SECS>    Time between passes [default: 60]
+      --once               Make one pass and exit
+
+Query options:
+      --since <AGE>        How far back to look, e.g. 90m, 24h, 7d [default: 24h]
+      --group-by <KEY>     hour, day, tier or failure-type [default: hour]";
+
+pub struct Args {
+    pub db: String,
+    pub namespace: String,
+    pub command: Command,
+    pub help: bool,
+}
+
+pub enum Command {
+    Run(RunOptions),
+    Query(QueryOptions),
+    None, // Only with --help
+}
+
+pub struct RunOptions {
+    pub url: String,
+    pub interval: Duration,
+    pub once: bool,
+}
+
+pub struct QueryOptions {
+    pub since: Duration,
+    pub group_by: GroupBy,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut db = "txguard-index.sqlite".to_string();
+    let mut namespace = String::new();
+    let mut help = false;
+    let mut command = None;
+    let mut run = RunOptions {
+        url: "localhost".to_string(),
+        interval: Duration::from_secs(60),
+        once: false,
+    };
+    let mut query = QueryOptions {
+        since: Duration::from_secs(24 * 3600),
+        group_by: GroupBy::Hour,
+    };
+    // Command-specific flags seen, to reject them under the other command
+    let mut run_flags = Vec::new();
+    let mut query_flags = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "-h" | "--help" => help = true,
+            "--db" => db = value()?,
+            "-n" | "--namespace" => namespace = value()?,
+            "-u" | "--url" => {
+                run.url = value()?;
+                run_flags.push(arg);
+            }
+            "--interval" => {
+                run.interval = parse_secs("--interval", &value()?)?;
+                run_flags.push(arg);
+            }
+            "--once" => {
+                run.once = true;
+                run_flags.push(arg);
+            }
+            "--since" => {
+                query.since = parse_age(&value()?)?;
+                query_flags.push(arg);
+            }
+            "--group-by" => {
+                query.group_by = match value()?.as_str() {
+                    "hour" => GroupBy::Hour,
+                    "day" => GroupBy::Day,
+                    "tier" => GroupBy::Tier,
+                    "failure-type" => GroupBy::FailureType,
+                    other => {
+                        return Err(format!(
+                            "--group-by expects hour, day, tier or failure-type, got {:?}",
+                            other
+                        ))
+                    }
+                };
+                query_flags.push(arg);
+            }
+            flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+            name if command.is_none() => match name {
+                "run" | "query" => command = Some(arg),
+                _ => return Err(format!("unknown command {}", name)),
+            },
+            extra => return Err(format!("unexpected argument {}", extra)),
+        }
+    }
+    if namespace.len() > MAX_NAMESPACE_LEN {
+        return Err(format!(
+            "--namespace is longer than {} bytes",
+            MAX_NAMESPACE_LEN
+        ));
+    }
+
+    let command = match command.as_deref() {
+        Some("run") => {
+            if let Some(flag) = query_flags.first() {
+                return Err(format!("{} only applies to query", flag));
+            }
+            run.url = resolve_url(&run.url);
+            Command::Run(run)
+        }
+        Some(_) => {
+            if let Some(flag) = run_flags.first() {
+                return Err(format!("{} only applies to run", flag));
+            }
+            Command::Query(query)
+        }
+        None if help => Command::None,
+        None => return Err("no command given".to_string()),
+    };
+    Ok(Args {
+        db: expand_home(&db),
+        namespace,
+        command,
+        help,
+    })
+}
+
+fn parse_number<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a whole number, got {:?}", flag, value))
+}
+
+fn parse_secs(flag: &str, value: &str) -> Result<Duration, String> {
+    match parse_number(flag, value)? {
+        0 => Err(format!("{} must be at least 1", flag)),
+        secs => Ok(Duration::from_secs(secs)),
+    }
+}
+
+// A count of seconds, minutes, hours or days, e.g. 24h
+fn parse_age(value: &str) -> Result<Duration, String> {
+    let invalid = || {
+        format!(
+            "--since expects an age like 90m, 24h or 7d, got {:?}",
+            value
+        )
+    };
+    let unit = match value.chars().last() {
+        Some('s') => 1,
+        Some('m') => 60,
+        Some('h') => 3600,
+        Some('d') => 86_400,
+        _ => return Err(invalid()),
+    };
+    let count: u64 = value[..value.len() - 1].parse().map_err(|_| invalid())?;
+    count
+        .checked_mul(unit)
+        .map(Duration::from_secs)
+        .ok_or_else(invalid)
+}
+
+// Same monikers as `solana --url`
+fn resolve_url(url: &str) -> String {
+    match url {
+        "l" | "localhost" => "http://127.0.0.1:8899",
+        "d" | "devnet" => "https://api.devnet.solana.com",
+        "t" | "testnet" => "https://api.testnet.solana.com",
+        "m" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{}/{}", home, rest),
+        _ => path.to_string(),
+    }
+}
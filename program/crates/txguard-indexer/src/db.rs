@@ -0,0 +1,300 @@
+use std::fmt;
+
+use rusqlite::{params, Connection, Transaction, TransactionBehavior};
+use txguard::FAILURE_CODES;
+use txguard_client::{TxGuardAccounts, FAILURE_TYPE_NAMES};
+
+use crate::decode::Report;
+
+// One entry per schema version, applied in order and never edited once released;
+// PRAGMA user_version holds how many have run
+const MIGRATIONS: &[&str] = &[
+    // v1
+    "CREATE TABLE cursors (
+        namespace TEXT PRIMARY KEY,
+        signature TEXT NOT NULL -- Newest transaction indexed
+    );
+    CREATE TABLE outcomes (
+        namespace TEXT NOT NULL,
+        signature TEXT NOT NULL,
+        report_index INTEGER NOT NULL, -- Position among the transaction's reports
+        slot INTEGER NOT NULL,
+        block_time INTEGER NOT NULL, -- Unix seconds
+        success INTEGER NOT NULL,
+        priority_fee_tier INTEGER NOT NULL,
+        fee_lamports INTEGER NOT NULL,
+        PRIMARY KEY (namespace, signature, report_index)
+    );
+    CREATE INDEX outcomes_by_time ON outcomes (namespace, block_time);
+    CREATE TABLE failures (
+        namespace TEXT NOT NULL,
+        signature TEXT NOT NULL,
+        report_index INTEGER NOT NULL,
+        block_time INTEGER NOT NULL,
+        failure_type INTEGER NOT NULL,
+        PRIMARY KEY (namespace, signature, report_index)
+    );
+    CREATE INDEX failures_by_time ON failures (namespace, block_time);
+    CREATE TABLE snapshots (
+        id INTEGER PRIMARY KEY,
+        namespace TEXT NOT NULL,
+        taken_at INTEGER NOT NULL, -- Unix seconds
+        slot INTEGER NOT NULL,
+        tx_count INTEGER NOT NULL,
+        success_count INTEGER NOT NULL,
+        failure_count INTEGER NOT NULL
+    );
+    CREATE INDEX snapshots_by_time ON snapshots (namespace, taken_at);
+    CREATE TABLE fee_tiers (
+        snapshot_id INTEGER NOT NULL REFERENCES snapshots (id),
+        tier INTEGER NOT NULL,
+        count INTEGER NOT NULL,
+        successes INTEGER NOT NULL,
+        PRIMARY KEY (snapshot_id, tier)
+    );",
+];
+
+pub const SCHEMA_VERSION: i64 = MIGRATIONS.len() as i64;
+
+#[derive(Debug)]
+pub enum DatabaseError {
+    Sqlite(rusqlite::Error),
+    NewerSchema(i64), // Written by a later indexer; this one would misread it
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sqlite(err) => write!(f, "database error: {}", err),
+            Self::NewerSchema(version) => write!(
+                f,
+                "database schema v{} is newer than this indexer's v{}",
+                version, SCHEMA_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DatabaseError {}
+
+impl From<rusqlite::Error> for DatabaseError {
+    fn from(err: rusqlite::Error) -> Self {
+        Self::Sqlite(err)
+    }
+}
+
+// A transaction that touched the registry, with whatever reports it carried
+pub struct IndexedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: i64,
+    pub reports: Vec<Report>, // Empty for failed transactions and other instructions
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupBy {
+    Hour,
+    Day,
+    Tier,
+    FailureType,
+}
+
+// One row of a grouped query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub key: String, // UTC hour or day, tier number or failure type name
+    pub reports: u64,
+    pub successes: u64,
+}
+
+pub struct Database {
+    connection: Connection,
+}
+
+impl Database {
+    // Opens or creates the file and brings its schema up to date
+    pub fn open(path: &str) -> Result<Self, DatabaseError> {
+        let connection = Connection::open(path)?;
+        // Another indexer or a query may hold the write lock for a moment
+        connection.busy_timeout(std::time::Duration::from_secs(5))?;
+        let database = Self { connection };
+        database.migrate()?;
+        Ok(database)
+    }
+
+    pub fn schema_version(&self) -> Result<i64, DatabaseError> {
+        let version = self
+            .connection
+            .query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        Ok(version)
+    }
+
+    fn migrate(&self) -> Result<(), DatabaseError> {
+        let version = self.schema_version()?;
+        if version > SCHEMA_VERSION {
+            return Err(DatabaseError::NewerSchema(version));
+        }
+        for (applied, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+            let transaction = self.transaction()?;
+            transaction.execute_batch(migration)?;
+            transaction.pragma_update(None, "user_version", applied as i64 + 1)?;
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+
+    // Newest indexed signature for the namespace, None before its first run
+    pub fn cursor(&self, namespace: &str) -> Result<Option<String>, DatabaseError> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT signature FROM cursors WHERE namespace = ?1")?;
+        let mut cursor = statement.query_map([namespace], |row| row.get(0))?;
+        Ok(cursor.next().transpose()?)
+    }
+
+    // A write transaction, rolled back if dropped before commit
+    fn transaction(&self) -> rusqlite::Result<Transaction<'_>> {
+        Transaction::new_unchecked(&self.connection, TransactionBehavior::Immediate)
+    }
+
+    // Writes the transaction's reports and moves the cursor to it, all or nothing.
+    // Rows already present are left alone, so indexing a transaction twice is harmless.
+    // Returns the number of reports newly written
+    pub fn record_transaction(
+        &self,
+        namespace: &str,
+        tx: &IndexedTransaction,
+    ) -> Result<usize, DatabaseError> {
+        let transaction = self.transaction()?;
+        let mut written = 0;
+        for (index, report) in tx.reports.iter().enumerate() {
+            written += transaction.execute(
+                "INSERT OR IGNORE INTO outcomes (namespace, signature, report_index, slot,
+                    block_time, success, priority_fee_tier, fee_lamports)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    namespace,
+                    tx.signature,
+                    index as i64,
+                    tx.slot as i64,
+                    tx.block_time,
+                    report.success,
+                    report.priority_fee_tier,
+                    report.fee_lamports as i64,
+                ],
+            )?;
+            if !report.success {
+                transaction.execute(
+                    "INSERT OR IGNORE INTO failures (namespace, signature, report_index,
+                        block_time, failure_type)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        namespace,
+                        tx.signature,
+                        index as i64,
+                        tx.block_time,
+                        report.failure_type,
+                    ],
+                )?;
+            }
+        }
+        transaction.execute(
+            "INSERT INTO cursors (namespace, signature) VALUES (?1, ?2)
+             ON CONFLICT (namespace) DO UPDATE SET signature = excluded.signature",
+            params![namespace, tx.signature],
+        )?;
+        transaction.commit()?;
+        Ok(written)
+    }
+
+    // The namespace's counters as read at `slot`, with one fee_tiers row per tier
+    pub fn record_snapshot(
+        &self,
+        namespace: &str,
+        taken_at: i64,
+        slot: u64,
+        accounts: &TxGuardAccounts,
+    ) -> Result<(), DatabaseError> {
+        let transaction = self.transaction()?;
+        let registry = &accounts.registry;
+        transaction.execute(
+            "INSERT INTO snapshots (namespace, taken_at, slot, tx_count, success_count,
+                failure_count)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                namespace,
+                taken_at,
+                slot as i64,
+                registry.tx_count as i64,
+                registry.success_count as i64,
+                registry.failure_count as i64,
+            ],
+        )?;
+        let snapshot_id = transaction.last_insert_rowid();
+        let stats = &accounts.priority_stats;
+        for (tier, count) in stats.tiers.iter().enumerate() {
+            let successes = stats.tier_successes.get(tier).copied().unwrap_or(0);
+            transaction.execute(
+                "INSERT INTO fee_tiers (snapshot_id, tier, count, successes)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![snapshot_id, tier as i64, *count as i64, successes as i64],
+            )?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    // Reports since the Unix time `since`, oldest bucket first. Grouped by failure type,
+    // only failures are counted, most frequent first
+    pub fn outcome_buckets(
+        &self,
+        namespace: &str,
+        since: i64,
+        group_by: GroupBy,
+    ) -> Result<Vec<Bucket>, DatabaseError> {
+        if group_by == GroupBy::FailureType {
+            let mut statement = self.connection.prepare(
+                "SELECT failure_type, COUNT(*) FROM failures
+                 WHERE namespace = ?1 AND block_time >= ?2
+                 GROUP BY failure_type ORDER BY COUNT(*) DESC, failure_type",
+            )?;
+            let rows = statement.query_map(params![namespace, since], |row| {
+                Ok(Bucket {
+                    key: failure_type_name(row.get(0)?).to_string(),
+                    reports: row.get::<_, i64>(1)? as u64,
+                    successes: 0,
+                })
+            })?;
+            return Ok(rows.collect::<Result<_, _>>()?);
+        }
+
+        // Dates sort as text; tiers need their number
+        let (key, order) = match group_by {
+            GroupBy::Hour => ("strftime('%Y-%m-%d %H:00', block_time, 'unixepoch')", "1"),
+            GroupBy::Day => ("strftime('%Y-%m-%d', block_time, 'unixepoch')", "1"),
+            _ => ("CAST(priority_fee_tier AS TEXT)", "MIN(priority_fee_tier)"),
+        };
+        let sql = format!(
+            "SELECT {key}, COUNT(*), SUM(success) FROM outcomes
+             WHERE namespace = ?1 AND block_time >= ?2
+             GROUP BY 1 ORDER BY {order}",
+        );
+        let mut statement = self.connection.prepare(&sql)?;
+        let rows = statement.query_map(params![namespace, since], |row| {
+            Ok(Bucket {
+                key: row.get(0)?,
+                reports: row.get::<_, i64>(1)? as u64,
+                successes: row.get::<_, i64>(2)? as u64,
+            })
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+}
+
+// FAILURE_TYPE_NAMES entry for a failure_type code; unassigned codes count as other
+pub fn failure_type_name(code: i64) -> &'static str {
+    FAILURE_CODES
+        .iter()
+        .position(|known| *known as i64 == code)
+        .map_or("other", |index| FAILURE_TYPE_NAMES[index])
+}
@@ -0,0 +1,103 @@
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status_client_types::InnerInstructions;
+use txguard::instruction::{
+    RecordInlineSuccess, RegisterTxOutcome, RegisterTxOutcomeDual, RegisterTxOutcomeV2,
+    RegisterTxOutcomeWithMetadata, RegisterTxOutcomesBatch,
+};
+use txguard::{FailureType, Outcome, TxOutcomeParams};
+
+// The part of a report the indexer keeps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub success: bool,
+    pub failure_type: u8, // Only meaningful for failures
+    pub priority_fee_tier: u8,
+    pub fee_lamports: u64, // 0 when the instruction doesn't carry it
+}
+
+impl From<&TxOutcomeParams> for Report {
+    fn from(params: &TxOutcomeParams) -> Self {
+        Self {
+            success: params.success,
+            failure_type: params.failure_type.into(),
+            priority_fee_tier: params.priority_fee_tier,
+            fee_lamports: params.fee_lamports,
+        }
+    }
+}
+
+// The reports carried by a TxGuard instruction's data: none unless it is one of the
+// reporting instructions and names `namespace`, several for a batch
+pub fn decode_reports(data: &[u8], namespace: &str) -> Vec<Report> {
+    decode(data)
+        .filter(|(reported_namespace, _)| reported_namespace == namespace)
+        .map(|(_, reports)| reports)
+        .unwrap_or_default()
+}
+
+fn decode(data: &[u8]) -> Option<(String, Vec<Report>)> {
+    let (discriminator, mut args) = data.split_at_checked(8)?;
+    let (namespace, report) = if discriminator == RegisterTxOutcome::DISCRIMINATOR {
+        let (namespace, outcome, failure_type, priority_fee_tier) =
+            <(String, Outcome, FailureType, u8)>::deserialize(&mut args).ok()?;
+        let report = Report {
+            success: outcome != Outcome::Failure,
+            failure_type: failure_type.into(),
+            priority_fee_tier,
+            fee_lamports: 0,
+        };
+        (namespace, report)
+    } else if [
+        RegisterTxOutcomeV2::DISCRIMINATOR,
+        RegisterTxOutcomeWithMetadata::DISCRIMINATOR,
+        RegisterTxOutcomeDual::DISCRIMINATOR,
+    ]
+    .contains(&discriminator)
+    {
+        // All three start with the namespace and params; metadata after them is ignored
+        let (namespace, params) = <(String, TxOutcomeParams)>::deserialize(&mut args).ok()?;
+        (namespace, Report::from(&params))
+    } else if discriminator == RegisterTxOutcomesBatch::DISCRIMINATOR {
+        let (namespace, batch) = <(String, Vec<TxOutcomeParams>)>::deserialize(&mut args).ok()?;
+        return Some((namespace, batch.iter().map(Report::from).collect()));
+    } else if discriminator == RecordInlineSuccess::DISCRIMINATOR {
+        let (namespace, priority_fee_tier) = <(String, u8)>::deserialize(&mut args).ok()?;
+        let report = Report {
+            success: true,
+            failure_type: 0,
+            priority_fee_tier,
+            fee_lamports: 0,
+        };
+        (namespace, report)
+    } else {
+        return None;
+    };
+    Some((namespace, vec![report]))
+}
+
+// Every report for `namespace` in a transaction, in the order they ran: each top-level
+// instruction, then the CPIs it made. `account_keys` includes any addresses loaded from
+// lookup tables, which CPI targets may come from
+pub fn reports_in(
+    account_keys: &[Pubkey],
+    instructions: &[CompiledInstruction],
+    inner: &[InnerInstructions],
+    namespace: &str,
+) -> Vec<Report> {
+    let report = |ix: &CompiledInstruction| match account_keys.get(ix.program_id_index as usize) {
+        Some(program) if *program == txguard::ID => decode_reports(&ix.data, namespace),
+        _ => Vec::new(),
+    };
+    let mut reports = Vec::new();
+    for (index, ix) in instructions.iter().enumerate() {
+        reports.extend(report(ix));
+        let cpis = inner
+            .iter()
+            .filter(|inner| inner.index as usize == index)
+            .flat_map(|inner| &inner.instructions);
+        reports.extend(cpis.flat_map(|cpi| report(&cpi.instruction)));
+    }
+    reports
+}
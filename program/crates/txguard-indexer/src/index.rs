@@ -0,0 +1,168 @@
+use std::str::FromStr;
+use std::thread::sleep;
+
+use solana_rpc_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_rpc_client_api::config::RpcTransactionConfig;
+use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status_client_types::option_serializer::OptionSerializer;
+use solana_transaction_status_client_types::{
+    InnerInstruction, InnerInstructions, UiInstruction, UiTransactionEncoding,
+};
+use txguard_client::blocking::TxGuardClient;
+use txguard_indexer::{reports_in, Database, IndexedTransaction};
+
+use crate::args::RunOptions;
+use crate::{now, IndexerError};
+
+const SIGNATURES_PER_PAGE: usize = 1000; // getSignaturesForAddress's maximum
+
+struct Indexer {
+    rpc: RpcClient,
+    client: TxGuardClient,
+    namespace: String,
+    db: Database,
+}
+
+// Finalized data only, so nothing indexed can be rolled back. Each transaction's rows
+// and the cursor move together, so stopping at any point loses nothing
+pub fn run(db: Database, namespace: String, options: RunOptions) -> Result<(), IndexerError> {
+    let commitment = CommitmentConfig::finalized();
+    let indexer = Indexer {
+        rpc: RpcClient::new_with_commitment(options.url.clone(), commitment),
+        client: TxGuardClient::for_namespace(options.url, &namespace),
+        namespace,
+        db,
+    };
+    loop {
+        match indexer.pass() {
+            Ok((transactions, reports)) => println!(
+                "Indexed {} report(s) from {} transaction(s)",
+                reports, transactions
+            ),
+            Err(err) if options.once => return Err(err),
+            // Retried from the cursor on the next pass
+            Err(err) => eprintln!("error: {}", err),
+        }
+        if options.once {
+            return Ok(());
+        }
+        sleep(options.interval);
+    }
+}
+
+impl Indexer {
+    fn pass(&self) -> Result<(usize, usize), IndexerError> {
+        let indexed = self.catch_up()?;
+        let accounts = self.client.fetch_all()?;
+        let slot = self.rpc.get_slot()?;
+        self.db
+            .record_snapshot(&self.namespace, now(), slot, &accounts)?;
+        Ok(indexed)
+    }
+
+    // Indexes every registry transaction after the cursor, oldest first. The first pass
+    // reaches back as far as the RPC keeps history
+    fn catch_up(&self) -> Result<(usize, usize), IndexerError> {
+        let until = self
+            .db
+            .cursor(&self.namespace)?
+            .and_then(|signature| Signature::from_str(&signature).ok());
+        let mut statuses = Vec::new();
+        let mut before = None;
+        loop {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(SIGNATURES_PER_PAGE),
+                commitment: Some(CommitmentConfig::finalized()),
+            };
+            let page = self
+                .rpc
+                .get_signatures_for_address_with_config(&self.client.addresses.registry, config)?;
+            let more = page.len() == SIGNATURES_PER_PAGE;
+            before = page.last().and_then(|status| status.signature.parse().ok());
+            statuses.extend(page);
+            if !more {
+                break;
+            }
+        }
+
+        let mut reports = 0;
+        for status in statuses.iter().rev() {
+            let tx = self.fetch(status)?;
+            reports += self.db.record_transaction(&self.namespace, &tx)?;
+        }
+        Ok((statuses.len(), reports))
+    }
+
+    // Failed transactions changed nothing, so only their place in the history is kept
+    fn fetch(
+        &self,
+        status: &RpcConfirmedTransactionStatusWithSignature,
+    ) -> Result<IndexedTransaction, IndexerError> {
+        let mut tx = IndexedTransaction {
+            signature: status.signature.clone(),
+            slot: status.slot,
+            block_time: status.block_time.unwrap_or_else(now),
+            reports: Vec::new(),
+        };
+        if status.err.is_some() {
+            return Ok(tx);
+        }
+        let signature = Signature::from_str(&status.signature)
+            .map_err(|_| IndexerError::Decode(status.signature.clone()))?;
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::finalized()),
+            max_supported_transaction_version: Some(0),
+        };
+        let fetched = self.rpc.get_transaction_with_config(&signature, config)?;
+        let Some(decoded) = fetched.transaction.transaction.decode() else {
+            return Err(IndexerError::Decode(status.signature.clone()));
+        };
+
+        let mut account_keys = decoded.message.static_account_keys().to_vec();
+        let mut inner = Vec::new();
+        if let Some(meta) = fetched.transaction.meta {
+            if let OptionSerializer::Some(loaded) = meta.loaded_addresses {
+                let loaded = loaded.writable.iter().chain(&loaded.readonly);
+                account_keys.extend(loaded.filter_map(|key| Pubkey::from_str(key).ok()));
+            }
+            if let OptionSerializer::Some(inner_instructions) = meta.inner_instructions {
+                inner = inner_instructions
+                    .into_iter()
+                    .map(|ui| InnerInstructions {
+                        index: ui.index,
+                        instructions: ui.instructions.iter().filter_map(compiled).collect(),
+                    })
+                    .collect();
+            }
+        }
+        tx.reports = reports_in(
+            &account_keys,
+            decoded.message.instructions(),
+            &inner,
+            &self.namespace,
+        );
+        Ok(tx)
+    }
+}
+
+// Base64-encoded transactions list their CPIs compiled, with base58 data
+fn compiled(instruction: &UiInstruction) -> Option<InnerInstruction> {
+    let UiInstruction::Compiled(ui) = instruction else {
+        return None;
+    };
+    Some(InnerInstruction {
+        instruction: CompiledInstruction {
+            program_id_index: ui.program_id_index,
+            accounts: ui.accounts.clone(),
+            data: bs58::decode(&ui.data).into_vec().ok()?,
+        },
+        stack_height: ui.stack_height,
+    })
+}
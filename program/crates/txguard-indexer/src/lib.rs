@@ -0,0 +1,10 @@
+//! Storage and decoding for txguard-indexer: the SQLite schema and its queries, and the
+//! reports found in a transaction's TxGuard instructions.
+
+mod db;
+mod decode;
+
+pub use db::{
+    failure_type_name, Bucket, Database, DatabaseError, GroupBy, IndexedTransaction, SCHEMA_VERSION,
+};
+pub use decode::{decode_reports, reports_in, Report};
@@ -0,0 +1,139 @@
+//! Historical indexer: copies each report sent to a namespace's registry, and snapshots
+//! of its counters, into SQLite so they can be queried over time.
+
+mod args;
+mod index;
+
+use std::fmt;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use solana_rpc_client_api::client_error::Error as ClientError;
+use txguard_client::HealthCheckError;
+use txguard_indexer::{Bucket, Database, DatabaseError, GroupBy};
+
+use args::{Command, QueryOptions, USAGE};
+
+enum IndexerError {
+    Database(DatabaseError),
+    Rpc(Box<ClientError>),
+    Fetch(HealthCheckError),
+    Decode(String), // Signature of a transaction the RPC returned in an unreadable form
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Database(err) => write!(f, "{}", err),
+            Self::Rpc(err) => write!(f, "RPC request failed: {}", err),
+            Self::Fetch(err) => write!(f, "{}", err),
+            Self::Decode(signature) => write!(f, "could not decode transaction {}", signature),
+        }
+    }
+}
+
+impl From<DatabaseError> for IndexerError {
+    fn from(err: DatabaseError) -> Self {
+        Self::Database(err)
+    }
+}
+
+impl From<ClientError> for IndexerError {
+    fn from(err: ClientError) -> Self {
+        Self::Rpc(Box::new(err))
+    }
+}
+
+impl From<HealthCheckError> for IndexerError {
+    fn from(err: HealthCheckError) -> Self {
+        Self::Fetch(err)
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    if args.help {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let result = Database::open(&args.db)
+        .map_err(IndexerError::from)
+        .and_then(|db| match args.command {
+            Command::Run(options) => index::run(db, args.namespace, options),
+            Command::Query(options) => query(&db, &args.namespace, &options),
+            Command::None => Ok(()),
+        });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn query(db: &Database, namespace: &str, options: &QueryOptions) -> Result<(), IndexerError> {
+    let since = now().saturating_sub(options.since.as_secs() as i64);
+    let buckets = db.outcome_buckets(namespace, since, options.group_by)?;
+    if buckets.is_empty() {
+        println!("No reports indexed in that time");
+        return Ok(());
+    }
+    print!("{}", format_buckets(&buckets, options.group_by));
+    Ok(())
+}
+
+fn format_buckets(buckets: &[Bucket], group_by: GroupBy) -> String {
+    let mut text = String::new();
+    let rows = buckets.iter().map(|bucket| (bucket, bucket.reports));
+    if group_by == GroupBy::FailureType {
+        let failures: u64 = buckets.iter().map(|bucket| bucket.reports).sum();
+        text.push_str(&format!(
+            "{:<24} {:>9} {:>7}\n",
+            "failure type", "failures", "share"
+        ));
+        for (bucket, count) in rows {
+            let share = percent(count, failures);
+            text.push_str(&format!("{:<24} {:>9} {:>7}\n", bucket.key, count, share));
+        }
+        return text;
+    }
+
+    let key = match group_by {
+        GroupBy::Hour => "hour (UTC)",
+        GroupBy::Day => "day (UTC)",
+        _ => "tier",
+    };
+    text.push_str(&format!(
+        "{:<17} {:>9} {:>9} {:>9} {:>8}\n",
+        key, "reports", "success", "failure", "rate"
+    ));
+    for (bucket, reports) in rows {
+        text.push_str(&format!(
+            "{:<17} {:>9} {:>9} {:>9} {:>8}\n",
+            bucket.key,
+            reports,
+            bucket.successes,
+            reports - bucket.successes,
+            percent(bucket.successes, reports)
+        ));
+    }
+    text
+}
+
+fn percent(part: u64, whole: u64) -> String {
+    format!("{:.2}%", part as f64 * 100.0 / whole.max(1) as f64)
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
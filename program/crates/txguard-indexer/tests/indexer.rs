@@ -0,0 +1,360 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_lang::InstructionData;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status_client_types::{InnerInstruction, InnerInstructions};
+use txguard::{FailureType, Outcome, TxOutcomeParams};
+use txguard_client::{batch_report_instruction, report_instruction, NamespaceAddresses};
+use txguard_indexer::{
+    reports_in, Bucket, Database, DatabaseError, GroupBy, IndexedTransaction, Report,
+    SCHEMA_VERSION,
+};
+
+fn txguard_indexer(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_txguard-indexer"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+// A fresh database file unique to the test, under the target directory
+fn db_path(test: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.sqlite", test));
+    let _ = std::fs::remove_file(&path);
+    path.to_str().unwrap().to_string()
+}
+
+fn report(success: bool, failure_type: u8, priority_fee_tier: u8) -> Report {
+    Report {
+        success,
+        failure_type,
+        priority_fee_tier,
+        fee_lamports: 5000,
+    }
+}
+
+fn indexed(signature: &str, block_time: i64, reports: Vec<Report>) -> IndexedTransaction {
+    IndexedTransaction {
+        signature: signature.to_string(),
+        slot: block_time as u64 * 2,
+        block_time,
+        reports,
+    }
+}
+
+#[test]
+fn help_prints_usage() {
+    let output = txguard_indexer(&["--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Usage: txguard-indexer"));
+}
+
+#[test]
+fn invalid_arguments_exit_with_usage() {
+    let long_namespace = "n".repeat(33);
+    for (args, message) in [
+        (&[][..], "no command given"),
+        (&["index"][..], "unknown command index"),
+        (&["query", "run"][..], "unexpected argument run"),
+        (&["query", "--once"][..], "--once only applies to run"),
+        (
+            &["run", "--since", "1h"][..],
+            "--since only applies to query",
+        ),
+        (
+            &["run", "--interval", "0"][..],
+            "--interval must be at least 1",
+        ),
+        (
+            &["query", "--since", "yesterday"][..],
+            "--since expects an age",
+        ),
+        (&["query", "--since", "h"][..], "--since expects an age"),
+        (
+            &["query", "--group-by", "week"][..],
+            "--group-by expects hour, day, tier or failure-type",
+        ),
+        (
+            &["query", "-n", &long_namespace][..],
+            "--namespace is longer than 32 bytes",
+        ),
+        (&["query", "--verbose"][..], "unknown option --verbose"),
+    ] {
+        let output = txguard_indexer(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        let stderr = stderr(&output);
+        assert!(stderr.contains(message), "{:?}: {}", args, stderr);
+        assert!(stderr.contains("Usage: txguard-indexer"));
+    }
+}
+
+#[test]
+fn query_on_an_empty_database_says_so() {
+    let db = db_path("empty");
+    let output = txguard_indexer(&["query", "--db", &db, "--group-by", "day"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "No reports indexed in that time\n"
+    );
+}
+
+#[test]
+fn run_fails_on_an_unreachable_rpc_in_a_single_pass() {
+    let db = db_path("unreachable");
+    let output = txguard_indexer(&["run", "--db", &db, "--url", "http://127.0.0.1:1", "--once"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("RPC request failed"));
+    // Nothing was indexed, so the cursor didn't move
+    assert_eq!(Database::open(&db).unwrap().cursor("").unwrap(), None);
+}
+
+#[test]
+fn reports_are_found_in_order_including_cpis() {
+    let payer = Pubkey::new_unique();
+    let params = |priority_fee_tier| TxOutcomeParams {
+        success: false,
+        failure_type: FailureType::Mev,
+        priority_fee_tier,
+        fee_lamports: 7000,
+        ..Default::default()
+    };
+    let other_program = Instruction::new_with_bytes(Pubkey::new_unique(), &[1, 2, 3], vec![]);
+    let other_namespace = report_instruction("arb", &payer, params(4));
+    // The deprecated instruction carries no fee
+    let addresses = NamespaceAddresses::derive("");
+    let legacy = Instruction {
+        program_id: txguard::ID,
+        accounts: vec![AccountMeta::new(addresses.registry, false)],
+        data: txguard::instruction::RegisterTxOutcome {
+            _namespace: String::new(),
+            outcome: Outcome::Success,
+            failure_type: FailureType::Slippage,
+            priority_fee_tier: 1,
+        }
+        .data(),
+    };
+    let message = Message::new(
+        &[
+            other_program,
+            report_instruction("", &payer, params(2)),
+            other_namespace,
+            legacy,
+            batch_report_instruction("", &payer, vec![params(0), params(1)]),
+        ],
+        Some(&payer),
+    );
+
+    // A bot's program reporting through CPI from the first instruction
+    let mut cpi = message.instructions[1].clone();
+    cpi.data = txguard::instruction::RegisterTxOutcomeV2 {
+        _namespace: String::new(),
+        params: params(3),
+    }
+    .data();
+    let inner = [InnerInstructions {
+        index: 0,
+        instructions: vec![InnerInstruction {
+            instruction: cpi,
+            stack_height: Some(2),
+        }],
+    }];
+
+    let reports = reports_in(&message.account_keys, &message.instructions, &inner, "");
+    let fee = |tier| Report {
+        fee_lamports: 7000,
+        ..report(false, txguard::FAILURE_MEV, tier)
+    };
+    assert_eq!(
+        reports,
+        [
+            fee(3),
+            fee(2),
+            Report {
+                fee_lamports: 0,
+                ..report(true, 0, 1)
+            },
+            fee(0),
+            fee(1),
+        ]
+    );
+    assert_eq!(
+        reports_in(&message.account_keys, &message.instructions, &[], "arb"),
+        [fee(4)]
+    );
+}
+
+#[test]
+fn transactions_are_recorded_once_and_move_the_cursor() {
+    let db = Database::open(&db_path("idempotent")).unwrap();
+    assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+    assert_eq!(db.cursor("").unwrap(), None);
+
+    let tx = indexed(
+        "first",
+        1_000,
+        vec![report(true, 0, 1), report(false, 2, 1)],
+    );
+    assert_eq!(db.record_transaction("", &tx).unwrap(), 2);
+    // A pass interrupted after writing, then repeated
+    assert_eq!(db.record_transaction("", &tx).unwrap(), 0);
+    assert_eq!(db.cursor("").unwrap().as_deref(), Some("first"));
+
+    // A failed transaction only moves the cursor
+    db.record_transaction("", &indexed("second", 1_100, vec![]))
+        .unwrap();
+    assert_eq!(db.cursor("").unwrap().as_deref(), Some("second"));
+    assert_eq!(db.cursor("arb").unwrap(), None);
+
+    let buckets = db.outcome_buckets("", 0, GroupBy::Day).unwrap();
+    assert_eq!(
+        buckets,
+        [Bucket {
+            key: "1970-01-01".to_string(),
+            reports: 2,
+            successes: 1
+        }]
+    );
+}
+
+#[test]
+fn buckets_group_by_hour_tier_and_failure_type() {
+    let db = Database::open(&db_path("buckets")).unwrap();
+    let hour = 3600;
+    db.record_transaction(
+        "",
+        &indexed("a", hour, vec![report(true, 0, 2), report(false, 0, 2)]),
+    )
+    .unwrap();
+    db.record_transaction(
+        "",
+        &indexed(
+            "b",
+            2 * hour + 59,
+            vec![report(false, txguard::FAILURE_MEV, 0), report(false, 0, 10)],
+        ),
+    )
+    .unwrap();
+    db.record_transaction("", &indexed("c", 3 * hour, vec![report(false, 2, 0)]))
+        .unwrap();
+    // Another namespace's reports stay out of the default one's buckets
+    db.record_transaction("arb", &indexed("d", 3 * hour, vec![report(true, 0, 0)]))
+        .unwrap();
+
+    let bucket = |key: &str, reports, successes| Bucket {
+        key: key.to_string(),
+        reports,
+        successes,
+    };
+    assert_eq!(
+        db.outcome_buckets("", 0, GroupBy::Hour).unwrap(),
+        [
+            bucket("1970-01-01 01:00", 2, 1),
+            bucket("1970-01-01 02:00", 2, 0),
+            bucket("1970-01-01 03:00", 1, 0),
+        ]
+    );
+    // Since the start of hour 2
+    assert_eq!(
+        db.outcome_buckets("", 2 * hour, GroupBy::Day).unwrap(),
+        [bucket("1970-01-01", 3, 0)]
+    );
+    // Tier 10 sorts after 2 by number, not as text
+    assert_eq!(
+        db.outcome_buckets("", 0, GroupBy::Tier).unwrap(),
+        [bucket("0", 2, 0), bucket("2", 2, 1), bucket("10", 1, 0)]
+    );
+    assert_eq!(
+        db.outcome_buckets("", 0, GroupBy::FailureType).unwrap(),
+        [bucket("slippage", 2, 0), bucket("mev", 2, 0)]
+    );
+    assert_eq!(
+        db.outcome_buckets("arb", 0, GroupBy::Hour).unwrap(),
+        [bucket("1970-01-01 03:00", 1, 1)]
+    );
+}
+
+#[test]
+fn query_prints_the_grouped_reports() {
+    let path = db_path("query");
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    {
+        let db = Database::open(&path).unwrap();
+        let reports = vec![report(true, 0, 3), report(true, 0, 3), report(false, 0, 3)];
+        db.record_transaction("", &indexed("recent", now - 60, reports))
+            .unwrap();
+        let old = vec![report(false, 0, 1)];
+        db.record_transaction("", &indexed("old", now - 2 * 86_400, old))
+            .unwrap();
+    }
+
+    let output = txguard_indexer(&[
+        "query",
+        "--db",
+        &path,
+        "--since",
+        "1d",
+        "--group-by",
+        "tier",
+    ]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", stdout);
+    assert!(lines[0].starts_with("tier"));
+    let columns: Vec<&str> = lines[1].split_whitespace().collect();
+    assert_eq!(columns, ["3", "3", "2", "1", "66.67%"]);
+
+    let output = txguard_indexer(&[
+        "query",
+        "--db",
+        &path,
+        "--since",
+        "3d",
+        "--group-by",
+        "failure-type",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("failure type"));
+    assert!(stdout.contains("slippage"));
+    assert!(stdout.contains("100.00%"));
+}
+
+#[test]
+fn databases_from_a_newer_indexer_are_refused() {
+    let path = db_path("newer");
+    let db = Database::open(&path).unwrap();
+    db.record_transaction("", &indexed("kept", 1, vec![report(true, 0, 0)]))
+        .unwrap();
+    drop(db);
+
+    // Reopening an up-to-date database migrates nothing and keeps its rows
+    let db = Database::open(&path).unwrap();
+    assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+    assert_eq!(db.cursor("").unwrap().as_deref(), Some("kept"));
+    drop(db);
+
+    // PRAGMA user_version lives at byte 60 of the file header, big-endian
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes[60..64].copy_from_slice(&99u32.to_be_bytes());
+    std::fs::write(&path, bytes).unwrap();
+    match Database::open(&path) {
+        Err(DatabaseError::NewerSchema(99)) => {}
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("opened a v99 database"),
+    }
+    let output = txguard_indexer(&["query", "--db", &path]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("database schema v99 is newer than this indexer's v1"));
+}
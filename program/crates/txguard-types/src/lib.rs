@@ -0,0 +1,122 @@
+//! Types that TxGuard instructions take as arguments, kept out of the program crate so
+//! clients can build them without pulling in the program or hand-encoding Borsh.
+
+use anchor_lang::prelude::*;
+
+// Per-transaction details for register_tx_outcome_with_metadata. Every field is
+// optional: leave it at its sentinel (see Default) when the reporter doesn't know it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TxMetadata {
+    pub tx_sig_hash: [u8; 32], // sha256 of the transaction signature, zero = unknown
+    pub tx_slot: u64, // Slot the transaction landed in, 0 = unknown
+    pub priority_fee_micro_lamports: u64, // Per-CU price, u64::MAX = unknown
+    pub compute_units_consumed: u32, // u32::MAX = unknown
+    pub cu_limit: u32, // Requested compute unit limit, u32::MAX = unknown
+    pub tx_size_bytes: u16, // Serialized size, u16::MAX = unknown
+    pub num_retries: u8, // Resends before the outcome was known, u8::MAX = unknown
+    pub tx_version: u8, // 0 = legacy, 1 = v0; anything else counts as unknown
+    pub num_lookup_tables: u8, // Address lookup tables referenced, u8::MAX = unknown
+    pub num_looked_up_accounts: u8, // Accounts loaded through them, u8::MAX = unknown
+    pub num_instructions: u8, // Top-level instructions, u8::MAX = unknown; 0 is invalid
+    pub num_signers: u8, // Required signatures, u8::MAX = unknown; otherwise 1-12
+    pub blockhash_age_slots: u8, // Age of the recent blockhash when first sent, u8::MAX = unknown
+    pub was_simulated: bool, // Sent with preflight simulation; false when skipped (or unknown)
+    pub send_method: u8, // 0 = RPC sendTransaction, 1 = Jito bundle, 2 = other relay; 3+ = unknown
+    pub route_hops: u8, // Swap route length, 0 = not a swap (or unknown)
+}
+
+impl TxMetadata {
+    pub const UNKNOWN_SLOT: u64 = 0;
+    pub const UNKNOWN_PRIORITY_FEE: u64 = u64::MAX;
+    pub const UNKNOWN_COMPUTE_UNITS: u32 = u32::MAX;
+    pub const UNKNOWN_TX_SIZE: u16 = u16::MAX;
+    pub const UNKNOWN_RETRIES: u8 = u8::MAX;
+    pub const TX_VERSION_LEGACY: u8 = 0;
+    pub const TX_VERSION_V0: u8 = 1;
+    pub const UNKNOWN_TX_VERSION: u8 = u8::MAX;
+    pub const UNKNOWN_LOOKUPS: u8 = u8::MAX;
+    pub const UNKNOWN_INSTRUCTIONS: u8 = u8::MAX;
+    pub const UNKNOWN_SIGNERS: u8 = u8::MAX;
+    pub const UNKNOWN_BLOCKHASH_AGE: u8 = u8::MAX;
+    pub const SEND_METHOD_RPC: u8 = 0;
+    pub const SEND_METHOD_JITO: u8 = 1;
+    pub const SEND_METHOD_RELAY: u8 = 2;
+    pub const SEND_METHOD_UNKNOWN: u8 = 3;
+    pub const NOT_A_SWAP: u8 = 0;
+
+    pub fn sig_hash(&self) -> Option<[u8; 32]> {
+        (self.tx_sig_hash != [0; 32]).then_some(self.tx_sig_hash)
+    }
+
+    pub fn slot(&self) -> Option<u64> {
+        (self.tx_slot != Self::UNKNOWN_SLOT).then_some(self.tx_slot)
+    }
+
+    pub fn priority_fee(&self) -> Option<u64> {
+        (self.priority_fee_micro_lamports != Self::UNKNOWN_PRIORITY_FEE).then_some(self.priority_fee_micro_lamports)
+    }
+
+    pub fn compute_units(&self) -> Option<u32> {
+        (self.compute_units_consumed != Self::UNKNOWN_COMPUTE_UNITS).then_some(self.compute_units_consumed)
+    }
+
+    pub fn compute_unit_limit(&self) -> Option<u32> {
+        (self.cu_limit != Self::UNKNOWN_COMPUTE_UNITS).then_some(self.cu_limit)
+    }
+
+    pub fn size_bytes(&self) -> Option<u16> {
+        (self.tx_size_bytes != Self::UNKNOWN_TX_SIZE).then_some(self.tx_size_bytes)
+    }
+
+    pub fn retries(&self) -> Option<u8> {
+        (self.num_retries != Self::UNKNOWN_RETRIES).then_some(self.num_retries)
+    }
+
+    pub fn lookup_tables(&self) -> Option<u8> {
+        (self.num_lookup_tables != Self::UNKNOWN_LOOKUPS).then_some(self.num_lookup_tables)
+    }
+
+    pub fn looked_up_accounts(&self) -> Option<u8> {
+        (self.num_looked_up_accounts != Self::UNKNOWN_LOOKUPS).then_some(self.num_looked_up_accounts)
+    }
+
+    pub fn instructions(&self) -> Option<u8> {
+        (self.num_instructions != Self::UNKNOWN_INSTRUCTIONS).then_some(self.num_instructions)
+    }
+
+    pub fn signers(&self) -> Option<u8> {
+        (self.num_signers != Self::UNKNOWN_SIGNERS).then_some(self.num_signers)
+    }
+
+    pub fn swap_route_hops(&self) -> Option<u8> {
+        (self.route_hops != Self::NOT_A_SWAP).then_some(self.route_hops)
+    }
+
+    pub fn blockhash_age(&self) -> Option<u8> {
+        (self.blockhash_age_slots != Self::UNKNOWN_BLOCKHASH_AGE).then_some(self.blockhash_age_slots)
+    }
+}
+
+// Everything unknown
+impl Default for TxMetadata {
+    fn default() -> Self {
+        Self {
+            tx_sig_hash: [0; 32],
+            tx_slot: Self::UNKNOWN_SLOT,
+            priority_fee_micro_lamports: Self::UNKNOWN_PRIORITY_FEE,
+            compute_units_consumed: Self::UNKNOWN_COMPUTE_UNITS,
+            cu_limit: Self::UNKNOWN_COMPUTE_UNITS,
+            tx_size_bytes: Self::UNKNOWN_TX_SIZE,
+            num_retries: Self::UNKNOWN_RETRIES,
+            tx_version: Self::UNKNOWN_TX_VERSION,
+            num_lookup_tables: Self::UNKNOWN_LOOKUPS,
+            num_looked_up_accounts: Self::UNKNOWN_LOOKUPS,
+            num_instructions: Self::UNKNOWN_INSTRUCTIONS,
+            num_signers: Self::UNKNOWN_SIGNERS,
+            blockhash_age_slots: Self::UNKNOWN_BLOCKHASH_AGE,
+            was_simulated: false,
+            send_method: Self::SEND_METHOD_UNKNOWN,
+            route_hops: Self::NOT_A_SWAP,
+        }
+    }
+}
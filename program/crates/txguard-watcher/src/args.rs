@@ -0,0 +1,132 @@
+use std::env;
+use std::str::FromStr;
+use std::time::Duration;
+
+use solana_sdk::pubkey::Pubkey;
+
+pub const USAGE: &str = "\
+Usage: txguard-watcher [OPTIONS] --wallet <ADDRESS>...
+
+Reports the outcome of every transaction the watched wallets pay for, classified by
+failure type. New transactions arrive over a websocket logs subscription, or by polling
+when that is unavailable; only those after startup are reported.
+
+Options:
+  -w, --wallet <ADDRESS>      Fee payer to watch; repeat for each wallet
+  -u, --url <URL>             RPC URL or moniker: localhost, devnet, testnet, mainnet-beta [default: localhost]
+  -k, --keypair <PATH>        Reporter keypair, pays for the reports [default: ~/.config/solana/id.json]
+  -n, --namespace <NAME>      TxGuard namespace [default: \"\"]
+      --tier <N>              Priority fee tier the wallets send at [default: 0]
+      --flush-interval <SECS> Send the pending reports this often [default: 10]
+      --max-pending <N>       Or as soon as this many are waiting [default: 32]
+      --poll-interval <SECS>  Check the wallets this often while the websocket is down [default: 5]
+      --dry-run               Print each report instead of sending it; no keypair is read
+  -h, --help                  Print this help";
+
+pub struct Args {
+    pub url: String,
+    pub keypair: String,
+    pub namespace: String,
+    pub options: WatchOptions,
+    pub help: bool,
+}
+
+pub struct WatchOptions {
+    pub wallets: Vec<Pubkey>,
+    pub tier: u8,
+    pub flush_interval: Duration,
+    pub max_pending: usize,
+    pub poll_interval: Duration,
+    pub dry_run: bool,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut url = "localhost".to_string();
+    let mut keypair = "~/.config/solana/id.json".to_string();
+    let mut namespace = String::new();
+    let mut help = false;
+    let mut options = WatchOptions {
+        wallets: Vec::new(),
+        tier: 0,
+        flush_interval: Duration::from_secs(10),
+        max_pending: 32,
+        poll_interval: Duration::from_secs(5),
+        dry_run: false,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "-h" | "--help" => help = true,
+            "-w" | "--wallet" => {
+                let address = value()?;
+                let wallet = Pubkey::from_str(&address)
+                    .map_err(|_| format!("--wallet expects a base58 address, got {:?}", address))?;
+                if !options.wallets.contains(&wallet) {
+                    options.wallets.push(wallet);
+                }
+            }
+            "-u" | "--url" => url = value()?,
+            "-k" | "--keypair" => keypair = value()?,
+            "-n" | "--namespace" => namespace = value()?,
+            "--tier" => options.tier = parse_number("--tier", &value()?)?,
+            "--flush-interval" => {
+                options.flush_interval = parse_secs("--flush-interval", &value()?)?
+            }
+            "--max-pending" => {
+                options.max_pending = parse_number("--max-pending", &value()?)?;
+                if options.max_pending == 0 {
+                    return Err("--max-pending must be at least 1".to_string());
+                }
+            }
+            "--poll-interval" => options.poll_interval = parse_secs("--poll-interval", &value()?)?,
+            "--dry-run" => options.dry_run = true,
+            flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+            extra => return Err(format!("unexpected argument {}", extra)),
+        }
+    }
+    if !help && options.wallets.is_empty() {
+        return Err("no --wallet given".to_string());
+    }
+
+    Ok(Args {
+        url: resolve_url(&url),
+        keypair: expand_home(&keypair),
+        namespace,
+        options,
+        help,
+    })
+}
+
+fn parse_number<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a whole number, got {:?}", flag, value))
+}
+
+fn parse_secs(flag: &str, value: &str) -> Result<Duration, String> {
+    match parse_number(flag, value)? {
+        0 => Err(format!("{} must be at least 1", flag)),
+        secs => Ok(Duration::from_secs(secs)),
+    }
+}
+
+// Same monikers as `solana --url`
+fn resolve_url(url: &str) -> String {
+    match url {
+        "l" | "localhost" => "http://127.0.0.1:8899",
+        "d" | "devnet" => "https://api.devnet.solana.com",
+        "t" | "testnet" => "https://api.testnet.solana.com",
+        "m" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{}/{}", home, rest),
+        _ => path.to_string(),
+    }
+}
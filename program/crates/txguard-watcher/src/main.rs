@@ -0,0 +1,81 @@
+//! Sidecar for bots that don't report to TxGuard themselves: watches their wallets and
+//! reports each transaction's outcome on their behalf.
+
+mod args;
+mod subscribe;
+mod watcher;
+
+use std::fmt;
+use std::process::ExitCode;
+
+use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_sdk::signature::read_keypair_file;
+use txguard_client::HealthCheckError;
+
+use args::USAGE;
+
+enum WatcherError {
+    Io(std::io::Error),
+    Keypair(String),
+    Rpc(Box<ClientError>),
+    Fetch(HealthCheckError),
+    Duplicate, // A report in the batch was already recorded
+}
+
+impl fmt::Display for WatcherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{}", err),
+            Self::Keypair(err) => write!(f, "could not read keypair: {}", err),
+            Self::Rpc(err) => write!(f, "RPC request failed: {}", err),
+            Self::Fetch(err) => write!(f, "{}", err),
+            Self::Duplicate => write!(f, "a report in the batch was already recorded"),
+        }
+    }
+}
+
+impl From<ClientError> for WatcherError {
+    fn from(err: ClientError) -> Self {
+        Self::Rpc(Box::new(err))
+    }
+}
+
+impl From<HealthCheckError> for WatcherError {
+    fn from(err: HealthCheckError) -> Self {
+        Self::Fetch(err)
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    if args.help {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let reporter = if args.options.dry_run {
+        None
+    } else {
+        match read_keypair_file(&args.keypair) {
+            Ok(keypair) => Some(keypair),
+            Err(err) => {
+                let err = WatcherError::Keypair(format!("{}: {}", args.keypair, err));
+                eprintln!("error: {}", err);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    match watcher::watch(args.url, args.namespace, reporter, args.options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
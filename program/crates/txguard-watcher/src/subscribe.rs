@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use futures_util::stream::{select_all, StreamExt};
+use solana_pubsub_client::nonblocking::pubsub_client::{PubsubClient, PubsubClientError};
+use solana_rpc_client_api::config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc::UnboundedSender;
+
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+pub enum Event {
+    Connected,
+    Disconnected(String),
+    Activity(usize), // Index into the watched wallets
+}
+
+// Keeps one logs subscription per wallet open, reconnecting with exponential backoff.
+// Notifications only say which wallet to poll: the transactions themselves are read over
+// RPC, so one missed while disconnected is still picked up by the next poll
+pub async fn subscribe(url: String, wallets: Vec<Pubkey>, events: UnboundedSender<Event>) {
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        let reason = match stream(&url, &wallets, &events, &mut backoff).await {
+            Ok(()) => "websocket closed".to_string(),
+            Err(err) => err.to_string(),
+        };
+        if events.send(Event::Disconnected(reason)).is_err() {
+            return; // The watcher stopped
+        }
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+async fn stream(
+    url: &str,
+    wallets: &[Pubkey],
+    events: &UnboundedSender<Event>,
+    backoff: &mut Duration,
+) -> Result<(), PubsubClientError> {
+    let pubsub = PubsubClient::new(url).await?;
+    let mut streams = Vec::new();
+    for (index, wallet) in wallets.iter().enumerate() {
+        // Mentions takes a single address
+        let filter = RpcTransactionLogsFilter::Mentions(vec![wallet.to_string()]);
+        let config = RpcTransactionLogsConfig {
+            commitment: Some(CommitmentConfig::confirmed()),
+        };
+        let (logs, _unsubscribe) = pubsub.logs_subscribe(filter, config).await?;
+        streams.push(logs.map(move |_| index));
+    }
+    *backoff = MIN_BACKOFF;
+    if events.send(Event::Connected).is_err() {
+        return Ok(());
+    }
+
+    // Every stream ends together when the connection drops
+    let mut activity = select_all(streams);
+    while let Some(index) = activity.next().await {
+        if events.send(Event::Activity(index)).is_err() {
+            break;
+        }
+    }
+    drop(activity);
+    let _ = pubsub.shutdown().await;
+    Ok(())
+}
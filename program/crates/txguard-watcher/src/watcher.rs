@@ -0,0 +1,366 @@
+use std::collections::{HashSet, VecDeque};
+
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_rpc_client_api::config::RpcTransactionConfig;
+use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::hash;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_transaction_status_client_types::option_serializer::OptionSerializer;
+use solana_transaction_status_client_types::UiTransactionEncoding;
+use tokio::sync::mpsc;
+use txguard::{TransactionRegistry, TxGuardError, TxOutcomeParams};
+use txguard_client::{
+    batch_report_instruction, outcome_params, pack_reports, websocket_url, Classifier, FailureType,
+    Landing, TxGuardClient,
+};
+
+use crate::args::WatchOptions;
+use crate::subscribe::{subscribe, Event};
+use crate::WatcherError;
+
+const SIGNATURES_PER_PAGE: usize = 1000; // getSignaturesForAddress's maximum
+const REMEMBERED_SIGNATURES: usize = 10_000;
+
+// A classified transaction waiting for the next flush
+struct Report {
+    wallet: Pubkey,
+    signature: Signature,
+    failure_type: Option<FailureType>,
+    params: TxOutcomeParams,
+}
+
+struct Watcher {
+    rpc: RpcClient,
+    client: TxGuardClient,
+    namespace: String,
+    reporter: Option<Keypair>, // None in a dry run
+    classifier: Classifier,
+    tier: u8,
+    cursors: Vec<(Pubkey, Option<Signature>)>, // Each wallet's newest signature already looked at
+    seen: HashSet<Signature>, // Queued or reported, oldest evicted first through seen_order
+    seen_order: VecDeque<Signature>,
+    pending: Vec<Report>,
+}
+
+pub fn watch(
+    url: String,
+    namespace: String,
+    reporter: Option<Keypair>,
+    options: WatchOptions,
+) -> Result<(), WatcherError> {
+    let runtime = tokio::runtime::Runtime::new().map_err(WatcherError::Io)?;
+    runtime.block_on(run(url, namespace, reporter, options))
+}
+
+async fn run(
+    url: String,
+    namespace: String,
+    reporter: Option<Keypair>,
+    options: WatchOptions,
+) -> Result<(), WatcherError> {
+    let mut watcher = Watcher {
+        rpc: RpcClient::new_with_commitment(url.clone(), CommitmentConfig::confirmed()),
+        client: TxGuardClient::for_namespace(url.clone(), &namespace),
+        namespace,
+        reporter,
+        classifier: Classifier::default(),
+        tier: options.tier,
+        cursors: options
+            .wallets
+            .iter()
+            .map(|wallet| (*wallet, None))
+            .collect(),
+        seen: HashSet::new(),
+        seen_order: VecDeque::new(),
+        pending: Vec::new(),
+    };
+    // Fails fast on a wrong URL, and leaves the wallets' history unreported
+    watcher.start().await?;
+    println!(
+        "Watching {} wallet(s){}",
+        options.wallets.len(),
+        if options.dry_run { ", dry run" } else { "" }
+    );
+
+    let (sender, mut events) = mpsc::unbounded_channel();
+    let subscriber = tokio::spawn(subscribe(
+        websocket_url(&url),
+        options.wallets.clone(),
+        sender,
+    ));
+    let mut streaming = false;
+    let mut polls = tokio::time::interval(options.poll_interval);
+    let mut flushes = tokio::time::interval(options.flush_interval);
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    loop {
+        let result = tokio::select! {
+            _ = &mut ctrl_c => break,
+            Some(event) = events.recv() => match event {
+                Event::Connected => {
+                    println!("Websocket connected");
+                    streaming = true;
+                    // Catch up on whatever landed while it was down
+                    watcher.poll_all().await
+                }
+                Event::Disconnected(reason) => {
+                    if streaming {
+                        eprintln!("Websocket lost ({}), polling until it reconnects", reason);
+                    }
+                    streaming = false;
+                    Ok(())
+                }
+                Event::Activity(index) => watcher.poll(index).await,
+            },
+            _ = polls.tick(), if !streaming => watcher.poll_all().await,
+            // A last poll first, in case a notification went missing
+            _ = flushes.tick() => match watcher.poll_all().await {
+                Ok(()) => watcher.flush().await,
+                Err(err) => Err(err),
+            },
+        };
+        let result = match result {
+            Ok(()) if watcher.pending.len() >= options.max_pending => watcher.flush().await,
+            result => result,
+        };
+        // Whatever failed is retried on the next tick
+        if let Err(err) = result {
+            eprintln!("error: {}", err);
+        }
+    }
+
+    subscriber.abort();
+    watcher.flush().await
+}
+
+impl Watcher {
+    async fn start(&mut self) -> Result<(), WatcherError> {
+        for (wallet, cursor) in &mut self.cursors {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                limit: Some(1),
+                commitment: Some(CommitmentConfig::confirmed()),
+                ..Default::default()
+            };
+            let newest = self
+                .rpc
+                .get_signatures_for_address_with_config(wallet, config)
+                .await?;
+            *cursor = newest
+                .first()
+                .and_then(|status| status.signature.parse().ok());
+        }
+        Ok(())
+    }
+
+    async fn poll_all(&mut self) -> Result<(), WatcherError> {
+        for index in 0..self.cursors.len() {
+            self.poll(index).await?;
+        }
+        Ok(())
+    }
+
+    // Queues a report for each transaction the wallet paid for since its cursor, oldest
+    // first. The cursor only moves once all of them are queued
+    async fn poll(&mut self, index: usize) -> Result<(), WatcherError> {
+        let (wallet, until) = self.cursors[index];
+        let mut statuses = Vec::new();
+        let mut before = None;
+        loop {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(SIGNATURES_PER_PAGE),
+                commitment: Some(CommitmentConfig::confirmed()),
+            };
+            let page = self
+                .rpc
+                .get_signatures_for_address_with_config(&wallet, config)
+                .await?;
+            let more = page.len() == SIGNATURES_PER_PAGE;
+            before = page.last().and_then(|status| status.signature.parse().ok());
+            statuses.extend(page);
+            if !more {
+                break;
+            }
+        }
+
+        for status in statuses.iter().rev() {
+            let Ok(signature) = status.signature.parse::<Signature>() else {
+                continue;
+            };
+            if self.seen.contains(&signature) {
+                continue;
+            }
+            if let Some(report) = self.classify(wallet, signature, status).await? {
+                self.pending.push(report);
+            }
+            self.remember(signature);
+        }
+        if let Some(newest) = statuses.first() {
+            self.cursors[index].1 = newest.signature.parse().ok();
+        }
+        Ok(())
+    }
+
+    // None for transactions that only mention the wallet; someone else paid for those
+    async fn classify(
+        &self,
+        wallet: Pubkey,
+        signature: Signature,
+        status: &RpcConfirmedTransactionStatusWithSignature,
+    ) -> Result<Option<Report>, WatcherError> {
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        let fetched = self
+            .rpc
+            .get_transaction_with_config(&signature, config)
+            .await?;
+        let fee_payer = fetched
+            .transaction
+            .transaction
+            .decode()
+            .and_then(|tx| tx.message.static_account_keys().first().copied());
+        if fee_payer != Some(wallet) {
+            return Ok(None);
+        }
+
+        let meta = fetched.transaction.meta;
+        let (landing, failure_type) = match &status.err {
+            None => (Landing::Succeeded { slot: status.slot }, None),
+            Some(err) => {
+                let logs = match meta.as_ref().map(|meta| &meta.log_messages) {
+                    Some(OptionSerializer::Some(logs)) => logs.clone(),
+                    _ => Vec::new(),
+                };
+                let failure_type = self.classifier.classify(err, &logs);
+                let landing = Landing::Errored {
+                    slot: status.slot,
+                    err: err.clone(),
+                };
+                (landing, Some(failure_type))
+            }
+        };
+        let mut params = outcome_params(&signature, &landing, failure_type, self.tier);
+        params.fee_lamports = meta.map_or(0, |meta| meta.fee);
+        Ok(Some(Report {
+            wallet,
+            signature,
+            failure_type,
+            params,
+        }))
+    }
+
+    fn remember(&mut self, signature: Signature) {
+        if self.seen_order.len() == REMEMBERED_SIGNATURES {
+            if let Some(oldest) = self.seen_order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(signature);
+        self.seen_order.push_back(signature);
+    }
+
+    // Sends every pending report, as few transactions as they fit in. Ones the program
+    // already holds are dropped; the rest stay pending if a transaction fails
+    async fn flush(&mut self) -> Result<(), WatcherError> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let Some(reporter) = &self.reporter else {
+            for report in self.pending.drain(..) {
+                println!("Would report {}", describe(&report));
+            }
+            return Ok(());
+        };
+
+        // Reported before a restart, or by the bot itself
+        let registry = self.client.fetch_registry().await?;
+        drop_reported(&mut self.pending, &registry);
+
+        let params = self.pending.iter().map(|report| report.params.clone());
+        let batches = pack_reports(&self.namespace, &reporter.pubkey(), params.collect());
+        let mut reports = std::mem::take(&mut self.pending).into_iter();
+        for batch in batches {
+            let mut batch: Vec<Report> = reports.by_ref().take(batch.len()).collect();
+            while !batch.is_empty() {
+                let params = batch.iter().map(|report| report.params.clone()).collect();
+                let ix = batch_report_instruction(&self.namespace, &reporter.pubkey(), params);
+                let err = match send(&self.rpc, reporter, ix).await {
+                    Ok(signature) => {
+                        for report in &batch {
+                            println!("Reported {}", describe(report));
+                        }
+                        println!("  in {}", signature);
+                        break;
+                    }
+                    Err(err) => err,
+                };
+                // Someone reported part of the batch since the registry was read
+                if let WatcherError::Duplicate = err {
+                    if let Ok(registry) = self.client.fetch_registry().await {
+                        let before = batch.len();
+                        drop_reported(&mut batch, &registry);
+                        if batch.len() < before {
+                            continue;
+                        }
+                    }
+                }
+                self.pending = batch.into_iter().chain(reports).collect();
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Drop reports whose signature is still in the registry's dedup ring
+fn drop_reported(reports: &mut Vec<Report>, registry: &TransactionRegistry) {
+    reports.retain(|report| {
+        let reported = registry
+            .recent_sig_hashes
+            .contains(&hash(report.signature.as_ref()).to_bytes());
+        if reported {
+            println!("Skipping {}, already reported", report.signature);
+        }
+        !reported
+    });
+}
+
+async fn send(
+    rpc: &RpcClient,
+    reporter: &Keypair,
+    ix: Instruction,
+) -> Result<Signature, WatcherError> {
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&reporter.pubkey()), &[reporter], blockhash);
+    rpc.send_and_confirm_transaction(&tx)
+        .await
+        .map_err(|err| match err.get_transaction_error() {
+            Some(TransactionError::InstructionError(_, InstructionError::Custom(code)))
+                if code == u32::from(TxGuardError::DuplicateReport) =>
+            {
+                WatcherError::Duplicate
+            }
+            _ => WatcherError::Rpc(Box::new(err)),
+        })
+}
+
+fn describe(report: &Report) -> String {
+    let outcome = match report.failure_type {
+        None => "success".to_string(),
+        Some(failure_type) => format!("failure ({})", failure_type.name()),
+    };
+    format!(
+        "{} from {}: {}, slot {}",
+        report.signature, report.wallet, outcome, report.params.tx_slot
+    )
+}
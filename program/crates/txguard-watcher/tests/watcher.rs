@@ -0,0 +1,86 @@
+use std::process::{Command, Output};
+
+const WALLET: &str = "9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin";
+
+fn txguard_watcher(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_txguard-watcher"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn help_prints_usage() {
+    let output = txguard_watcher(&["--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Usage: txguard-watcher"));
+}
+
+#[test]
+fn invalid_arguments_exit_with_usage() {
+    for (args, message) in [
+        (&[][..], "no --wallet given"),
+        (&["--dry-run"][..], "no --wallet given"),
+        (
+            &["--wallet", "bot"][..],
+            "--wallet expects a base58 address",
+        ),
+        (
+            &["-w", WALLET, "--flush-interval", "0"][..],
+            "--flush-interval must be at least 1",
+        ),
+        (
+            &["-w", WALLET, "--poll-interval", "soon"][..],
+            "--poll-interval expects a whole number",
+        ),
+        (
+            &["-w", WALLET, "--max-pending", "0"][..],
+            "--max-pending must be at least 1",
+        ),
+        (
+            &["-w", WALLET, "--tier", "256"][..],
+            "--tier expects a whole number",
+        ),
+        (&["-w", WALLET, "--verbose"][..], "unknown option --verbose"),
+        (&["-w", WALLET, "watch"][..], "unexpected argument watch"),
+    ] {
+        let output = txguard_watcher(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        let stderr = stderr(&output);
+        assert!(stderr.contains(message), "{:?}: {}", args, stderr);
+        assert!(stderr.contains("Usage: txguard-watcher"));
+    }
+}
+
+#[test]
+fn unreadable_keypair_fails_before_watching() {
+    let output = txguard_watcher(&[
+        "-w",
+        WALLET,
+        "--keypair",
+        "/nonexistent/id.json",
+        "--url",
+        "http://127.0.0.1:1",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("could not read keypair: /nonexistent/id.json"));
+}
+
+#[test]
+fn dry_run_needs_no_keypair_but_exits_on_an_unreachable_rpc() {
+    let output = txguard_watcher(&[
+        "-w",
+        WALLET,
+        "--dry-run",
+        "--keypair",
+        "/nonexistent/id.json",
+        "-u",
+        "http://127.0.0.1:1",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("RPC request failed"));
+}
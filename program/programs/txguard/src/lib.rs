@@ -1,4 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program;
+pub use txguard_types::TxMetadata;
 
 declare_id!("FxYDzyGPggfBeQsoLCJqmhAq9danG1qQJXaUjrWTwhp1");
 
@@ -6,127 +13,232 @@ declare_id!("FxYDzyGPggfBeQsoLCJqmhAq9danG1qQJXaUjrWTwhp1");
 pub mod txguard {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        let registry = &mut ctx.accounts.registry;
-        registry.tx_count = 0;
-        registry.success_count = 0;
-        registry.failure_count = 0;
-        registry.cursor = 0;
-        
-        // Initialize all outcomes to 2 (pending/unknown)
-        // Vec will be initialized empty, we'll handle this properly
-        registry.last_100_outcomes.clear();
-        for _ in 0..100 {
-            registry.last_100_outcomes.push(2);
-        }
+    pub fn initialize(ctx: Context<Initialize>, namespace: String) -> Result<()> {
+        // The default namespace's authority may re-initialize it; named namespaces are
+        // created once
+        let config = &mut ctx.accounts.config;
+        let exists = config.authority != Pubkey::default();
+        require!(!exists || namespace.is_empty(), TxGuardError::NamespaceExists);
+        require!(
+            !exists || config.authority == ctx.accounts.payer.key(),
+            TxGuardError::Unauthorized
+        );
+
+        config.init_defaults(ctx.accounts.payer.key(), &namespace);
+
+        ctx.accounts.registry.ensure_unlocked()?;
+        ctx.accounts.registry.reset();
+        // Nothing to snapshot until the next epoch begins
+        ctx.accounts.registry.last_seen_epoch = Clock::get()?.epoch;
         
-            // Reset failure catalog so re-runs start from a clean slate
-            let catalog = &mut ctx.accounts.failure_catalog;
-            catalog.slippage_exceeded = 0;
-            catalog.insufficient_liquidity = 0;
-            catalog.mev_detected = 0;
-            catalog.dropped_tx = 0;
-            catalog.insufficient_funds = 0;
-            catalog.other = 0;
+        // Reset failure catalog so re-runs start from a clean slate
+        ctx.accounts.failure_catalog.reset();
 
         // Initialize priority fee stats
-        let stats = &mut ctx.accounts.priority_fee_stats;
-        stats.tiers.clear();
-        for _ in 0..5 {
-            stats.tiers.push(0);
-        }
+        ctx.accounts.priority_fee_stats.reset();
+
+        ctx.accounts.hourly_stats.reset();
+        ctx.accounts.daily_stats.reset();
+        ctx.accounts.seasonality_stats.reset();
+        ctx.accounts.compute_stats.reset();
+        ctx.accounts.latency_stats.reset();
+        ctx.accounts.tx_size_stats.reset();
+        ctx.accounts.instruction_stats.reset();
+        ctx.accounts.blockhash_age_stats.reset();
+        ctx.accounts.route_stats.reset();
+        ctx.accounts.tag_stats.reset();
         
-        msg!("Transaction Registry initialized");
+        msg!("Transaction Registry initialized: namespace={:?}", namespace);
         Ok(())
     }
 
-    pub fn register_tx_outcome(
-        ctx: Context<RegisterTxOutcome>,
-        success: bool,
-        failure_type: u8,
+    /// Deprecated: prefer `register_tx_outcome_v2`, which takes a `TxOutcomeParams` struct
+    /// and can grow new fields without breaking callers.
+    pub fn register_tx_outcome<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterTxOutcome<'info>>,
+        _namespace: String,
+        outcome: Outcome,
+        failure_type: FailureType,
         priority_fee_tier: u8,
-    ) -> Result<()> {
-        let registry = &mut ctx.accounts.registry;
-        let catalog = &mut ctx.accounts.failure_catalog;
-        let stats = &mut ctx.accounts.priority_fee_stats;
+    ) -> Result<u64> {
+        // Pending only marks window entries nothing has been reported into
+        require!(outcome != Outcome::Pending, TxGuardError::InvalidOutcome);
+        let params = TxOutcomeParams {
+            success: outcome != Outcome::Failure,
+            failure_type,
+            priority_fee_tier,
+            partial_fill: outcome == Outcome::Partial,
+            ..Default::default()
+        };
+        let tx_count = ctx.accounts.record_outcome(ctx.program_id, ctx.remaining_accounts, &params)?;
 
-        // Validate priority fee tier (0-4)
-        require!(
-            priority_fee_tier < 5,
-            TxGuardError::InvalidPriorityFeeTier
-        );
+        msg!("Transaction recorded: outcome={:?}, failure_type={}, tier={}", 
+             outcome, u8::from(failure_type), priority_fee_tier);
+        Ok(tx_count)
+    }
 
-        // Update registry
-        registry.tx_count = registry.tx_count.checked_add(1)
-            .ok_or(TxGuardError::CountOverflow)?;
+    /// Returns the new `tx_count`, which callers can keep as a sequential reference ID
+    /// for the report (read it via return data, including after a CPI).
+    pub fn register_tx_outcome_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterTxOutcome<'info>>,
+        _namespace: String,
+        params: TxOutcomeParams,
+    ) -> Result<u64> {
+        let tx_count = ctx.accounts.record_outcome(ctx.program_id, ctx.remaining_accounts, &params)?;
 
-        // Update circular buffer
-        let cursor_idx = registry.cursor as usize;
-        if cursor_idx < registry.last_100_outcomes.len() {
-            registry.last_100_outcomes[cursor_idx] = if success { 1 } else { 0 };
+        msg!("Transaction recorded: success={}, failure_type={}, tier={}, fee={}, value={}, mev_score={}, nonce={}",
+             params.success, u8::from(params.failure_type), params.priority_fee_tier,
+             params.fee_lamports, params.value_lamports, params.mev_score, params.nonce);
+        Ok(tx_count)
+    }
+
+    /// Several `register_tx_outcome_v2` reports in one instruction, recorded in order.
+    /// The reporter's cooldown and the signature dedup are checked once for the whole
+    /// batch, before anything is recorded. Returns the `tx_count` after the last report.
+    pub fn register_tx_outcomes_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterTxOutcome<'info>>,
+        _namespace: String,
+        reports: Vec<TxOutcomeParams>,
+    ) -> Result<u64> {
+        require!(!reports.is_empty(), TxGuardError::EmptyBatch);
+        ctx.accounts.check_cooldown(Clock::get()?.slot)?;
+        // Epoch registries keep their own ring, checked as each report lands
+        if !ctx.accounts.config.epoch_scoped {
+            ctx.accounts.registry.check_new_sigs(&reports)?;
         }
-        registry.cursor = (registry.cursor + 1) % 100;
 
-        if success {
-            registry.success_count = registry.success_count.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?;
-        } else {
-            registry.failure_count = registry.failure_count.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?;
+        let mut tx_count = 0;
+        for params in &reports {
+            tx_count = ctx.accounts.apply_report(ctx.program_id, ctx.remaining_accounts, params)?;
+        }
 
-            // Update failure catalog
-            match failure_type {
-                0 => catalog.slippage_exceeded = catalog.slippage_exceeded.checked_add(1)
-                    .ok_or(TxGuardError::CountOverflow)?,
-                1 => catalog.insufficient_liquidity = catalog.insufficient_liquidity.checked_add(1)
-                    .ok_or(TxGuardError::CountOverflow)?,
-                2 => catalog.mev_detected = catalog.mev_detected.checked_add(1)
-                    .ok_or(TxGuardError::CountOverflow)?,
-                3 => catalog.dropped_tx = catalog.dropped_tx.checked_add(1)
-                    .ok_or(TxGuardError::CountOverflow)?,
-                4 => catalog.insufficient_funds = catalog.insufficient_funds.checked_add(1)
-                    .ok_or(TxGuardError::CountOverflow)?,
-                _ => catalog.other = catalog.other.checked_add(1)
-                    .ok_or(TxGuardError::CountOverflow)?,
-            }
-        }
-
-        // Update priority fee stats
-        if (priority_fee_tier as usize) < stats.tiers.len() {
-            stats.tiers[priority_fee_tier as usize] = stats.tiers[priority_fee_tier as usize]
-                .checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?;
+        msg!("Transactions recorded: reports={}, tx_count={}", reports.len(), tx_count);
+        Ok(tx_count)
+    }
+
+    /// `register_tx_outcome_v2` plus a `TxMetadata` (defined in txguard-types) describing
+    /// the transaction itself. Metadata fields left at their sentinel are ignored; a
+    /// signature hash or tx_slot given in the metadata takes precedence over `params`.
+    pub fn register_tx_outcome_with_metadata<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterTxOutcome<'info>>,
+        _namespace: String,
+        params: TxOutcomeParams,
+        metadata: TxMetadata,
+    ) -> Result<u64> {
+        if let Some(size) = metadata.size_bytes() {
+            require!(size <= MAX_TX_SIZE_BYTES, TxGuardError::InvalidTxSize);
+        }
+        check_lookup_usage(&metadata)?;
+        require!(metadata.num_instructions != 0, TxGuardError::InvalidInstructionCount);
+        if let Some(signers) = metadata.signers() {
+            require!((1..=MAX_SIGNERS).contains(&signers), TxGuardError::InvalidSignerCount);
+        }
+        let params = params.with_metadata(&metadata);
+        let tx_count = ctx.accounts.record_outcome(ctx.program_id, ctx.remaining_accounts, &params)?;
+        // Only this path knows compute usage and size; partial fills stay out as elsewhere
+        if !params.partial_fill {
+            if let Some(compute_stats) = ctx.accounts.compute_stats.as_mut() {
+                compute_stats.record(params.success, &metadata);
+            }
+            if let (Some(tx_size_stats), Some(size)) = (ctx.accounts.tx_size_stats.as_mut(), metadata.size_bytes()) {
+                tx_size_stats.record(size, params.success);
+            }
+            if let (Some(instruction_stats), Some(count)) = (ctx.accounts.instruction_stats.as_mut(), metadata.instructions()) {
+                instruction_stats.record(count, params.success);
+            }
+            if let (Some(blockhash_age_stats), Some(age)) = (ctx.accounts.blockhash_age_stats.as_mut(), metadata.blockhash_age()) {
+                blockhash_age_stats.record(age, params.success);
+            }
+            if let (Some(route_stats), Some(hops)) = (ctx.accounts.route_stats.as_mut(), metadata.swap_route_hops()) {
+                route_stats.record(hops, params.success, params.failure_type.into());
+            }
+            ctx.accounts.registry.record_tx_version(metadata.tx_version, params.success);
+            ctx.accounts.registry.record_lookup_usage(&metadata, params.success);
+            if let Some(signers) = metadata.signers() {
+                ctx.accounts.registry.record_signers(signers, params.success);
+            }
+            ctx.accounts.registry.record_simulation(metadata.was_simulated, params.success);
+            ctx.accounts.registry.record_send_method(metadata.send_method, params.success, params.failure_type.into());
+        }
+
+        msg!("Transaction recorded: success={}, failure_type={}, tier={}, metadata={:?}",
+             params.success, u8::from(params.failure_type), params.priority_fee_tier, metadata);
+        Ok(tx_count)
+    }
+
+    pub fn register_tx_outcome_dual<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterTxOutcomeDual<'info>>,
+        _namespace: String,
+        params: TxOutcomeParams,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+        accounts.user_registry.ensure_unlocked()?;
+        accounts.base.record_outcome(ctx.program_id, ctx.remaining_accounts, &params)?;
+
+        // A freshly created user registry is zeroed; give it a pending window first
+        let user_registry = &mut accounts.user_registry;
+        if user_registry.last_100_outcomes.is_empty() {
+            user_registry.reset();
         }
+        user_registry.apply_outcome(&params)?;
 
-        msg!("Transaction recorded: success={}, failure_type={}, tier={}", 
-             success, failure_type, priority_fee_tier);
+        msg!("Transaction recorded for global and user registries: success={}, failure_type={}, tier={}",
+             params.success, u8::from(params.failure_type), params.priority_fee_tier);
         Ok(())
     }
 
-    pub fn record_failure(ctx: Context<RecordFailure>, failure_type: u8) -> Result<()> {
-        let catalog = &mut ctx.accounts.failure_catalog;
-        
-        match failure_type {
-            0 => catalog.slippage_exceeded = catalog.slippage_exceeded.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?,
-            1 => catalog.insufficient_liquidity = catalog.insufficient_liquidity.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?,
-            2 => catalog.mev_detected = catalog.mev_detected.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?,
-            3 => catalog.dropped_tx = catalog.dropped_tx.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?,
-            4 => catalog.insufficient_funds = catalog.insufficient_funds.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?,
-            _ => catalog.other = catalog.other.checked_add(1)
-                .ok_or(TxGuardError::CountOverflow)?,
+    /// Records a success from inside the transaction it describes. Append this after the
+    /// instruction(s) being tracked: if any of them fails the whole transaction reverts,
+    /// so only successes can be captured this way. Failures still need to be reported
+    /// off-chain through `register_tx_outcome_v2`.
+    pub fn record_inline_success<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecordInlineSuccess<'info>>,
+        _namespace: String,
+        priority_fee_tier: u8,
+    ) -> Result<()> {
+        let target_program = ctx.accounts.target_program.key();
+        require_keys_neq!(target_program, *ctx.program_id, TxGuardError::InvalidTargetProgram);
+
+        // Some earlier instruction in this transaction must invoke the target program
+        let ix_sysvar = ctx.accounts.instructions.to_account_info();
+        let current = instructions_sysvar::load_current_index_checked(&ix_sysvar)?;
+        let mut found = false;
+        for index in 0..current {
+            let ix = instructions_sysvar::load_instruction_at_checked(index as usize, &ix_sysvar)?;
+            if ix.program_id == target_program {
+                found = true;
+                break;
+            }
         }
+        require!(found, TxGuardError::MissingTargetInstruction);
+
+        // Running inside the swap's transaction, so it landed
+        let params = TxOutcomeParams {
+            success: true,
+            priority_fee_tier,
+            landing: LANDING_SUCCEEDED,
+            ..Default::default()
+        };
+        let accounts = ctx.accounts;
+        accounts.base.record_outcome(ctx.program_id, ctx.remaining_accounts, &params)?;
+
+        let slot = Clock::get()?.slot;
+        accounts.program_stats.record_success(target_program, slot)?;
+
+        msg!("Inline success recorded: program={}, tier={}", target_program, priority_fee_tier);
+        Ok(())
+    }
+
+    pub fn record_failure(ctx: Context<RecordFailure>, _namespace: String, failure_type: FailureType) -> Result<()> {
+        let clock = Clock::get()?;
+        ctx.accounts.failure_catalog.increment(failure_type.into(), clock.unix_timestamp, clock.slot)?;
         
-        msg!("Failure recorded: type={}", failure_type);
+        msg!("Failure recorded: type={}", u8::from(failure_type));
         Ok(())
     }
 
-    pub fn update_priority_fee(ctx: Context<UpdatePriorityFee>, tier: u8) -> Result<()> {
-        require!(tier < 5, TxGuardError::InvalidPriorityFeeTier);
+    pub fn update_priority_fee(ctx: Context<UpdatePriorityFee>, _namespace: String, tier: u8) -> Result<()> {
+        require!(tier < MAX_TIERS, TxGuardError::InvalidPriorityFeeTier);
         
         let stats = &mut ctx.accounts.priority_fee_stats;
         
@@ -136,111 +248,5754 @@ pub mod txguard {
                 .checked_add(1)
                 .ok_or(TxGuardError::CountOverflow)?;
         }
+
+        // Kept apart from report-driven counts so the two paths can be compared
+        stats.direct_update_counts[tier as usize] = stats.direct_update_counts[tier as usize]
+            .checked_add(1)
+            .ok_or(TxGuardError::CountOverflow)?;
         
         msg!("Priority fee tier updated: tier={}", tier);
         Ok(())
     }
-}
 
-// Transaction Registry Account
-#[account]
-#[derive(InitSpace)]
-pub struct TransactionRegistry {
-    pub tx_count: u64,
-    pub success_count: u64,
-    pub failure_count: u64,
-    #[max_len(100)]
-    pub last_100_outcomes: Vec<u8>, // 0=failure, 1=success, 2=pending
-    pub cursor: u8,
-}
+    pub fn get_reporter_stats(
+        ctx: Context<GetReporterStats>,
+        _namespace: String,
+        _reporter: Pubkey,
+    ) -> Result<ReporterStats> {
+        let record = &ctx.accounts.reporter_record;
+        let config = &ctx.accounts.config;
+        let reputation = record.current_reputation(Clock::get()?.slot, config.reputation_half_life_slots);
+        let total = record.successes_reported.saturating_add(record.failures_reported);
+        let success_rate_bps = if total == 0 {
+            0
+        } else {
+            (record.successes_reported as u128 * 10_000 / total as u128) as u16
+        };
 
-// Failure Catalog Account
-#[account]
-#[derive(InitSpace)]
-pub struct FailureCatalog {
-    pub slippage_exceeded: u32,
-    pub insufficient_liquidity: u32,
-    pub mev_detected: u32,
-    pub dropped_tx: u32,
-    pub insufficient_funds: u32,
-    pub other: u32,
-}
+        Ok(ReporterStats {
+            reporter: record.reporter,
+            successes_reported: record.successes_reported,
+            failures_reported: record.failures_reported,
+            success_rate_bps,
+            recent_outcomes: record.recent_outcomes,
+            cursor: record.cursor,
+            last_report_slot: record.last_report_slot,
+            points: record.points,
+            reputation,
+            trusted: reputation >= config.min_reputation,
+        })
+    }
 
-// Priority Fee Statistics Account
-#[account]
-#[derive(InitSpace)]
-pub struct PriorityFeeStats {
-    #[max_len(5)]
-    pub tiers: Vec<u64>, // Counts for 5 priority fee tiers (0-4)
-}
+    pub fn close_reporter_record(_ctx: Context<CloseReporterRecord>, _namespace: String) -> Result<()> {
+        msg!("Reporter record closed");
+        Ok(())
+    }
 
-// Instruction Contexts
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    pub fn set_tier_names(
+        ctx: Context<SetTierNames>,
+        _namespace: String,
+        names: Vec<[u8; 16]>,
+    ) -> Result<()> {
+        require!(names.len() <= MAX_TIERS as usize, TxGuardError::TooManyTierNames);
+        for name in names.iter() {
+            require!(std::str::from_utf8(name).is_ok(), TxGuardError::InvalidTierName);
+        }
 
-    #[account(
-            init_if_needed,
-        payer = payer,
-        space = 8 + TransactionRegistry::INIT_SPACE,
-        seeds = [b"registry"],
-        bump
-    )]
-    pub registry: Account<'info, TransactionRegistry>,
-    
-    #[account(
-            init_if_needed,
-        payer = payer,
-        space = 8 + FailureCatalog::INIT_SPACE,
-        seeds = [b"catalog"],
-        bump
-    )]
-    pub failure_catalog: Account<'info, FailureCatalog>,
-    
-    #[account(
-            init_if_needed,
-        payer = payer,
-        space = 8 + PriorityFeeStats::INIT_SPACE,
-        seeds = [b"priority"],
-        bump
-    )]
-    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
-    
-    pub system_program: Program<'info, System>,
-}
+        ctx.accounts.priority_fee_stats.tier_names = names;
 
-#[derive(Accounts)]
-pub struct RegisterTxOutcome<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    
-    #[account(mut, seeds = [b"registry"], bump)]
-    pub registry: Account<'info, TransactionRegistry>,
-    
-    #[account(mut, seeds = [b"catalog"], bump)]
-    pub failure_catalog: Account<'info, FailureCatalog>,
-    
-    #[account(mut, seeds = [b"priority"], bump)]
-    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
-}
+        msg!("Tier names updated");
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct RecordFailure<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    
-    #[account(mut, seeds = [b"catalog"], bump)]
-    pub failure_catalog: Account<'info, FailureCatalog>,
-}
+    pub fn get_tier_stats(ctx: Context<GetTierStats>, _namespace: String) -> Result<Vec<TierInfo>> {
+        let stats = &ctx.accounts.priority_fee_stats;
 
-#[derive(Accounts)]
-pub struct UpdatePriorityFee<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    
-    #[account(mut, seeds = [b"priority"], bump)]
-    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+        // Unnamed tiers report an all-blank label
+        Ok(stats.tiers.iter().enumerate().map(|(tier, count)| TierInfo {
+            tier: tier as u8,
+            name: stats.tier_names.get(tier).copied().unwrap_or([b' '; 16]),
+            count: *count,
+        }).collect())
+    }
+
+    pub fn get_summary(ctx: Context<GetSummary>, _namespace: String) -> Result<RegistrySummary> {
+        Ok(RegistrySummary::from_accounts(
+            &ctx.accounts.registry,
+            &ctx.accounts.failure_catalog,
+            &ctx.accounts.priority_fee_stats,
+        ))
+    }
+
+    pub fn compute_success_rate(
+        ctx: Context<ComputeSuccessRate>,
+        _namespace: String,
+        min_tx_count: u64,
+    ) -> Result<SuccessRate> {
+        let registry = &ctx.accounts.registry;
+        registry.require_min_tx_count(min_tx_count)?;
+
+        let rate = SuccessRate {
+            success_rate_bps: registry.success_rate_bps(),
+            lower_bound_bps: registry.success_rate_lower_bound_bps(),
+        };
+        msg!("Success rate: {} bps (lower bound {} bps) over {} reports",
+             rate.success_rate_bps, rate.lower_bound_bps, registry.tx_count);
+        Ok(rate)
+    }
+
+    pub fn compute_dominant_failure(
+        ctx: Context<ComputeDominantFailure>,
+        _namespace: String,
+        min_tx_count: u64,
+    ) -> Result<u8> {
+        ctx.accounts.registry.require_min_tx_count(min_tx_count)?;
+
+        let (failure_type, count) = ctx.accounts.failure_catalog.dominant_failure();
+        msg!("Dominant failure: type={}, count={}", failure_type, count);
+        Ok(failure_type)
+    }
+
+    /// Baseline guess at the next failure type: the one with the largest share of past
+    /// failures. `recent_context` (RECENT_CONTEXT_*) is validated but doesn't bias the
+    /// guess yet, since the catalog keeps no per-context counts.
+    pub fn project_next_failure(
+        ctx: Context<ProjectNextFailure>,
+        _namespace: String,
+        recent_context: u8,
+    ) -> Result<u8> {
+        require!(recent_context <= RECENT_CONTEXT_VOLATILE, TxGuardError::InvalidRecentContext);
+        let failure_type = ctx.accounts.failure_catalog.project_next_failure()
+            .ok_or(TxGuardError::InsufficientData)?;
+
+        msg!("Projected next failure: type={}, context={}", failure_type, recent_context);
+        Ok(failure_type)
+    }
+
+    pub fn format_failure_pie(ctx: Context<FormatFailurePie>, _namespace: String) -> Result<String> {
+        let pie = ctx.accounts.failure_catalog.format_failure_pie();
+
+        msg!("{}", pie);
+        Ok(pie)
+    }
+
+    pub fn get_stats(ctx: Context<GetStats>, _namespace: String) -> Result<StatsReport> {
+        let hour = current_hour(Clock::get()?.unix_timestamp);
+        Ok(StatsReport {
+            hours: ctx.accounts.hourly_stats.last_24_hours(hour),
+            failure_gap_histogram: ctx.accounts.failure_catalog.failure_gap_histogram,
+            success_compute: ctx.accounts.compute_stats.success_compute,
+            failure_compute: ctx.accounts.compute_stats.failure_compute,
+            near_cu_limit_count: ctx.accounts.compute_stats.near_cu_limit_count,
+            tx_size_buckets: ctx.accounts.tx_size_stats.buckets(),
+            instruction_buckets: ctx.accounts.instruction_stats.buckets(),
+            max_instructions: ctx.accounts.instruction_stats.max_instructions,
+            signer_buckets: ctx.accounts.registry.signer_buckets(),
+            blockhash_age_buckets: ctx.accounts.blockhash_age_stats.buckets(),
+            stale_blockhash_failures: ctx.accounts.blockhash_age_stats.stale_failures,
+            simulated_successes: ctx.accounts.registry.simulation_successes[1],
+            simulated_failures: ctx.accounts.registry.simulation_failures[1],
+            unsimulated_successes: ctx.accounts.registry.simulation_successes[0],
+            unsimulated_failures: ctx.accounts.registry.simulation_failures[0],
+            send_method_buckets: ctx.accounts.registry.send_method_buckets(),
+            send_method_dominant_failures: ctx.accounts.registry.send_method_dominant_failures(),
+            route_hop_buckets: ctx.accounts.route_stats.buckets(),
+            route_slippage_failures: ctx.accounts.route_stats.slippage_failures_by_hops,
+            fee_sample_count: ctx.accounts.priority_fee_stats.fee_sample_count,
+            fee_mean_lamports: ctx.accounts.priority_fee_stats.fee_mean_lamports(),
+            fee_variance: ctx.accounts.priority_fee_stats.fee_variance(),
+        })
+    }
+
+    pub fn compute_wasted_fees(
+        ctx: Context<ComputeWastedFees>,
+        _namespace: String,
+        tier_lamports: [u64; MAX_TIERS as usize],
+    ) -> Result<u64> {
+        let wasted = ctx.accounts.priority_fee_stats.wasted_fee_lamports(&tier_lamports);
+
+        msg!("Estimated wasted priority fees: {} lamports", wasted);
+        Ok(wasted)
+    }
+
+    /// What each step up a tier buys: the success rate gained over the tier below and the
+    /// extra lamports it costs under the configured tier mapping.
+    pub fn compute_fee_roi(ctx: Context<ComputeFeeRoi>, _namespace: String) -> Result<Vec<FeeROI>> {
+        let lamports: [u64; MAX_TIERS as usize] = ctx.accounts.tier_lamport_config.tier_min_lamports.as_slice()
+            .try_into()
+            .map_err(|_| TxGuardError::InvalidTierLamports)?;
+        let roi = ctx.accounts.priority_fee_stats.fee_roi(&lamports);
+
+        for entry in &roi {
+            msg!("Tier {}: {:+} bps for {} lamports", entry.tier, entry.marginal_success_gain_bps, entry.marginal_lamport_cost);
+        }
+        Ok(roi)
+    }
+
+    pub fn get_recent_rate(
+        ctx: Context<GetRecentRate>,
+        _namespace: String,
+        max_age_slots: u64,
+    ) -> Result<RecentRate> {
+        Ok(ctx.accounts.registry.recent_rate(Clock::get()?.slot, max_age_slots))
+    }
+
+    /// Per-bucket report counts for `tier` over the last-100 window, oldest bucket first, for
+    /// drawing a sparkline. See TransactionRegistry::tier_sparkline for how entries are bucketed.
+    pub fn get_tier_sparkline(
+        ctx: Context<GetTierSparkline>,
+        _namespace: String,
+        tier: u8,
+        buckets: u8,
+    ) -> Result<Vec<u16>> {
+        require!(tier < MAX_TIERS, TxGuardError::InvalidPriorityFeeTier);
+        require!(buckets > 0 && buckets <= MAX_SPARKLINE_BUCKETS, TxGuardError::InvalidSparklineBuckets);
+
+        let sparkline = ctx.accounts.registry.tier_sparkline(tier, buckets);
+        msg!("Tier {} sparkline over {} buckets: {:?}", tier, buckets, sparkline);
+        Ok(sparkline)
+    }
+
+    /// Success, MEV failure, success runs in the last-100 window. `sandwich_pattern_count`
+    /// on the registry keeps the all-time total.
+    pub fn detect_sandwich_patterns(ctx: Context<DetectSandwichPatterns>, _namespace: String) -> Result<u32> {
+        let registry = &ctx.accounts.registry;
+        let patterns = registry.sandwich_patterns();
+        msg!("Sandwich patterns: {} in window, {} all time", patterns, registry.sandwich_pattern_count);
+        Ok(patterns)
+    }
+
+    /// How much of the last-100 window is filled, so callers can tell how much of it is
+    /// still pending before trusting windowed statistics.
+    pub fn get_buffer_utilization(ctx: Context<GetBufferUtilization>, _namespace: String) -> Result<BufferUtilization> {
+        let utilization = ctx.accounts.registry.buffer_utilization();
+        require!(utilization.non_pending_count > 0, TxGuardError::BufferEmpty);
+
+        msg!("Buffer utilization: {}/{} filled, {} pending",
+             utilization.non_pending_count, utilization.buffer_len, utilization.pending_count);
+        Ok(utilization)
+    }
+
+    /// Success rate in bps for each `TxOutcomeParams::tags` bit, indexed by bit; bits
+    /// never reported come back as 0. What each bit means is up to the deployer.
+    pub fn get_tag_stats(ctx: Context<GetTagStats>, _namespace: String) -> Result<[u16; 16]> {
+        let rates = ctx.accounts.tag_stats.success_rates_bps();
+        msg!("Tag success rates (bps): {:?}", rates);
+        Ok(rates)
+    }
+
+    /// Success and failure totals for one protocol instruction type, as declared through
+    /// `TxOutcomeParams::instruction_type`. Types never reported return zeros.
+    pub fn get_instruction_stats(
+        ctx: Context<GetInstructionStats>,
+        _namespace: String,
+        instruction_type: u8,
+    ) -> Result<InstructionTypeTotals> {
+        let totals = ctx.accounts.registry.instruction_totals(instruction_type);
+        msg!("Instruction type {}: {} successes, {} failures", instruction_type, totals.success, totals.failure);
+        Ok(totals)
+    }
+
+    /// Flags the failure type that has grown furthest out of line with the baseline
+    /// snapshot's failure mix, see FailureCatalog::detect_anomaly.
+    pub fn detect_anomaly(ctx: Context<DetectAnomaly>, _namespace: String, z_threshold_bps: u16) -> Result<AnomalyReport> {
+        let report = ctx.accounts.failure_catalog.detect_anomaly(&ctx.accounts.baseline_snapshot, z_threshold_bps);
+        msg!("Anomaly check: detected={}, type={}, z={} bps",
+             report.anomaly_detected, report.anomalous_type, report.z_score_bps);
+        Ok(report)
+    }
+
+    /// Before/after result for a protocol upgrade: failures per core type since `snapshot`,
+    /// and which types now fail more or less often per report than they did up to it.
+    pub fn compare_to_snapshot(ctx: Context<CompareToSnapshot>, _namespace: String) -> Result<CatalogDiff> {
+        let diff = ctx.accounts.failure_catalog.compare_to_snapshot(&ctx.accounts.registry, &ctx.accounts.snapshot);
+        msg!("Snapshot comparison: epoch={}, improved={:#08b}, regressed={:#08b}",
+             ctx.accounts.snapshot.epoch, diff.improved_types, diff.regressed_types);
+        Ok(diff)
+    }
+
+    /// Copy the catalog's counters into `dest` for a program later in the same transaction
+    /// to read. `dest` is an account the caller controls and signs for: a fresh one is
+    /// allocated and handed to TxGuard on the first write, which claims it for `writer`;
+    /// only they can overwrite it.
+    pub fn write_catalog_snapshot_to_account(ctx: Context<WriteCatalogSnapshot>, _namespace: String) -> Result<()> {
+        let writer = ctx.accounts.writer.key();
+        let info = ctx.accounts.dest.to_account_info();
+        let space = 8 + CatalogBorshSnapshot::INIT_SPACE;
+
+        // Only an account's owner may write its data. A fresh account the caller holds
+        // the key to (a keypair, or a PDA of the calling program) is handed to TxGuard on
+        // its first write, with `writer` paying the rent
+        if info.owner == &system_program::ID {
+            let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+            if shortfall > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.writer.to_account_info(),
+                            to: info.clone(),
+                        },
+                    ),
+                    shortfall,
+                )?;
+            }
+            system_program::allocate(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Allocate { account_to_allocate: info.clone() },
+                ),
+                space as u64,
+            )?;
+            system_program::assign(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Assign { account_to_assign: info.clone() },
+                ),
+                ctx.program_id,
+            )?;
+        }
+        require_keys_eq!(*info.owner, *ctx.program_id, TxGuardError::InvalidSnapshotAccount);
+        {
+            let data = info.try_borrow_data()?;
+            let claimable = data.iter().all(|byte| *byte == 0)
+                || CatalogBorshSnapshot::try_deserialize(&mut &data[..])
+                    .is_ok_and(|snapshot| snapshot.writer == writer);
+            require!(
+                data.len() >= space && claimable,
+                TxGuardError::InvalidSnapshotAccount
+            );
+        }
+
+        let snapshot = CatalogBorshSnapshot::capture(writer, ctx.accounts.failure_catalog.key(), &ctx.accounts.failure_catalog)?;
+        let mut data = info.try_borrow_mut_data()?;
+        snapshot.try_serialize(&mut &mut data[..])?;
+        msg!("Catalog snapshot written: dest={}, failures={}", info.key(), snapshot.total_failures);
+        Ok(())
+    }
+
+    /// Daily counts for `tier` over the last `days` days, oldest first and ending today.
+    /// Pass the TierDailySnapshot PDAs as remaining accounts; days without one read as 0.
+    pub fn get_tier_trend(ctx: Context<GetTierTrend>, namespace: String, tier: u8, days: u8) -> Result<Vec<u64>> {
+        require!(tier < MAX_TIERS, TxGuardError::InvalidPriorityFeeTier);
+        require!(days > 0 && days <= MAX_TREND_DAYS, TxGuardError::InvalidTrendWindow);
+
+        let today = current_day(Clock::get()?.unix_timestamp) as i64;
+        let mut trend = Vec::with_capacity(days as usize);
+        for day in (today - days as i64 + 1)..=today {
+            let (expected, _) = tier_daily_address(&namespace, day, ctx.program_id);
+            let count = match ctx.remaining_accounts.iter().find(|info| info.key() == expected) {
+                Some(info) => {
+                    require_keys_eq!(*info.owner, *ctx.program_id, TxGuardError::InvalidTierSnapshot);
+                    let snapshot = TierDailySnapshot::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+                    snapshot.tier_counts.get(tier as usize).copied().unwrap_or(0)
+                }
+                None => 0,
+            };
+            trend.push(count);
+        }
+
+        msg!("Tier {} trend over {} days: {:?}", tier, days, trend);
+        Ok(trend)
+    }
+
+    pub fn check_health(ctx: Context<CheckHealth>, _namespace: String) -> Result<HealthStatus> {
+        let health = ctx.accounts.registry.health(Clock::get()?.slot, &ctx.accounts.config);
+        msg!("Health: success_rate={} bps, lower_bound={} bps, slots_since_last_report={}, stale={}, below_min={}",
+             health.success_rate_bps, health.success_rate_lower_bound_bps, health.slots_since_last_report,
+             health.stale, health.below_min_success_rate);
+        Ok(health)
+    }
+
+    pub fn get_peak_hour_of_week(ctx: Context<GetPeakHourOfWeek>, _namespace: String) -> Result<PeakHour> {
+        let peak = ctx.accounts.registry.peak_hour_of_week();
+        msg!("Peak hour of week: {} ({} reports)", peak.hour_of_week, peak.count);
+        Ok(peak)
+    }
+
+    /// Weekday (Monday = 0, UTC) with the best all-time success rate, for scheduling work
+    /// that can wait for a historically reliable day.
+    pub fn get_best_day(ctx: Context<GetBestDay>, _namespace: String) -> Result<BestDay> {
+        let best = ctx.accounts.registry.best_day();
+        msg!("Best day: {} ({} bps over {} reports)", best.day_of_week, best.success_rate_bps, best.sample_count);
+        Ok(best)
+    }
+
+    /// One page of the registry, catalog or priority fee stats encoding; the accounts outgrew
+    /// return data long ago, so callers page through them. See EXPORT_SCHEMA_VERSION.
+    pub fn export_state(ctx: Context<ExportState>, _namespace: String, section: u8, offset: u32) -> Result<Vec<u8>> {
+        let payload = match section {
+            EXPORT_REGISTRY => export_page(&*ctx.accounts.registry, offset),
+            EXPORT_CATALOG => export_page(&*ctx.accounts.failure_catalog, offset),
+            EXPORT_PRIORITY_STATS => export_page(&*ctx.accounts.priority_fee_stats, offset),
+            _ => None,
+        }
+        .ok_or(TxGuardError::InvalidExportPage)?;
+        require!(payload.len() <= MAX_EXPORT_LEN, TxGuardError::ExportTooLarge);
+        Ok(payload)
+    }
+
+    pub fn merge_registries(
+        ctx: Context<MergeRegistries>,
+        source_namespace: String,
+        destination_namespace: String,
+        source_user: Option<Pubkey>,
+        destination_user: Option<Pubkey>,
+        close_source: bool,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+        require_keys_neq!(accounts.source.key(), accounts.destination.key(), TxGuardError::SelfMerge);
+        accounts.source.ensure_unlocked()?;
+        accounts.destination.ensure_unlocked()?;
+
+        let (source_address, _) = registry_address(&source_namespace, source_user, ctx.program_id);
+        let (destination_address, _) = registry_address(&destination_namespace, destination_user, ctx.program_id);
+        require_keys_eq!(accounts.source.key(), source_address, TxGuardError::InvalidRegistry);
+        require_keys_eq!(accounts.destination.key(), destination_address, TxGuardError::InvalidRegistry);
+
+        accounts.destination.merge_from(&accounts.source)?;
+
+        // Catalogs are only merged when both sides are supplied
+        if let (Some(source_catalog), Some(destination_catalog)) =
+            (accounts.source_catalog.as_ref(), accounts.destination_catalog.as_mut())
+        {
+            require_keys_neq!(source_catalog.key(), destination_catalog.key(), TxGuardError::SelfMerge);
+            destination_catalog.merge_from(source_catalog)?;
+            destination_catalog.partial_fill_rate_bps = (destination_catalog.partial_fill as u128 * 10_000
+                / accounts.destination.tx_count.max(1) as u128) as u16;
+        }
+
+        if close_source {
+            // Rent goes back to whoever owns the source: the user or the namespace authority
+            let rent_recipient = accounts.rent_recipient.as_ref()
+                .ok_or(TxGuardError::InvalidRentRecipient)?;
+            let owner = source_user.unwrap_or(accounts.authority.key());
+            require_keys_eq!(rent_recipient.key(), owner, TxGuardError::InvalidRentRecipient);
+            accounts.source.close(rent_recipient.to_account_info())?;
+        }
+
+        msg!("Registries merged: source={}, destination={}, closed={}",
+             source_address, destination_address, close_source);
+        Ok(())
+    }
+
+    /// Folds a child namespace's failure catalog into a parent's, e.g. one TxGuard
+    /// namespace per pool rolled up into a protocol-wide summary. Both namespaces must
+    /// share the signing authority, which may be a PDA of the calling program.
+    pub fn merge_into_parent(
+        ctx: Context<MergeIntoParent>,
+        child_namespace: String,
+        parent_namespace: String,
+        zero_child: bool,
+    ) -> Result<()> {
+        require!(child_namespace != parent_namespace, TxGuardError::SelfMerge);
+
+        let accounts = ctx.accounts;
+        let parent = &mut accounts.parent_catalog;
+        parent.merge_from(&accounts.child_catalog)?;
+        parent.child_count = parent.child_count.checked_add(1)
+            .ok_or(TxGuardError::CountOverflow)?;
+
+        if zero_child {
+            accounts.child_catalog.reset();
+        }
+
+        msg!("Catalog merged into parent: child={:?}, parent={:?}, children={}, zeroed={}",
+             child_namespace, parent_namespace, accounts.parent_catalog.child_count, zero_child);
+        Ok(())
+    }
+
+    /// Moves the registry's all-time counters into a new ArchiveRegistry PDA and restarts
+    /// them from zero, so old reports stop weighing on current metrics. Every counted
+    /// report must predate `archive_before_slot`. The last-100 window is kept, but its
+    /// entries can no longer be disputed.
+    pub fn archive_registry(ctx: Context<CreateArchive>, namespace: String, archive_before_slot: u64) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.ensure_unlocked()?;
+        let slot = Clock::get()?.slot;
+        require!(
+            registry.last_report_slot < archive_before_slot
+                && registry.archived_before_slot < archive_before_slot
+                && archive_before_slot <= slot,
+            TxGuardError::InvalidArchiveSlot
+        );
+
+        // session_id only grows, so this only trips on an account created some other way
+        require!(ctx.accounts.archive.registry == Pubkey::default(), TxGuardError::ArchiveExists);
+        let registry_key = registry.key();
+        let catalog = &mut ctx.accounts.failure_catalog;
+        ctx.accounts.archive.set_inner(ArchiveRegistry::capture(registry_key, registry, catalog, archive_before_slot, slot));
+        registry.archive_counters(archive_before_slot)?;
+        catalog.archive_counts();
+
+        msg!("Registry archived: namespace={:?}, session={}, before_slot={}, tx_count={}",
+             namespace, ctx.accounts.archive.session_id, archive_before_slot, ctx.accounts.archive.tx_count);
+        Ok(())
+    }
+
+    pub fn transfer_user_registry(
+        ctx: Context<TransferUserRegistry>,
+        namespace: String,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+        let old_owner = accounts.owner.key();
+        require_keys_neq!(old_owner, new_owner, TxGuardError::InvalidNewOwner);
+        accounts.user_registry.ensure_unlocked()?;
+
+        // init_if_needed lets us fail with a pointer to merge_registries instead of
+        // the system program's generic "already in use"
+        require!(
+            accounts.new_user_registry.last_100_outcomes.is_empty(),
+            TxGuardError::DestinationRegistryExists
+        );
+        accounts.new_user_registry.set_inner((*accounts.user_registry).clone());
+
+        // The old registry is closed by the `close = owner` constraint on exit
+        emit!(UserRegistryTransferred {
+            namespace,
+            old_owner,
+            new_owner,
+            registry: accounts.new_user_registry.key(),
+        });
+        Ok(())
+    }
+
+    pub fn set_tier_lamports(
+        ctx: Context<SetTierLamports>,
+        _namespace: String,
+        tier_min_lamports: Vec<u64>,
+    ) -> Result<()> {
+        require!(tier_min_lamports.len() == MAX_TIERS as usize, TxGuardError::InvalidTierLamports);
+        require!(
+            tier_min_lamports.windows(2).all(|pair| pair[0] <= pair[1]),
+            TxGuardError::InvalidTierLamports
+        );
+
+        ctx.accounts.tier_lamport_config.tier_min_lamports = tier_min_lamports;
+
+        msg!("Tier lamport mapping updated");
+        Ok(())
+    }
+
+    /// Respaces the tier lamport boundaries within [lamport_min, lamport_max] so each tier
+    /// would have seen an equal share of past usage, see PriorityFeeStats::calibrated_boundaries.
+    pub fn calibrate_tiers(
+        ctx: Context<CalibrateTiers>,
+        namespace: String,
+        lamport_min: u64,
+        lamport_max: u64,
+    ) -> Result<()> {
+        require!(lamport_min < lamport_max, TxGuardError::InvalidTierLamports);
+        let old_boundaries: [u64; MAX_TIERS as usize] = ctx.accounts.tier_lamport_config.tier_min_lamports.as_slice()
+            .try_into()
+            .map_err(|_| TxGuardError::InvalidTierLamports)?;
+        let new_boundaries = ctx.accounts.priority_fee_stats
+            .calibrated_boundaries(&old_boundaries, lamport_min, lamport_max)
+            .ok_or(TxGuardError::InsufficientData)?;
+
+        ctx.accounts.tier_lamport_config.tier_min_lamports = new_boundaries.to_vec();
+
+        emit!(TiersCalibrated {
+            namespace,
+            old_boundaries,
+            new_boundaries,
+        });
+        msg!("Tiers calibrated: {:?} -> {:?}", old_boundaries, new_boundaries);
+        Ok(())
+    }
+
+    pub fn recommend_fee_lamports(
+        ctx: Context<RecommendFeeLamports>,
+        _namespace: String,
+        target_success_rate_bps: u16,
+    ) -> Result<u64> {
+        let stats = &ctx.accounts.priority_fee_stats;
+        let lamports = &ctx.accounts.tier_lamport_config.tier_min_lamports;
+        require!(lamports.len() == MAX_TIERS as usize, TxGuardError::InvalidTierLamports);
+
+        // Cheapest tier that has historically met the target; fall back to the top tier
+        let tier = (0..MAX_TIERS as usize)
+            .find(|&tier| {
+                stats.tier_success_rate_bps(tier)
+                    .is_some_and(|rate| rate >= target_success_rate_bps)
+            })
+            .unwrap_or(MAX_TIERS as usize - 1);
+
+        msg!("Recommended tier {} for target {} bps", tier, target_success_rate_bps);
+        Ok(lamports[tier])
+    }
+
+    /// Cheapest tier whose average confirmation latency fits within `deadline_slots`, or
+    /// the fastest tier on average when none does, with the share of that tier's reports
+    /// confirmed within the deadline. Only reports that declare submitted_slot and
+    /// confirmed_slot and pass the latency account count.
+    pub fn recommend_tier_for_deadline(
+        ctx: Context<RecommendTierForDeadline>,
+        _namespace: String,
+        deadline_slots: u64,
+    ) -> Result<DeadlineRecommendation> {
+        let recommendation = ctx.accounts.priority_fee_stats
+            .recommend_for_deadline(&ctx.accounts.latency_stats, deadline_slots)
+            .ok_or(TxGuardError::InsufficientData)?;
+
+        msg!("Recommended tier {} for a {}-slot deadline: confidence={} bps",
+             recommendation.tier, deadline_slots, recommendation.confidence_bps);
+        Ok(recommendation)
+    }
+
+    pub fn set_blockhash_validity_window(
+        ctx: Context<SetBlockhashValidityWindow>,
+        _namespace: String,
+        blockhash_validity_window: u8,
+    ) -> Result<()> {
+        require!(blockhash_validity_window > 0, TxGuardError::InvalidValidityWindow);
+        ctx.accounts.registry.ensure_unlocked()?;
+        ctx.accounts.registry.blockhash_validity_window = blockhash_validity_window;
+
+        msg!("Blockhash validity window updated: slots={}", blockhash_validity_window);
+        Ok(())
+    }
+
+    /// Declares whether this namespace's transactions use the ed25519 or secp256k1
+    /// verify precompiles, i.e. whether a zero signature_verify_failure count means anything.
+    pub fn set_precompile_usage(
+        ctx: Context<SetPrecompileUsage>,
+        _namespace: String,
+        used_ed25519_precompile: bool,
+    ) -> Result<()> {
+        ctx.accounts.registry.ensure_unlocked()?;
+        ctx.accounts.registry.used_ed25519_precompile = used_ed25519_precompile;
+
+        msg!("Signature precompile usage updated: {}", used_ed25519_precompile);
+        Ok(())
+    }
+
+    pub fn set_report_cooldown(
+        ctx: Context<UpdateConfig>,
+        _namespace: String,
+        min_slots_between_reports: u64,
+    ) -> Result<()> {
+        ctx.accounts.config.min_slots_between_reports = min_slots_between_reports;
+
+        msg!("Report cooldown updated: min_slots={}", min_slots_between_reports);
+        Ok(())
+    }
+
+    /// Per-registry settings, kept apart from the namespace-wide GuardConfig; see RegistryConfig
+    pub fn update_registry_config(
+        ctx: Context<UpdateRegistryConfig>,
+        _namespace: String,
+        max_tx_count: Option<u64>,
+        buffer_size: u16,
+        strict_failure_types: bool,
+        observer: Option<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            (1..=WINDOW_SIZE).contains(&buffer_size),
+            TxGuardError::InvalidBufferSize
+        );
+        let authority = ctx.accounts.authority.key();
+        let registry_config = &mut ctx.accounts.registry_config;
+        registry_config.init_defaults(authority);
+        require_keys_eq!(registry_config.authority, authority, TxGuardError::Unauthorized);
+
+        registry_config.max_tx_count = max_tx_count;
+        registry_config.buffer_size = buffer_size;
+        registry_config.strict_failure_types = strict_failure_types;
+        registry_config.observer = observer;
+
+        msg!("Registry config updated: max_tx_count={:?}, buffer_size={}, strict_failure_types={}, observer={:?}",
+             max_tx_count, buffer_size, strict_failure_types, observer);
+        Ok(())
+    }
+
+    pub fn reset_seasonality(ctx: Context<ResetSeasonality>, _namespace: String) -> Result<()> {
+        ctx.accounts.seasonality_stats.reset();
+
+        msg!("Seasonality stats reset");
+        Ok(())
+    }
+
+    /// Zeroes only the listed failure types (counters, occurrence timestamps and any
+    /// per-type detail), e.g. MEV after shipping a MEV-protection fix.
+    pub fn reset_failure_types(
+        ctx: Context<ResetFailureTypes>,
+        namespace: String,
+        types_to_reset: Vec<u8>,
+    ) -> Result<()> {
+        require!(
+            !types_to_reset.is_empty() && types_to_reset.iter().all(|code| FAILURE_CODES.contains(code)),
+            TxGuardError::InvalidFailureType
+        );
+        let catalog = &mut ctx.accounts.failure_catalog;
+        for failure_type in &types_to_reset {
+            catalog.reset_type(*failure_type);
+        }
+        catalog.catalog_reset_count = catalog.catalog_reset_count.saturating_add(1);
+
+        emit!(SelectiveReset {
+            namespace,
+            types_reset: types_to_reset.clone(),
+        });
+        msg!("Failure types reset: {:?}", types_to_reset);
+        Ok(())
+    }
+
+    pub fn set_freshness_bound(
+        ctx: Context<UpdateConfig>,
+        _namespace: String,
+        max_report_staleness_slots: u64,
+    ) -> Result<()> {
+        ctx.accounts.config.max_report_staleness_slots = max_report_staleness_slots;
+
+        msg!("Freshness bound updated: max_report_staleness_slots={}", max_report_staleness_slots);
+        Ok(())
+    }
+
+    pub fn set_health_threshold(
+        ctx: Context<UpdateConfig>,
+        _namespace: String,
+        min_success_rate_bps: u16,
+        use_lower_bound: bool,
+    ) -> Result<()> {
+        require!(min_success_rate_bps <= 10_000, TxGuardError::InvalidSuccessRateThreshold);
+        let config = &mut ctx.accounts.config;
+        config.min_success_rate_bps = min_success_rate_bps;
+        config.health_uses_lower_bound = use_lower_bound;
+
+        msg!("Health threshold updated: min_success_rate_bps={}, use_lower_bound={}", min_success_rate_bps, use_lower_bound);
+        Ok(())
+    }
+
+    pub fn set_slot_quota(
+        ctx: Context<UpdateConfig>,
+        _namespace: String,
+        max_reports_per_slot: u32,
+    ) -> Result<()> {
+        ctx.accounts.config.max_reports_per_slot = max_reports_per_slot;
+
+        msg!("Slot quota updated: max_reports_per_slot={}", max_reports_per_slot);
+        Ok(())
+    }
+
+    pub fn set_report_fee(
+        ctx: Context<SetReportFee>,
+        _namespace: String,
+        report_fee_lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.config.report_fee_lamports = report_fee_lamports;
+
+        msg!("Report fee updated: lamports={}", report_fee_lamports);
+        Ok(())
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, _namespace: String, amount: u64) -> Result<()> {
+        let recipient = ctx.accounts.recipient.to_account_info();
+
+        // The treasury stays rent-exempt; only collected fees can leave
+        Treasury::pay_out(&ctx.accounts.treasury.to_account_info(), &recipient, amount)?;
+
+        msg!("Treasury withdrawal: amount={}, recipient={}", amount, recipient.key());
+        Ok(())
+    }
+
+    pub fn set_crank_config(
+        ctx: Context<UpdateConfig>,
+        _namespace: String,
+        crank_interval_slots: u64,
+        crank_reward_lamports: u64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.crank_interval_slots = crank_interval_slots;
+        config.crank_reward_lamports = crank_reward_lamports;
+
+        msg!("Crank config updated: interval_slots={}, reward={}", crank_interval_slots, crank_reward_lamports);
+        Ok(())
+    }
+
+    /// Permissionless maintenance, callable once per `crank_interval_slots`. Pays the
+    /// caller `crank_reward_lamports` from the treasury; fails without side effects if
+    /// called too early or if the treasury can't cover the bounty.
+    pub fn crank_aggregate(ctx: Context<CrankAggregate>, _namespace: String) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let config = &ctx.accounts.config;
+        require!(
+            config.last_crank_slot == 0
+                || slot.saturating_sub(config.last_crank_slot) >= config.crank_interval_slots,
+            TxGuardError::CrankTooEarly
+        );
+
+        let reward = config.crank_reward_lamports;
+        Treasury::pay_out(
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.cranker.to_account_info(),
+            reward,
+        )?;
+
+        // Maintenance: open today's daily slot even if nothing has been reported yet
+        if let Some(daily_stats) = ctx.accounts.daily_stats.as_mut() {
+            daily_stats.advance(current_day(Clock::get()?.unix_timestamp));
+        }
+
+        // Refresh rates derived from the registry
+        let catalog = &mut ctx.accounts.failure_catalog;
+        let tx_count = ctx.accounts.registry.tx_count;
+        if tx_count > 0 {
+            catalog.partial_fill_rate_bps = (catalog.partial_fill as u128 * 10_000 / tx_count as u128) as u16;
+        }
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_crank_rewards = treasury.total_crank_rewards.checked_add(reward)
+            .ok_or(TxGuardError::CountOverflow)?;
+        ctx.accounts.config.last_crank_slot = slot;
+
+        msg!("Crank run: slot={}, reward={}", slot, reward);
+        Ok(())
+    }
+
+    pub fn set_bulk_reporter(
+        ctx: Context<SetBulkReporter>,
+        _namespace: String,
+        reporter: Pubkey,
+        bulk_reporter: bool,
+    ) -> Result<()> {
+        ctx.accounts.reporter_record.bulk_reporter = bulk_reporter;
+
+        msg!("Bulk reporter updated: reporter={}, exempt={}", reporter, bulk_reporter);
+        Ok(())
+    }
+
+    pub fn set_max_report_age(ctx: Context<UpdateConfig>, _namespace: String, max_report_age_slots: u64) -> Result<()> {
+        ctx.accounts.config.max_report_age_slots = max_report_age_slots;
+
+        msg!("Max report age updated: max_report_age_slots={}", max_report_age_slots);
+        Ok(())
+    }
+
+    pub fn set_reward_points(
+        ctx: Context<UpdateConfig>,
+        _namespace: String,
+        success_report_points: u64,
+        failure_report_points: u64,
+        max_points_per_slot: u64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.success_report_points = success_report_points;
+        config.failure_report_points = failure_report_points;
+        config.max_points_per_slot = max_points_per_slot;
+
+        msg!(
+            "Reward points updated: success={}, failure={}, max_per_slot={}",
+            success_report_points,
+            failure_report_points,
+            max_points_per_slot
+        );
+        Ok(())
+    }
+
+    pub fn set_points_multiplier(ctx: Context<UpdateConfig>, _namespace: String, points_multiplier: u16) -> Result<()> {
+        ctx.accounts.config.points_multiplier = points_multiplier;
+
+        msg!("Points multiplier updated: multiplier={}", points_multiplier);
+        Ok(())
+    }
+
+    pub fn set_reputation_params(
+        ctx: Context<UpdateConfig>,
+        _namespace: String,
+        increment: u16,
+        penalty: u16,
+        half_life_slots: u64,
+        min_reputation: u16,
+    ) -> Result<()> {
+        require!(min_reputation <= MAX_REPUTATION, TxGuardError::InvalidReputation);
+        let config = &mut ctx.accounts.config;
+        config.reputation_increment = increment;
+        config.reputation_penalty = penalty;
+        config.reputation_half_life_slots = half_life_slots;
+        config.min_reputation = min_reputation;
+
+        msg!(
+            "Reputation updated: increment={}, penalty={}, half_life_slots={}, min={}",
+            increment,
+            penalty,
+            half_life_slots,
+            min_reputation
+        );
+        Ok(())
+    }
+
+    // window_index points into the reporter's recent_outcomes ring; each report can be
+    // flagged once, and the flag clears when the ring overwrites it
+    pub fn flag_report(ctx: Context<FlagReport>, namespace: String, reporter: Pubkey, window_index: u8) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let record = &mut ctx.accounts.reporter_record;
+        record.flag(window_index)?;
+        let slot = Clock::get()?.slot;
+        record.adjust_reputation(-(config.reputation_penalty as i32), slot, config.reputation_half_life_slots);
+
+        emit!(ReportFlagged {
+            namespace,
+            reporter,
+            window_index,
+            reputation: record.reputation,
+        });
+        Ok(())
+    }
+
+    // Challenges one last_100_outcomes entry of the namespace registry, escrowing the bond
+    // in the Dispute PDA until the authority rules on it
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        namespace: String,
+        window_index: u8,
+        claimed_success: bool,
+        bond_lamports: u64,
+    ) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let dispute = &mut ctx.accounts.dispute;
+        // init_if_needed: the PDA is closed on resolution, so a live one is still open
+        require!(dispute.disputer == Pubkey::default(), TxGuardError::DisputeAlreadyOpen);
+        require!((window_index as usize) < registry.last_100_outcomes.len(), TxGuardError::InvalidWindowIndex);
+        let index = window_index as usize;
+        let outcome = registry.last_100_outcomes[index];
+        let disputable = match outcome {
+            OUTCOME_SUCCESS => !claimed_success,
+            OUTCOME_FAILURE => claimed_success,
+            _ => false,
+        };
+        require!(disputable, TxGuardError::InvalidDispute);
+
+        if bond_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.disputer.to_account_info(),
+                        to: dispute.to_account_info(),
+                    },
+                ),
+                bond_lamports,
+            )?;
+        }
+
+        // The ring reaches this entry again after this many more reports
+        let reports_until_overwrite = (index + 100 - registry.cursor as usize) % 100 + 1;
+        dispute.disputer = ctx.accounts.disputer.key();
+        dispute.window_index = window_index;
+        dispute.claimed_success = claimed_success;
+        dispute.bond_lamports = bond_lamports;
+        dispute.outcome_slot = registry.outcome_slots.get(index).copied().unwrap_or(0);
+        dispute.overwrite_tx_count = registry.tx_count.saturating_add(reports_until_overwrite as u64);
+        dispute.opened_slot = Clock::get()?.slot;
+
+        emit!(DisputeOpened {
+            namespace,
+            disputer: dispute.disputer,
+            window_index,
+            claimed_success,
+            bond_lamports,
+        });
+        Ok(())
+    }
+
+    // Accepting amends the entry and refunds the bond; rejecting sends the bond to the
+    // treasury. Either way the Dispute PDA closes and its rent goes back to the disputer.
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, namespace: String, window_index: u8, accept: bool) -> Result<()> {
+        ctx.accounts.registry.ensure_unlocked()?;
+        let dispute = &ctx.accounts.dispute;
+        let bond_lamports = dispute.bond_lamports;
+
+        if accept {
+            let registry = &mut ctx.accounts.registry;
+            let index = window_index as usize;
+            // The entry may have been overwritten by later reports since the dispute opened
+            require!(
+                registry.tx_count < dispute.overwrite_tx_count
+                    && registry.outcome_slots.get(index).copied().unwrap_or(0) == dispute.outcome_slot,
+                TxGuardError::DisputeEntryOverwritten
+            );
+            // Its outcome is counted in an archive, not the live counters
+            require!(dispute.outcome_slot >= registry.archived_before_slot, TxGuardError::DisputeEntryArchived);
+            registry.amend_outcome(index, dispute.claimed_success)?;
+        } else if bond_lamports > 0 {
+            let treasury = ctx.accounts.treasury.as_mut().ok_or(TxGuardError::MissingTreasury)?;
+            **dispute.to_account_info().try_borrow_mut_lamports()? -= bond_lamports;
+            let treasury_info = treasury.to_account_info();
+            **treasury_info.try_borrow_mut_lamports()? = treasury_info.lamports()
+                .checked_add(bond_lamports)
+                .ok_or(TxGuardError::CountOverflow)?;
+            treasury.total_fees_collected = treasury.total_fees_collected.checked_add(bond_lamports)
+                .ok_or(TxGuardError::CountOverflow)?;
+        }
+
+        emit!(DisputeResolved {
+            namespace,
+            disputer: dispute.disputer,
+            window_index,
+            accepted: accept,
+            bond_lamports,
+        });
+        Ok(())
+    }
+
+    // Pubkey::default() switches token rewards off again; points keep accruing either way
+    pub fn set_reward_mint(
+        ctx: Context<SetRewardMint>,
+        _namespace: String,
+        mint: Pubkey,
+        claim_interval_slots: u64,
+    ) -> Result<()> {
+        if mint != Pubkey::default() {
+            require!(
+                reward_mint_authority(&ctx.accounts.reward_mint) == Some(ctx.accounts.mint_authority.key()),
+                TxGuardError::InvalidRewardMint
+            );
+        }
+        let config = &mut ctx.accounts.config;
+        config.reward_mint = mint;
+        config.claim_interval_slots = claim_interval_slots;
+
+        msg!("Reward mint updated: mint={}, claim_interval_slots={}", mint, claim_interval_slots);
+        Ok(())
+    }
+
+    // Mints the reporter's whole points balance, one base unit per point, to their
+    // associated token account for the reward mint
+    pub fn claim_rewards(ctx: Context<ClaimRewards>, namespace: String) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let record = &mut ctx.accounts.reporter_record;
+        record.check_claim_interval(slot, ctx.accounts.config.claim_interval_slots)?;
+        let amount = record.points;
+        require!(amount > 0, TxGuardError::InsufficientPoints);
+        record.debit_points(amount)?;
+        record.last_claim_slot = slot;
+
+        let mint = ctx.accounts.reward_mint.to_account_info();
+        let token_account = ctx.accounts.reporter_token_account.to_account_info();
+        let mint_authority = ctx.accounts.mint_authority.to_account_info();
+        let mut data = vec![TOKEN_MINT_TO];
+        data.extend_from_slice(&amount.to_le_bytes());
+        invoke_signed(
+            &Instruction {
+                program_id: TOKEN_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(mint.key(), false),
+                    AccountMeta::new(token_account.key(), false),
+                    AccountMeta::new_readonly(mint_authority.key(), true),
+                ],
+                data,
+            },
+            &[mint, token_account, mint_authority],
+            &[&[b"reward_authority", namespace.as_bytes(), &[ctx.bumps.mint_authority]]],
+        )?;
+
+        emit!(RewardsClaimed {
+            namespace,
+            reporter: ctx.accounts.reporter.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    // Shares SetBulkReporter's accounts: both flip an authority-managed reporter flag
+    pub fn set_backfill_reporter(
+        ctx: Context<SetBulkReporter>,
+        _namespace: String,
+        reporter: Pubkey,
+        backfill_reporter: bool,
+    ) -> Result<()> {
+        ctx.accounts.reporter_record.backfill_reporter = backfill_reporter;
+
+        msg!("Backfill reporter updated: reporter={}, exempt={}", reporter, backfill_reporter);
+        Ok(())
+    }
+
+    pub fn set_epoch_mode(ctx: Context<UpdateConfig>, _namespace: String, epoch_scoped: bool) -> Result<()> {
+        ctx.accounts.config.epoch_scoped = epoch_scoped;
+
+        msg!("Registry mode updated: epoch_scoped={}", epoch_scoped);
+        Ok(())
+    }
+
+    /// Bring a namespace's accounts written by an earlier version of the program up to
+    /// the current layout, topping up their rent from `payer`. Pass any of the
+    /// namespace's stats PDAs (hourly, daily, ...) as writable remaining accounts to
+    /// migrate them too. Deployments from before GuardConfig get one, with `payer` as
+    /// authority as `initialize` would; only the program's upgrade authority may claim
+    /// one that way, passing `program_data`. Safe to repeat; accounts more than 10 KiB
+    /// short need one call per 10 KiB.
+    pub fn migrate_registry<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateRegistry<'info>>,
+        namespace: String,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+        // Whoever runs the first migration would own the namespace, and anyone can watch
+        // for the upgrade that adds it
+        if accounts.config.authority == Pubkey::default() {
+            let upgrade_authority = accounts.program_data.as_ref()
+                .and_then(|program_data| program_data.upgrade_authority_address);
+            require!(upgrade_authority == Some(accounts.payer.key()), TxGuardError::Unauthorized);
+        }
+        accounts.config.init_defaults(accounts.payer.key(), &namespace);
+        let payer = accounts.payer.to_account_info();
+        let system = accounts.system_program.to_account_info();
+        let epoch = Clock::get()?.epoch;
+
+        migrate_account(
+            &accounts.registry.to_account_info(), &payer, &system,
+            8 + TransactionRegistry::INIT_SPACE,
+            |registry: &mut TransactionRegistry| registry.upgrade(epoch),
+        )?;
+        migrate_account(
+            &accounts.failure_catalog.to_account_info(), &payer, &system,
+            8 + FailureCatalog::INIT_SPACE,
+            FailureCatalog::upgrade,
+        )?;
+        migrate_account(
+            &accounts.priority_fee_stats.to_account_info(), &payer, &system,
+            8 + PriorityFeeStats::INIT_SPACE,
+            PriorityFeeStats::upgrade,
+        )?;
+
+        // Stats PDAs only ever grew by appended fields, and initialize sized their rings;
+        // growing them is enough
+        for info in ctx.remaining_accounts {
+            require!(info.owner == ctx.program_id && info.is_writable, TxGuardError::InvalidMigrationAccount);
+            let discriminator = info.try_borrow_data()?.get(..8).map(<[u8]>::to_vec)
+                .ok_or(TxGuardError::InvalidMigrationAccount)?;
+            let space = match discriminator.as_slice() {
+                d if d == HourlyStats::DISCRIMINATOR => HourlyStats::INIT_SPACE,
+                d if d == DailyStats::DISCRIMINATOR => DailyStats::INIT_SPACE,
+                d if d == SeasonalityStats::DISCRIMINATOR => SeasonalityStats::INIT_SPACE,
+                d if d == ComputeStats::DISCRIMINATOR => ComputeStats::INIT_SPACE,
+                d if d == LatencyStats::DISCRIMINATOR => LatencyStats::INIT_SPACE,
+                d if d == TxSizeStats::DISCRIMINATOR => TxSizeStats::INIT_SPACE,
+                d if d == InstructionStats::DISCRIMINATOR => InstructionStats::INIT_SPACE,
+                d if d == BlockhashAgeStats::DISCRIMINATOR => BlockhashAgeStats::INIT_SPACE,
+                d if d == RouteStats::DISCRIMINATOR => RouteStats::INIT_SPACE,
+                d if d == TagStats::DISCRIMINATOR => TagStats::INIT_SPACE,
+                _ => return err!(TxGuardError::InvalidMigrationAccount),
+            };
+            grow_account(info, &payer, &system, 8 + space)?;
+        }
+
+        msg!("Registry migrated: namespace={:?}", namespace);
+        Ok(())
+    }
+}
+
+// First seed of the per-namespace PDAs, for off-chain address derivation: [seed, namespace]
+#[constant]
+pub const REGISTRY_SEED: &[u8] = b"registry";
+#[constant]
+pub const CATALOG_SEED: &[u8] = b"catalog";
+#[constant]
+pub const PRIORITY_SEED: &[u8] = b"priority";
+// ArchiveRegistry PDAs are [ARCHIVE_SEED, registry, session_id_le_bytes] instead
+#[constant]
+pub const ARCHIVE_SEED: &[u8] = b"archive";
+// Priority fee tiers, 0 (cheapest) to MAX_TIERS - 1
+#[constant]
+pub const MAX_TIERS: u8 = 5;
+
+// Namespace registries live at [b"registry", ns]; per-user ones at [b"user_registry", ns, user]
+fn registry_address(namespace: &str, user: Option<Pubkey>, program_id: &Pubkey) -> (Pubkey, u8) {
+    match user {
+        Some(user) => Pubkey::find_program_address(
+            &[b"user_registry", namespace.as_bytes(), user.as_ref()],
+            program_id,
+        ),
+        None => Pubkey::find_program_address(&[REGISTRY_SEED, namespace.as_bytes()], program_id),
+    }
+}
+
+// Grow an account to `space` bytes, up to the per-instruction limit, topping its rent up
+// from `payer`. The new bytes are zeroed. Returns whether it reached `space`.
+fn grow_account<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system: &AccountInfo<'info>,
+    space: usize,
+) -> Result<bool> {
+    let target = space.min(info.data_len() + MAX_PERMITTED_DATA_INCREASE);
+    if info.data_len() < target {
+        let shortfall = Rent::get()?.minimum_balance(target).saturating_sub(info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system.clone(),
+                    system_program::Transfer { from: payer.clone(), to: info.clone() },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(target)?;
+    }
+    Ok(target >= space)
+}
+
+// Rewrite an account written by an earlier version of the program in the current layout.
+// Fields are only ever appended, so the zeroed tail reads as the new fields; `upgrade`
+// fills in the ones zero doesn't suit. The discriminator check rejects other accounts.
+fn migrate_account<'info, T: AccountSerialize + AccountDeserialize>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system: &AccountInfo<'info>,
+    space: usize,
+    upgrade: impl FnOnce(&mut T),
+) -> Result<()> {
+    if !grow_account(info, payer, system, space)? {
+        return Ok(());
+    }
+    let mut account = T::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    upgrade(&mut account);
+    account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+}
+
+// Failure codes in FailureCatalog::counts_by_type order
+pub const FAILURE_CODES: [u8; FAILURE_KINDS] =
+    [0, 1, 2, 3, 4, FAILURE_OTHER, FAILURE_BRIDGE, FAILURE_DEADLINE, FAILURE_WSOL,
+     FAILURE_FROZEN, FAILURE_ORACLE_CACHE, FAILURE_SIG_VERIFY, FAILURE_VERSION_MISMATCH,
+     FAILURE_BLOCKHASH_NOT_FOUND, FAILURE_CPI_DEPTH, FAILURE_NONCE_EXPIRED, FAILURE_GAS_SPIKE,
+     FAILURE_DATA_TOO_SMALL, FAILURE_NOT_EXECUTABLE, FAILURE_WRONG_TOKEN_PROGRAM];
+
+fn failure_index(failure_type: u8) -> usize {
+    FAILURE_CODES.iter()
+        .position(|code| *code == failure_type)
+        .unwrap_or(FAILURE_OTHER as usize)
+}
+
+// Upper bound on TxMetadata::num_signers
+pub const MAX_SIGNERS: u8 = 12;
+
+// Lookup tables only exist in v0 transactions, and accounts can only be looked up
+// through a table
+fn check_lookup_usage(metadata: &TxMetadata) -> Result<()> {
+    if let Some(tables) = metadata.lookup_tables() {
+        require!(
+            tables == 0 || metadata.tx_version != TxMetadata::TX_VERSION_LEGACY,
+            TxGuardError::InvalidLookupUsage
+        );
+        if let Some(accounts) = metadata.looked_up_accounts() {
+            require!(tables > 0 || accounts == 0, TxGuardError::InvalidLookupUsage);
+        }
+    }
+    Ok(())
+}
+
+// Integer square root (floor), for Z-scores without floating point
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+// z^2 for a two-sided 95% interval (z = 1.96), scaled by WILSON_SCALE
+pub const WILSON_Z_SQUARED: u128 = 38_416;
+pub const WILSON_SCALE: u128 = 10_000;
+
+// Lower bound of the 95% Wilson score interval for successes out of total, in basis
+// points, rounded down; 0 with no samples. With a = z^2 the bound is
+//   (2s + a - sqrt(a * (4s(n - s)/n + a))) / (2(n + a))
+// which, scaled by WILSON_SCALE, keeps every term under 2^95 for any u64 counts: the
+// s(n - s)/n term is split into quotient and remainder so it's never multiplied at full size
+pub fn wilson_lower_bound_bps(successes: u64, total: u64) -> u16 {
+    if total == 0 {
+        return 0;
+    }
+    let (s, n) = (successes.min(total) as u128, total as u128);
+    let product = s * (n - s); // At most n^2 / 4
+    let variance = product / n * 4 * WILSON_SCALE + product % n * 4 * WILSON_SCALE / n;
+    let radicand = WILSON_Z_SQUARED * (variance + WILSON_Z_SQUARED);
+    // Rounded up, so the bound is never overstated
+    let mut root = isqrt(radicand);
+    if root * root < radicand {
+        root += 1;
+    }
+    let numerator = (2 * s * WILSON_SCALE + WILSON_Z_SQUARED).saturating_sub(root);
+    let denominator = 2 * (n * WILSON_SCALE + WILSON_Z_SQUARED);
+    (numerator * 10_000 / denominator) as u16
+}
+
+// Short labels for format_failure_pie
+fn failure_label(failure_type: u8) -> &'static str {
+    match failure_type {
+        0 => "SLIP",
+        1 => "LIQ",
+        2 => "MEV",
+        3 => "DROP",
+        4 => "FUND",
+        FAILURE_BRIDGE => "BRDG",
+        FAILURE_DEADLINE => "EXPD",
+        FAILURE_WSOL => "WSOL",
+        FAILURE_FROZEN => "FRZN",
+        FAILURE_ORACLE_CACHE => "ORCL",
+        FAILURE_SIG_VERIFY => "SIGV",
+        FAILURE_VERSION_MISMATCH => "VERS",
+        FAILURE_BLOCKHASH_NOT_FOUND => "BHNF",
+        FAILURE_CPI_DEPTH => "CPID",
+        FAILURE_NONCE_EXPIRED => "NONC",
+        FAILURE_GAS_SPIKE => "SPIK",
+        FAILURE_DATA_TOO_SMALL => "SIZE",
+        FAILURE_NOT_EXECUTABLE => "NEXE",
+        FAILURE_WRONG_TOKEN_PROGRAM => "TOKP",
+        _ => "OTHR",
+    }
+}
+
+// Shared outcome bookkeeping for register_tx_outcome and register_tx_outcome_v2
+fn apply_tx_outcome(
+    registry: &mut TransactionRegistry,
+    catalog: &mut FailureCatalog,
+    stats: &mut PriorityFeeStats,
+    params: &TxOutcomeParams,
+) -> Result<()> {
+    let outcome = registry.apply_outcome(params)?;
+
+    // Partial fills count towards tx_count only, not success or failure
+    if outcome == OUTCOME_PARTIAL {
+        catalog.partial_fill = catalog.partial_fill.checked_add(1)
+            .ok_or(TxGuardError::CountOverflow)?;
+    } else if outcome == OUTCOME_FAILURE {
+        // Update failure catalog
+        let clock = Clock::get()?;
+        catalog.increment(params.failure_type.into(), clock.unix_timestamp, clock.slot)?;
+        if params.failure_type == FailureType::Bridge {
+            catalog.record_bridge_failure(params.bridge_chain_id)?;
+        }
+        if params.failure_type == FailureType::Wsol {
+            catalog.wsol_error_code = params.wsol_error_code;
+        }
+        if params.failure_type == FailureType::TokenFrozen {
+            catalog.frozen_mint = params.frozen_mint;
+        }
+        if params.failure_type == FailureType::VersionMismatch {
+            catalog.expected_version = params.expected_version;
+            catalog.actual_version = params.actual_version;
+        }
+        if params.failure_type == FailureType::NonceAccountExpired {
+            catalog.last_nonce_failure_slot = clock.slot;
+        }
+        if params.failure_type == FailureType::GasPriceSpike {
+            catalog.last_spike_fee_tier = params.priority_fee_tier;
+        }
+        if params.failure_type == FailureType::AccountDataTooSmall {
+            catalog.last_required_space = params.required_space;
+            catalog.last_available_space = params.available_space;
+        }
+        if params.failure_type == FailureType::WrongTokenProgram {
+            catalog.last_expected_program = params.expected_program;
+            catalog.last_actual_program = params.actual_program;
+        }
+    } else if outcome == OUTCOME_SUCCESS {
+        catalog.max_observed_cpi_depth = catalog.max_observed_cpi_depth.max(params.cpi_depth);
+    }
+    catalog.partial_fill_rate_bps = (catalog.partial_fill as u128 * 10_000
+        / registry.tx_count as u128) as u16;
+
+    // Update priority fee stats
+    let tier = params.priority_fee_tier as usize;
+    if tier < stats.tiers.len() {
+        stats.tiers[tier] = stats.tiers[tier]
+            .checked_add(1)
+            .ok_or(TxGuardError::CountOverflow)?;
+    }
+    if outcome == OUTCOME_SUCCESS {
+        stats.tier_successes[tier] = stats.tier_successes[tier]
+            .checked_add(1)
+            .ok_or(TxGuardError::CountOverflow)?;
+        // 0 = not declared
+        if params.fee_lamports > 0 {
+            stats.record_fee(params.fee_lamports);
+        }
+    }
+
+    Ok(())
+}
+
+// Outcome codes stored in the circular buffer
+pub const OUTCOME_FAILURE: u8 = Outcome::Failure as u8;
+pub const OUTCOME_SUCCESS: u8 = Outcome::Success as u8;
+pub const OUTCOME_PENDING: u8 = Outcome::Pending as u8;
+pub const OUTCOME_PARTIAL: u8 = Outcome::Partial as u8;
+
+// register_tx_outcome's outcome argument, and what each circular buffer entry holds. The
+// variant order is the wire format: Failure and Success encode as the bool they replaced
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Outcome {
+    Failure = 0,
+    Success = 1,
+    Pending = 2,
+    Partial = 3,
+}
+
+impl From<Outcome> for u8 {
+    fn from(outcome: Outcome) -> u8 {
+        outcome as u8
+    }
+}
+
+impl TryFrom<u8> for Outcome {
+    type Error = u8;
+
+    fn try_from(code: u8) -> std::result::Result<Self, u8> {
+        match code {
+            0 => Ok(Self::Failure),
+            1 => Ok(Self::Success),
+            2 => Ok(Self::Pending),
+            3 => Ok(Self::Partial),
+            code => Err(code),
+        }
+    }
+}
+
+// export_state payload layout: [version: u8] ++ [len: u32 LE] ++ up to MAX_EXPORT_PAGE bytes
+// of borsh(account) from `offset`, where len is the encoding's full length. Callers read
+// from offset 0 until they hold len bytes. Up to v14 one call returned borsh(registry,
+// catalog, stats) with the larger vecs left empty to fit
+pub const EXPORT_SCHEMA_VERSION: u8 = 15;
+// Return data is capped at 1024 bytes, including the Vec length prefix
+pub const MAX_EXPORT_LEN: usize = 1020;
+// What's left of it after the version and length
+pub const MAX_EXPORT_PAGE: usize = MAX_EXPORT_LEN - 5;
+
+// export_state sections
+pub const EXPORT_REGISTRY: u8 = 0;
+pub const EXPORT_CATALOG: u8 = 1;
+pub const EXPORT_PRIORITY_STATS: u8 = 2;
+
+// The export_state payload for the page of `account`'s encoding at `offset`, None past its
+// end. Borsh stands in for JSON, which isn't available on-chain; the first byte is the
+// schema version so consumers can reject layouts they don't know
+pub fn export_page(account: &impl AnchorSerialize, offset: u32) -> Option<Vec<u8>> {
+    let mut page = ExportPage::new(offset as usize);
+    account.serialize(&mut page).ok()?;
+    if offset as usize > page.len {
+        return None;
+    }
+    let mut payload = vec![EXPORT_SCHEMA_VERSION];
+    payload.extend_from_slice(&(page.len as u32).to_le_bytes());
+    payload.extend_from_slice(&page.bytes);
+    Some(payload)
+}
+
+// Keeps the MAX_EXPORT_PAGE bytes of an encoding that start at `offset`, and its length
+struct ExportPage {
+    offset: usize,
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+impl ExportPage {
+    fn new(offset: usize) -> Self {
+        Self { offset, len: 0, bytes: Vec::with_capacity(MAX_EXPORT_PAGE) }
+    }
+}
+
+impl std::io::Write for ExportPage {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let start = self.offset.max(self.len);
+        let end = (self.offset + MAX_EXPORT_PAGE).min(self.len + buf.len());
+        if start < end {
+            self.bytes.extend_from_slice(&buf[start - self.len..end - self.len]);
+        }
+        self.len += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Namespaces are a PDA seed, so they share the 32-byte seed limit
+pub const MAX_NAMESPACE_LEN: usize = 32;
+
+// Guard Configuration Account
+#[account]
+#[derive(InitSpace)]
+pub struct GuardConfig {
+    pub authority: Pubkey,
+    #[max_len(32)]
+    pub namespace: String, // Empty = the original un-namespaced deployment
+    pub epoch_scoped: bool, // true = outcomes go to [b"registry", namespace, epoch] PDAs
+    pub min_slots_between_reports: u64, // Per-reporter cooldown; 0 = no rate limit
+    pub max_reports_per_slot: u32, // Namespace-wide cap across all reporters; 0 = unlimited
+    pub report_fee_lamports: u64, // Charged per report into the treasury; 0 = free
+    pub crank_interval_slots: u64, // Minimum slots between crank_aggregate calls
+    pub crank_reward_lamports: u64, // Bounty paid from the treasury per crank
+    pub last_crank_slot: u64,
+    pub max_report_staleness_slots: u64, // check_health flags the data stale past this; 0 = never
+    pub max_report_age_slots: u64, // Oldest declared tx_slot accepted, in slots; 0 = no limit
+    pub success_report_points: u64, // Reward points per accepted success report
+    pub failure_report_points: u64, // Reward points per accepted failure report
+    pub points_multiplier: u16, // Applied to both; 1 on new namespaces, 0 pauses rewards
+    pub max_points_per_slot: u64, // Per-reporter cap within one slot; 0 = uncapped
+    pub reward_mint: Pubkey, // SPL mint claim_rewards pays points out in; default = no token rewards
+    pub claim_interval_slots: u64, // Minimum slots between a reporter's claims
+    pub reputation_increment: u16, // Added per accepted report
+    pub reputation_penalty: u16, // Taken per flag_report
+    pub reputation_half_life_slots: u64, // Time for the distance to REPUTATION_BASELINE to halve; 0 = no decay
+    pub min_reputation: u16, // get_reporter_stats marks reporters below this untrusted
+    pub min_success_rate_bps: u16, // check_health flags rates below this; 0 = no threshold
+    pub health_uses_lower_bound: bool, // Compare min_success_rate_bps against the Wilson lower bound, not the point estimate
+}
+
+impl GuardConfig {
+    // First initializer becomes the config authority; later calls keep it
+    pub fn init_defaults(&mut self, authority: Pubkey, namespace: &str) {
+        if self.authority != Pubkey::default() {
+            return;
+        }
+        self.authority = authority;
+        self.namespace = namespace.to_string();
+        self.points_multiplier = 1;
+        self.reputation_increment = DEFAULT_REPUTATION_INCREMENT;
+        self.reputation_penalty = DEFAULT_REPUTATION_PENALTY;
+        self.reputation_half_life_slots = DEFAULT_REPUTATION_HALF_LIFE_SLOTS;
+    }
+
+    // Backfilled reports would land in the "recent" window as if they just happened;
+    // reports without a declared tx_slot can't be aged and always pass
+    pub fn check_report_age(&self, tx_slot: u64, current_slot: u64) -> Result<()> {
+        if self.max_report_age_slots == 0 || tx_slot == 0 {
+            return Ok(());
+        }
+        require!(
+            current_slot.saturating_sub(tx_slot) <= self.max_report_age_slots,
+            TxGuardError::StaleReport
+        );
+        Ok(())
+    }
+}
+
+// Registry-scoped settings, seeds [b"registry_config", registry]. Created with defaults by the
+// first report or update_registry_config; GuardConfig keeps what spans the namespace
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryConfig {
+    pub authority: Pubkey, // The namespace authority when created
+    pub max_tx_count: Option<u64>, // Reports are refused once the registry holds this many
+    pub buffer_size: u16, // Newest outcomes kept in the window, up to WINDOW_SIZE
+    pub strict_failure_types: bool, // Reject the unassigned codes 6-12 instead of counting them as other
+    pub observer: Option<Pubkey>, // When set, the only reporter besides the authority, e.g. a watcher sidecar
+    pub version: u8, // Layout version, 0 until the defaults are written
+}
+
+pub const REGISTRY_CONFIG_VERSION: u8 = 1;
+// Entries in a registry's outcome window, last_100_outcomes and the vecs parallel to it
+#[constant]
+pub const WINDOW_SIZE: u16 = 100;
+
+impl RegistryConfig {
+    // Accounts created by init_if_needed are zeroed; defaults keep them permissive
+    pub fn init_defaults(&mut self, authority: Pubkey) {
+        if self.version == 0 {
+            self.authority = authority;
+            self.max_tx_count = None;
+            self.buffer_size = WINDOW_SIZE;
+            self.strict_failure_types = false;
+            self.observer = None;
+            self.version = REGISTRY_CONFIG_VERSION;
+        }
+    }
+
+    pub fn check_reporter(&self, reporter: &Pubkey) -> Result<()> {
+        if let Some(observer) = self.observer {
+            require!(*reporter == observer || *reporter == self.authority, TxGuardError::NotObserver);
+        }
+        Ok(())
+    }
+
+    pub fn check_failure_type(&self, params: &TxOutcomeParams) -> Result<()> {
+        if self.strict_failure_types && !params.success {
+            require!(FAILURE_CODES.contains(&params.failure_type.into()), TxGuardError::InvalidFailureType);
+        }
+        Ok(())
+    }
+
+    // tx_count is the registry's count before this report
+    pub fn check_capacity(&self, tx_count: u64) -> Result<()> {
+        if let Some(max_tx_count) = self.max_tx_count {
+            require!(tx_count < max_tx_count, TxGuardError::RegistryFull);
+        }
+        Ok(())
+    }
+}
+
+// Report fee treasury, seeds [b"treasury", namespace]
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub total_fees_collected: u64,
+    pub total_crank_rewards: u64,
+}
+
+impl Treasury {
+    // Lamports above the rent-exempt reserve, i.e. what can be paid out
+    pub fn available_lamports(info: &AccountInfo) -> Result<u64> {
+        let reserve = Rent::get()?.minimum_balance(info.data_len());
+        Ok(info.lamports().saturating_sub(reserve))
+    }
+
+    pub fn pay_out(info: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+        require!(amount <= Self::available_lamports(info)?, TxGuardError::InsufficientTreasury);
+
+        **info.try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? = recipient.lamports()
+            .checked_add(amount)
+            .ok_or(TxGuardError::CountOverflow)?;
+        Ok(())
+    }
+}
+
+// Transaction Registry Account
+#[account]
+#[derive(InitSpace, Default)]
+pub struct TransactionRegistry {
+    pub tx_count: u64,
+    pub success_count: u64,
+    pub failure_count: u64,
+    #[max_len(100)]
+    pub last_100_outcomes: Vec<u8>, // 0=failure, 1=success, 2=pending, 3=partial fill
+    pub cursor: u8,
+    pub total_fee_lamports: u64,
+    pub total_value_lamports: u64,
+    #[max_len(64)]
+    pub recent_sig_hashes: Vec<[u8; 32]>, // Ring of reported signature hashes for dedup
+    pub sig_cursor: u8,
+    pub blockhash_validity_window: u8, // Slots a blockhash stays valid; 0 = DEFAULT_BLOCKHASH_VALIDITY_WINDOW
+    pub current_slot: u64, // Slot the reports_this_slot counter refers to
+    pub reports_this_slot: u32,
+    pub max_tx_per_slot: u32, // Peak reports_this_slot ever seen
+    pub locked: bool, // Reentrancy guard, see RegisterTxOutcome::record_outcome
+    #[max_len(100)]
+    pub outcome_slots: Vec<u64>, // Slot each last_100_outcomes entry was recorded in, same index
+    #[max_len(168)]
+    pub hourly_buckets: Vec<u32>, // Reports per hour of the week, see hour_of_week
+    pub last_seen_epoch: u64, // Epoch of the latest report; a higher Clock epoch triggers a snapshot
+    pub last_report_slot: u64, // 0 = no report yet
+    pub max_report_gap_slots: u64,
+    pub report_gap_histogram: [u32; 5], // Slots between consecutive reports, see REPORT_GAP_BOUNDS
+    #[max_len(100)]
+    pub report_delays: Vec<u16>, // Report slot - declared tx_slot per last_100_outcomes entry, capped at u16::MAX
+    pub sum_report_delay_slots: u64, // Over reports that declared a tx_slot
+    pub max_report_delay_slots: u64,
+    pub version_successes: [u32; 3], // Metadata reports by tx_version: legacy, v0, unknown
+    pub version_failures: [u32; 3],
+    pub lookup_reports: [u32; 2], // Metadata reports without / with address lookup tables
+    pub lookup_failures: [u32; 2],
+    pub max_looked_up_accounts: u8,
+    pub signer_successes: [u32; 3], // Metadata reports with 1, 2 and 3+ signers
+    pub signer_failures: [u32; 3],
+    #[max_len(8)]
+    pub instruction_types: Vec<InstructionTypeStats>, // Outcomes per protocol instruction type, see record_instruction_type
+    pub landing_counts: [u32; 3], // Reports that declared landing: never landed, landed and errored, landed and succeeded
+    pub used_ed25519_precompile: bool, // Sends signature verify instructions, so type 18 failures are possible
+    pub simulation_successes: [u32; 2], // Metadata reports sent without / with preflight simulation
+    pub simulation_failures: [u32; 2],
+    #[max_len(4)]
+    pub send_methods: Vec<SendMethodStats>, // Metadata reports by send_method: RPC, Jito bundle, other relay, unknown
+    pub unique_caller_count: u32, // Distinct payers that reported; approximate past MAX_SEEN_CALLERS
+    #[max_len(50)]
+    pub seen_callers: Vec<Pubkey>, // The first MAX_SEEN_CALLERS distinct payers
+    pub caller_filter: [u64; 4], // Bloom filter over the payers after those, see record_caller
+    #[max_len(100)]
+    pub outcome_tiers: Vec<u8>, // Priority fee tier per last_100_outcomes entry, TIER_UNKNOWN before tier tracking
+    #[max_len(100)]
+    pub outcome_failure_types: Vec<u8>, // Failure type per last_100_outcomes entry, NO_FAILURE_TYPE for the rest
+    pub sandwich_pattern_count: u32, // Success, MEV failure, success runs seen as reports arrived, all time
+    #[max_len(7)]
+    pub day_of_week_success: Vec<u32>, // Successes per UTC weekday, see day_of_week
+    #[max_len(7)]
+    pub day_of_week_failure: Vec<u32>,
+    pub session_id: u64, // archive_registry calls so far, i.e. the next ArchiveRegistry's seed; kept by reset
+    pub archived_before_slot: u64, // The latest archive's archive_before_slot, 0 = never archived
+}
+
+pub const MAX_SEEN_CALLERS: usize = 50;
+
+// outcome_tiers entry for reports recorded before the registry tracked tiers
+pub const TIER_UNKNOWN: u8 = u8::MAX;
+
+// outcome_failure_types entry for successes, partial fills, amended entries and
+// reports recorded before failure types were tracked
+pub const NO_FAILURE_TYPE: u8 = u8::MAX;
+
+// Three consecutive window entries, oldest first: a transaction that lost to MEV
+// between two that went through, as when a searcher brackets a victim's swap
+fn is_sandwich(entries: [(u8, u8); 3]) -> bool {
+    matches!(
+        entries,
+        [(OUTCOME_SUCCESS, _), (OUTCOME_FAILURE, FAILURE_MEV), (OUTCOME_SUCCESS, _)]
+    )
+}
+
+// get_tier_sparkline can't split the 100-entry window any finer
+pub const MAX_SPARKLINE_BUCKETS: u8 = 100;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct SendMethodStats {
+    pub success_count: u32,
+    pub failure_count: u32,
+    pub failures_by_type: [u16; FAILURE_KINDS], // Indexed like FailureCatalog::counts_by_type
+    pub dominant_failure_type: u8,
+}
+
+// Bit positions of a payer in TransactionRegistry::caller_filter. Pubkeys are already
+// uniformly distributed, so three of their bytes serve as the hashes.
+fn caller_filter_bits(caller: &Pubkey) -> [usize; 3] {
+    let bytes = caller.to_bytes();
+    [bytes[0] as usize, bytes[11] as usize, bytes[22] as usize]
+}
+
+// Entries in TransactionRegistry::send_methods; TxMetadata::send_method values past the
+// last one count as unknown
+pub const SEND_METHODS: usize = 4;
+
+impl SendMethodStats {
+    pub fn record(&mut self, success: bool, failure_type: u8) {
+        if success {
+            self.success_count = self.success_count.saturating_add(1);
+            return;
+        }
+        self.failure_count = self.failure_count.saturating_add(1);
+        let index = failure_index(failure_type);
+        self.failures_by_type[index] = self.failures_by_type[index].saturating_add(1);
+        let dominant = failure_index(self.dominant_failure_type);
+        if self.failures_by_type[index] > self.failures_by_type[dominant] {
+            self.dominant_failure_type = FAILURE_CODES[index];
+        }
+    }
+
+    pub fn merge_from(&mut self, source: &SendMethodStats) {
+        self.success_count = self.success_count.saturating_add(source.success_count);
+        self.failure_count = self.failure_count.saturating_add(source.failure_count);
+        for (count, added) in self.failures_by_type.iter_mut().zip(source.failures_by_type) {
+            *count = count.saturating_add(added);
+        }
+        // Ties go to the lowest index, as in FailureCatalog::dominant_failure
+        let (dominant, _) = self.failures_by_type.iter().enumerate()
+            .fold((0, 0), |best, (index, count)| if *count > best.1 { (index, *count) } else { best });
+        self.dominant_failure_type = FAILURE_CODES[dominant];
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct InstructionTypeStats {
+    pub instruction_type: u8,
+    pub success: u32,
+    pub failure: u32,
+}
+
+// Matches the max_len of TransactionRegistry::instruction_types
+pub const MAX_INSTRUCTION_TYPES: usize = 8;
+
+// Return value of get_instruction_stats; encodes the same as a (u32, u32) tuple
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct InstructionTypeTotals {
+    pub success: u32,
+    pub failure: u32,
+}
+
+// Return value of get_buffer_utilization; encodes the same as a (u8, u8, u8) tuple
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct BufferUtilization {
+    pub non_pending_count: u8,
+    pub pending_count: u8, // Entries still at OUTCOME_PENDING
+    pub buffer_len: u8,
+}
+
+// Upper bounds (exclusive) of the first four report_gap_histogram buckets: same slot,
+// under 10 slots, under a blockhash lifetime, under ~1 hour; the last is longer
+pub const REPORT_GAP_BOUNDS: [u64; 4] = [1, 10, 150, 9_000];
+
+// Return value of check_health
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct HealthStatus {
+    pub success_rate_bps: u16, // success_count / tx_count
+    pub tx_count: u64,
+    pub slots_since_last_report: u64, // u64::MAX when nothing was ever reported
+    pub stale: bool, // Past GuardConfig::max_report_staleness_slots; don't trust the rate
+    pub landed_error_rate_bps: u16, // See TransactionRegistry::landed_error_rate_bps
+    pub success_rate_lower_bound_bps: u16, // See TransactionRegistry::success_rate_lower_bound_bps
+    pub below_min_success_rate: bool, // Under GuardConfig::min_success_rate_bps, by the rate it picks
+}
+
+// Return value of compute_success_rate; success_rate_bps comes first, so readers of the
+// old bare u16 still decode it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SuccessRate {
+    pub success_rate_bps: u16,
+    pub lower_bound_bps: u16, // 95% Wilson score lower bound
+}
+
+// Return value of detect_anomaly
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct AnomalyReport {
+    pub anomaly_detected: bool,
+    pub anomalous_type: u8, // failure_type code with the highest Z-score; 0 when nothing is flagged
+    pub z_score_bps: u64, // That type's Z-score x 10_000
+}
+
+// Return value of compare_to_snapshot. Deltas are current minus snapshot counts, so they
+// only go negative after a reset. Bit n of the masks is failure_type n (0-5)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct CatalogDiff {
+    pub delta_slippage: i64,
+    pub delta_liquidity: i64,
+    pub delta_mev: i64,
+    pub delta_dropped: i64,
+    pub delta_funds: i64,
+    pub delta_other: i64,
+    pub improved_types: u8, // Lower failure rate per report since the snapshot than before it
+    pub regressed_types: u8, // Higher failure rate per report
+}
+
+// Return value of get_peak_hour_of_week
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct PeakHour {
+    pub hour_of_week: u8,
+    pub count: u32,
+}
+
+// Index into hourly_buckets; the Unix epoch began on a Thursday, so 0 is Thursday 00:00 UTC
+pub fn hour_of_week(unix_timestamp: i64) -> usize {
+    (unix_timestamp / 3600).rem_euclid(HOURS_PER_WEEK as i64) as usize
+}
+
+// Index into day_of_week_success and day_of_week_failure, Monday = 0; day 0 of the
+// Unix epoch was a Thursday (3)
+pub fn day_of_week(unix_timestamp: i64) -> usize {
+    (unix_timestamp.div_euclid(86_400) + 3).rem_euclid(DAYS_PER_WEEK as i64) as usize
+}
+
+// Return value of recommend_tier_for_deadline
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct DeadlineRecommendation {
+    pub tier: u8,
+    pub confidence_bps: u16, // Share of the tier's measured reports confirmed within the deadline
+}
+
+// Return value of get_best_day
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct BestDay {
+    pub day_of_week: u8, // Monday = 0
+    pub success_rate_bps: u16,
+    pub sample_count: u32, // Successes plus failures on that weekday; 0 = no reports yet
+}
+
+// Return value of get_recent_rate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct RecentRate {
+    pub success_rate_bps: u16,
+    pub sample_count: u8, // Entries inside the horizon; a low count means a noisy rate
+}
+
+// A registry's all-time counters, and its namespace catalog's failure counts, as
+// archive_registry found them before restarting them.
+// Seeds [ARCHIVE_SEED, registry, session_id_le_bytes]
+#[account]
+#[derive(InitSpace)]
+pub struct ArchiveRegistry {
+    pub registry: Pubkey,
+    pub session_id: u64,
+    pub since_slot: u64, // The previous archive's archive_before_slot, 0 for the first
+    pub archive_before_slot: u64, // Every archived report was made before this slot
+    pub archived_slot: u64, // Slot archive_registry ran in
+    pub tx_count: u64,
+    pub success_count: u64,
+    pub failure_count: u64,
+    pub total_fee_lamports: u64,
+    pub total_value_lamports: u64,
+    pub landing_counts: [u32; 3],
+    pub sandwich_pattern_count: u32,
+    pub day_of_week_success: [u32; 7],
+    pub day_of_week_failure: [u32; 7],
+    pub failures_by_type: [u32; FAILURE_KINDS], // Indexed like FailureCatalog::counts_by_type
+    pub partial_fill: u32,
+}
+
+impl ArchiveRegistry {
+    pub fn capture(
+        registry_key: Pubkey,
+        registry: &TransactionRegistry,
+        catalog: &FailureCatalog,
+        archive_before_slot: u64,
+        slot: u64,
+    ) -> Self {
+        let by_day = |counts: &[u32]| {
+            let mut days = [0; DAYS_PER_WEEK];
+            for (day, count) in days.iter_mut().zip(counts) {
+                *day = *count;
+            }
+            days
+        };
+        Self {
+            registry: registry_key,
+            session_id: registry.session_id,
+            since_slot: registry.archived_before_slot,
+            archive_before_slot,
+            archived_slot: slot,
+            tx_count: registry.tx_count,
+            success_count: registry.success_count,
+            failure_count: registry.failure_count,
+            total_fee_lamports: registry.total_fee_lamports,
+            total_value_lamports: registry.total_value_lamports,
+            landing_counts: registry.landing_counts,
+            sandwich_pattern_count: registry.sandwich_pattern_count,
+            day_of_week_success: by_day(&registry.day_of_week_success),
+            day_of_week_failure: by_day(&registry.day_of_week_failure),
+            failures_by_type: catalog.counts_by_type().map(|(_, count)| count),
+            partial_fill: catalog.partial_fill,
+        }
+    }
+}
+
+impl TransactionRegistry {
+    // Zeroes what ArchiveRegistry::capture copied. The window, dedup ring, caller tracking
+    // and metadata breakdowns carry on
+    pub fn archive_counters(&mut self, archive_before_slot: u64) -> Result<()> {
+        self.tx_count = 0;
+        self.success_count = 0;
+        self.failure_count = 0;
+        self.total_fee_lamports = 0;
+        self.total_value_lamports = 0;
+        self.landing_counts = [0; 3];
+        self.sandwich_pattern_count = 0;
+        self.day_of_week_success.clear();
+        self.day_of_week_success.resize(DAYS_PER_WEEK, 0);
+        self.day_of_week_failure.clear();
+        self.day_of_week_failure.resize(DAYS_PER_WEEK, 0);
+        self.session_id = self.session_id.checked_add(1).ok_or(TxGuardError::CountOverflow)?;
+        self.archived_before_slot = archive_before_slot;
+        Ok(())
+    }
+
+    pub fn reset(&mut self) {
+        self.tx_count = 0;
+        self.success_count = 0;
+        self.failure_count = 0;
+        self.cursor = 0;
+        self.total_fee_lamports = 0;
+        self.total_value_lamports = 0;
+
+        // Initialize all outcomes to 2 (pending/unknown)
+        self.last_100_outcomes.clear();
+        self.last_100_outcomes.resize(WINDOW_SIZE as usize, OUTCOME_PENDING);
+        self.outcome_slots.clear();
+        self.outcome_slots.resize(WINDOW_SIZE as usize, 0);
+        self.outcome_tiers.clear();
+        self.outcome_tiers.resize(WINDOW_SIZE as usize, TIER_UNKNOWN);
+        self.outcome_failure_types.clear();
+        self.outcome_failure_types.resize(WINDOW_SIZE as usize, NO_FAILURE_TYPE);
+        self.sandwich_pattern_count = 0;
+        self.hourly_buckets.clear();
+        self.hourly_buckets.resize(HOURS_PER_WEEK, 0);
+        self.day_of_week_success.clear();
+        self.day_of_week_success.resize(DAYS_PER_WEEK, 0);
+        self.day_of_week_failure.clear();
+        self.day_of_week_failure.resize(DAYS_PER_WEEK, 0);
+
+        self.recent_sig_hashes.clear();
+        self.sig_cursor = 0;
+        self.blockhash_validity_window = DEFAULT_BLOCKHASH_VALIDITY_WINDOW;
+        self.max_tx_per_slot = 0;
+        self.report_delays.clear();
+        self.report_delays.resize(WINDOW_SIZE as usize, 0);
+        self.sum_report_delay_slots = 0;
+        self.max_report_delay_slots = 0;
+        self.last_report_slot = 0;
+        self.max_report_gap_slots = 0;
+        self.report_gap_histogram = [0; 5];
+        self.version_successes = [0; 3];
+        self.version_failures = [0; 3];
+        self.lookup_reports = [0; 2];
+        self.lookup_failures = [0; 2];
+        self.max_looked_up_accounts = 0;
+        self.signer_successes = [0; 3];
+        self.signer_failures = [0; 3];
+        self.instruction_types.clear();
+        self.landing_counts = [0; 3];
+        self.used_ed25519_precompile = false;
+        self.simulation_successes = [0; 2];
+        self.simulation_failures = [0; 2];
+        self.send_methods.clear();
+        self.send_methods.resize(SEND_METHODS, SendMethodStats::default());
+        self.unique_caller_count = 0;
+        self.seen_callers.clear();
+        self.caller_filter = [0; 4];
+    }
+
+    // Fill in what a zeroed tail from migrate_registry doesn't stand for, keeping
+    // everything already recorded. Window entries from before per-entry tracking read
+    // as slot 0, unknown tier and no failure type.
+    pub fn upgrade(&mut self, current_epoch: u64) {
+        let window = WINDOW_SIZE as usize;
+        if self.last_100_outcomes.len() < window {
+            self.last_100_outcomes.resize(window, OUTCOME_PENDING);
+        }
+        if self.outcome_slots.len() < window {
+            self.outcome_slots.resize(window, 0);
+        }
+        if self.outcome_tiers.len() < window {
+            self.outcome_tiers.resize(window, TIER_UNKNOWN);
+        }
+        if self.outcome_failure_types.len() < window {
+            self.outcome_failure_types.resize(window, NO_FAILURE_TYPE);
+        }
+        if self.report_delays.len() < window {
+            self.report_delays.resize(window, 0);
+        }
+        if self.hourly_buckets.len() < HOURS_PER_WEEK {
+            self.hourly_buckets.resize(HOURS_PER_WEEK, 0);
+        }
+        if self.day_of_week_success.len() < DAYS_PER_WEEK {
+            self.day_of_week_success.resize(DAYS_PER_WEEK, 0);
+            self.day_of_week_failure.resize(DAYS_PER_WEEK, 0);
+        }
+        if self.send_methods.len() < SEND_METHODS {
+            self.send_methods.resize(SEND_METHODS, SendMethodStats::default());
+        }
+        if self.blockhash_validity_window == 0 {
+            self.blockhash_validity_window = DEFAULT_BLOCKHASH_VALIDITY_WINDOW;
+        }
+        // Otherwise the next report would snapshot all-time counts as epoch 0
+        if self.last_seen_epoch == 0 {
+            self.last_seen_epoch = current_epoch;
+        }
+    }
+
+    // Exact for the first MAX_SEEN_CALLERS payers. After that a 256-bit Bloom filter
+    // decides, so a new payer whose bits earlier ones already set goes uncounted and
+    // the count drifts low as the filter fills.
+    pub fn record_caller(&mut self, caller: &Pubkey) {
+        if self.seen_callers.contains(caller) {
+            return;
+        }
+        if self.seen_callers.len() < MAX_SEEN_CALLERS {
+            self.seen_callers.push(*caller);
+        } else {
+            let bits = caller_filter_bits(caller);
+            if bits.iter().all(|bit| self.caller_filter[bit / 64] & (1 << (bit % 64)) != 0) {
+                return;
+            }
+            for bit in bits {
+                self.caller_filter[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+        self.unique_caller_count = self.unique_caller_count.saturating_add(1);
+    }
+
+    // Flip a success/failure ring entry and move it between success_count and
+    // failure_count. The catalog is left alone: a dispute doesn't say which failure
+    // type an entry should have had, so an amended entry's type is unknown.
+    pub fn amend_outcome(&mut self, index: usize, success: bool) -> Result<()> {
+        let amended = if success { OUTCOME_SUCCESS } else { OUTCOME_FAILURE };
+        let current = self.last_100_outcomes.get(index).copied();
+        require!(
+            matches!(current, Some(OUTCOME_SUCCESS | OUTCOME_FAILURE)) && current != Some(amended),
+            TxGuardError::InvalidDispute
+        );
+        let (from, to) = if success {
+            (&mut self.failure_count, &mut self.success_count)
+        } else {
+            (&mut self.success_count, &mut self.failure_count)
+        };
+        *from = from.checked_sub(1).ok_or(TxGuardError::CountOverflow)?;
+        *to = to.checked_add(1).ok_or(TxGuardError::CountOverflow)?;
+        self.last_100_outcomes[index] = amended;
+        if let Some(failure_type) = self.outcome_failure_types.get_mut(index) {
+            *failure_type = NO_FAILURE_TYPE;
+        }
+        Ok(())
+    }
+
+    pub fn record_signers(&mut self, num_signers: u8, success: bool) {
+        let index = (num_signers.clamp(1, 3) - 1) as usize;
+        let counter = if success {
+            &mut self.signer_successes[index]
+        } else {
+            &mut self.signer_failures[index]
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    // Types beyond the vec's capacity are not tracked, like bridge chains
+    pub fn record_instruction_type(&mut self, instruction_type: u8, success: u32, failure: u32) -> Result<()> {
+        if let Some(entry) = self.instruction_types.iter_mut().find(|e| e.instruction_type == instruction_type) {
+            entry.success = entry.success.checked_add(success).ok_or(TxGuardError::CountOverflow)?;
+            entry.failure = entry.failure.checked_add(failure).ok_or(TxGuardError::CountOverflow)?;
+        } else if self.instruction_types.len() < MAX_INSTRUCTION_TYPES {
+            self.instruction_types.push(InstructionTypeStats { instruction_type, success, failure });
+        }
+        Ok(())
+    }
+
+    pub fn instruction_totals(&self, instruction_type: u8) -> InstructionTypeTotals {
+        self.instruction_types.iter()
+            .find(|e| e.instruction_type == instruction_type)
+            .map_or(InstructionTypeTotals { success: 0, failure: 0 }, |e| InstructionTypeTotals {
+                success: e.success,
+                failure: e.failure,
+            })
+    }
+
+    pub fn record_simulation(&mut self, was_simulated: bool, success: bool) {
+        let counter = if success {
+            &mut self.simulation_successes[was_simulated as usize]
+        } else {
+            &mut self.simulation_failures[was_simulated as usize]
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    pub fn record_send_method(&mut self, send_method: u8, success: bool, failure_type: u8) {
+        let index = send_method.min(TxMetadata::SEND_METHOD_UNKNOWN) as usize;
+        self.send_methods[index].record(success, failure_type);
+    }
+
+    pub fn send_method_buckets(&self) -> [OutcomeBucket; SEND_METHODS] {
+        std::array::from_fn(|index| match self.send_methods.get(index) {
+            Some(method) => OutcomeBucket::new(method.success_count, method.failure_count),
+            None => OutcomeBucket::new(0, 0),
+        })
+    }
+
+    pub fn send_method_dominant_failures(&self) -> [u8; SEND_METHODS] {
+        std::array::from_fn(|index| self.send_methods.get(index).map_or(0, |method| method.dominant_failure_type))
+    }
+
+    pub fn signer_buckets(&self) -> [OutcomeBucket; 3] {
+        std::array::from_fn(|index| OutcomeBucket::new(self.signer_successes[index], self.signer_failures[index]))
+    }
+
+    // Reports whose metadata leaves num_lookup_tables unknown aren't counted
+    pub fn record_lookup_usage(&mut self, metadata: &TxMetadata, success: bool) {
+        if let Some(tables) = metadata.lookup_tables() {
+            let index = (tables > 0) as usize;
+            self.lookup_reports[index] = self.lookup_reports[index].saturating_add(1);
+            if !success {
+                self.lookup_failures[index] = self.lookup_failures[index].saturating_add(1);
+            }
+        }
+        if let Some(accounts) = metadata.looked_up_accounts() {
+            self.max_looked_up_accounts = self.max_looked_up_accounts.max(accounts);
+        }
+    }
+
+    // Failure rate without / with lookup tables, 0 for a side with no reports
+    pub fn lookup_failure_rates_bps(&self) -> [u16; 2] {
+        std::array::from_fn(|index| match self.lookup_reports[index] {
+            0 => 0,
+            reports => (self.lookup_failures[index] as u64 * 10_000 / reports as u64) as u16,
+        })
+    }
+
+    // Future formats land in the unknown pair instead of being rejected
+    pub fn record_tx_version(&mut self, tx_version: u8, success: bool) {
+        let index = match tx_version {
+            TxMetadata::TX_VERSION_LEGACY => 0,
+            TxMetadata::TX_VERSION_V0 => 1,
+            _ => 2,
+        };
+        let counter = if success {
+            &mut self.version_successes[index]
+        } else {
+            &mut self.version_failures[index]
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    // Reject a batch if any signature hash is still in the dedup ring or repeats within it
+    pub fn check_new_sigs(&self, reports: &[TxOutcomeParams]) -> Result<()> {
+        let hashes: Vec<&[u8; 32]> = reports.iter()
+            .map(|params| &params.tx_sig_hash)
+            .filter(|hash| **hash != [0; 32])
+            .collect();
+        for (index, hash) in hashes.iter().enumerate() {
+            require!(
+                !self.recent_sig_hashes.contains(hash) && !hashes[..index].contains(hash),
+                TxGuardError::DuplicateReport
+            );
+        }
+        Ok(())
+    }
+
+    // Reject a signature hash still in the dedup ring, then remember it
+    pub fn check_and_remember_sig(&mut self, sig_hash: &[u8; 32]) -> Result<()> {
+        require!(
+            !self.recent_sig_hashes.iter().any(|h| h == sig_hash),
+            TxGuardError::DuplicateReport
+        );
+
+        if self.recent_sig_hashes.len() < SIG_RING_SIZE {
+            self.recent_sig_hashes.push(*sig_hash);
+        } else {
+            self.recent_sig_hashes[self.sig_cursor as usize] = *sig_hash;
+        }
+        self.sig_cursor = ((self.sig_cursor as usize + 1) % SIG_RING_SIZE) as u8;
+        Ok(())
+    }
+
+    // Keeps the newest len window entries and returns the older ones to pending
+    pub fn trim_window(&mut self, len: usize) {
+        let size = self.last_100_outcomes.len();
+        for back in len..size {
+            let idx = (self.cursor as usize + size - 1 - back) % size;
+            self.last_100_outcomes[idx] = OUTCOME_PENDING;
+        }
+    }
+
+    // Non-pending window entries, oldest first
+    pub fn chronological_outcomes(&self) -> Vec<u8> {
+        self.chronological_entries().into_iter().map(|(outcome, _)| outcome).collect()
+    }
+
+    // Ring indices of non-pending entries, oldest first
+    fn chronological_indices(&self) -> Vec<usize> {
+        let len = self.last_100_outcomes.len();
+        (0..len)
+            .map(|i| (self.cursor as usize + i) % len)
+            .filter(|idx| self.last_100_outcomes[*idx] != OUTCOME_PENDING)
+            .collect()
+    }
+
+    // Non-pending (outcome, slot) pairs, oldest first; entries without a recorded slot,
+    // such as those migrate_registry carried over, report slot 0
+    pub fn chronological_entries(&self) -> Vec<(u8, u64)> {
+        self.chronological_indices()
+            .into_iter()
+            .map(|idx| (self.last_100_outcomes[idx], self.outcome_slots.get(idx).copied().unwrap_or(0)))
+            .collect()
+    }
+
+    pub fn buffer_utilization(&self) -> BufferUtilization {
+        let len = self.last_100_outcomes.len();
+        let pending = self.last_100_outcomes.iter().filter(|outcome| **outcome == OUTCOME_PENDING).count();
+        BufferUtilization {
+            non_pending_count: (len - pending) as u8,
+            pending_count: pending as u8,
+            buffer_len: len as u8,
+        }
+    }
+
+    // Success rate over window entries recorded after current_slot - max_age_slots,
+    // however full the window is
+    pub fn recent_rate(&self, current_slot: u64, max_age_slots: u64) -> RecentRate {
+        let horizon = current_slot.saturating_sub(max_age_slots);
+        let (samples, successes) = self.chronological_entries()
+            .into_iter()
+            .filter(|(_, slot)| *slot > horizon)
+            .fold((0u32, 0u32), |(samples, successes), (outcome, _)| {
+                (samples + 1, successes + (outcome == OUTCOME_SUCCESS) as u32)
+            });
+
+        RecentRate {
+            success_rate_bps: if samples == 0 { 0 } else { (successes * 10_000 / samples) as u16 },
+            sample_count: samples as u8,
+        }
+    }
+
+    // Reports at `tier` per equal-width slot range, oldest range first. Uses the tier's
+    // newest entries in the window, trimmed to a multiple of `buckets`; the ranges span
+    // the first to the last of those slots
+    pub fn tier_sparkline(&self, tier: u8, buckets: u8) -> Vec<u16> {
+        let mut sparkline = vec![0u16; buckets as usize];
+        let slots: Vec<u64> = self.chronological_indices()
+            .into_iter()
+            .filter(|idx| self.outcome_tiers.get(*idx) == Some(&tier))
+            .map(|idx| self.outcome_slots.get(idx).copied().unwrap_or(0))
+            .collect();
+        let used = slots.len() / buckets as usize * buckets as usize;
+        let slots = &slots[slots.len() - used..];
+        let (Some(first), Some(last)) = (slots.first(), slots.last()) else {
+            return sparkline;
+        };
+
+        let span = (last - first + 1) as u128;
+        for slot in slots {
+            let bucket = ((slot - first) as u128 * buckets as u128 / span) as usize;
+            sparkline[bucket] += 1;
+        }
+        sparkline
+    }
+
+    // Sandwich runs among the entries still in the window; runs may overlap, so
+    // S, MEV, S, MEV, S counts twice
+    pub fn sandwich_patterns(&self) -> u32 {
+        let entries: Vec<(u8, u8)> = self.chronological_indices()
+            .into_iter()
+            .map(|idx| (
+                self.last_100_outcomes[idx],
+                self.outcome_failure_types.get(idx).copied().unwrap_or(NO_FAILURE_TYPE),
+            ))
+            .collect();
+        entries.windows(3).filter(|run| is_sandwich([run[0], run[1], run[2]])).count() as u32
+    }
+
+    // Busiest hour of the week so far; ties go to the earliest hour
+    pub fn peak_hour_of_week(&self) -> PeakHour {
+        let (hour, count) = self.hourly_buckets
+            .iter()
+            .enumerate()
+            .fold((0, 0), |best, (hour, &count)| if count > best.1 { (hour, count) } else { best });
+        PeakHour { hour_of_week: hour as u8, count }
+    }
+
+    // Weekday with the highest success rate; ties go to the earlier day, weekdays without
+    // reports are skipped
+    pub fn best_day(&self) -> BestDay {
+        let mut best = BestDay { day_of_week: 0, success_rate_bps: 0, sample_count: 0 };
+        for day in 0..DAYS_PER_WEEK {
+            let successes = self.day_of_week_success.get(day).copied().unwrap_or(0) as u64;
+            let failures = self.day_of_week_failure.get(day).copied().unwrap_or(0) as u64;
+            let samples = successes + failures;
+            if samples == 0 {
+                continue;
+            }
+            let rate = (successes * 10_000 / samples) as u16;
+            if best.sample_count == 0 || rate > best.success_rate_bps {
+                best = BestDay {
+                    day_of_week: day as u8,
+                    success_rate_bps: rate,
+                    sample_count: samples.min(u32::MAX as u64) as u32,
+                };
+            }
+        }
+        best
+    }
+
+    // Track the slot gap since the previous report; the first report only sets the baseline
+    pub fn record_report_cadence(&mut self, slot: u64) {
+        if self.last_report_slot != 0 {
+            let gap = slot.saturating_sub(self.last_report_slot);
+            self.max_report_gap_slots = self.max_report_gap_slots.max(gap);
+            let bucket = REPORT_GAP_BOUNDS
+                .iter()
+                .position(|bound| gap < *bound)
+                .unwrap_or(REPORT_GAP_BOUNDS.len());
+            self.report_gap_histogram[bucket] = self.report_gap_histogram[bucket].saturating_add(1);
+        }
+        self.last_report_slot = slot;
+    }
+
+    // Share of landing-declared reports that landed and reverted, i.e. paid fees for nothing
+    pub fn landed_error_rate_bps(&self) -> u16 {
+        let declared: u64 = self.landing_counts.iter().map(|count| *count as u64).sum();
+        if declared == 0 {
+            0
+        } else {
+            (self.landing_counts[LANDING_ERRORED as usize] as u64 * 10_000 / declared) as u16
+        }
+    }
+
+    // success_count / tx_count in basis points, 0 for an empty registry
+    pub fn success_rate_bps(&self) -> u16 {
+        if self.tx_count == 0 {
+            0
+        } else {
+            (self.success_count as u128 * 10_000 / self.tx_count as u128) as u16
+        }
+    }
+
+    // 19 successes in 20 and 19,000 in 20,000 are both 9500 bps, but only the second
+    // is reliably above 9000; the lower bound says how far the rate can be trusted
+    pub fn success_rate_lower_bound_bps(&self) -> u16 {
+        wilson_lower_bound_bps(self.success_count, self.tx_count)
+    }
+
+    // Analytics on a handful of reports are noise; callers pick the sample size they
+    // need, and 0 skips the check
+    pub fn require_min_tx_count(&self, min_tx_count: u64) -> Result<()> {
+        require!(self.tx_count >= min_tx_count, TxGuardError::InsufficientData);
+        Ok(())
+    }
+
+    // Lifetime success rate, flagged stale when the reporter has gone quiet for longer
+    // than the config's max_report_staleness_slots (0 = never stale), and below the
+    // config's min_success_rate_bps by the point estimate or, if it asks, the lower bound
+    pub fn health(&self, current_slot: u64, config: &GuardConfig) -> HealthStatus {
+        let slots_since_last_report = match self.last_report_slot {
+            0 => u64::MAX,
+            last => current_slot.saturating_sub(last),
+        };
+        let max_staleness_slots = config.max_report_staleness_slots;
+        let success_rate_bps = self.success_rate_bps();
+        let success_rate_lower_bound_bps = self.success_rate_lower_bound_bps();
+        let judged_rate_bps = if config.health_uses_lower_bound {
+            success_rate_lower_bound_bps
+        } else {
+            success_rate_bps
+        };
+        HealthStatus {
+            success_rate_bps,
+            tx_count: self.tx_count,
+            slots_since_last_report,
+            stale: max_staleness_slots != 0 && slots_since_last_report > max_staleness_slots,
+            landed_error_rate_bps: self.landed_error_rate_bps(),
+            success_rate_lower_bound_bps,
+            below_min_success_rate: judged_rate_bps < config.min_success_rate_bps,
+        }
+    }
+
+    // Every instruction that mutates a registry checks this first
+    pub fn ensure_unlocked(&self) -> Result<()> {
+        require!(!self.locked, TxGuardError::ReentrancyDetected);
+        Ok(())
+    }
+
+    // Count a report against the per-slot quota, starting over whenever the slot advances,
+    // and keep the per-slot peak for capacity planning
+    pub fn consume_slot_quota(&mut self, slot: u64, max_reports_per_slot: u32) -> Result<()> {
+        if slot != self.current_slot {
+            self.current_slot = slot;
+            self.reports_this_slot = 0;
+        }
+        require!(
+            max_reports_per_slot == 0 || self.reports_this_slot < max_reports_per_slot,
+            TxGuardError::SlotQuotaExceeded
+        );
+        self.reports_this_slot = self.reports_this_slot.saturating_add(1);
+        self.max_tx_per_slot = self.max_tx_per_slot.max(self.reports_this_slot);
+        Ok(())
+    }
+
+    // Fold another registry's history in, treating it as older than ours
+    pub fn merge_from(&mut self, source: &TransactionRegistry) -> Result<()> {
+        self.tx_count = self.tx_count.checked_add(source.tx_count)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.success_count = self.success_count.checked_add(source.success_count)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.failure_count = self.failure_count.checked_add(source.failure_count)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.total_fee_lamports = self.total_fee_lamports.checked_add(source.total_fee_lamports)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.total_value_lamports = self.total_value_lamports.checked_add(source.total_value_lamports)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.max_tx_per_slot = self.max_tx_per_slot.max(source.max_tx_per_slot);
+        self.last_report_slot = self.last_report_slot.max(source.last_report_slot);
+        self.max_report_gap_slots = self.max_report_gap_slots.max(source.max_report_gap_slots);
+        for (bucket, added) in self.report_gap_histogram.iter_mut().zip(source.report_gap_histogram) {
+            *bucket = bucket.checked_add(added).ok_or(TxGuardError::CountOverflow)?;
+        }
+        for (counter, added) in self.version_successes.iter_mut()
+            .chain(self.version_failures.iter_mut())
+            .zip(source.version_successes.iter().chain(&source.version_failures))
+        {
+            *counter = counter.checked_add(*added).ok_or(TxGuardError::CountOverflow)?;
+        }
+        for (counter, added) in self.lookup_reports.iter_mut()
+            .chain(self.lookup_failures.iter_mut())
+            .zip(source.lookup_reports.iter().chain(&source.lookup_failures))
+        {
+            *counter = counter.checked_add(*added).ok_or(TxGuardError::CountOverflow)?;
+        }
+        self.max_looked_up_accounts = self.max_looked_up_accounts.max(source.max_looked_up_accounts);
+        for (counter, added) in self.signer_successes.iter_mut()
+            .chain(self.signer_failures.iter_mut())
+            .zip(source.signer_successes.iter().chain(&source.signer_failures))
+        {
+            *counter = counter.checked_add(*added).ok_or(TxGuardError::CountOverflow)?;
+        }
+        self.used_ed25519_precompile |= source.used_ed25519_precompile;
+        for (counter, added) in self.simulation_successes.iter_mut()
+            .chain(self.simulation_failures.iter_mut())
+            .zip(source.simulation_successes.iter().chain(&source.simulation_failures))
+        {
+            *counter = counter.checked_add(*added).ok_or(TxGuardError::CountOverflow)?;
+        }
+        for (method, added) in self.send_methods.iter_mut().zip(&source.send_methods) {
+            method.merge_from(added);
+        }
+        for caller in &source.seen_callers {
+            self.record_caller(caller);
+        }
+        // Callers the source only knows through its filter can't be told apart from
+        // ours, so they are added as is and may be counted twice
+        let filtered_callers = source.unique_caller_count.saturating_sub(source.seen_callers.len() as u32);
+        self.unique_caller_count = self.unique_caller_count.saturating_add(filtered_callers);
+        for (word, added) in self.caller_filter.iter_mut().zip(source.caller_filter) {
+            *word |= added;
+        }
+        for (counter, added) in self.landing_counts.iter_mut().zip(source.landing_counts) {
+            *counter = counter.checked_add(added).ok_or(TxGuardError::CountOverflow)?;
+        }
+        self.sandwich_pattern_count = self.sandwich_pattern_count.checked_add(source.sandwich_pattern_count)
+            .ok_or(TxGuardError::CountOverflow)?;
+        for entry in &source.instruction_types {
+            self.record_instruction_type(entry.instruction_type, entry.success, entry.failure)?;
+        }
+        for (bucket, count) in self.hourly_buckets.iter_mut().zip(&source.hourly_buckets) {
+            *bucket = bucket.checked_add(*count).ok_or(TxGuardError::CountOverflow)?;
+        }
+        for (bucket, count) in self.day_of_week_success.iter_mut().zip(&source.day_of_week_success)
+            .chain(self.day_of_week_failure.iter_mut().zip(&source.day_of_week_failure)) {
+            *bucket = bucket.checked_add(*count).ok_or(TxGuardError::CountOverflow)?;
+        }
+
+        self.sum_report_delay_slots = self.sum_report_delay_slots.checked_add(source.sum_report_delay_slots)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.max_report_delay_slots = self.max_report_delay_slots.max(source.max_report_delay_slots);
+
+        // Source entries fill whatever room our own entries leave, newest first
+        let entries = |registry: &TransactionRegistry| -> Vec<(u8, u64, u16, u8, u8)> {
+            registry.chronological_indices()
+                .into_iter()
+                .map(|idx| (
+                    registry.last_100_outcomes[idx],
+                    registry.outcome_slots.get(idx).copied().unwrap_or(0),
+                    registry.report_delays.get(idx).copied().unwrap_or(0),
+                    registry.outcome_tiers.get(idx).copied().unwrap_or(TIER_UNKNOWN),
+                    registry.outcome_failure_types.get(idx).copied().unwrap_or(NO_FAILURE_TYPE),
+                ))
+                .collect()
+        };
+        let mut merged = entries(source);
+        merged.extend(entries(self));
+        let keep = merged.len().min(100);
+        let merged = &merged[merged.len() - keep..];
+
+        self.last_100_outcomes.clear();
+        self.last_100_outcomes.extend(merged.iter().map(|(outcome, _, _, _, _)| *outcome));
+        self.last_100_outcomes.resize(WINDOW_SIZE as usize, OUTCOME_PENDING);
+        self.outcome_slots.clear();
+        self.outcome_slots.extend(merged.iter().map(|(_, slot, _, _, _)| *slot));
+        self.outcome_slots.resize(WINDOW_SIZE as usize, 0);
+        self.report_delays.clear();
+        self.report_delays.extend(merged.iter().map(|(_, _, delay, _, _)| *delay));
+        self.report_delays.resize(WINDOW_SIZE as usize, 0);
+        self.outcome_tiers.clear();
+        self.outcome_tiers.extend(merged.iter().map(|(_, _, _, tier, _)| *tier));
+        self.outcome_tiers.resize(WINDOW_SIZE as usize, TIER_UNKNOWN);
+        self.outcome_failure_types.clear();
+        self.outcome_failure_types.extend(merged.iter().map(|(_, _, _, _, failure_type)| *failure_type));
+        self.outcome_failure_types.resize(WINDOW_SIZE as usize, NO_FAILURE_TYPE);
+        self.cursor = (keep % 100) as u8;
+        Ok(())
+    }
+
+    // Validate a report and apply it to the counters and circular buffer
+    pub fn apply_outcome(&mut self, params: &TxOutcomeParams) -> Result<u8> {
+        // Validate priority fee tier (0-4)
+        require!(
+            params.priority_fee_tier < MAX_TIERS,
+            TxGuardError::InvalidPriorityFeeTier
+        );
+
+        // A partial fill executed, so it can't also be reported as a failure
+        require!(!params.partial_fill || params.success, TxGuardError::InvalidPartialFill);
+        params.check_landing()?;
+        require!(params.cpi_depth <= MAX_CPI_DEPTH, TxGuardError::InvalidCpiDepth);
+        // A transaction can't have landed in a slot that hasn't happened yet
+        let clock = Clock::get()?;
+        require!(params.tx_slot <= clock.slot, TxGuardError::InvalidTxSlot);
+        // An expiry can't be reported before the blockhash could have expired
+        if !params.success && params.failure_type == FailureType::Deadline && params.slots_in_flight != 0 {
+            let window = match self.blockhash_validity_window {
+                0 => DEFAULT_BLOCKHASH_VALIDITY_WINDOW,
+                window => window,
+            };
+            require!(
+                params.slots_in_flight >= window as u64,
+                TxGuardError::DeadlineNotExceeded
+            );
+        }
+        // An all-zero hash means the caller didn't supply a signature
+        if params.tx_sig_hash != [0; 32] {
+            self.check_and_remember_sig(&params.tx_sig_hash)?;
+        }
+
+        let outcome = if params.partial_fill {
+            OUTCOME_PARTIAL
+        } else if params.success {
+            OUTCOME_SUCCESS
+        } else {
+            OUTCOME_FAILURE
+        };
+
+        self.tx_count = self.tx_count.checked_add(1)
+            .ok_or(TxGuardError::CountOverflow)?;
+
+        // Update circular buffer
+        let cursor_idx = self.cursor as usize;
+        if cursor_idx < self.last_100_outcomes.len() {
+            self.last_100_outcomes[cursor_idx] = outcome;
+        }
+        self.outcome_slots[cursor_idx % 100] = clock.slot;
+        self.outcome_tiers[cursor_idx % 100] = params.priority_fee_tier;
+        self.outcome_failure_types[cursor_idx % 100] =
+            if outcome == OUTCOME_FAILURE { params.failure_type.into() } else { NO_FAILURE_TYPE };
+        // This entry may close a sandwich with the two before it
+        let entry = |back: usize| {
+            let idx = (cursor_idx % 100 + 100 - back) % 100;
+            (self.last_100_outcomes.get(idx).copied().unwrap_or(OUTCOME_PENDING), self.outcome_failure_types[idx])
+        };
+        if is_sandwich([entry(2), entry(1), entry(0)]) {
+            self.sandwich_pattern_count = self.sandwich_pattern_count.checked_add(1)
+                .ok_or(TxGuardError::CountOverflow)?;
+        }
+        // tx_slot 0 = not declared; the entry then records no delay
+        let delay = if params.tx_slot == 0 { 0 } else { clock.slot - params.tx_slot };
+        self.report_delays[cursor_idx % 100] = delay.min(u16::MAX as u64) as u16;
+        self.cursor = (self.cursor + 1) % 100;
+        if params.tx_slot != 0 {
+            self.sum_report_delay_slots = self.sum_report_delay_slots.checked_add(delay)
+                .ok_or(TxGuardError::CountOverflow)?;
+            self.max_report_delay_slots = self.max_report_delay_slots.max(delay);
+        }
+
+        let hour = hour_of_week(clock.unix_timestamp);
+        self.hourly_buckets[hour] = self.hourly_buckets[hour].saturating_add(1);
+
+        // Partial fills count on neither weekday side
+        let day = day_of_week(clock.unix_timestamp);
+        if outcome == OUTCOME_SUCCESS {
+            self.success_count = self.success_count.checked_add(1)
+                .ok_or(TxGuardError::CountOverflow)?;
+            self.day_of_week_success[day] = self.day_of_week_success[day].saturating_add(1);
+        } else if outcome == OUTCOME_FAILURE {
+            self.failure_count = self.failure_count.checked_add(1)
+                .ok_or(TxGuardError::CountOverflow)?;
+            self.day_of_week_failure[day] = self.day_of_week_failure[day].saturating_add(1);
+        }
+        // Instruction type 0 = not declared; partial fills stay out as elsewhere
+        if params.instruction_type != 0 && outcome != OUTCOME_PARTIAL {
+            let success = (outcome == OUTCOME_SUCCESS) as u32;
+            self.record_instruction_type(params.instruction_type, success, 1 - success)?;
+        }
+        // A rejected signature proves the precompile is in use even if nobody declared it
+        if outcome == OUTCOME_FAILURE && params.failure_type == FailureType::SignatureVerifyFailure {
+            self.used_ed25519_precompile = true;
+        }
+        if params.landing != LANDING_UNKNOWN && outcome != OUTCOME_PARTIAL {
+            let counter = &mut self.landing_counts[params.landing as usize];
+            *counter = counter.checked_add(1).ok_or(TxGuardError::CountOverflow)?;
+        }
+
+        // Accumulate lamport totals (zero values leave the registry as v1 would)
+        self.total_fee_lamports = self.total_fee_lamports
+            .checked_add(params.fee_lamports)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.total_value_lamports = self.total_value_lamports
+            .checked_add(params.value_lamports)
+            .ok_or(TxGuardError::CountOverflow)?;
+
+        Ok(outcome)
+    }
+}
+
+// Namespace counters as of the end of an epoch; seeds [b"snapshot", namespace, epoch_le_bytes].
+// Written by the first report of the following epoch, see snapshot_on_rollover
+#[account]
+#[derive(InitSpace)]
+pub struct EpochSnapshot {
+    pub epoch: u64,
+    pub tx_count: u64,
+    pub success_count: u64,
+    pub failure_count: u64,
+    pub failures_by_type: [u32; FAILURE_KINDS], // Indexed like FailureCatalog::counts_by_type
+    pub captured_slot: u64, // Slot of the report that triggered the snapshot
+}
+
+impl EpochSnapshot {
+    pub fn capture(epoch: u64, registry: &TransactionRegistry, catalog: &FailureCatalog) -> Result<Self> {
+        Ok(Self {
+            epoch,
+            tx_count: registry.tx_count,
+            success_count: registry.success_count,
+            failure_count: registry.failure_count,
+            failures_by_type: catalog.counts_by_type().map(|(_, count)| count),
+            captured_slot: Clock::get()?.slot,
+        })
+    }
+}
+
+// A FailureCatalog's counters, written by write_catalog_snapshot_to_account into an
+// account of the caller's choosing
+#[account]
+#[derive(InitSpace)]
+pub struct CatalogBorshSnapshot {
+    pub writer: Pubkey, // Signer of the first write; nobody else can overwrite it
+    pub catalog: Pubkey,
+    pub failures_by_type: [u32; FAILURE_KINDS], // Indexed like FailureCatalog::counts_by_type
+    pub total_failures: u64,
+    pub captured_slot: u64,
+}
+
+impl CatalogBorshSnapshot {
+    pub fn capture(writer: Pubkey, address: Pubkey, catalog: &FailureCatalog) -> Result<Self> {
+        let failures_by_type = catalog.counts_by_type().map(|(_, count)| count);
+        Ok(Self {
+            writer,
+            catalog: address,
+            failures_by_type,
+            total_failures: failures_by_type.iter().map(|count| *count as u64).sum(),
+            captured_slot: Clock::get()?.slot,
+        })
+    }
+}
+
+// Arguments for register_tx_outcome_v2
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TxOutcomeParams {
+    pub success: bool,
+    pub failure_type: FailureType, // Only read for failures
+    pub priority_fee_tier: u8,
+    pub fee_lamports: u64,
+    pub value_lamports: u64,
+    pub mev_score: u8,
+    pub nonce: u64,
+    pub bridge_chain_id: u16, // Destination chain for bridge failures (type 13)
+    pub partial_fill: bool, // Executed but filled less than requested (requires success)
+    pub tx_sig_hash: [u8; 32], // sha256 of the transaction signature, zero = skip dedup
+    pub slots_in_flight: u64, // Slots from blockhash to expiry for deadline failures (type 14), 0 = unknown
+    pub wsol_error_code: u16, // SPL Token error code for WSOL failures (type 15)
+    pub tx_slot: u64, // Slot the transaction landed in, 0 = not declared
+    pub frozen_mint: Pubkey, // Mint of the frozen token account for type 16 failures
+    pub submitted_slot: u64, // Slot the transaction was first sent in, 0 = unknown
+    pub confirmed_slot: u64, // Slot it was confirmed in, 0 = unknown
+    pub instruction_type: u8, // Protocol-defined instruction kind (swap, add_liquidity, ...), 0 = not tracked
+    pub landing: u8, // LANDING_* code; must agree with success unless LANDING_UNKNOWN
+    pub expected_version: u8, // Program versions the caller expected and found, for type 19 failures
+    pub actual_version: u8,
+    pub tags: u16, // Caller-defined bitflags, counted per bit in TagStats; 0 = untagged
+    pub cpi_depth: u8, // Deepest CPI the transaction made, up to MAX_CPI_DEPTH; 0 = none or unknown
+    pub required_space: u32, // Bytes the failed write needed and the account held, for type 24 failures
+    pub available_space: u32,
+    pub expected_program: Pubkey, // Token program the instruction expected and the account's actual owner, for type 26 failures
+    pub actual_program: Pubkey,
+}
+
+// Everything optional undeclared. Landing 0 means "never landed", so its unknown
+// value isn't zero and the derive can't be used
+impl Default for TxOutcomeParams {
+    fn default() -> Self {
+        Self {
+            success: false,
+            failure_type: FailureType::Slippage,
+            priority_fee_tier: 0,
+            fee_lamports: 0,
+            value_lamports: 0,
+            mev_score: 0,
+            nonce: 0,
+            bridge_chain_id: 0,
+            partial_fill: false,
+            tx_sig_hash: [0; 32],
+            slots_in_flight: 0,
+            wsol_error_code: 0,
+            tx_slot: 0,
+            frozen_mint: Pubkey::default(),
+            submitted_slot: 0,
+            confirmed_slot: 0,
+            instruction_type: 0,
+            landing: LANDING_UNKNOWN,
+            expected_version: 0,
+            actual_version: 0,
+            tags: 0,
+            cpi_depth: 0,
+            required_space: 0,
+            available_space: 0,
+            expected_program: Pubkey::default(),
+            actual_program: Pubkey::default(),
+        }
+    }
+}
+
+// Market conditions a caller can pass to project_next_failure
+pub const RECENT_CONTEXT_NORMAL: u8 = 0;
+pub const RECENT_CONTEXT_CONGESTED: u8 = 1;
+pub const RECENT_CONTEXT_VOLATILE: u8 = 2;
+
+// Whether a reported transaction made it on-chain. A transaction that landed and reverted
+// still paid fees, one that never landed didn't
+pub const LANDING_NEVER: u8 = 0;
+pub const LANDING_ERRORED: u8 = 1;
+pub const LANDING_SUCCEEDED: u8 = 2;
+pub const LANDING_UNKNOWN: u8 = u8::MAX;
+
+impl TxOutcomeParams {
+    // A success must have landed and succeeded; a failure either never landed or reverted
+    pub fn check_landing(&self) -> Result<()> {
+        match self.landing {
+            LANDING_UNKNOWN => {}
+            LANDING_SUCCEEDED => require!(self.success, TxGuardError::ContradictoryReport),
+            LANDING_NEVER | LANDING_ERRORED => require!(!self.success, TxGuardError::ContradictoryReport),
+            _ => return err!(TxGuardError::InvalidLanding),
+        }
+        Ok(())
+    }
+
+    // Slots from submission to confirmation, None unless both slots were declared
+    pub fn confirmation_latency(&self) -> Result<Option<u64>> {
+        if self.submitted_slot == 0 || self.confirmed_slot == 0 {
+            return Ok(None);
+        }
+        let latency = self.confirmed_slot.checked_sub(self.submitted_slot)
+            .ok_or(TxGuardError::InvalidConfirmationSlots)?;
+        require!(latency <= MAX_CONFIRMATION_LATENCY_SLOTS, TxGuardError::InvalidConfirmationSlots);
+        Ok(Some(latency))
+    }
+
+    // Fold in the metadata fields the reporting path already understands
+    pub fn with_metadata(mut self, metadata: &TxMetadata) -> Self {
+        if let Some(sig_hash) = metadata.sig_hash() {
+            self.tx_sig_hash = sig_hash;
+        }
+        if let Some(tx_slot) = metadata.slot() {
+            self.tx_slot = tx_slot;
+        }
+        self
+    }
+}
+
+// Failure Catalog Account
+#[account]
+#[derive(InitSpace, Default)]
+pub struct FailureCatalog {
+    pub slippage_exceeded: u32,
+    pub insufficient_liquidity: u32,
+    pub mev_detected: u32,
+    pub dropped_tx: u32,
+    pub insufficient_funds: u32,
+    pub other: u32,
+    pub bridge_failure: u32,
+    pub bridge_chain_id: u16, // Destination chain of the most recent bridge failure
+    #[max_len(10)]
+    pub bridge_failure_by_chain: Vec<ChainFailureCount>,
+    pub partial_fill: u32,
+    pub partial_fill_rate_bps: u16, // partial_fill / tx_count, refreshed on every report
+    #[max_len(32)]
+    pub first_occurrence: Vec<i64>, // Unix timestamp per failure_type code, 0 = never
+    #[max_len(32)]
+    pub last_occurrence: Vec<i64>,
+    pub deadline_exceeded: u32, // Blockhash expired before confirmation (type 14)
+    pub last_failure_slot: u64, // 0 = no failure recorded yet
+    pub failure_gap_histogram: [u32; 5], // Slots between consecutive failures, see FAILURE_GAP_BOUNDS
+    pub child_count: u8, // Catalogs folded in through merge_into_parent
+    pub wrapped_sol_error: u32, // WSOL wrap/unwrap failures, e.g. a bad close_authority (type 15)
+    pub wsol_error_code: u16, // SPL Token error code of the most recent WSOL failure
+    pub token_frozen: u32, // Transfers out of a frozen token account, e.g. a USDC freeze (type 16)
+    pub frozen_mint: Pubkey, // Mint behind the most recent token_frozen failure
+    pub stale_oracle_update: u32, // Oracle published but the protocol's cached price was outdated (type 17)
+    pub signature_verify_failure: u32, // Rejected by the ed25519 or secp256k1 verify precompile (type 18)
+    pub version_mismatch: u32, // CPI into a program upgraded past (or not yet to) the version the caller expects (type 19)
+    pub expected_version: u8, // Versions behind the most recent version_mismatch failure
+    pub actual_version: u8,
+    pub blockhash_not_found: u32, // recent_blockhash unknown to the leader, rejected on arrival (type 20)
+    pub cpi_depth_exceeded: u32, // Call chain went past MAX_CPI_DEPTH (type 21)
+    pub max_observed_cpi_depth: u8, // Deepest cpi_depth declared by a successful report
+    pub nonce_account_expired: u32, // Durable nonce advanced before the transaction was submitted (type 22)
+    pub last_nonce_failure_slot: u64, // Compare with the count to spot bursts, e.g. from clock skew
+    pub gas_price_spike: u32, // Priority fee was outbid by a sudden spike in the going rate (type 23)
+    pub last_spike_fee_tier: u8, // Tier that fell short in the most recent gas_price_spike failure
+    pub account_data_too_small: u32, // A write outgrew the account's allocation, e.g. a missed realloc (type 24)
+    pub last_required_space: u32, // Bytes the most recent account_data_too_small write needed
+    pub last_available_space: u32, // and the bytes the account had
+    pub program_not_executable: u32, // An account passed as a program wasn't executable, e.g. a test account or a buffer (type 25)
+    pub wrong_token_program: u32, // A token account was passed to the other token program, e.g. Token-2022 to legacy SPL Token (type 26)
+    pub last_expected_program: Pubkey, // Token program the most recent wrong_token_program failure expected
+    pub last_actual_program: Pubkey, // and the program that actually owned the account
+    pub catalog_reset_count: u32, // reset_failure_types calls since initialize
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ChainFailureCount {
+    pub chain_id: u16,
+    pub count: u32,
+}
+
+// Failure type codes (anything unmapped counts as `other`)
+pub const FAILURE_MEV: u8 = 2;
+pub const FAILURE_OTHER: u8 = 5;
+pub const FAILURE_BRIDGE: u8 = 13;
+pub const FAILURE_DEADLINE: u8 = 14;
+pub const FAILURE_WSOL: u8 = 15;
+pub const FAILURE_FROZEN: u8 = 16;
+pub const FAILURE_ORACLE_CACHE: u8 = 17;
+pub const FAILURE_SIG_VERIFY: u8 = 18;
+pub const FAILURE_VERSION_MISMATCH: u8 = 19;
+pub const FAILURE_BLOCKHASH_NOT_FOUND: u8 = 20;
+pub const FAILURE_CPI_DEPTH: u8 = 21;
+pub const FAILURE_NONCE_EXPIRED: u8 = 22;
+pub const FAILURE_GAS_SPIKE: u8 = 23;
+pub const FAILURE_DATA_TOO_SMALL: u8 = 24;
+pub const FAILURE_NOT_EXECUTABLE: u8 = 25;
+pub const FAILURE_WRONG_TOKEN_PROGRAM: u8 = 26;
+
+// The failure_type argument, one variant per code. Each variant's index is its code, so
+// it encodes as the u8 it replaced; codes 6-12 were never assigned and still count as
+// other, while codes past the last variant no longer deserialize
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FailureType {
+    Slippage = 0,
+    Liquidity = 1,
+    Mev = FAILURE_MEV,
+    Dropped = 3,
+    InsufficientFunds = 4,
+    Other = FAILURE_OTHER,
+    Unassigned6 = 6,
+    Unassigned7 = 7,
+    Unassigned8 = 8,
+    Unassigned9 = 9,
+    Unassigned10 = 10,
+    Unassigned11 = 11,
+    Unassigned12 = 12,
+    Bridge = FAILURE_BRIDGE,
+    Deadline = FAILURE_DEADLINE,
+    Wsol = FAILURE_WSOL,
+    TokenFrozen = FAILURE_FROZEN,
+    StaleOracleUpdate = FAILURE_ORACLE_CACHE,
+    SignatureVerifyFailure = FAILURE_SIG_VERIFY,
+    VersionMismatch = FAILURE_VERSION_MISMATCH,
+    BlockhashNotFound = FAILURE_BLOCKHASH_NOT_FOUND,
+    CpiDepthExceeded = FAILURE_CPI_DEPTH,
+    NonceAccountExpired = FAILURE_NONCE_EXPIRED,
+    GasPriceSpike = FAILURE_GAS_SPIKE,
+    AccountDataTooSmall = FAILURE_DATA_TOO_SMALL,
+    ProgramNotExecutable = FAILURE_NOT_EXECUTABLE,
+    WrongTokenProgram = FAILURE_WRONG_TOKEN_PROGRAM,
+}
+
+impl From<FailureType> for u8 {
+    fn from(failure_type: FailureType) -> u8 {
+        failure_type as u8
+    }
+}
+
+impl TryFrom<u8> for FailureType {
+    type Error = u8;
+
+    fn try_from(code: u8) -> std::result::Result<Self, u8> {
+        Self::deserialize(&mut &[code][..]).map_err(|_| code)
+    }
+}
+// Number of failure buckets, i.e. FailureCatalog::counts_by_type entries
+pub const FAILURE_KINDS: usize = 20;
+// Deepest cross-program invocation the runtime allows
+pub const MAX_CPI_DEPTH: u8 = 4;
+// format_failure_pie: bar width in blocks, and the output cap in bytes
+pub const PIE_WIDTH: usize = 8;
+pub const MAX_PIE_LEN: usize = 256;
+// Solana blockhashes expire after ~150 slots
+pub const DEFAULT_BLOCKHASH_VALIDITY_WINDOW: u8 = 150;
+// TransactionRegistry::hourly_buckets length, one per hour of a week
+pub const HOURS_PER_WEEK: usize = 24 * 7;
+// TransactionRegistry::day_of_week_success and day_of_week_failure length
+pub const DAYS_PER_WEEK: usize = 7;
+// Number of recent signature hashes kept for duplicate detection
+pub const SIG_RING_SIZE: usize = 64;
+// Upper bounds (exclusive) of the first four failure_gap_histogram buckets; the last is 500+
+pub const FAILURE_GAP_BOUNDS: [u64; 4] = [5, 25, 100, 500];
+// Occurrence timestamps are indexed by failure_type code
+pub const FAILURE_SLOTS: usize = 32;
+
+impl FailureCatalog {
+    // See TransactionRegistry::upgrade
+    pub fn upgrade(&mut self) {
+        if self.first_occurrence.len() < FAILURE_SLOTS {
+            self.first_occurrence.resize(FAILURE_SLOTS, 0);
+            self.last_occurrence.resize(FAILURE_SLOTS, 0);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.slippage_exceeded = 0;
+        self.insufficient_liquidity = 0;
+        self.mev_detected = 0;
+        self.dropped_tx = 0;
+        self.insufficient_funds = 0;
+        self.other = 0;
+        self.bridge_failure = 0;
+        self.deadline_exceeded = 0;
+        self.wrapped_sol_error = 0;
+        self.wsol_error_code = 0;
+        self.token_frozen = 0;
+        self.frozen_mint = Pubkey::default();
+        self.stale_oracle_update = 0;
+        self.signature_verify_failure = 0;
+        self.version_mismatch = 0;
+        self.expected_version = 0;
+        self.actual_version = 0;
+        self.blockhash_not_found = 0;
+        self.cpi_depth_exceeded = 0;
+        self.max_observed_cpi_depth = 0;
+        self.nonce_account_expired = 0;
+        self.last_nonce_failure_slot = 0;
+        self.gas_price_spike = 0;
+        self.last_spike_fee_tier = 0;
+        self.account_data_too_small = 0;
+        self.last_required_space = 0;
+        self.last_available_space = 0;
+        self.program_not_executable = 0;
+        self.wrong_token_program = 0;
+        self.last_expected_program = Pubkey::default();
+        self.last_actual_program = Pubkey::default();
+        self.catalog_reset_count = 0;
+        self.last_failure_slot = 0;
+        self.failure_gap_histogram = [0; 5];
+        self.child_count = 0;
+        self.bridge_chain_id = 0;
+        self.bridge_failure_by_chain.clear();
+        self.partial_fill = 0;
+        self.partial_fill_rate_bps = 0;
+        self.first_occurrence.clear();
+        self.first_occurrence.resize(FAILURE_SLOTS, 0);
+        self.last_occurrence.clear();
+        self.last_occurrence.resize(FAILURE_SLOTS, 0);
+    }
+
+    // Counter and occurrence slot for a failure type; unmapped codes land in `other`
+    fn counter_mut(&mut self, failure_type: u8) -> (&mut u32, usize) {
+        match failure_type {
+            0 => (&mut self.slippage_exceeded, 0),
+            1 => (&mut self.insufficient_liquidity, 1),
+            2 => (&mut self.mev_detected, 2),
+            3 => (&mut self.dropped_tx, 3),
+            4 => (&mut self.insufficient_funds, 4),
+            FAILURE_BRIDGE => (&mut self.bridge_failure, FAILURE_BRIDGE as usize),
+            FAILURE_DEADLINE => (&mut self.deadline_exceeded, FAILURE_DEADLINE as usize),
+            FAILURE_WSOL => (&mut self.wrapped_sol_error, FAILURE_WSOL as usize),
+            FAILURE_FROZEN => (&mut self.token_frozen, FAILURE_FROZEN as usize),
+            FAILURE_ORACLE_CACHE => (&mut self.stale_oracle_update, FAILURE_ORACLE_CACHE as usize),
+            FAILURE_SIG_VERIFY => (&mut self.signature_verify_failure, FAILURE_SIG_VERIFY as usize),
+            FAILURE_VERSION_MISMATCH => (&mut self.version_mismatch, FAILURE_VERSION_MISMATCH as usize),
+            FAILURE_BLOCKHASH_NOT_FOUND => (&mut self.blockhash_not_found, FAILURE_BLOCKHASH_NOT_FOUND as usize),
+            FAILURE_CPI_DEPTH => (&mut self.cpi_depth_exceeded, FAILURE_CPI_DEPTH as usize),
+            FAILURE_NONCE_EXPIRED => (&mut self.nonce_account_expired, FAILURE_NONCE_EXPIRED as usize),
+            FAILURE_GAS_SPIKE => (&mut self.gas_price_spike, FAILURE_GAS_SPIKE as usize),
+            FAILURE_DATA_TOO_SMALL => (&mut self.account_data_too_small, FAILURE_DATA_TOO_SMALL as usize),
+            FAILURE_NOT_EXECUTABLE => (&mut self.program_not_executable, FAILURE_NOT_EXECUTABLE as usize),
+            FAILURE_WRONG_TOKEN_PROGRAM => (&mut self.wrong_token_program, FAILURE_WRONG_TOKEN_PROGRAM as usize),
+            _ => (&mut self.other, FAILURE_OTHER as usize),
+        }
+    }
+
+    pub fn increment(&mut self, failure_type: u8, now: i64, current_slot: u64) -> Result<()> {
+        let (counter, slot) = self.counter_mut(failure_type);
+        *counter = counter.checked_add(1).ok_or(TxGuardError::CountOverflow)?;
+        let first_seen = *counter == 1;
+
+        if first_seen {
+            self.first_occurrence[slot] = now;
+        }
+        self.last_occurrence[slot] = now;
+        self.record_failure_gap(current_slot);
+        Ok(())
+    }
+
+    // Zeroes what ArchiveRegistry::capture copied, so rates against the restarted tx_count
+    // stay in range. Occurrence times and per-type details carry on
+    pub fn archive_counts(&mut self) {
+        for code in FAILURE_CODES {
+            *self.counter_mut(code).0 = 0;
+        }
+        self.partial_fill = 0;
+        self.partial_fill_rate_bps = 0;
+    }
+
+    // Forget one failure type as if it had never occurred
+    pub fn reset_type(&mut self, failure_type: u8) {
+        let (counter, slot) = self.counter_mut(failure_type);
+        *counter = 0;
+        if let Some(first) = self.first_occurrence.get_mut(slot) {
+            *first = 0;
+        }
+        if let Some(last) = self.last_occurrence.get_mut(slot) {
+            *last = 0;
+        }
+        match failure_type {
+            FAILURE_BRIDGE => {
+                self.bridge_chain_id = 0;
+                self.bridge_failure_by_chain.clear();
+            }
+            FAILURE_WSOL => self.wsol_error_code = 0,
+            FAILURE_FROZEN => self.frozen_mint = Pubkey::default(),
+            FAILURE_VERSION_MISMATCH => {
+                self.expected_version = 0;
+                self.actual_version = 0;
+            }
+            FAILURE_NONCE_EXPIRED => self.last_nonce_failure_slot = 0,
+            FAILURE_GAS_SPIKE => self.last_spike_fee_tier = 0,
+            FAILURE_DATA_TOO_SMALL => {
+                self.last_required_space = 0;
+                self.last_available_space = 0;
+            }
+            FAILURE_WRONG_TOKEN_PROGRAM => {
+                self.last_expected_program = Pubkey::default();
+                self.last_actual_program = Pubkey::default();
+            }
+            _ => {}
+        }
+    }
+
+    // Bucket the gap since the previous failure; the first failure only sets the baseline
+    pub fn record_failure_gap(&mut self, slot: u64) {
+        if self.last_failure_slot != 0 {
+            let gap = slot.saturating_sub(self.last_failure_slot);
+            let bucket = FAILURE_GAP_BOUNDS
+                .iter()
+                .position(|bound| gap < *bound)
+                .unwrap_or(FAILURE_GAP_BOUNDS.len());
+            self.failure_gap_histogram[bucket] = self.failure_gap_histogram[bucket].saturating_add(1);
+        }
+        self.last_failure_slot = slot;
+    }
+
+    // (failure_type code, count) for every bucket the catalog tracks
+    pub fn counts_by_type(&self) -> [(u8, u32); FAILURE_KINDS] {
+        [
+            (0, self.slippage_exceeded),
+            (1, self.insufficient_liquidity),
+            (2, self.mev_detected),
+            (3, self.dropped_tx),
+            (4, self.insufficient_funds),
+            (FAILURE_OTHER, self.other),
+            (FAILURE_BRIDGE, self.bridge_failure),
+            (FAILURE_DEADLINE, self.deadline_exceeded),
+            (FAILURE_WSOL, self.wrapped_sol_error),
+            (FAILURE_FROZEN, self.token_frozen),
+            (FAILURE_ORACLE_CACHE, self.stale_oracle_update),
+            (FAILURE_SIG_VERIFY, self.signature_verify_failure),
+            (FAILURE_VERSION_MISMATCH, self.version_mismatch),
+            (FAILURE_BLOCKHASH_NOT_FOUND, self.blockhash_not_found),
+            (FAILURE_CPI_DEPTH, self.cpi_depth_exceeded),
+            (FAILURE_NONCE_EXPIRED, self.nonce_account_expired),
+            (FAILURE_GAS_SPIKE, self.gas_price_spike),
+            (FAILURE_DATA_TOO_SMALL, self.account_data_too_small),
+            (FAILURE_NOT_EXECUTABLE, self.program_not_executable),
+            (FAILURE_WRONG_TOKEN_PROGRAM, self.wrong_token_program),
+        ]
+    }
+
+    // Every oracle-related failure. Only stale_oracle_update is tracked today; a separate
+    // "oracle hasn't published" bucket would be added to this sum
+    pub fn total_oracle_failures(&self) -> u64 {
+        self.stale_oracle_update as u64
+    }
+
+    // Most frequent failure type and its count; ties go to the lowest code, and
+    // (0, 0) means nothing has failed yet
+    pub fn dominant_failure(&self) -> (u8, u32) {
+        self.counts_by_type()
+            .into_iter()
+            .fold((0, 0), |best, (code, count)| if count > best.1 { (code, count) } else { best })
+    }
+
+    // Highest count / total ratio is simply the highest count; None until something failed
+    pub fn project_next_failure(&self) -> Option<u8> {
+        match self.dominant_failure() {
+            (_, 0) => None,
+            (failure_type, _) => Some(failure_type),
+        }
+    }
+
+    // e.g. "SLIP: ███░░░░░ 42%  MEV: ██░░░░░░ 21%", largest share first, at most MAX_PIE_LEN bytes
+    pub fn format_failure_pie(&self) -> String {
+        let mut counts: Vec<(u8, u32)> = self.counts_by_type()
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        let total: u64 = counts.iter().map(|(_, count)| *count as u64).sum();
+        if total == 0 {
+            return String::from("no failures recorded");
+        }
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let mut pie = String::new();
+        for (failure_type, count) in counts {
+            let filled = (count as u64 * PIE_WIDTH as u64 / total) as usize;
+            let entry = format!(
+                "{}{}: {}{} {}%",
+                if pie.is_empty() { "" } else { "  " },
+                failure_label(failure_type),
+                "█".repeat(filled),
+                "░".repeat(PIE_WIDTH - filled),
+                count as u64 * 100 / total,
+            );
+            if pie.len() + entry.len() > MAX_PIE_LEN {
+                break;
+            }
+            pie.push_str(&entry);
+        }
+        pie
+    }
+
+    pub fn merge_from(&mut self, source: &FailureCatalog) -> Result<()> {
+        for (counter, added) in [
+            (&mut self.slippage_exceeded, source.slippage_exceeded),
+            (&mut self.insufficient_liquidity, source.insufficient_liquidity),
+            (&mut self.mev_detected, source.mev_detected),
+            (&mut self.dropped_tx, source.dropped_tx),
+            (&mut self.insufficient_funds, source.insufficient_funds),
+            (&mut self.other, source.other),
+            (&mut self.bridge_failure, source.bridge_failure),
+            (&mut self.deadline_exceeded, source.deadline_exceeded),
+            (&mut self.wrapped_sol_error, source.wrapped_sol_error),
+            (&mut self.token_frozen, source.token_frozen),
+            (&mut self.stale_oracle_update, source.stale_oracle_update),
+            (&mut self.signature_verify_failure, source.signature_verify_failure),
+            (&mut self.version_mismatch, source.version_mismatch),
+            (&mut self.blockhash_not_found, source.blockhash_not_found),
+            (&mut self.cpi_depth_exceeded, source.cpi_depth_exceeded),
+            (&mut self.nonce_account_expired, source.nonce_account_expired),
+            (&mut self.gas_price_spike, source.gas_price_spike),
+            (&mut self.account_data_too_small, source.account_data_too_small),
+            (&mut self.program_not_executable, source.program_not_executable),
+            (&mut self.wrong_token_program, source.wrong_token_program),
+            (&mut self.partial_fill, source.partial_fill),
+        ] {
+            *counter = counter.checked_add(added).ok_or(TxGuardError::CountOverflow)?;
+        }
+
+        for entry in source.bridge_failure_by_chain.iter() {
+            if let Some(existing) = self.bridge_failure_by_chain.iter_mut().find(|e| e.chain_id == entry.chain_id) {
+                existing.count = existing.count.checked_add(entry.count)
+                    .ok_or(TxGuardError::CountOverflow)?;
+            } else if self.bridge_failure_by_chain.len() < 10 {
+                self.bridge_failure_by_chain.push(entry.clone());
+            }
+        }
+
+        // Keep the earliest first sighting and the latest last sighting per type
+        for slot in 0..source.first_occurrence.len().min(FAILURE_SLOTS) {
+            let theirs = source.first_occurrence[slot];
+            if theirs != 0 && (self.first_occurrence[slot] == 0 || theirs < self.first_occurrence[slot]) {
+                self.first_occurrence[slot] = theirs;
+            }
+        }
+        for slot in 0..source.last_occurrence.len().min(FAILURE_SLOTS) {
+            self.last_occurrence[slot] = self.last_occurrence[slot].max(source.last_occurrence[slot]);
+        }
+
+        for (bucket, added) in self.failure_gap_histogram.iter_mut().zip(source.failure_gap_histogram) {
+            *bucket = bucket.checked_add(added).ok_or(TxGuardError::CountOverflow)?;
+        }
+        self.last_failure_slot = self.last_failure_slot.max(source.last_failure_slot);
+        self.max_observed_cpi_depth = self.max_observed_cpi_depth.max(source.max_observed_cpi_depth);
+        self.last_nonce_failure_slot = self.last_nonce_failure_slot.max(source.last_nonce_failure_slot);
+        Ok(())
+    }
+
+    // Rates compare failures per report since the snapshot against failures per report up
+    // to it; with no reports on either side no type is marked improved or regressed
+    pub fn compare_to_snapshot(&self, registry: &TransactionRegistry, snapshot: &EpochSnapshot) -> CatalogDiff {
+        let counts = self.counts_by_type();
+        let delta = |code: usize| counts[code].1 as i64 - snapshot.failures_by_type[code] as i64;
+        let reports_before = snapshot.tx_count as u128;
+        let reports_since = registry.tx_count.saturating_sub(snapshot.tx_count) as u128;
+
+        let (mut improved_types, mut regressed_types) = (0u8, 0u8);
+        if reports_before > 0 && reports_since > 0 {
+            for code in 0..=FAILURE_OTHER as usize {
+                let before = snapshot.failures_by_type[code] as u128 * reports_since;
+                let since = delta(code).max(0) as u128 * reports_before;
+                if since < before {
+                    improved_types |= 1 << code;
+                } else if since > before {
+                    regressed_types |= 1 << code;
+                }
+            }
+        }
+
+        CatalogDiff {
+            delta_slippage: delta(0),
+            delta_liquidity: delta(1),
+            delta_mev: delta(2),
+            delta_dropped: delta(3),
+            delta_funds: delta(4),
+            delta_other: delta(FAILURE_OTHER as usize),
+            improved_types,
+            regressed_types,
+        }
+    }
+
+    // Poisson Z-score per failure type for the failures recorded since `baseline`: a type
+    // is expected to take the same share of them as it held at the snapshot, so
+    // z = (observed - expected) / sqrt(expected), with expected floored at 1. Only spikes
+    // above the threshold are flagged; a baseline with no failures has no mix to compare.
+    pub fn detect_anomaly(&self, baseline: &EpochSnapshot, z_threshold_bps: u16) -> AnomalyReport {
+        const SCALE: u128 = 10_000;
+        let mut report = AnomalyReport { anomaly_detected: false, anomalous_type: 0, z_score_bps: 0 };
+
+        let baseline_total: u128 = baseline.failures_by_type.iter().map(|c| *c as u128).sum();
+        if baseline_total == 0 {
+            return report;
+        }
+        let counts = self.counts_by_type();
+        let observed: Vec<u128> = counts.iter()
+            .zip(baseline.failures_by_type)
+            .map(|((_, now), then)| now.saturating_sub(then) as u128)
+            .collect();
+        let observed_total: u128 = observed.iter().sum();
+
+        for (index, (code, _)) in counts.iter().enumerate() {
+            // expected and observed carry SCALE^2 so the square root keeps SCALE
+            let expected = (observed_total * baseline.failures_by_type[index] as u128 * SCALE * SCALE
+                / baseline_total)
+                .max(SCALE * SCALE);
+            let observed = observed[index] * SCALE * SCALE;
+            if observed <= expected {
+                continue;
+            }
+            let z_score_bps = ((observed - expected) / isqrt(expected)).min(u64::MAX as u128) as u64;
+            if z_score_bps > z_threshold_bps as u64 && z_score_bps > report.z_score_bps {
+                report = AnomalyReport { anomaly_detected: true, anomalous_type: *code, z_score_bps };
+            }
+        }
+        report
+    }
+
+    // Track the destination chain; chains beyond the histogram capacity are not bucketed
+    pub fn record_bridge_failure(&mut self, chain_id: u16) -> Result<()> {
+        self.bridge_chain_id = chain_id;
+
+        if let Some(entry) = self.bridge_failure_by_chain.iter_mut().find(|e| e.chain_id == chain_id) {
+            entry.count = entry.count.checked_add(1).ok_or(TxGuardError::CountOverflow)?;
+        } else if self.bridge_failure_by_chain.len() < 10 {
+            self.bridge_failure_by_chain.push(ChainFailureCount { chain_id, count: 1 });
+        }
+        Ok(())
+    }
+}
+
+// Per-day tier counts; seeds [b"tier_daily", namespace, day_le_bytes] with day =
+// unix_timestamp / 86_400. Written by reports that pass today's PDA as `tier_daily`
+#[account]
+#[derive(InitSpace)]
+pub struct TierDailySnapshot {
+    pub day: i64,
+    #[max_len(MAX_TIERS)]
+    pub tier_counts: Vec<u64>,
+}
+
+// get_tier_trend reads at most a week of TierDailySnapshot accounts
+pub const MAX_TREND_DAYS: u8 = 7;
+
+fn tier_daily_address(namespace: &str, day: i64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"tier_daily", namespace.as_bytes(), &day.to_le_bytes()], program_id)
+}
+
+// Priority Fee Statistics Account
+#[account]
+#[derive(InitSpace, Default)]
+pub struct PriorityFeeStats {
+    #[max_len(MAX_TIERS)]
+    pub tiers: Vec<u64>, // Counts per priority fee tier (0 to MAX_TIERS - 1)
+    #[max_len(MAX_TIERS)]
+    pub tier_names: Vec<[u8; 16]>, // UTF-8 labels, space padded (e.g. b"ultra-low       ")
+    #[max_len(MAX_TIERS)]
+    pub tier_successes: Vec<u64>, // Successful outcomes per tier, for per-tier success rates
+    #[max_len(MAX_TIERS)]
+    pub direct_update_counts: Vec<u64>, // The share of `tiers` that came from update_priority_fee
+    #[max_len(MAX_TIERS)]
+    pub tier_avg_latency_slots: Vec<u16>, // Mean confirmation latency per tier, as LatencyStats counts it
+    #[max_len(MAX_TIERS)]
+    pub tier_latency_counts: Vec<u32>, // Reports behind each tier's mean
+    pub fee_sample_count: u64, // Successful reports that declared fee_lamports, see record_fee
+    pub fee_mean_fp: u128, // Their mean fee in lamports, scaled by FEE_MEAN_SCALE
+    pub fee_m2: u128, // Welford's sum of squared deviations from that mean, in lamports^2
+}
+
+// Fractional bits of PriorityFeeStats::fee_mean_fp
+pub const FEE_MEAN_SCALE: u128 = 1 << 32;
+
+// a * b / FEE_MEAN_SCALE^2 for a, b < 2^96, rounded down. The full product can need 192
+// bits, so it is summed from 64-bit halves; the result fits whenever a and b are two
+// scaled deviations of u64 fees, as each is then under 2^64 lamports
+fn unscale_product(a: u128, b: u128) -> u128 {
+    let (a_hi, a_lo) = (a >> 64, a as u64 as u128);
+    let (b_hi, b_lo) = (b >> 64, b as u64 as u128);
+    // a_hi and b_hi are under 2^32, so no partial product overflows
+    let high = (a_hi * b_hi) << 64;
+    let middle = a_hi * b_lo + a_lo * b_hi;
+    let low = (a_lo * b_lo) >> 64;
+    high.saturating_add(middle).saturating_add(low)
+}
+
+impl PriorityFeeStats {
+    // Tier names are configuration and survive a reset
+    pub fn reset(&mut self) {
+        self.tiers.clear();
+        self.tiers.resize(MAX_TIERS as usize, 0);
+        self.tier_successes.clear();
+        self.tier_successes.resize(MAX_TIERS as usize, 0);
+        self.direct_update_counts.clear();
+        self.direct_update_counts.resize(MAX_TIERS as usize, 0);
+        self.tier_avg_latency_slots.clear();
+        self.tier_avg_latency_slots.resize(MAX_TIERS as usize, 0);
+        self.tier_latency_counts.clear();
+        self.tier_latency_counts.resize(MAX_TIERS as usize, 0);
+        self.fee_sample_count = 0;
+        self.fee_mean_fp = 0;
+        self.fee_m2 = 0;
+    }
+
+    // See TransactionRegistry::upgrade
+    pub fn upgrade(&mut self) {
+        let tiers = MAX_TIERS as usize;
+        for counts in [&mut self.tiers, &mut self.tier_successes, &mut self.direct_update_counts] {
+            if counts.len() < tiers {
+                counts.resize(tiers, 0);
+            }
+        }
+        if self.tier_latency_counts.len() < tiers {
+            self.tier_avg_latency_slots.resize(tiers, 0);
+            self.tier_latency_counts.resize(tiers, 0);
+        }
+    }
+
+    // Welford's update. The mean moves by a truncated fraction of the deviation, off by
+    // under 2^-32 lamports per sample; m2 only saturates for fees near u64::MAX
+    pub fn record_fee(&mut self, fee_lamports: u64) {
+        let count = self.fee_sample_count.saturating_add(1);
+        let fee = fee_lamports as u128 * FEE_MEAN_SCALE;
+        let mean = self.fee_mean_fp;
+        let new_mean = if fee >= mean {
+            mean + (fee - mean) / count as u128
+        } else {
+            mean - (mean - fee) / count as u128
+        };
+        // The new mean lies between the old one and the fee, so both deviations share a sign
+        let deviation = unscale_product(fee.abs_diff(mean), fee.abs_diff(new_mean));
+        self.fee_m2 = self.fee_m2.saturating_add(deviation);
+        self.fee_mean_fp = new_mean;
+        self.fee_sample_count = count;
+    }
+
+    pub fn fee_mean_lamports(&self) -> u64 {
+        ((self.fee_mean_fp + FEE_MEAN_SCALE / 2) / FEE_MEAN_SCALE) as u64
+    }
+
+    // Sample variance in lamports^2, 0 below two samples
+    pub fn fee_variance(&self) -> u128 {
+        if self.fee_sample_count < 2 {
+            return 0;
+        }
+        self.fee_m2 / (self.fee_sample_count - 1) as u128
+    }
+
+    // Running mean, rounded to the nearest slot; the rounding never drifts past half a slot
+    pub fn record_latency(&mut self, tier: u8, latency: u64) {
+        let tier = tier as usize;
+        let count = self.tier_latency_counts[tier].saturating_add(1) as u64;
+        let total = self.tier_avg_latency_slots[tier] as u64 * (count - 1) + latency;
+        self.tier_avg_latency_slots[tier] = ((total + count / 2) / count) as u16;
+        self.tier_latency_counts[tier] = count as u32;
+    }
+
+    // Cheapest tier with latency data whose mean fits the deadline, else the one with the
+    // lowest mean (the cheaper on a tie). None until some tier has latency data
+    pub fn recommend_for_deadline(&self, latency_stats: &LatencyStats, deadline_slots: u64) -> Option<DeadlineRecommendation> {
+        let measured = || (0..MAX_TIERS).filter(|&tier| {
+            self.tier_latency_counts.get(tier as usize).is_some_and(|count| *count > 0)
+        });
+        let mean = |tier: u8| self.tier_avg_latency_slots[tier as usize] as u64;
+        let tier = measured()
+            .find(|&tier| mean(tier) <= deadline_slots)
+            .or_else(|| measured().min_by_key(|&tier| mean(tier)))?;
+        Some(DeadlineRecommendation {
+            tier,
+            confidence_bps: latency_stats.within_deadline_bps(tier, deadline_slots),
+        })
+    }
+
+    // Lamports spent on tiers whose success rate a cheaper tier already matched:
+    // sum over tiers of (price - cheapest matching price) * transactions at that tier
+    pub fn wasted_fee_lamports(&self, tier_lamports: &[u64; MAX_TIERS as usize]) -> u64 {
+        let mut wasted = 0u64;
+        for tier in 1..MAX_TIERS as usize {
+            let Some(rate) = self.tier_success_rate_bps(tier) else { continue };
+            let cheapest = (0..tier).find(|&cheaper| {
+                self.tier_success_rate_bps(cheaper).is_some_and(|r| r >= rate)
+            });
+            if let Some(cheaper) = cheapest {
+                let overpay = tier_lamports[tier].saturating_sub(tier_lamports[cheaper]);
+                wasted = wasted.saturating_add(overpay.saturating_mul(self.tiers[tier]));
+            }
+        }
+        wasted
+    }
+
+    // Tier minimums that split past usage into MAX_TIERS equal shares. Each tier's reports are taken to be
+    // spread evenly between its current minimum and the next tier's (lamport_max for the
+    // top tier), clamped to [lamport_min, lamport_max]; tier 0 starts at lamport_min.
+    // None while no tier has been used
+    pub fn calibrated_boundaries(
+        &self,
+        current: &[u64; MAX_TIERS as usize],
+        lamport_min: u64,
+        lamport_max: u64,
+    ) -> Option<[u64; MAX_TIERS as usize]> {
+        let tiers = MAX_TIERS as u128;
+        let counts: [u128; MAX_TIERS as usize] = std::array::from_fn(|tier| self.tiers.get(tier).copied().unwrap_or(0) as u128);
+        let total: u128 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let edge = |tier: usize| current.get(tier).copied().unwrap_or(lamport_max).clamp(lamport_min, lamport_max) as u128;
+
+        let mut boundaries = [lamport_min; MAX_TIERS as usize];
+        for (cut, boundary) in boundaries.iter_mut().enumerate().skip(1) {
+            // Scaled by the tier count so the cut points stay exact: usage below the
+            // boundary = cut / tiers of total
+            let target = total * cut as u128;
+            let mut below = 0u128;
+            for (tier, count) in counts.iter().enumerate() {
+                if *count > 0 && (below + count) * tiers >= target {
+                    let (low, high) = (edge(tier), edge(tier + 1));
+                    *boundary = (low + (high - low) * (target - below * tiers) / (count * tiers)) as u64;
+                    break;
+                }
+                below += count;
+            }
+        }
+        Some(boundaries)
+    }
+
+    // One entry per tier above 0 where both it and the tier below have outcomes
+    pub fn fee_roi(&self, tier_lamports: &[u64; MAX_TIERS as usize]) -> Vec<FeeROI> {
+        (1..MAX_TIERS as usize)
+            .filter_map(|tier| {
+                let gain = self.tier_success_rate_bps(tier)? as i32 - self.tier_success_rate_bps(tier - 1)? as i32;
+                let cost = tier_lamports[tier].saturating_sub(tier_lamports[tier - 1]);
+                Some(FeeROI {
+                    tier: tier as u8,
+                    marginal_success_gain_bps: gain as i16,
+                    marginal_lamport_cost: cost,
+                    gain_bps_per_million_lamports: match cost {
+                        0 => 0,
+                        cost => (gain as i128 * 1_000_000 / cost as i128) as i64,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    // Success rate in bps for a tier, None while it has no outcomes
+    pub fn tier_success_rate_bps(&self, tier: usize) -> Option<u16> {
+        let total = *self.tiers.get(tier)?;
+        let successes = self.tier_successes.get(tier).copied().unwrap_or(0);
+        if total == 0 {
+            return None;
+        }
+        Some((successes.min(total) as u128 * 10_000 / total as u128) as u16)
+    }
+}
+
+// Rolling 24-hour statistics; bucket = hour % 24, seeds [b"hourly", namespace]
+#[account]
+#[derive(InitSpace)]
+pub struct HourlyStats {
+    #[max_len(24)]
+    pub buckets: Vec<HourBucket>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct HourBucket {
+    pub hour: u64, // Absolute hour (unix_timestamp / 3600) the counts belong to
+    pub success_count: u32,
+    pub failure_count: u32,
+    pub failures_by_type: [u16; FAILURE_KINDS], // Indexed like FailureCatalog::counts_by_type
+    pub dominant_failure_type: u8,
+}
+
+// Bucket entry returned by get_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct HourlyBucketStats {
+    pub hour: u64,
+    pub success_count: u32,
+    pub failure_count: u32,
+    pub dominant_failure_type: u8,
+}
+
+// Return value of get_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StatsReport {
+    pub hours: Vec<HourlyBucketStats>, // Oldest first, see HourlyStats::last_24_hours
+    pub failure_gap_histogram: [u32; 5], // Bursty failures pile up in the first buckets
+    pub success_compute: ComputeTally,
+    pub failure_compute: ComputeTally,
+    pub near_cu_limit_count: u64, // Reports that used at least 95% of their CU limit
+    pub tx_size_buckets: [OutcomeBucket; 4], // See TX_SIZE_BOUNDS
+    pub instruction_buckets: [OutcomeBucket; 5], // See INSTRUCTION_COUNT_BOUNDS
+    pub max_instructions: u8,
+    pub signer_buckets: [OutcomeBucket; 3], // 1, 2 and 3+ signers
+    pub blockhash_age_buckets: [OutcomeBucket; 5], // See BLOCKHASH_AGE_BOUNDS
+    pub stale_blockhash_failures: u32, // Failures sent with a blockhash over STALE_BLOCKHASH_AGE_SLOTS old
+    pub simulated_successes: u32, // Metadata reports by TxMetadata::was_simulated x success
+    pub simulated_failures: u32,
+    pub unsimulated_successes: u32,
+    pub unsimulated_failures: u32,
+    pub send_method_buckets: [OutcomeBucket; 4], // RPC, Jito bundle, other relay, unknown
+    pub send_method_dominant_failures: [u8; 4], // Most frequent failure_type per send method, 0 if none
+    pub route_hop_buckets: [OutcomeBucket; 4], // Swaps with 1, 2, 3 and 4+ route hops
+    pub route_slippage_failures: [u32; 4], // Slippage failures (type 0) among those
+    pub fee_sample_count: u64, // Successful reports with a declared fee_lamports
+    pub fee_mean_lamports: u64, // Their mean fee, rounded
+    pub fee_variance: u128, // and its sample variance in lamports^2; 0 below two samples
+}
+
+// Outcome bucket entry returned by get_stats (size, instruction count, signers, blockhash age)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct OutcomeBucket {
+    pub success_count: u32,
+    pub failure_count: u32,
+    pub success_rate_bps: u16, // 0 for an empty bucket
+}
+
+impl OutcomeBucket {
+    pub fn new(success_count: u32, failure_count: u32) -> Self {
+        let total = success_count as u64 + failure_count as u64;
+        Self {
+            success_count,
+            failure_count,
+            success_rate_bps: if total == 0 { 0 } else { (success_count as u64 * 10_000 / total) as u16 },
+        }
+    }
+}
+
+pub fn current_hour(unix_timestamp: i64) -> u64 {
+    unix_timestamp.max(0) as u64 / 3600
+}
+
+impl HourlyStats {
+    pub fn reset(&mut self) {
+        self.buckets.clear();
+        self.buckets.resize(24, HourBucket::default());
+    }
+
+    pub fn record(&mut self, hour: u64, success: bool, failure_type: u8) {
+        // A bucket last written 24+ hours ago is stale; start it over
+        let bucket = &mut self.buckets[(hour % 24) as usize];
+        if bucket.hour != hour {
+            *bucket = HourBucket { hour, ..Default::default() };
+        }
+
+        if success {
+            bucket.success_count = bucket.success_count.saturating_add(1);
+            return;
+        }
+        bucket.failure_count = bucket.failure_count.saturating_add(1);
+        let index = failure_index(failure_type);
+        bucket.failures_by_type[index] = bucket.failures_by_type[index].saturating_add(1);
+        let dominant = failure_index(bucket.dominant_failure_type);
+        if bucket.failures_by_type[index] > bucket.failures_by_type[dominant] {
+            bucket.dominant_failure_type = FAILURE_CODES[index];
+        }
+    }
+
+    // Oldest first, ending at `hour`; hours with no reports come back zeroed
+    pub fn last_24_hours(&self, hour: u64) -> Vec<HourlyBucketStats> {
+        (0..24u64)
+            .rev()
+            .filter_map(|ago| hour.checked_sub(ago))
+            .map(|h| match self.buckets.get((h % 24) as usize) {
+                Some(bucket) if bucket.hour == h => HourlyBucketStats {
+                    hour: h,
+                    success_count: bucket.success_count,
+                    failure_count: bucket.failure_count,
+                    dominant_failure_type: bucket.dominant_failure_type,
+                },
+                _ => HourlyBucketStats {
+                    hour: h,
+                    success_count: 0,
+                    failure_count: 0,
+                    dominant_failure_type: 0,
+                },
+            })
+            .collect()
+    }
+}
+
+// 30-day ring of daily aggregates; slot = day % 30, seeds [b"daily", namespace]
+#[account]
+#[derive(InitSpace)]
+pub struct DailyStats {
+    #[max_len(30)]
+    pub days: Vec<DayBucket>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct DayBucket {
+    pub day_index: u64, // unix_timestamp / 86400
+    pub tx_count: u32,
+    pub success_count: u32,
+    pub failure_count: u32,
+    pub failures_by_type: [u16; FAILURE_KINDS], // Indexed like FailureCatalog::counts_by_type
+    pub top_failure_type: u8,
+    pub tier_sum: u64,
+    pub avg_tier: u16, // Mean priority_fee_tier × 100
+}
+
+pub fn current_day(unix_timestamp: i64) -> u64 {
+    unix_timestamp.max(0) as u64 / 86_400
+}
+
+impl DailyStats {
+    pub fn reset(&mut self) {
+        self.days.clear();
+        self.days.resize(30, DayBucket::default());
+    }
+
+    // Move the ring onto `day`, overwriting whatever that slot held 30+ days ago
+    pub fn advance(&mut self, day: u64) -> &mut DayBucket {
+        let bucket = &mut self.days[(day % 30) as usize];
+        if bucket.day_index != day {
+            *bucket = DayBucket { day_index: day, ..Default::default() };
+        }
+        bucket
+    }
+
+    pub fn record(&mut self, day: u64, params: &TxOutcomeParams) {
+        let bucket = self.advance(day);
+        bucket.tx_count = bucket.tx_count.saturating_add(1);
+        bucket.tier_sum = bucket.tier_sum.saturating_add(params.priority_fee_tier as u64);
+        bucket.avg_tier = (bucket.tier_sum * 100 / bucket.tx_count as u64) as u16;
+
+        // Partial fills count towards tx_count only, as in the registry
+        if params.partial_fill {
+            return;
+        }
+        if params.success {
+            bucket.success_count = bucket.success_count.saturating_add(1);
+            return;
+        }
+        bucket.failure_count = bucket.failure_count.saturating_add(1);
+        let index = failure_index(params.failure_type.into());
+        bucket.failures_by_type[index] = bucket.failures_by_type[index].saturating_add(1);
+        let top = failure_index(bucket.top_failure_type);
+        if bucket.failures_by_type[index] > bucket.failures_by_type[top] {
+            bucket.top_failure_type = FAILURE_CODES[index];
+        }
+    }
+}
+
+// All-time outcomes by UTC hour of day, seeds [b"seasonality", namespace]. Unlike
+// HourlyStats nothing rolls off; only reset_seasonality clears it
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonalityStats {
+    pub successes_by_hour: [u32; 24],
+    pub failures_by_hour: [u32; 24],
+}
+
+impl SeasonalityStats {
+    pub fn reset(&mut self) {
+        self.successes_by_hour = [0; 24];
+        self.failures_by_hour = [0; 24];
+    }
+
+    pub fn record(&mut self, unix_timestamp: i64, success: bool) {
+        let hour = (unix_timestamp / 3600).rem_euclid(24) as usize;
+        let counter = if success {
+            &mut self.successes_by_hour[hour]
+        } else {
+            &mut self.failures_by_hour[hour]
+        };
+        *counter = counter.saturating_add(1);
+    }
+}
+
+// Compute units consumed, seeds [b"compute", namespace]. Fed only by
+// register_tx_outcome_with_metadata, and only for reports whose metadata carries them
+#[account]
+#[derive(InitSpace)]
+pub struct ComputeStats {
+    pub success_compute: ComputeTally,
+    pub failure_compute: ComputeTally,
+    pub near_cu_limit_count: u64, // consumed >= NEAR_CU_LIMIT_PCT% of a known cu_limit
+}
+
+// min/max are only meaningful once count > 0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct ComputeTally {
+    pub count: u64,
+    pub sum: u64,
+    pub min: u32,
+    pub max: u32,
+}
+
+pub const NEAR_CU_LIMIT_PCT: u64 = 95;
+
+impl ComputeStats {
+    pub fn reset(&mut self) {
+        self.success_compute = ComputeTally::default();
+        self.failure_compute = ComputeTally::default();
+        self.near_cu_limit_count = 0;
+    }
+
+    pub fn record(&mut self, success: bool, metadata: &TxMetadata) {
+        let Some(consumed) = metadata.compute_units() else {
+            return;
+        };
+        let tally = if success {
+            &mut self.success_compute
+        } else {
+            &mut self.failure_compute
+        };
+        tally.record(consumed);
+
+        if let Some(cu_limit) = metadata.compute_unit_limit().filter(|&limit| limit > 0) {
+            if consumed as u64 * 100 >= cu_limit as u64 * NEAR_CU_LIMIT_PCT {
+                self.near_cu_limit_count = self.near_cu_limit_count.saturating_add(1);
+            }
+        }
+    }
+}
+
+impl ComputeTally {
+    pub fn record(&mut self, consumed: u32) {
+        if self.count == 0 {
+            self.min = consumed;
+            self.max = consumed;
+        } else {
+            self.min = self.min.min(consumed);
+            self.max = self.max.max(consumed);
+        }
+        self.count = self.count.saturating_add(1);
+        self.sum = self.sum.saturating_add(consumed as u64);
+    }
+}
+
+// Outcomes by serialized transaction size, seeds [b"tx_size", namespace]. Fed only by
+// register_tx_outcome_with_metadata, for reports whose metadata carries a size
+#[account]
+#[derive(InitSpace)]
+pub struct TxSizeStats {
+    pub successes_by_size: [u32; 4], // See TX_SIZE_BOUNDS
+    pub failures_by_size: [u32; 4],
+}
+
+// Bucket upper bounds (exclusive) in bytes: <400, 400-699, 700-999, 1000-1232
+pub const TX_SIZE_BOUNDS: [u16; 3] = [400, 700, 1_000];
+// Largest transaction the network accepts (PACKET_DATA_SIZE)
+pub const MAX_TX_SIZE_BYTES: u16 = 1_232;
+
+impl TxSizeStats {
+    pub fn reset(&mut self) {
+        self.successes_by_size = [0; 4];
+        self.failures_by_size = [0; 4];
+    }
+
+    pub fn record(&mut self, size: u16, success: bool) {
+        let bucket = TX_SIZE_BOUNDS
+            .iter()
+            .position(|bound| size < *bound)
+            .unwrap_or(TX_SIZE_BOUNDS.len());
+        let counter = if success {
+            &mut self.successes_by_size[bucket]
+        } else {
+            &mut self.failures_by_size[bucket]
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    pub fn buckets(&self) -> [OutcomeBucket; 4] {
+        std::array::from_fn(|bucket| OutcomeBucket::new(self.successes_by_size[bucket], self.failures_by_size[bucket]))
+    }
+}
+
+// Outcomes by instruction count, seeds [b"instructions", namespace]. Fed only by
+// register_tx_outcome_with_metadata, for reports whose metadata carries a count
+#[account]
+#[derive(InitSpace)]
+pub struct InstructionStats {
+    pub successes_by_count: [u32; 5], // See INSTRUCTION_COUNT_BOUNDS
+    pub failures_by_count: [u32; 5],
+    pub max_instructions: u8,
+}
+
+// Bucket upper bounds (exclusive): 1, 2-3, 4-6, 7-12, 13+ instructions
+pub const INSTRUCTION_COUNT_BOUNDS: [u8; 4] = [2, 4, 7, 13];
+
+impl InstructionStats {
+    pub fn reset(&mut self) {
+        self.successes_by_count = [0; 5];
+        self.failures_by_count = [0; 5];
+        self.max_instructions = 0;
+    }
+
+    pub fn record(&mut self, num_instructions: u8, success: bool) {
+        let bucket = INSTRUCTION_COUNT_BOUNDS
+            .iter()
+            .position(|bound| num_instructions < *bound)
+            .unwrap_or(INSTRUCTION_COUNT_BOUNDS.len());
+        let counter = if success {
+            &mut self.successes_by_count[bucket]
+        } else {
+            &mut self.failures_by_count[bucket]
+        };
+        *counter = counter.saturating_add(1);
+        self.max_instructions = self.max_instructions.max(num_instructions);
+    }
+
+    pub fn buckets(&self) -> [OutcomeBucket; 5] {
+        std::array::from_fn(|bucket| OutcomeBucket::new(self.successes_by_count[bucket], self.failures_by_count[bucket]))
+    }
+}
+
+// Outcomes by blockhash age at submission, seeds [b"blockhash_age", namespace]. Fed only by
+// register_tx_outcome_with_metadata, for reports whose metadata carries an age
+#[account]
+#[derive(InitSpace)]
+pub struct BlockhashAgeStats {
+    pub successes_by_age: [u32; 5], // See BLOCKHASH_AGE_BOUNDS
+    pub failures_by_age: [u32; 5],
+    pub stale_failures: u32, // Failures whose blockhash was over STALE_BLOCKHASH_AGE_SLOTS old
+}
+
+// Bucket upper bounds (exclusive): 0-10, 11-30, 31-60, 61-90, 91+ slots
+pub const BLOCKHASH_AGE_BOUNDS: [u8; 4] = [11, 31, 61, 91];
+// Close enough to the ~150 slot validity window that the tx likely expired in flight
+pub const STALE_BLOCKHASH_AGE_SLOTS: u8 = 120;
+
+impl BlockhashAgeStats {
+    pub fn reset(&mut self) {
+        self.successes_by_age = [0; 5];
+        self.failures_by_age = [0; 5];
+        self.stale_failures = 0;
+    }
+
+    pub fn record(&mut self, age_slots: u8, success: bool) {
+        let bucket = BLOCKHASH_AGE_BOUNDS
+            .iter()
+            .position(|bound| age_slots < *bound)
+            .unwrap_or(BLOCKHASH_AGE_BOUNDS.len());
+        let counter = if success {
+            &mut self.successes_by_age[bucket]
+        } else {
+            &mut self.failures_by_age[bucket]
+        };
+        *counter = counter.saturating_add(1);
+        if !success && age_slots > STALE_BLOCKHASH_AGE_SLOTS {
+            self.stale_failures = self.stale_failures.saturating_add(1);
+        }
+    }
+
+    pub fn buckets(&self) -> [OutcomeBucket; 5] {
+        std::array::from_fn(|bucket| OutcomeBucket::new(self.successes_by_age[bucket], self.failures_by_age[bucket]))
+    }
+}
+
+// Swap outcomes by aggregator route length, seeds [b"routes", namespace]. Fed only by
+// register_tx_outcome_with_metadata, for reports whose metadata declares a swap
+#[account]
+#[derive(InitSpace)]
+pub struct RouteStats {
+    pub successes_by_hops: [u32; 4], // 1, 2, 3 and 4+ hops
+    pub failures_by_hops: [u32; 4],
+    pub slippage_failures_by_hops: [u32; 4], // The share of failures_by_hops that were slippage
+}
+
+impl RouteStats {
+    pub fn reset(&mut self) {
+        self.successes_by_hops = [0; 4];
+        self.failures_by_hops = [0; 4];
+        self.slippage_failures_by_hops = [0; 4];
+    }
+
+    pub fn record(&mut self, hops: u8, success: bool, failure_type: u8) {
+        let bucket = (hops.clamp(1, 4) - 1) as usize;
+        if success {
+            self.successes_by_hops[bucket] = self.successes_by_hops[bucket].saturating_add(1);
+            return;
+        }
+        self.failures_by_hops[bucket] = self.failures_by_hops[bucket].saturating_add(1);
+        if failure_type == 0 {
+            self.slippage_failures_by_hops[bucket] = self.slippage_failures_by_hops[bucket].saturating_add(1);
+        }
+    }
+
+    pub fn buckets(&self) -> [OutcomeBucket; 4] {
+        std::array::from_fn(|bucket| OutcomeBucket::new(self.successes_by_hops[bucket], self.failures_by_hops[bucket]))
+    }
+}
+
+// Outcomes per TxOutcomeParams::tags bit, seeds [b"tags", namespace]. The program gives
+// the bits no meaning; a report with several bits set counts towards each of them
+#[account]
+#[derive(InitSpace)]
+pub struct TagStats {
+    pub successes_by_tag: [u64; 16], // Index = bit
+    pub failures_by_tag: [u64; 16],
+}
+
+impl TagStats {
+    pub fn reset(&mut self) {
+        self.successes_by_tag = [0; 16];
+        self.failures_by_tag = [0; 16];
+    }
+
+    pub fn record(&mut self, tags: u16, success: bool) {
+        let counters = if success { &mut self.successes_by_tag } else { &mut self.failures_by_tag };
+        for (bit, counter) in counters.iter_mut().enumerate() {
+            if tags & (1 << bit) != 0 {
+                *counter = counter.saturating_add(1);
+            }
+        }
+    }
+
+    // Per-bit success rate in bps; 0 for bits never reported
+    pub fn success_rates_bps(&self) -> [u16; 16] {
+        std::array::from_fn(|bit| {
+            let (successes, failures) = (self.successes_by_tag[bit] as u128, self.failures_by_tag[bit] as u128);
+            match successes + failures {
+                0 => 0,
+                total => (successes * 10_000 / total) as u16,
+            }
+        })
+    }
+}
+
+// Submission-to-confirmation latency, seeds [b"latency", namespace]. Only reports that
+// declare both submitted_slot and confirmed_slot count
+#[account]
+#[derive(InitSpace)]
+pub struct LatencyStats {
+    pub histogram: [u32; 6], // See LATENCY_BOUNDS
+    pub histogram_by_tier: [[u32; 6]; MAX_TIERS as usize], // Same buckets per priority_fee_tier
+    pub latency_sum: u64,
+    pub latency_count: u64, // latency_sum / latency_count is the mean
+}
+
+// Bucket upper bounds (exclusive) in slots: 0-1, 2-4, 5-8, 9-16, 17-32, 33+
+pub const LATENCY_BOUNDS: [u64; 5] = [2, 5, 9, 17, 33];
+// Anything slower is a reporting bug, not a slow transaction
+pub const MAX_CONFIRMATION_LATENCY_SLOTS: u64 = 10_000;
+
+impl LatencyStats {
+    pub fn reset(&mut self) {
+        self.histogram = [0; 6];
+        self.histogram_by_tier = [[0; 6]; MAX_TIERS as usize];
+        self.latency_sum = 0;
+        self.latency_count = 0;
+    }
+
+    pub fn record(&mut self, latency: u64, priority_fee_tier: u8) {
+        let bucket = LATENCY_BOUNDS
+            .iter()
+            .position(|bound| latency < *bound)
+            .unwrap_or(LATENCY_BOUNDS.len());
+        self.histogram[bucket] = self.histogram[bucket].saturating_add(1);
+        let tier_bucket = &mut self.histogram_by_tier[priority_fee_tier as usize][bucket];
+        *tier_bucket = tier_bucket.saturating_add(1);
+        self.latency_sum = self.latency_sum.saturating_add(latency);
+        self.latency_count = self.latency_count.saturating_add(1);
+    }
+
+    // Share of the tier's latencies known to fit the deadline. A bucket only counts once
+    // its whole range fits, so the estimate errs low; 0 while the tier has none
+    pub fn within_deadline_bps(&self, tier: u8, deadline_slots: u64) -> u16 {
+        let buckets = &self.histogram_by_tier[tier as usize];
+        let total: u64 = buckets.iter().map(|count| *count as u64).sum();
+        if total == 0 {
+            return 0;
+        }
+        // Nothing slower is ever recorded
+        if deadline_slots >= MAX_CONFIRMATION_LATENCY_SLOTS {
+            return 10_000;
+        }
+        let within: u64 = LATENCY_BOUNDS.iter()
+            .zip(buckets)
+            .take_while(|(bound, _)| **bound <= deadline_slots + 1)
+            .map(|(_, count)| *count as u64)
+            .sum();
+        (within * 10_000 / total) as u16
+    }
+}
+
+// Tier → minimum lamport mapping, set by the authority
+#[account]
+#[derive(InitSpace)]
+pub struct TierLamportConfig {
+    #[max_len(MAX_TIERS)]
+    pub tier_min_lamports: Vec<u64>,
+}
+
+// Entry returned by compute_fee_roi, comparing a tier with the one below it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FeeROI {
+    pub tier: u8,
+    pub marginal_success_gain_bps: i16, // Negative when the pricier tier did worse
+    pub marginal_lamport_cost: u64,
+    pub gain_bps_per_million_lamports: i64, // The ROI ratio; 0 when the tiers cost the same
+}
+
+// Tier entry returned by get_tier_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TierInfo {
+    pub tier: u8,
+    pub name: [u8; 16],
+    pub count: u64,
+}
+
+// Return value of get_summary: the common dashboard view in a single call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegistrySummary {
+    pub tx_count: u64,
+    pub success_count: u64,
+    pub failure_count: u64,
+    pub cursor: u8,
+    pub success_rate_bps: u16,
+    pub dominant_failure_type: u8, // 0 with a count of 0 when nothing has failed yet
+    pub dominant_failure_count: u32,
+    pub optimal_tier: u8, // 0 when no tier has activity yet
+    pub total_tier_activity: u64,
+    pub unique_caller_count: u32, // See TransactionRegistry::record_caller
+}
+
+impl RegistrySummary {
+    pub fn from_accounts(
+        registry: &TransactionRegistry,
+        catalog: &FailureCatalog,
+        stats: &PriorityFeeStats,
+    ) -> Self {
+        let success_rate_bps = registry.success_rate_bps();
+        let (dominant_failure_type, dominant_failure_count) = catalog.dominant_failure();
+
+        // Tier with the best historical success rate; ties go to the cheaper tier
+        let optimal_tier = (0..stats.tiers.len())
+            .filter_map(|tier| stats.tier_success_rate_bps(tier).map(|rate| (tier, rate)))
+            .fold(None, |best: Option<(usize, u16)>, (tier, rate)| match best {
+                Some((_, best_rate)) if best_rate >= rate => best,
+                _ => Some((tier, rate)),
+            })
+            .map_or(0, |(tier, _)| tier as u8);
+
+        let total_tier_activity = stats.tiers.iter().fold(0u64, |sum, count| sum.saturating_add(*count));
+
+        Self {
+            tx_count: registry.tx_count,
+            success_count: registry.success_count,
+            failure_count: registry.failure_count,
+            cursor: registry.cursor,
+            success_rate_bps,
+            dominant_failure_type,
+            dominant_failure_count,
+            optimal_tier,
+            total_tier_activity,
+            unique_caller_count: registry.unique_caller_count,
+        }
+    }
+}
+
+// Per-Reporter Statistics Account
+#[account]
+#[derive(InitSpace)]
+pub struct ReporterRecord {
+    pub reporter: Pubkey,
+    pub successes_reported: u64,
+    pub failures_reported: u64,
+    pub recent_outcomes: [u8; 32], // 0=failure, 1=success, 2=pending
+    pub cursor: u8,
+    pub last_report_slot: u64,
+    pub bulk_reporter: bool, // Set by the authority; exempt from the report cooldown
+    pub backfill_reporter: bool, // Set by the authority; exempt from max_report_age_slots
+    pub points: u64, // Reward points earned and not yet redeemed
+    pub points_slot: u64, // Slot points_this_slot was earned in
+    pub points_this_slot: u64,
+    pub last_claim_slot: u64, // 0 = never claimed
+    pub reputation: u16, // 0-MAX_REPUTATION, as of reputation_slot; see current_reputation
+    pub reputation_slot: u64,
+    pub flagged_outcomes: u32, // Bit per recent_outcomes entry already flagged by the authority
+}
+
+pub const MAX_REPUTATION: u16 = 10_000;
+// New reporters start here, and idle ones drift back to it
+pub const REPUTATION_BASELINE: u16 = 5_000;
+pub const DEFAULT_REPUTATION_INCREMENT: u16 = 10;
+pub const DEFAULT_REPUTATION_PENALTY: u16 = 500;
+pub const DEFAULT_REPUTATION_HALF_LIFE_SLOTS: u64 = 216_000; // About a day
+
+impl ReporterRecord {
+    pub fn check_cooldown(&self, slot: u64, min_slots: u64) -> Result<()> {
+        // A record that has never reported has nothing to cool down from
+        if min_slots == 0 || self.reporter == Pubkey::default() {
+            return Ok(());
+        }
+        require!(
+            slot.saturating_sub(self.last_report_slot) >= min_slots,
+            TxGuardError::RateLimited
+        );
+        Ok(())
+    }
+
+    pub fn record(&mut self, reporter: Pubkey, success: bool, slot: u64) -> Result<()> {
+        // Freshly created records are zeroed; mark the ring as pending first
+        if self.reporter == Pubkey::default() {
+            self.reporter = reporter;
+            self.recent_outcomes = [2; 32];
+            self.reputation = REPUTATION_BASELINE;
+            self.reputation_slot = slot;
+        }
+
+        if success {
+            self.successes_reported = self.successes_reported.checked_add(1)
+                .ok_or(TxGuardError::CountOverflow)?;
+        } else {
+            self.failures_reported = self.failures_reported.checked_add(1)
+                .ok_or(TxGuardError::CountOverflow)?;
+        }
+
+        self.recent_outcomes[self.cursor as usize] = if success { 1 } else { 0 };
+        self.flagged_outcomes &= !(1 << self.cursor);
+        self.cursor = (self.cursor + 1) % 32;
+        self.last_report_slot = slot;
+        Ok(())
+    }
+
+    // Credits `base * multiplier` points, clipped so one slot never earns more than
+    // max_per_slot; a loop of reports in a single slot stops paying once it hits the cap.
+    // Returns the points actually credited.
+    pub fn award_points(&mut self, base: u64, multiplier: u16, max_per_slot: u64, slot: u64) -> Result<u64> {
+        if self.points_slot != slot {
+            self.points_slot = slot;
+            self.points_this_slot = 0;
+        }
+
+        let earned = base.checked_mul(multiplier as u64).ok_or(TxGuardError::CountOverflow)?;
+        let awarded = if max_per_slot == 0 {
+            earned
+        } else {
+            earned.min(max_per_slot.saturating_sub(self.points_this_slot))
+        };
+        self.points_this_slot = self.points_this_slot.checked_add(awarded)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.points = self.points.checked_add(awarded)
+            .ok_or(TxGuardError::CountOverflow)?;
+        Ok(awarded)
+    }
+
+    // Reputation decayed from reputation_slot to `slot`: the distance to REPUTATION_BASELINE
+    // halves every half_life_slots, interpolating linearly within a half-life
+    pub fn current_reputation(&self, slot: u64, half_life_slots: u64) -> u16 {
+        let elapsed = slot.saturating_sub(self.reputation_slot);
+        if half_life_slots == 0 || elapsed == 0 {
+            return self.reputation;
+        }
+        let halvings = elapsed / half_life_slots;
+        if halvings >= 16 {
+            return REPUTATION_BASELINE;
+        }
+        let distance = (self.reputation as i64 - REPUTATION_BASELINE as i64) >> halvings;
+        let partial = (elapsed % half_life_slots) as i128;
+        let distance = distance - (distance as i128 * partial / (2 * half_life_slots as i128)) as i64;
+        (REPUTATION_BASELINE as i64 + distance) as u16
+    }
+
+    pub fn adjust_reputation(&mut self, delta: i32, slot: u64, half_life_slots: u64) {
+        let decayed = self.current_reputation(slot, half_life_slots) as i32;
+        self.reputation = (decayed + delta).clamp(0, MAX_REPUTATION as i32) as u16;
+        self.reputation_slot = slot;
+    }
+
+    pub fn flag(&mut self, window_index: u8) -> Result<()> {
+        // Records created by the authority's whitelisting start zeroed, not pending
+        require!(self.reporter != Pubkey::default(), TxGuardError::InvalidWindowIndex);
+        require!(window_index < 32, TxGuardError::InvalidWindowIndex);
+        require!(self.recent_outcomes[window_index as usize] != 2, TxGuardError::InvalidWindowIndex);
+        let bit = 1 << window_index;
+        require!(self.flagged_outcomes & bit == 0, TxGuardError::ReportAlreadyFlagged);
+        self.flagged_outcomes |= bit;
+        Ok(())
+    }
+
+    pub fn check_claim_interval(&self, slot: u64, interval_slots: u64) -> Result<()> {
+        if self.last_claim_slot == 0 {
+            return Ok(());
+        }
+        require!(
+            slot.saturating_sub(self.last_claim_slot) >= interval_slots,
+            TxGuardError::ClaimTooEarly
+        );
+        Ok(())
+    }
+
+    // For redemption: points can only be spent once
+    pub fn debit_points(&mut self, amount: u64) -> Result<()> {
+        self.points = self.points.checked_sub(amount)
+            .ok_or(TxGuardError::InsufficientPoints)?;
+        Ok(())
+    }
+}
+
+// SPL Token program ids; the reward CPI is built by hand rather than through anchor-spl
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const TOKEN_MINT_TO: u8 = 7; // Token instruction tag, followed by the u64 amount
+const MINT_LEN: usize = 82;
+
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    ).0
+}
+
+// Mint authority of an initialized SPL Token mint; None for anything else, including
+// mints without an authority
+fn reward_mint_authority(info: &AccountInfo) -> Option<Pubkey> {
+    if info.owner != &TOKEN_PROGRAM_ID {
+        return None;
+    }
+    let data = info.try_borrow_data().ok()?;
+    // Layout: COption<Pubkey> mint_authority, u64 supply, u8 decimals, bool is_initialized, ...
+    if data.len() != MINT_LEN || data[0..4] != [1, 0, 0, 0] || data[45] != 1 {
+        return None;
+    }
+    Pubkey::try_from(&data[4..36]).ok()
+}
+
+// Open challenge against one registry last_100_outcomes entry, seeds
+// [b"dispute", namespace, [window_index], disputer]; closed by resolve_dispute. One per
+// disputer, so an open dispute doesn't stop anyone else contesting the entry
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub disputer: Pubkey,
+    pub window_index: u8,
+    pub claimed_success: bool, // The outcome the disputer says the entry should have
+    pub bond_lamports: u64, // Held in this account on top of its rent
+    pub outcome_slot: u64, // outcome_slots entry when opened, to notice an overwrite
+    pub overwrite_tx_count: u64, // Registry tx_count at which the ring writes the entry again
+    pub opened_slot: u64,
+}
+
+// Per-Program Attribution Account (filled by record_inline_success)
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramStats {
+    pub program_id: Pubkey,
+    pub success_count: u64,
+    pub last_success_slot: u64,
+}
+
+impl ProgramStats {
+    pub fn record_success(&mut self, program_id: Pubkey, slot: u64) -> Result<()> {
+        self.program_id = program_id;
+        self.success_count = self.success_count.checked_add(1)
+            .ok_or(TxGuardError::CountOverflow)?;
+        self.last_success_slot = slot;
+        Ok(())
+    }
+}
+
+// Return value of get_reporter_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReporterStats {
+    pub reporter: Pubkey,
+    pub successes_reported: u64,
+    pub failures_reported: u64,
+    pub success_rate_bps: u16,
+    pub recent_outcomes: [u8; 32],
+    pub cursor: u8,
+    pub last_report_slot: u64,
+    pub points: u64,
+    pub reputation: u16, // Decayed to the current slot
+    pub trusted: bool, // reputation >= GuardConfig::min_reputation
+}
+
+// Instruction Contexts
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct Initialize<'info> {
+    #[account(mut, constraint = namespace.len() <= MAX_NAMESPACE_LEN @ TxGuardError::NamespaceTooLong)]
+    pub payer: Signer<'info>,
+
+    #[account(
+            init_if_needed,
+        payer = payer,
+        space = 8 + TransactionRegistry::INIT_SPACE,
+        seeds = [REGISTRY_SEED, namespace.as_bytes()],
+        bump
+    )]
+    pub registry: Account<'info, TransactionRegistry>,
+    
+    #[account(
+            init_if_needed,
+        payer = payer,
+        space = 8 + FailureCatalog::INIT_SPACE,
+        seeds = [CATALOG_SEED, namespace.as_bytes()],
+        bump
+    )]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+    
+    #[account(
+            init_if_needed,
+        payer = payer,
+        space = 8 + PriorityFeeStats::INIT_SPACE,
+        seeds = [PRIORITY_SEED, namespace.as_bytes()],
+        bump
+    )]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + GuardConfig::INIT_SPACE,
+        seeds = [b"config", namespace.as_bytes()],
+        bump
+    )]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + HourlyStats::INIT_SPACE,
+        seeds = [b"hourly", namespace.as_bytes()],
+        bump
+    )]
+    pub hourly_stats: Account<'info, HourlyStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DailyStats::INIT_SPACE,
+        seeds = [b"daily", namespace.as_bytes()],
+        bump
+    )]
+    pub daily_stats: Account<'info, DailyStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SeasonalityStats::INIT_SPACE,
+        seeds = [b"seasonality", namespace.as_bytes()],
+        bump
+    )]
+    pub seasonality_stats: Account<'info, SeasonalityStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ComputeStats::INIT_SPACE,
+        seeds = [b"compute", namespace.as_bytes()],
+        bump
+    )]
+    pub compute_stats: Account<'info, ComputeStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + LatencyStats::INIT_SPACE,
+        seeds = [b"latency", namespace.as_bytes()],
+        bump
+    )]
+    pub latency_stats: Account<'info, LatencyStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TxSizeStats::INIT_SPACE,
+        seeds = [b"tx_size", namespace.as_bytes()],
+        bump
+    )]
+    pub tx_size_stats: Account<'info, TxSizeStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + InstructionStats::INIT_SPACE,
+        seeds = [b"instructions", namespace.as_bytes()],
+        bump
+    )]
+    pub instruction_stats: Account<'info, InstructionStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BlockhashAgeStats::INIT_SPACE,
+        seeds = [b"blockhash_age", namespace.as_bytes()],
+        bump
+    )]
+    pub blockhash_age_stats: Account<'info, BlockhashAgeStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RouteStats::INIT_SPACE,
+        seeds = [b"routes", namespace.as_bytes()],
+        bump
+    )]
+    pub route_stats: Account<'info, RouteStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TagStats::INIT_SPACE,
+        seeds = [b"tags", namespace.as_bytes()],
+        bump
+    )]
+    pub tag_stats: Account<'info, TagStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// The three namespace accounts stay unchecked so older layouts load; migrate_account
+// checks their discriminators before rewriting them
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct MigrateRegistry<'info> {
+    #[account(mut, constraint = namespace.len() <= MAX_NAMESPACE_LEN @ TxGuardError::NamespaceTooLong)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: TransactionRegistry in any earlier layout
+    #[account(mut, owner = crate::ID, seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: UncheckedAccount<'info>,
+
+    /// CHECK: FailureCatalog in any earlier layout
+    #[account(mut, owner = crate::ID, seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: UncheckedAccount<'info>,
+
+    /// CHECK: PriorityFeeStats in any earlier layout
+    #[account(mut, owner = crate::ID, seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + GuardConfig::INIT_SPACE,
+        seeds = [b"config", namespace.as_bytes()],
+        bump
+    )]
+    pub config: Account<'info, GuardConfig>,
+
+    // Only needed when `config` doesn't exist yet, see migrate_registry
+    #[account(seeds = [crate::ID.as_ref()], bump, seeds::program = bpf_loader_upgradeable::ID)]
+    pub program_data: Option<Account<'info, ProgramData>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RegisterTxOutcome<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut, seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+    
+    #[account(mut, seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+    
+    #[account(mut, seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RegistryConfig::INIT_SPACE,
+        seeds = [b"registry_config", registry.key().as_ref()],
+        bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// CHECK: only used in epoch mode; address and ownership are verified in
+    /// `load_epoch_registry`, which also creates it on first use.
+    #[account(mut)]
+    pub epoch_registry: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ReporterRecord::INIT_SPACE,
+        seeds = [b"reporter", namespace.as_bytes(), payer.key().as_ref()],
+        bump
+    )]
+    pub reporter_record: Account<'info, ReporterRecord>,
+
+    // Only required when the namespace charges a report fee
+    #[account(mut, seeds = [b"treasury", namespace.as_bytes()], bump)]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    // Rolling 24-hour buckets, updated when supplied
+    #[account(mut, seeds = [b"hourly", namespace.as_bytes()], bump)]
+    pub hourly_stats: Option<Account<'info, HourlyStats>>,
+
+    // 30-day ring, updated when supplied
+    #[account(mut, seeds = [b"daily", namespace.as_bytes()], bump)]
+    pub daily_stats: Option<Account<'info, DailyStats>>,
+
+    // All-time hour-of-day histogram, updated when supplied
+    #[account(mut, seeds = [b"seasonality", namespace.as_bytes()], bump)]
+    pub seasonality_stats: Option<Account<'info, SeasonalityStats>>,
+
+    /// CHECK: today's TierDailySnapshot, updated when supplied; address and ownership
+    /// are verified in `record_tier_daily`, which also creates it on first use.
+    #[account(mut)]
+    pub tier_daily: Option<UncheckedAccount<'info>>,
+
+    // Compute unit usage, updated by register_tx_outcome_with_metadata when supplied
+    #[account(mut, seeds = [b"compute", namespace.as_bytes()], bump)]
+    pub compute_stats: Option<Account<'info, ComputeStats>>,
+
+    // Confirmation latency histograms, updated when supplied
+    #[account(mut, seeds = [b"latency", namespace.as_bytes()], bump)]
+    pub latency_stats: Option<Account<'info, LatencyStats>>,
+
+    // Outcomes by transaction size, updated by register_tx_outcome_with_metadata when supplied
+    #[account(mut, seeds = [b"tx_size", namespace.as_bytes()], bump)]
+    pub tx_size_stats: Option<Account<'info, TxSizeStats>>,
+
+    // Outcomes by instruction count, updated by register_tx_outcome_with_metadata when supplied
+    #[account(mut, seeds = [b"instructions", namespace.as_bytes()], bump)]
+    pub instruction_stats: Option<Account<'info, InstructionStats>>,
+
+    // Outcomes by blockhash age, updated by register_tx_outcome_with_metadata when supplied
+    #[account(mut, seeds = [b"blockhash_age", namespace.as_bytes()], bump)]
+    pub blockhash_age_stats: Option<Account<'info, BlockhashAgeStats>>,
+
+    // Swap outcomes by route length, updated by register_tx_outcome_with_metadata when supplied
+    #[account(mut, seeds = [b"routes", namespace.as_bytes()], bump)]
+    pub route_stats: Option<Account<'info, RouteStats>>,
+
+    // Outcomes per tag bit, updated on every reporting path when supplied
+    #[account(mut, seeds = [b"tags", namespace.as_bytes()], bump)]
+    pub tag_stats: Option<Account<'info, TagStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterTxOutcome<'info> {
+    // Returns the receiving registry's tx_count after the increment. `remaining_accounts`
+    // may carry the EpochSnapshot PDA for an epoch that just ended, see snapshot_on_rollover.
+    fn record_outcome(
+        &mut self,
+        program_id: &Pubkey,
+        remaining_accounts: &[AccountInfo<'info>],
+        params: &TxOutcomeParams,
+    ) -> Result<u64> {
+        self.check_cooldown(Clock::get()?.slot)?;
+        self.apply_report(program_id, remaining_accounts, params)
+    }
+
+    // The authority and whitelisted bulk reporters skip the cooldown
+    fn check_cooldown(&self, slot: u64) -> Result<()> {
+        if self.payer.key() != self.config.authority && !self.reporter_record.bulk_reporter {
+            self.reporter_record.check_cooldown(slot, self.config.min_slots_between_reports)?;
+        }
+        Ok(())
+    }
+
+    // record_outcome once the cooldown has been checked, which a batch does only once.
+    //
+    // Reentrancy guard: reporting may CPI into the system program (report fee, epoch
+    // registry creation). Anchor only writes accounts back on exit, so the lock is
+    // flushed to the registry before any CPI; a nested call that reaches a mutating
+    // TxGuard instruction then sees `locked` and fails with ReentrancyDetected. The
+    // flag is cleared on the normal exit path, and a failed call reverts it with the
+    // rest of the transaction.
+    fn apply_report(
+        &mut self,
+        program_id: &Pubkey,
+        remaining_accounts: &[AccountInfo<'info>],
+        params: &TxOutcomeParams,
+    ) -> Result<u64> {
+        self.registry.ensure_unlocked()?;
+        self.registry.locked = true;
+        self.registry.exit(program_id)?;
+
+        let reporter = self.payer.key();
+        let slot = Clock::get()?.slot;
+        // Only whitelisted backfill reporters may submit old transactions
+        if !self.reporter_record.backfill_reporter {
+            self.config.check_report_age(params.tx_slot, slot)?;
+        }
+        self.registry_config.init_defaults(self.config.authority);
+        self.registry_config.check_reporter(&reporter)?;
+        self.registry_config.check_failure_type(params)?;
+        let latency = params.confirmation_latency()?;
+        // The quota and cadence live on the namespace registry so they also hold in epoch mode
+        self.registry.consume_slot_quota(slot, self.config.max_reports_per_slot)?;
+        self.registry.record_report_cadence(slot);
+        self.registry.record_caller(&reporter);
+        self.collect_report_fee()?;
+        // Capture the finished epoch before this report lands in the new one
+        self.snapshot_on_rollover(program_id, remaining_accounts)?;
+
+        let window_len = self.registry_config.buffer_size as usize;
+        let tx_count = if self.config.epoch_scoped {
+            let mut epoch_registry = self.load_epoch_registry(program_id)?;
+            self.registry_config.check_capacity(epoch_registry.tx_count)?;
+            apply_tx_outcome(
+                &mut epoch_registry,
+                &mut self.failure_catalog,
+                &mut self.priority_fee_stats,
+                params,
+            )?;
+            epoch_registry.trim_window(window_len);
+            self.store_epoch_registry(&epoch_registry)?;
+            epoch_registry.tx_count
+        } else {
+            self.registry_config.check_capacity(self.registry.tx_count)?;
+            apply_tx_outcome(
+                &mut self.registry,
+                &mut self.failure_catalog,
+                &mut self.priority_fee_stats,
+                params,
+            )?;
+            self.registry.trim_window(window_len);
+            self.registry.tx_count
+        };
+
+        let now = Clock::get()?.unix_timestamp;
+        // Partial fills count as neither success nor failure, as in the registry
+        if let Some(hourly_stats) = self.hourly_stats.as_mut() {
+            if !params.partial_fill {
+                hourly_stats.record(current_hour(now), params.success, params.failure_type.into());
+            }
+        }
+        if let Some(daily_stats) = self.daily_stats.as_mut() {
+            daily_stats.record(current_day(now), params);
+        }
+        if let Some(seasonality_stats) = self.seasonality_stats.as_mut() {
+            if !params.partial_fill {
+                seasonality_stats.record(now, params.success);
+            }
+        }
+        self.record_tier_daily(program_id, current_day(now) as i64, params.priority_fee_tier)?;
+        if let (Some(latency_stats), Some(latency)) = (self.latency_stats.as_mut(), latency) {
+            latency_stats.record(latency, params.priority_fee_tier);
+            self.priority_fee_stats.record_latency(params.priority_fee_tier, latency);
+        }
+        if let Some(tag_stats) = self.tag_stats.as_mut() {
+            if !params.partial_fill {
+                tag_stats.record(params.tags, params.success);
+            }
+        }
+
+        // Attribute the report to the signing reporter
+        self.reporter_record.record(reporter, params.success, slot)?;
+        self.reporter_record.adjust_reputation(
+            self.config.reputation_increment as i32,
+            slot,
+            self.config.reputation_half_life_slots,
+        );
+        self.reward_reporter(reporter, params.success, slot)?;
+
+        self.registry.locked = false;
+        Ok(tx_count)
+    }
+
+    fn reward_reporter(&mut self, reporter: Pubkey, success: bool, slot: u64) -> Result<()> {
+        let base = if success { self.config.success_report_points } else { self.config.failure_report_points };
+        let awarded = self.reporter_record.award_points(
+            base,
+            self.config.points_multiplier,
+            self.config.max_points_per_slot,
+            slot,
+        )?;
+        if awarded > 0 {
+            emit!(ReporterRewarded {
+                namespace: self.config.namespace.clone(),
+                reporter,
+                points_awarded: awarded,
+                total_points: self.reporter_record.points,
+            });
+        }
+        Ok(())
+    }
+
+    // Free namespaces never touch the treasury, so it can be left out of the accounts
+    fn collect_report_fee(&mut self) -> Result<()> {
+        let fee = self.config.report_fee_lamports;
+        if fee == 0 {
+            return Ok(());
+        }
+        let treasury = self.treasury.as_mut().ok_or(TxGuardError::MissingTreasury)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.payer.to_account_info(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+        treasury.total_fees_collected = treasury.total_fees_collected.checked_add(fee)
+            .ok_or(TxGuardError::CountOverflow)?;
+        Ok(())
+    }
+
+    // On the first report of a new epoch, write the counters as they stood at the end of
+    // `last_seen_epoch` into that epoch's EpochSnapshot PDA, creating it on first use. If
+    // the caller didn't pass the PDA the report still goes through and SnapshotMissed
+    // carries the same counters so a crank can backfill from logs.
+    fn snapshot_on_rollover(&mut self, program_id: &Pubkey, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let current_epoch = Clock::get()?.epoch;
+        let finished_epoch = self.registry.last_seen_epoch;
+        if current_epoch <= finished_epoch {
+            return Ok(());
+        }
+        self.registry.last_seen_epoch = current_epoch;
+
+        let snapshot = EpochSnapshot::capture(finished_epoch, &self.registry, &self.failure_catalog)?;
+        let namespace = self.config.namespace.as_bytes();
+        let epoch = finished_epoch.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(&[b"snapshot", namespace, &epoch], program_id);
+        let Some(info) = remaining_accounts.iter().find(|info| info.key() == expected) else {
+            emit!(SnapshotMissed {
+                namespace: self.config.namespace.clone(),
+                epoch: finished_epoch,
+                tx_count: snapshot.tx_count,
+                success_count: snapshot.success_count,
+                failure_count: snapshot.failure_count,
+            });
+            return Ok(());
+        };
+
+        if info.owner != program_id {
+            let space = 8 + EpochSnapshot::INIT_SPACE;
+            self.create_pda(info, space, &[b"snapshot", namespace, &epoch, &[bump]], program_id)?;
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        snapshot.try_serialize(&mut &mut data[..])?;
+        msg!("Epoch snapshot written: epoch={}", finished_epoch);
+        Ok(())
+    }
+
+    // Count the report's tier in today's TierDailySnapshot, when one was passed
+    fn record_tier_daily(&self, program_id: &Pubkey, day: i64, tier: u8) -> Result<()> {
+        let Some(tier_daily) = self.tier_daily.as_ref() else {
+            return Ok(());
+        };
+        let (expected, bump) = tier_daily_address(&self.config.namespace, day, program_id);
+        require_keys_eq!(tier_daily.key(), expected, TxGuardError::InvalidTierSnapshot);
+
+        let info = tier_daily.to_account_info();
+        let mut snapshot = if info.owner == program_id {
+            let data = info.try_borrow_data()?;
+            TierDailySnapshot::try_deserialize(&mut &data[..])?
+        } else {
+            let space = 8 + TierDailySnapshot::INIT_SPACE;
+            let seeds: &[&[u8]] = &[b"tier_daily", self.config.namespace.as_bytes(), &day.to_le_bytes(), &[bump]];
+            self.create_pda(&info, space, seeds, program_id)?;
+            TierDailySnapshot { day, tier_counts: vec![0; MAX_TIERS as usize] }
+        };
+
+        // Pre-created accounts come back zeroed
+        snapshot.day = day;
+        snapshot.tier_counts.resize(MAX_TIERS as usize, 0);
+        let count = &mut snapshot.tier_counts[tier as usize];
+        *count = count.checked_add(1).ok_or(TxGuardError::CountOverflow)?;
+
+        let mut data = info.try_borrow_mut_data()?;
+        snapshot.try_serialize(&mut &mut data[..])
+    }
+
+    // Resolve the current epoch's registry PDA, creating it on first use
+    fn load_epoch_registry(&self, program_id: &Pubkey) -> Result<TransactionRegistry> {
+        let epoch_registry = self.epoch_registry.as_ref()
+            .ok_or(TxGuardError::MissingEpochRegistry)?;
+
+        let namespace = self.config.namespace.as_bytes();
+        let epoch = Clock::get()?.epoch.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(&[REGISTRY_SEED, namespace, &epoch], program_id);
+        require_keys_eq!(epoch_registry.key(), expected, TxGuardError::InvalidEpochRegistry);
+
+        let info = epoch_registry.to_account_info();
+        if info.owner == program_id {
+            let data = info.try_borrow_data()?;
+            return TransactionRegistry::try_deserialize(&mut &data[..]);
+        }
+
+        let space = 8 + TransactionRegistry::INIT_SPACE;
+        self.create_pda(&info, space, &[REGISTRY_SEED, namespace, &epoch, &[bump]], program_id)?;
+
+        let mut registry = TransactionRegistry::default();
+        registry.reset();
+        msg!("Epoch registry created: epoch={}", Clock::get()?.epoch);
+        Ok(registry)
+    }
+
+    fn store_epoch_registry(&self, registry: &TransactionRegistry) -> Result<()> {
+        let epoch_registry = self.epoch_registry.as_ref()
+            .ok_or(TxGuardError::MissingEpochRegistry)?;
+        let info = epoch_registry.to_account_info();
+        let mut data = info.try_borrow_mut_data()?;
+        registry.try_serialize(&mut &mut data[..])
+    }
+
+    // Give the PDA at `seeds` `space` bytes owned by the program, with the payer covering
+    // rent. Anyone can send lamports to a PDA before it exists, which would make
+    // create_account fail for good, so top up and allocate/assign the way
+    // write_catalog_snapshot_to_account does.
+    fn create_pda(&self, info: &AccountInfo<'info>, space: usize, seeds: &[&[u8]], program_id: &Pubkey) -> Result<()> {
+        let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Allocate { account_to_allocate: info.clone() },
+                &[seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Assign { account_to_assign: info.clone() },
+                &[seeds],
+            ),
+            program_id,
+        )
+    }
+}
+
+// Composite contexts can't use #[instruction]: Anchor hands `base` the instruction data
+// left over after the outer struct's args, so seeds read the namespace from base.config
+#[derive(Accounts)]
+pub struct RegisterTxOutcomeDual<'info> {
+    pub base: RegisterTxOutcome<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TransactionRegistry::INIT_SPACE,
+        seeds = [b"user_registry", base.config.namespace.as_bytes(), payer.key().as_ref()],
+        bump
+    )]
+    pub user_registry: Account<'info, TransactionRegistry>,
+
+    #[account(mut, address = base.payer.key())]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordInlineSuccess<'info> {
+    pub base: RegisterTxOutcome<'info>,
+
+    /// CHECK: only its address is used, to find its instruction in this transaction
+    pub target_program: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProgramStats::INIT_SPACE,
+        seeds = [b"program_stats", base.config.namespace.as_bytes(), target_program.key().as_ref()],
+        bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// CHECK: address-constrained to the instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    #[account(mut, address = base.payer.key())]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecordFailure<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut, seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct UpdatePriorityFee<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut, seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, reporter: Pubkey)]
+pub struct GetReporterStats<'info> {
+    #[account(seeds = [b"config", namespace.as_bytes()], bump)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(seeds = [b"reporter", namespace.as_bytes(), reporter.as_ref()], bump)]
+    pub reporter_record: Account<'info, ReporterRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, window_index: u8)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = disputer,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", namespace.as_bytes(), &[window_index], disputer.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, window_index: u8)]
+pub struct ResolveDispute<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(
+        mut,
+        close = disputer,
+        seeds = [b"dispute", namespace.as_bytes(), &[window_index], disputer.key().as_ref()],
+        bump,
+        has_one = disputer
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// CHECK: has_one-constrained to the dispute's opener; receives the refund and rent
+    #[account(mut)]
+    pub disputer: UncheckedAccount<'info>,
+
+    // Only needed to reject a dispute that posted a bond
+    #[account(mut, seeds = [b"treasury", namespace.as_bytes()], bump)]
+    pub treasury: Option<Account<'info, Treasury>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, reporter: Pubkey)]
+pub struct FlagReport<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [b"reporter", namespace.as_bytes(), reporter.as_ref()], bump)]
+    pub reporter_record: Account<'info, ReporterRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CloseReporterRecord<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(mut, close = reporter, seeds = [b"reporter", namespace.as_bytes(), reporter.key().as_ref()], bump)]
+    pub reporter_record: Account<'info, ReporterRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetBlockhashValidityWindow<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetPrecompileUsage<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetReportFee<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury", namespace.as_bytes()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct WithdrawTreasury<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [b"treasury", namespace.as_bytes()], bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: any account may receive the withdrawn lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CrankAggregate<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(mut, seeds = [b"config", namespace.as_bytes()], bump)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(mut, seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    #[account(mut, seeds = [b"treasury", namespace.as_bytes()], bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut, seeds = [b"daily", namespace.as_bytes()], bump)]
+    pub daily_stats: Option<Account<'info, DailyStats>>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, reporter: Pubkey)]
+pub struct SetBulkReporter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    // Created on demand so reporters can be whitelisted before their first report
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ReporterRecord::INIT_SPACE,
+        seeds = [b"reporter", namespace.as_bytes(), reporter.as_ref()],
+        bump
+    )]
+    pub reporter_record: Account<'info, ReporterRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetTierNames<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetTierLamports<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TierLamportConfig::INIT_SPACE,
+        seeds = [b"tier_lamports", namespace.as_bytes()],
+        bump
+    )]
+    pub tier_lamport_config: Account<'info, TierLamportConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CalibrateTiers<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+
+    #[account(mut, seeds = [b"tier_lamports", namespace.as_bytes()], bump)]
+    pub tier_lamport_config: Account<'info, TierLamportConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecommendFeeLamports<'info> {
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+
+    #[account(seeds = [b"tier_lamports", namespace.as_bytes()], bump)]
+    pub tier_lamport_config: Account<'info, TierLamportConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct RecommendTierForDeadline<'info> {
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+
+    #[account(seeds = [b"latency", namespace.as_bytes()], bump)]
+    pub latency_stats: Account<'info, LatencyStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ComputeFeeRoi<'info> {
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+
+    #[account(seeds = [b"tier_lamports", namespace.as_bytes()], bump)]
+    pub tier_lamport_config: Account<'info, TierLamportConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ComputeWastedFees<'info> {
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetTierStats<'info> {
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ComputeSuccessRate<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ComputeDominantFailure<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ProjectNextFailure<'info> {
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetSummary<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct FormatFailurePie<'info> {
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetStats<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(seeds = [b"hourly", namespace.as_bytes()], bump)]
+    pub hourly_stats: Account<'info, HourlyStats>,
+
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    #[account(seeds = [b"compute", namespace.as_bytes()], bump)]
+    pub compute_stats: Account<'info, ComputeStats>,
+
+    #[account(seeds = [b"tx_size", namespace.as_bytes()], bump)]
+    pub tx_size_stats: Account<'info, TxSizeStats>,
+
+    #[account(seeds = [b"instructions", namespace.as_bytes()], bump)]
+    pub instruction_stats: Account<'info, InstructionStats>,
+
+    #[account(seeds = [b"blockhash_age", namespace.as_bytes()], bump)]
+    pub blockhash_age_stats: Account<'info, BlockhashAgeStats>,
+
+    #[account(seeds = [b"routes", namespace.as_bytes()], bump)]
+    pub route_stats: Account<'info, RouteStats>,
+
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetRecentRate<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetTierSparkline<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DetectSandwichPatterns<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetBufferUtilization<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetTagStats<'info> {
+    #[account(seeds = [b"tags", namespace.as_bytes()], bump)]
+    pub tag_stats: Account<'info, TagStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetInstructionStats<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct DetectAnomaly<'info> {
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    #[account(seeds = [b"snapshot", namespace.as_bytes(), &baseline_snapshot.epoch.to_le_bytes()], bump)]
+    pub baseline_snapshot: Account<'info, EpochSnapshot>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CompareToSnapshot<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    #[account(seeds = [b"snapshot", namespace.as_bytes(), &snapshot.epoch.to_le_bytes()], bump)]
+    pub snapshot: Account<'info, EpochSnapshot>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct WriteCatalogSnapshot<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    // The caller's own account, so its signature authorises the write. Must be fresh, or
+    // empty or an earlier snapshot by `writer`, checked in the handler
+    #[account(mut)]
+    pub dest: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetTierTrend<'info> {
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CheckHealth<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump)]
+    pub config: Account<'info, GuardConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetPeakHourOfWeek<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct GetBestDay<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ExportState<'info> {
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    #[account(seeds = [PRIORITY_SEED, namespace.as_bytes()], bump)]
+    pub priority_fee_stats: Account<'info, PriorityFeeStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String, new_owner: Pubkey)]
+pub struct TransferUserRegistry<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"user_registry", namespace.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub user_registry: Account<'info, TransactionRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + TransactionRegistry::INIT_SPACE,
+        seeds = [b"user_registry", namespace.as_bytes(), new_owner.as_ref()],
+        bump
+    )]
+    pub new_user_registry: Account<'info, TransactionRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(source_namespace: String, destination_namespace: String)]
+pub struct MergeRegistries<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", source_namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub source_config: Account<'info, GuardConfig>,
+
+    #[account(seeds = [b"config", destination_namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub destination_config: Account<'info, GuardConfig>,
+
+    // Addresses are checked against the namespace/user arguments in the handler
+    #[account(mut)]
+    pub source: Account<'info, TransactionRegistry>,
+
+    #[account(mut)]
+    pub destination: Account<'info, TransactionRegistry>,
+
+    #[account(seeds = [CATALOG_SEED, source_namespace.as_bytes()], bump)]
+    pub source_catalog: Option<Account<'info, FailureCatalog>>,
+
+    #[account(mut, seeds = [CATALOG_SEED, destination_namespace.as_bytes()], bump)]
+    pub destination_catalog: Option<Account<'info, FailureCatalog>>,
+
+    /// CHECK: receives the source's rent when closing; must be the source's owner
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(child_namespace: String, parent_namespace: String)]
+pub struct MergeIntoParent<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", child_namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub child_config: Account<'info, GuardConfig>,
+
+    #[account(seeds = [b"config", parent_namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub parent_config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [CATALOG_SEED, child_namespace.as_bytes()], bump)]
+    pub child_catalog: Account<'info, FailureCatalog>,
+
+    #[account(mut, seeds = [CATALOG_SEED, parent_namespace.as_bytes()], bump)]
+    pub parent_catalog: Account<'info, FailureCatalog>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct CreateArchive<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(mut, seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ArchiveRegistry::INIT_SPACE,
+        seeds = [ARCHIVE_SEED, registry.key().as_ref(), &registry.session_id.to_le_bytes()],
+        bump
+    )]
+    pub archive: Account<'info, ArchiveRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ResetSeasonality<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [b"seasonality", namespace.as_bytes()], bump)]
+    pub seasonality_stats: Account<'info, SeasonalityStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ResetFailureTypes<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [CATALOG_SEED, namespace.as_bytes()], bump)]
+    pub failure_catalog: Account<'info, FailureCatalog>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct UpdateRegistryConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(seeds = [REGISTRY_SEED, namespace.as_bytes()], bump)]
+    pub registry: Account<'info, TransactionRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RegistryConfig::INIT_SPACE,
+        seeds = [b"registry_config", registry.key().as_ref()],
+        bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct SetRewardMint<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config", namespace.as_bytes()], bump, has_one = authority @ TxGuardError::Unauthorized)]
+    pub config: Account<'info, GuardConfig>,
+
+    /// CHECK: parsed in `reward_mint_authority`; ignored when switching rewards off
+    pub reward_mint: UncheckedAccount<'info>,
+
+    /// CHECK: signs mint_to in claim_rewards; holds no data
+    #[account(seeds = [b"reward_authority", namespace.as_bytes()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: String)]
+pub struct ClaimRewards<'info> {
+    pub reporter: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", namespace.as_bytes()],
+        bump,
+        constraint = config.reward_mint != Pubkey::default() @ TxGuardError::RewardsDisabled
+    )]
+    pub config: Account<'info, GuardConfig>,
+
+    #[account(mut, seeds = [b"reporter", namespace.as_bytes(), reporter.key().as_ref()], bump)]
+    pub reporter_record: Account<'info, ReporterRecord>,
+
+    /// CHECK: address-constrained to the configured reward mint
+    #[account(mut, address = config.reward_mint @ TxGuardError::InvalidRewardMint)]
+    pub reward_mint: UncheckedAccount<'info>,
+
+    /// CHECK: the reporter's associated token account; the token program checks the rest
+    #[account(
+        mut,
+        address = associated_token_address(&reporter.key(), &config.reward_mint) @ TxGuardError::InvalidRewardAccount
+    )]
+    pub reporter_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: seeds-constrained PDA the reward mint's authority is set to
+    #[account(seeds = [b"reward_authority", namespace.as_bytes()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: address-constrained to the SPL Token program
+    #[account(address = TOKEN_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+}
+
+// Events
+#[event]
+pub struct UserRegistryTransferred {
+    pub namespace: String,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub registry: Pubkey,
+}
+
+#[event]
+pub struct SnapshotMissed {
+    pub namespace: String,
+    pub epoch: u64,
+    pub tx_count: u64,
+    pub success_count: u64,
+    pub failure_count: u64,
+}
+
+#[event]
+pub struct SelectiveReset {
+    pub namespace: String,
+    pub types_reset: Vec<u8>,
+}
+
+#[event]
+pub struct TiersCalibrated {
+    pub namespace: String,
+    pub old_boundaries: [u64; MAX_TIERS as usize],
+    pub new_boundaries: [u64; MAX_TIERS as usize],
+}
+
+#[event]
+pub struct ReporterRewarded {
+    pub namespace: String,
+    pub reporter: Pubkey,
+    pub points_awarded: u64,
+    pub total_points: u64,
+}
+
+#[event]
+pub struct ReportFlagged {
+    pub namespace: String,
+    pub reporter: Pubkey,
+    pub window_index: u8,
+    pub reputation: u16, // After the penalty
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub namespace: String,
+    pub disputer: Pubkey,
+    pub window_index: u8,
+    pub claimed_success: bool,
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub namespace: String,
+    pub disputer: Pubkey,
+    pub window_index: u8,
+    pub accepted: bool, // true = entry amended and bond refunded, false = bond to the treasury
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub namespace: String,
+    pub reporter: Pubkey,
+    pub amount: u64, // Points redeemed, minted as base units of the reward token
 }
 
 // Custom Errors
@@ -250,4 +6005,136 @@ pub enum TxGuardError {
     InvalidPriorityFeeTier,
     #[msg("Count overflow")]
     CountOverflow,
+    #[msg("Signer is not the config authority")]
+    Unauthorized,
+    #[msg("Epoch mode requires the epoch registry account")]
+    MissingEpochRegistry,
+    #[msg("Epoch registry does not match the current epoch PDA")]
+    InvalidEpochRegistry,
+    #[msg("At most MAX_TIERS tier names can be set")]
+    TooManyTierNames,
+    #[msg("Tier name is not valid UTF-8")]
+    InvalidTierName,
+    #[msg("Namespace is longer than 32 bytes")]
+    NamespaceTooLong,
+    #[msg("Namespace has already been initialized")]
+    NamespaceExists,
+    #[msg("A partial fill must be reported with success = true")]
+    InvalidPartialFill,
+    #[msg("Exported state does not fit in return data")]
+    ExportTooLarge,
+    #[msg("Cannot merge a registry into itself")]
+    SelfMerge,
+    #[msg("Registry does not match the given namespace and user")]
+    InvalidRegistry,
+    #[msg("Rent recipient must be the owner of the closed account")]
+    InvalidRentRecipient,
+    #[msg("New owner must differ from the current owner")]
+    InvalidNewOwner,
+    #[msg("Destination registry already exists; use merge_registries instead")]
+    DestinationRegistryExists,
+    #[msg("This transaction signature was already reported")]
+    DuplicateReport,
+    #[msg("Tier lamports must list MAX_TIERS non-decreasing values")]
+    InvalidTierLamports,
+    #[msg("Target program cannot be TxGuard itself")]
+    InvalidTargetProgram,
+    #[msg("No earlier instruction in this transaction targets the given program")]
+    MissingTargetInstruction,
+    #[msg("Reporter must wait min_slots_between_reports before reporting again")]
+    RateLimited,
+    #[msg("Namespace charges a report fee; pass the treasury account")]
+    MissingTreasury,
+    #[msg("Withdrawal would leave the treasury below its rent-exempt reserve")]
+    InsufficientTreasury,
+    #[msg("Registry is locked by an in-progress TxGuard instruction")]
+    ReentrancyDetected,
+    #[msg("crank_interval_slots has not elapsed since the last crank")]
+    CrankTooEarly,
+    #[msg("Namespace has reached max_reports_per_slot for this slot")]
+    SlotQuotaExceeded,
+    #[msg("Deadline failure reported before the blockhash validity window elapsed")]
+    DeadlineNotExceeded,
+    #[msg("Blockhash validity window must be at least 1 slot")]
+    InvalidValidityWindow,
+    #[msg("Tier daily snapshot does not match today's PDA")]
+    InvalidTierSnapshot,
+    #[msg("Trend window must be between 1 and 7 days")]
+    InvalidTrendWindow,
+    #[msg("Declared tx_slot is ahead of the current slot")]
+    InvalidTxSlot,
+    #[msg("Registry has fewer reports than the requested min_tx_count")]
+    InsufficientData,
+    #[msg("Declared tx_slot is older than max_report_age_slots")]
+    StaleReport,
+    #[msg("confirmed_slot is before submitted_slot or more than 10,000 slots after it")]
+    InvalidConfirmationSlots,
+    #[msg("tx_size_bytes exceeds the 1232-byte transaction limit")]
+    InvalidTxSize,
+    #[msg("Lookup table counts are inconsistent with the transaction version")]
+    InvalidLookupUsage,
+    #[msg("No outcomes recorded in the window yet")]
+    BufferEmpty,
+    #[msg("Unknown failure type code, or no failure types given")]
+    InvalidFailureType,
+    #[msg("A transaction has at least one instruction")]
+    InvalidInstructionCount,
+    #[msg("num_signers must be between 1 and 12")]
+    InvalidSignerCount,
+    #[msg("Landing status contradicts the reported success")]
+    ContradictoryReport,
+    #[msg("landing must be 0 (never landed), 1 (landed and errored), 2 (landed and succeeded) or 255 (unknown)")]
+    InvalidLanding,
+    #[msg("recent_context must be 0 (normal), 1 (high congestion) or 2 (volatile market)")]
+    InvalidRecentContext,
+    #[msg("Reporter does not have enough points")]
+    InsufficientPoints,
+    #[msg("No reward mint is configured for this namespace")]
+    RewardsDisabled,
+    #[msg("Reward mint must be an SPL Token mint whose authority is the reward_authority PDA")]
+    InvalidRewardMint,
+    #[msg("Reward tokens go to the reporter's associated token account for the reward mint")]
+    InvalidRewardAccount,
+    #[msg("claim_interval_slots has not elapsed since the reporter's last claim")]
+    ClaimTooEarly,
+    #[msg("cpi_depth cannot exceed the runtime's maximum of 4")]
+    InvalidCpiDepth,
+    #[msg("Reputation values cannot exceed 10,000")]
+    InvalidReputation,
+    #[msg("window_index must point at a filled entry of the outcome window")]
+    InvalidWindowIndex,
+    #[msg("This report has already been flagged")]
+    ReportAlreadyFlagged,
+    #[msg("A dispute is already open on this window entry")]
+    DisputeAlreadyOpen,
+    #[msg("Only success or failure entries can be disputed, and only to the opposite outcome")]
+    InvalidDispute,
+    #[msg("The disputed entry has been overwritten by later reports")]
+    DisputeEntryOverwritten,
+    #[msg("Snapshot destination must be fresh, or large enough and empty or last written by the writer")]
+    InvalidSnapshotAccount,
+    #[msg("Sparkline bucket count must be between 1 and 100")]
+    InvalidSparklineBuckets,
+    #[msg("buffer_size must be between 1 and 100")]
+    InvalidBufferSize,
+    #[msg("The registry has reached its configured max_tx_count")]
+    RegistryFull,
+    #[msg("Only the registry's observer or its authority may report")]
+    NotObserver,
+    #[msg("Reported outcomes are failure, success or partial; pending only marks empty window entries")]
+    InvalidOutcome,
+    #[msg("archive_before_slot must be after the last report and the last archive, and not in the future")]
+    InvalidArchiveSlot,
+    #[msg("The disputed entry was archived; its outcome no longer counts towards the registry")]
+    DisputeEntryArchived,
+    #[msg("This session's archive account already holds an archive")]
+    ArchiveExists,
+    #[msg("min_success_rate_bps cannot exceed 10,000")]
+    InvalidSuccessRateThreshold,
+    #[msg("Accounts to migrate must be writable TxGuard stats PDAs")]
+    InvalidMigrationAccount,
+    #[msg("A batch must hold at least one report")]
+    EmptyBatch,
+    #[msg("Unknown export section, or an offset past the end of its account")]
+    InvalidExportPage,
 }
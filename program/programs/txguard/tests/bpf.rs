@@ -0,0 +1,418 @@
+// The native processor in txguard_tests.rs can't CPI or set return data, and runs without
+// the SBF heap and compute limits. These run the compiled program against accounts filled
+// to their max_len, so they need the .so first:
+//
+//   cargo build-sbf --manifest-path programs/txguard/Cargo.toml
+//   SBF_OUT_DIR=$PWD/target/deploy cargo test -p txguard --test bpf -- --ignored
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, InstructionData};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use txguard::{
+    ChainFailureCount, EpochSnapshot, FailureCatalog, GuardConfig, InstructionTypeStats,
+    PriorityFeeStats, TierDailySnapshot, TransactionRegistry, TxOutcomeParams,
+};
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &txguard::ID).0
+}
+
+fn registry_pda() -> Pubkey {
+    pda(&[b"registry", b""])
+}
+
+fn catalog_pda() -> Pubkey {
+    pda(&[b"catalog", b""])
+}
+
+fn priority_pda() -> Pubkey {
+    pda(&[b"priority", b""])
+}
+
+fn config_pda() -> Pubkey {
+    pda(&[b"config", b""])
+}
+
+fn user_registry_pda(user: &Pubkey) -> Pubkey {
+    pda(&[b"user_registry", b"", user.as_ref()])
+}
+
+fn registry_config_pda() -> Pubkey {
+    pda(&[b"registry_config", registry_pda().as_ref()])
+}
+
+fn reporter_pda(reporter: &Pubkey) -> Pubkey {
+    pda(&[b"reporter", b"", reporter.as_ref()])
+}
+
+fn epoch_registry_pda(epoch: u64) -> Pubkey {
+    pda(&[b"registry", b"", &epoch.to_le_bytes()])
+}
+
+fn snapshot_pda(epoch: u64) -> Pubkey {
+    pda(&[b"snapshot", b"", &epoch.to_le_bytes()])
+}
+
+fn tier_daily_pda(day: i64) -> Pubkey {
+    pda(&[b"tier_daily", b"", &day.to_le_bytes()])
+}
+
+fn register_accounts(payer: Pubkey) -> txguard::accounts::RegisterTxOutcome {
+    txguard::accounts::RegisterTxOutcome {
+        payer,
+        registry: registry_pda(),
+        failure_catalog: catalog_pda(),
+        priority_fee_stats: priority_pda(),
+        config: config_pda(),
+        registry_config: registry_config_pda(),
+        epoch_registry: None,
+        reporter_record: reporter_pda(&payer),
+        treasury: None,
+        hourly_stats: None,
+        daily_stats: None,
+        seasonality_stats: None,
+        tier_daily: None,
+        compute_stats: None,
+        latency_stats: None,
+        tx_size_stats: None,
+        instruction_stats: None,
+        blockhash_age_stats: None,
+        route_stats: None,
+        tag_stats: None,
+        system_program: anchor_lang::system_program::ID,
+    }
+}
+
+fn report_ix(accounts: txguard::accounts::RegisterTxOutcome) -> Instruction {
+    let data = txguard::instruction::RegisterTxOutcomeV2 {
+        _namespace: String::new(),
+        params: TxOutcomeParams {
+            success: true,
+            ..Default::default()
+        },
+    };
+    instruction(accounts, data)
+}
+
+// What anyone can do to a PDA before TxGuard creates it
+fn prefund(ctx: &mut ProgramTestContext, address: &Pubkey) {
+    let account = SolanaAccount::new(1_000, 0, &anchor_lang::system_program::ID);
+    ctx.set_account(address, &account.into());
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: txguard::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// A rent-exempt program account of `space` bytes holding `value`
+fn program_account(value: &impl AccountSerialize, space: usize) -> SolanaAccount {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    data.resize(8 + space, 0);
+    SolanaAccount {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: txguard::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+// Every vec at its max_len, as a long-running deployment ends up
+fn full_registry(tx_count: u64) -> TransactionRegistry {
+    let mut registry = TransactionRegistry {
+        tx_count,
+        success_count: tx_count,
+        recent_sig_hashes: (0..64).map(|index| [index as u8 + 1; 32]).collect(),
+        instruction_types: (1..=txguard::MAX_INSTRUCTION_TYPES as u8)
+            .map(|instruction_type| InstructionTypeStats {
+                instruction_type,
+                success: 1,
+                failure: 1,
+            })
+            .collect(),
+        seen_callers: (0..txguard::MAX_SEEN_CALLERS)
+            .map(|_| Pubkey::new_unique())
+            .collect(),
+        ..Default::default()
+    };
+    registry.upgrade(0);
+    registry
+}
+
+fn full_catalog() -> FailureCatalog {
+    let mut catalog = FailureCatalog {
+        bridge_failure_by_chain: (0..10)
+            .map(|chain_id| ChainFailureCount { chain_id, count: 1 })
+            .collect(),
+        ..Default::default()
+    };
+    catalog.upgrade();
+    catalog
+}
+
+fn full_stats() -> PriorityFeeStats {
+    let mut stats = PriorityFeeStats {
+        tier_names: vec![[b' '; 16]; txguard::MAX_TIERS as usize],
+        ..Default::default()
+    };
+    stats.upgrade();
+    stats
+}
+
+fn config(authority: Pubkey) -> GuardConfig {
+    let mut data = GuardConfig::DISCRIMINATOR.to_vec();
+    data.resize(8 + GuardConfig::INIT_SPACE, 0);
+    let mut config = GuardConfig::try_deserialize(&mut &data[..]).unwrap();
+    config.init_defaults(authority, "");
+    config
+}
+
+// The compiled program, with the payer as the namespace authority and the namespace's
+// registry, catalog and stats at capacity
+async fn start() -> ProgramTestContext {
+    let mut program_test = ProgramTest::new("txguard", txguard::ID, None);
+    program_test.prefer_bpf(true);
+    let mut ctx = program_test.start_with_context().await;
+    let payer = ctx.payer.pubkey();
+    let accounts = [
+        (
+            config_pda(),
+            program_account(&config(payer), GuardConfig::INIT_SPACE),
+        ),
+        (
+            registry_pda(),
+            program_account(&full_registry(1), TransactionRegistry::INIT_SPACE),
+        ),
+        (
+            catalog_pda(),
+            program_account(&full_catalog(), FailureCatalog::INIT_SPACE),
+        ),
+        (
+            priority_pda(),
+            program_account(&full_stats(), PriorityFeeStats::INIT_SPACE),
+        ),
+        (
+            user_registry_pda(&payer),
+            program_account(&full_registry(2), TransactionRegistry::INIT_SPACE),
+        ),
+    ];
+    for (address, account) in accounts {
+        ctx.set_account(&address, &account.into());
+    }
+    ctx
+}
+
+async fn send(ctx: &mut ProgramTestContext, ix: Instruction) {
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn fetch<T: AccountDeserialize>(ctx: &mut ProgramTestContext, address: Pubkey) -> Option<T> {
+    let account = ctx.banks_client.get_account(address).await.unwrap()?;
+    Some(T::try_deserialize(&mut &account.data[..]).unwrap())
+}
+
+#[tokio::test]
+#[ignore]
+async fn transfer_user_registry_moves_a_full_registry() {
+    let mut ctx = start().await;
+    let owner = ctx.payer.pubkey();
+    let new_owner = Keypair::new().pubkey();
+
+    // The new registry is created through a system program CPI
+    let accounts = txguard::accounts::TransferUserRegistry {
+        owner,
+        user_registry: user_registry_pda(&owner),
+        new_user_registry: user_registry_pda(&new_owner),
+        system_program: anchor_lang::system_program::ID,
+    };
+    let data = txguard::instruction::TransferUserRegistry {
+        namespace: String::new(),
+        new_owner,
+    };
+    send(&mut ctx, instruction(accounts, data)).await;
+
+    let moved: TransactionRegistry = fetch(&mut ctx, user_registry_pda(&new_owner))
+        .await
+        .unwrap();
+    assert_eq!(
+        moved.try_to_vec().unwrap(),
+        full_registry(2).try_to_vec().unwrap()
+    );
+    assert!(
+        fetch::<TransactionRegistry>(&mut ctx, user_registry_pda(&owner))
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn merge_registries_folds_and_closes_a_full_registry() {
+    let mut ctx = start().await;
+    let authority = ctx.payer.pubkey();
+
+    let accounts = txguard::accounts::MergeRegistries {
+        authority,
+        source_config: config_pda(),
+        destination_config: config_pda(),
+        source: user_registry_pda(&authority),
+        destination: registry_pda(),
+        source_catalog: None,
+        destination_catalog: None,
+        rent_recipient: Some(authority),
+    };
+    let data = txguard::instruction::MergeRegistries {
+        source_namespace: String::new(),
+        destination_namespace: String::new(),
+        source_user: Some(authority),
+        destination_user: None,
+        close_source: true,
+    };
+    send(&mut ctx, instruction(accounts, data)).await;
+
+    let merged: TransactionRegistry = fetch(&mut ctx, registry_pda()).await.unwrap();
+    assert_eq!(merged.tx_count, 3);
+    assert_eq!(merged.success_count, 3);
+    assert!(
+        fetch::<TransactionRegistry>(&mut ctx, user_registry_pda(&authority))
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn export_state_pages_full_accounts_through_return_data() {
+    let mut ctx = start().await;
+    let sections = [
+        (
+            txguard::EXPORT_REGISTRY,
+            full_registry(1).try_to_vec().unwrap(),
+        ),
+        (
+            txguard::EXPORT_CATALOG,
+            full_catalog().try_to_vec().unwrap(),
+        ),
+        (
+            txguard::EXPORT_PRIORITY_STATS,
+            full_stats().try_to_vec().unwrap(),
+        ),
+    ];
+    for (section, encoding) in sections {
+        let mut exported = Vec::new();
+        while exported.len() < encoding.len() {
+            let accounts = txguard::accounts::ExportState {
+                registry: registry_pda(),
+                failure_catalog: catalog_pda(),
+                priority_fee_stats: priority_pda(),
+            };
+            let data = txguard::instruction::ExportState {
+                _namespace: String::new(),
+                section,
+                offset: exported.len() as u32,
+            };
+            let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+            let tx = Transaction::new_signed_with_payer(
+                &[instruction(accounts, data)],
+                Some(&ctx.payer.pubkey()),
+                &[&ctx.payer],
+                blockhash,
+            );
+            let simulation = ctx.banks_client.simulate_transaction(tx).await.unwrap();
+            simulation.result.unwrap().unwrap();
+            // Anchor returns the Vec<u8> borsh encoded; the runtime drops trailing zeros
+            let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+            let mut returned = return_data.data;
+            returned.resize(txguard::MAX_EXPORT_LEN + 4, 0);
+            let payload = Vec::<u8>::deserialize(&mut &returned[..]).unwrap();
+
+            assert_eq!(payload[0], txguard::EXPORT_SCHEMA_VERSION);
+            let len = u32::from_le_bytes(payload[1..5].try_into().unwrap()) as usize;
+            assert_eq!(len, encoding.len());
+            exported.extend_from_slice(&payload[5..]);
+        }
+        assert_eq!(exported, encoding);
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn epoch_registry_is_created_even_when_prefunded() {
+    let mut ctx = start().await;
+    let payer = ctx.payer.pubkey();
+    let mut epoch_mode = config(payer);
+    epoch_mode.epoch_scoped = true;
+    let account = program_account(&epoch_mode, GuardConfig::INIT_SPACE);
+    ctx.set_account(&config_pda(), &account.into());
+    let epoch = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().epoch;
+    prefund(&mut ctx, &epoch_registry_pda(epoch));
+
+    let mut accounts = register_accounts(payer);
+    accounts.epoch_registry = Some(epoch_registry_pda(epoch));
+    send(&mut ctx, report_ix(accounts)).await;
+
+    let registry: TransactionRegistry = fetch(&mut ctx, epoch_registry_pda(epoch)).await.unwrap();
+    assert_eq!(registry.tx_count, 1);
+    assert_eq!(registry.success_count, 1);
+}
+
+#[tokio::test]
+#[ignore]
+async fn epoch_snapshot_is_written_even_when_prefunded() {
+    let mut ctx = start().await;
+    let payer = ctx.payer.pubkey();
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let finished = clock.epoch;
+    clock.epoch += 1;
+    ctx.set_sysvar(&clock);
+    let mut registry = full_registry(1);
+    registry.last_seen_epoch = finished;
+    let account = program_account(&registry, TransactionRegistry::INIT_SPACE);
+    ctx.set_account(&registry_pda(), &account.into());
+    prefund(&mut ctx, &snapshot_pda(finished));
+
+    let mut ix = report_ix(register_accounts(payer));
+    ix.accounts
+        .push(AccountMeta::new(snapshot_pda(finished), false));
+    send(&mut ctx, ix).await;
+
+    let snapshot: EpochSnapshot = fetch(&mut ctx, snapshot_pda(finished)).await.unwrap();
+    assert_eq!(snapshot.epoch, finished);
+    assert_eq!(snapshot.tx_count, 1);
+}
+
+#[tokio::test]
+#[ignore]
+async fn tier_daily_snapshot_is_created_even_when_prefunded() {
+    let mut ctx = start().await;
+    let payer = ctx.payer.pubkey();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let day = clock.unix_timestamp.div_euclid(86_400);
+    prefund(&mut ctx, &tier_daily_pda(day));
+
+    let mut accounts = register_accounts(payer);
+    accounts.tier_daily = Some(tier_daily_pda(day));
+    send(&mut ctx, report_ix(accounts)).await;
+
+    let snapshot: TierDailySnapshot = fetch(&mut ctx, tier_daily_pda(day)).await.unwrap();
+    assert_eq!(snapshot.day, day);
+    assert_eq!(snapshot.tier_counts[0], 1);
+}
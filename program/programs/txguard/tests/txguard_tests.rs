@@ -0,0 +1,5309 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    bpf_loader_upgradeable, entrypoint::ProgramResult, instruction::Instruction,
+};
+use anchor_lang::{Discriminator, InstructionData};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use txguard::{
+    AnomalyReport, ArchiveRegistry, BestDay, BlockhashAgeStats, BufferUtilization, ComputeStats,
+    DailyStats, Dispute, EpochSnapshot, FailureCatalog, FailureType, GuardConfig, HourlyStats,
+    InstructionStats, InstructionTypeTotals, LatencyStats, Outcome, PeakHour, PriorityFeeStats,
+    ProgramStats, RegistryConfig, RegistrySummary, ReporterRecord, RouteStats, SeasonalityStats,
+    TagStats, TierDailySnapshot, TierLamportConfig, TransactionRegistry, Treasury, TxGuardError,
+    TxMetadata, TxOutcomeParams, TxSizeStats,
+};
+
+// Anchor's entrypoint ties the account slice to the AccountInfo lifetime; the
+// native processor hands us independent lifetimes, so bridge them here.
+fn process_instruction<'a, 'b, 'c, 'd>(
+    program_id: &'a Pubkey,
+    accounts: &'b [AccountInfo<'c>],
+    data: &'d [u8],
+) -> ProgramResult {
+    let accounts: &'c [AccountInfo<'c>] = unsafe { std::mem::transmute(accounts) };
+    txguard::entry(program_id, accounts, data)
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &txguard::ID).0
+}
+
+fn registry_pda() -> Pubkey {
+    pda(&[b"registry", b""])
+}
+
+fn catalog_pda() -> Pubkey {
+    pda(&[b"catalog", b""])
+}
+
+fn priority_pda() -> Pubkey {
+    pda(&[b"priority", b""])
+}
+
+fn config_pda() -> Pubkey {
+    pda(&[b"config", b""])
+}
+
+fn registry_config_pda() -> Pubkey {
+    pda(&[b"registry_config", registry_pda().as_ref()])
+}
+
+fn reporter_pda(reporter: &Pubkey) -> Pubkey {
+    pda(&[b"reporter", b"", reporter.as_ref()])
+}
+
+fn user_registry_pda(user: &Pubkey) -> Pubkey {
+    pda(&[b"user_registry", b"", user.as_ref()])
+}
+
+fn hourly_pda() -> Pubkey {
+    pda(&[b"hourly", b""])
+}
+
+fn daily_pda() -> Pubkey {
+    pda(&[b"daily", b""])
+}
+
+fn seasonality_pda() -> Pubkey {
+    pda(&[b"seasonality", b""])
+}
+
+fn compute_pda() -> Pubkey {
+    pda(&[b"compute", b""])
+}
+
+fn latency_pda() -> Pubkey {
+    pda(&[b"latency", b""])
+}
+
+fn tx_size_pda() -> Pubkey {
+    pda(&[b"tx_size", b""])
+}
+
+fn instructions_pda() -> Pubkey {
+    pda(&[b"instructions", b""])
+}
+
+fn blockhash_age_pda() -> Pubkey {
+    pda(&[b"blockhash_age", b""])
+}
+
+fn tier_daily_pda(day: i64) -> Pubkey {
+    pda(&[b"tier_daily", b"", &day.to_le_bytes()])
+}
+
+fn routes_pda() -> Pubkey {
+    pda(&[b"routes", b""])
+}
+
+fn tags_pda() -> Pubkey {
+    pda(&[b"tags", b""])
+}
+
+fn tier_lamports_pda() -> Pubkey {
+    pda(&[b"tier_lamports", b""])
+}
+
+fn dispute_pda(window_index: u8, disputer: &Pubkey) -> Pubkey {
+    pda(&[b"dispute", b"", &[window_index], disputer.as_ref()])
+}
+
+fn archive_pda(session_id: u64) -> Pubkey {
+    pda(&[
+        b"archive",
+        registry_pda().as_ref(),
+        &session_id.to_le_bytes(),
+    ])
+}
+
+fn treasury_pda() -> Pubkey {
+    pda(&[b"treasury", b""])
+}
+
+fn program_stats_pda(program: &Pubkey) -> Pubkey {
+    pda(&[b"program_stats", b"", program.as_ref()])
+}
+
+fn snapshot_pda(epoch: u64) -> Pubkey {
+    pda(&[b"snapshot", b"", &epoch.to_le_bytes()])
+}
+
+// Anchor 0.32 CPIs through `sol_invoke_signed`, which only exists on-chain, so
+// `init` / `init_if_needed` can't create accounts under the native processor.
+// Pre-create every PDA as a zeroed, program-owned account instead; Anchor then
+// takes the "already initialized" path and no CPI is needed.
+fn zeroed_account(discriminator: &[u8], space: usize) -> SolanaAccount {
+    let mut data = discriminator.to_vec();
+    data.resize(8 + space, 0);
+    SolanaAccount {
+        lamports: 1_000_000_000,
+        data,
+        owner: txguard::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn reward_authority_pda() -> Pubkey {
+    pda(&[b"reward_authority", b""])
+}
+
+// Initialized SPL Token mint with no freeze authority
+fn spl_mint_account(mint_authority: &Pubkey, supply: u64) -> SolanaAccount {
+    let mut data = vec![1, 0, 0, 0];
+    data.extend_from_slice(mint_authority.as_ref());
+    data.extend_from_slice(&supply.to_le_bytes());
+    data.extend_from_slice(&[6, 1]);
+    data.resize(82, 0);
+    SolanaAccount {
+        lamports: 1_000_000_000,
+        data,
+        owner: txguard::TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn initialize_ix(payer: Pubkey) -> Instruction {
+    let accounts = txguard::accounts::Initialize {
+        payer,
+        registry: registry_pda(),
+        failure_catalog: catalog_pda(),
+        priority_fee_stats: priority_pda(),
+        config: config_pda(),
+        hourly_stats: hourly_pda(),
+        daily_stats: daily_pda(),
+        seasonality_stats: seasonality_pda(),
+        compute_stats: compute_pda(),
+        latency_stats: latency_pda(),
+        tx_size_stats: tx_size_pda(),
+        instruction_stats: instructions_pda(),
+        blockhash_age_stats: blockhash_age_pda(),
+        route_stats: routes_pda(),
+        tag_stats: tags_pda(),
+        system_program: system_program::ID,
+    };
+    let data = txguard::instruction::Initialize {
+        namespace: String::new(),
+    };
+    instruction(accounts, data)
+}
+
+struct TestEnv {
+    ctx: ProgramTestContext,
+}
+
+impl TestEnv {
+    // Fresh validator with all PDAs seeded and `initialize` already run
+    async fn new() -> Self {
+        let mut env = Self::uninitialized().await;
+        env.initialize().await.unwrap();
+        env
+    }
+
+    async fn uninitialized() -> Self {
+        let program_test =
+            ProgramTest::new("txguard", txguard::ID, processor!(process_instruction));
+        let mut ctx = program_test.start_with_context().await;
+
+        let reporter = ctx.payer.pubkey();
+        for (address, discriminator, space) in [
+            (
+                registry_pda(),
+                TransactionRegistry::DISCRIMINATOR,
+                TransactionRegistry::INIT_SPACE,
+            ),
+            (
+                catalog_pda(),
+                FailureCatalog::DISCRIMINATOR,
+                FailureCatalog::INIT_SPACE,
+            ),
+            (
+                priority_pda(),
+                PriorityFeeStats::DISCRIMINATOR,
+                PriorityFeeStats::INIT_SPACE,
+            ),
+            (
+                config_pda(),
+                GuardConfig::DISCRIMINATOR,
+                GuardConfig::INIT_SPACE,
+            ),
+            (
+                registry_config_pda(),
+                RegistryConfig::DISCRIMINATOR,
+                RegistryConfig::INIT_SPACE,
+            ),
+            (
+                reporter_pda(&reporter),
+                ReporterRecord::DISCRIMINATOR,
+                ReporterRecord::INIT_SPACE,
+            ),
+            (
+                hourly_pda(),
+                HourlyStats::DISCRIMINATOR,
+                HourlyStats::INIT_SPACE,
+            ),
+            (
+                daily_pda(),
+                DailyStats::DISCRIMINATOR,
+                DailyStats::INIT_SPACE,
+            ),
+            (
+                seasonality_pda(),
+                SeasonalityStats::DISCRIMINATOR,
+                SeasonalityStats::INIT_SPACE,
+            ),
+            (
+                compute_pda(),
+                ComputeStats::DISCRIMINATOR,
+                ComputeStats::INIT_SPACE,
+            ),
+            (
+                latency_pda(),
+                LatencyStats::DISCRIMINATOR,
+                LatencyStats::INIT_SPACE,
+            ),
+            (
+                tx_size_pda(),
+                TxSizeStats::DISCRIMINATOR,
+                TxSizeStats::INIT_SPACE,
+            ),
+            (
+                instructions_pda(),
+                InstructionStats::DISCRIMINATOR,
+                InstructionStats::INIT_SPACE,
+            ),
+            (
+                blockhash_age_pda(),
+                BlockhashAgeStats::DISCRIMINATOR,
+                BlockhashAgeStats::INIT_SPACE,
+            ),
+            (
+                routes_pda(),
+                RouteStats::DISCRIMINATOR,
+                RouteStats::INIT_SPACE,
+            ),
+            (tags_pda(), TagStats::DISCRIMINATOR, TagStats::INIT_SPACE),
+            (
+                tier_lamports_pda(),
+                TierLamportConfig::DISCRIMINATOR,
+                TierLamportConfig::INIT_SPACE,
+            ),
+            (
+                user_registry_pda(&reporter),
+                TransactionRegistry::DISCRIMINATOR,
+                TransactionRegistry::INIT_SPACE,
+            ),
+            (
+                program_stats_pda(&system_program::ID),
+                ProgramStats::DISCRIMINATOR,
+                ProgramStats::INIT_SPACE,
+            ),
+        ] {
+            ctx.set_account(&address, &zeroed_account(discriminator, space).into());
+        }
+
+        Self { ctx }
+    }
+
+    async fn send(&mut self, ix: Instruction) -> std::result::Result<(), TransactionError> {
+        self.send_all(&[ix]).await
+    }
+
+    async fn send_all(&mut self, ixs: &[Instruction]) -> std::result::Result<(), TransactionError> {
+        let blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.ctx.payer.pubkey()),
+            &[&self.ctx.payer],
+            blockhash,
+        );
+        self.ctx
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .map_err(|err| err.unwrap())
+    }
+
+    // Send with an extra signer, e.g. a reporter other than the fee payer
+    async fn send_as(
+        &mut self,
+        ix: Instruction,
+        signer: &Keypair,
+    ) -> std::result::Result<(), TransactionError> {
+        self.send_signed(ix, &[signer]).await
+    }
+
+    async fn send_signed(
+        &mut self,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), TransactionError> {
+        let blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.ctx
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .map_err(|err| err.unwrap())
+    }
+
+    // Move Clock::slot without warping; warp_to_slot flushes the accounts db to disk
+    async fn set_slot(&mut self, slot: u64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.slot = slot;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    async fn set_time(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    async fn set_epoch(&mut self, epoch: u64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.epoch = epoch;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    // Funded non-authority reporter with its ReporterRecord pre-seeded
+    fn add_reporter(&mut self) -> Keypair {
+        let reporter = Keypair::new();
+        self.ctx.set_account(
+            &reporter.pubkey(),
+            &SolanaAccount::new(1_000_000_000, 0, &system_program::ID).into(),
+        );
+        self.ctx.set_account(
+            &reporter_pda(&reporter.pubkey()),
+            &zeroed_account(ReporterRecord::DISCRIMINATOR, ReporterRecord::INIT_SPACE).into(),
+        );
+        reporter
+    }
+
+    async fn initialize(&mut self) -> std::result::Result<(), TransactionError> {
+        let ix = initialize_ix(self.ctx.payer.pubkey());
+        self.send(ix).await
+    }
+
+    fn register_accounts(&self) -> txguard::accounts::RegisterTxOutcome {
+        register_accounts_for(self.ctx.payer.pubkey())
+    }
+
+    async fn register_as(
+        &mut self,
+        reporter: &Keypair,
+        nonce: u64,
+    ) -> std::result::Result<(), TransactionError> {
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params: TxOutcomeParams {
+                success: true,
+                nonce,
+                ..Default::default()
+            },
+        };
+        let accounts = register_accounts_for(reporter.pubkey());
+        self.send_as(instruction(accounts, data), reporter).await
+    }
+
+    async fn set_report_cooldown(&mut self, min_slots_between_reports: u64) {
+        let accounts = txguard::accounts::UpdateConfig {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::SetReportCooldown {
+            _namespace: String::new(),
+            min_slots_between_reports,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn set_freshness_bound(&mut self, max_report_staleness_slots: u64) {
+        let accounts = txguard::accounts::UpdateConfig {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::SetFreshnessBound {
+            _namespace: String::new(),
+            max_report_staleness_slots,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn set_health_threshold(
+        &mut self,
+        min_success_rate_bps: u16,
+        use_lower_bound: bool,
+    ) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::UpdateConfig {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::SetHealthThreshold {
+            _namespace: String::new(),
+            min_success_rate_bps,
+            use_lower_bound,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn set_bulk_reporter(&mut self, reporter: Pubkey, bulk_reporter: bool) {
+        let accounts = txguard::accounts::SetBulkReporter {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+            reporter_record: reporter_pda(&reporter),
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::SetBulkReporter {
+            _namespace: String::new(),
+            reporter,
+            bulk_reporter,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn set_backfill_reporter(&mut self, reporter: Pubkey, backfill_reporter: bool) {
+        let accounts = txguard::accounts::SetBulkReporter {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+            reporter_record: reporter_pda(&reporter),
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::SetBackfillReporter {
+            _namespace: String::new(),
+            reporter,
+            backfill_reporter,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn set_max_report_age(&mut self, max_report_age_slots: u64) {
+        let accounts = txguard::accounts::UpdateConfig {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::SetMaxReportAge {
+            _namespace: String::new(),
+            max_report_age_slots,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn set_reward_points(&mut self, success: u64, failure: u64, max_per_slot: u64) {
+        let accounts = txguard::accounts::UpdateConfig {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::SetRewardPoints {
+            _namespace: String::new(),
+            success_report_points: success,
+            failure_report_points: failure,
+            max_points_per_slot: max_per_slot,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn open_dispute(
+        &mut self,
+        window_index: u8,
+        claimed_success: bool,
+    ) -> std::result::Result<(), TransactionError> {
+        let ix = self.open_dispute_ix(self.ctx.payer.pubkey(), window_index, claimed_success);
+        self.send(ix).await
+    }
+
+    // The Dispute PDA is pre-seeded so open_dispute's init_if_needed skips the CPI
+    fn open_dispute_ix(
+        &mut self,
+        disputer: Pubkey,
+        window_index: u8,
+        claimed_success: bool,
+    ) -> Instruction {
+        self.ctx.set_account(
+            &dispute_pda(window_index, &disputer),
+            &zeroed_account(Dispute::DISCRIMINATOR, Dispute::INIT_SPACE).into(),
+        );
+        let accounts = txguard::accounts::OpenDispute {
+            disputer,
+            registry: registry_pda(),
+            dispute: dispute_pda(window_index, &disputer),
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::OpenDispute {
+            namespace: String::new(),
+            window_index,
+            claimed_success,
+            bond_lamports: 0,
+        };
+        instruction(accounts, data)
+    }
+
+    async fn resolve_dispute(
+        &mut self,
+        window_index: u8,
+        accept: bool,
+    ) -> std::result::Result<(), TransactionError> {
+        let disputer = self.ctx.payer.pubkey();
+        self.resolve_dispute_of(disputer, window_index, accept)
+            .await
+    }
+
+    async fn resolve_dispute_of(
+        &mut self,
+        disputer: Pubkey,
+        window_index: u8,
+        accept: bool,
+    ) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::ResolveDispute {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+            registry: registry_pda(),
+            dispute: dispute_pda(window_index, &disputer),
+            disputer,
+            // Only a forfeited bond needs the treasury
+            treasury: (!accept).then(treasury_pda),
+        };
+        let data = txguard::instruction::ResolveDispute {
+            namespace: String::new(),
+            window_index,
+            accept,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    // Pre-seeds the session's archive PDA, as for disputes
+    async fn archive_registry(
+        &mut self,
+        archive_before_slot: u64,
+    ) -> std::result::Result<(), TransactionError> {
+        let archive = archive_pda(self.registry().await.session_id);
+        self.ctx.set_account(
+            &archive,
+            &zeroed_account(ArchiveRegistry::DISCRIMINATOR, ArchiveRegistry::INIT_SPACE).into(),
+        );
+        let accounts = txguard::accounts::CreateArchive {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+            registry: registry_pda(),
+            failure_catalog: catalog_pda(),
+            archive,
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::ArchiveRegistry {
+            namespace: String::new(),
+            archive_before_slot,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn flag_report(
+        &mut self,
+        reporter: Pubkey,
+        window_index: u8,
+    ) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::FlagReport {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+            reporter_record: reporter_pda(&reporter),
+        };
+        let data = txguard::instruction::FlagReport {
+            namespace: String::new(),
+            reporter,
+            window_index,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn set_points_multiplier(&mut self, points_multiplier: u16) {
+        let accounts = txguard::accounts::UpdateConfig {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::SetPointsMultiplier {
+            _namespace: String::new(),
+            points_multiplier,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn set_reward_mint(
+        &mut self,
+        mint: Pubkey,
+        claim_interval_slots: u64,
+    ) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::SetRewardMint {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+            reward_mint: mint,
+            mint_authority: reward_authority_pda(),
+        };
+        let data = txguard::instruction::SetRewardMint {
+            _namespace: String::new(),
+            mint,
+            claim_interval_slots,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn claim_rewards(
+        &mut self,
+        mint: Pubkey,
+        reporter_token_account: Pubkey,
+    ) -> std::result::Result<(), TransactionError> {
+        let reporter = self.ctx.payer.pubkey();
+        let accounts = txguard::accounts::ClaimRewards {
+            reporter,
+            config: config_pda(),
+            reporter_record: reporter_pda(&reporter),
+            reward_mint: mint,
+            reporter_token_account,
+            mint_authority: reward_authority_pda(),
+            token_program: txguard::TOKEN_PROGRAM_ID,
+        };
+        let data = txguard::instruction::ClaimRewards {
+            namespace: String::new(),
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn last_report_slot(&mut self, reporter: Pubkey) -> u64 {
+        let record: ReporterRecord = self.fetch(reporter_pda(&reporter)).await;
+        record.last_report_slot
+    }
+
+    async fn register(
+        &mut self,
+        success: bool,
+        failure_type: FailureType,
+        priority_fee_tier: u8,
+    ) -> std::result::Result<(), TransactionError> {
+        let outcome = if success {
+            Outcome::Success
+        } else {
+            Outcome::Failure
+        };
+        self.register_outcome(outcome, failure_type, priority_fee_tier)
+            .await
+    }
+
+    async fn register_outcome(
+        &mut self,
+        outcome: Outcome,
+        failure_type: FailureType,
+        priority_fee_tier: u8,
+    ) -> std::result::Result<(), TransactionError> {
+        let data = txguard::instruction::RegisterTxOutcome {
+            _namespace: String::new(),
+            outcome,
+            failure_type,
+            priority_fee_tier,
+        };
+        self.send(instruction(self.register_accounts(), data)).await
+    }
+
+    async fn register_v2(
+        &mut self,
+        params: TxOutcomeParams,
+    ) -> std::result::Result<(), TransactionError> {
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params,
+        };
+        self.send(instruction(self.register_accounts(), data)).await
+    }
+
+    // register_tx_outcome_v2 with extra remaining accounts, e.g. an epoch snapshot PDA
+    async fn register_with(
+        &mut self,
+        params: TxOutcomeParams,
+        remaining: &[Pubkey],
+    ) -> std::result::Result<(), TransactionError> {
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params,
+        };
+        let mut ix = instruction(self.register_accounts(), data);
+        ix.accounts.extend(
+            remaining
+                .iter()
+                .map(|address| AccountMeta::new(*address, false)),
+        );
+        self.send(ix).await
+    }
+
+    fn inline_success_ix(&self, target_program: Pubkey, priority_fee_tier: u8) -> Instruction {
+        let accounts = txguard::accounts::RecordInlineSuccess {
+            base: self.register_accounts(),
+            target_program,
+            program_stats: program_stats_pda(&target_program),
+            instructions: solana_sdk::sysvar::instructions::ID,
+            payer: self.ctx.payer.pubkey(),
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::RecordInlineSuccess {
+            _namespace: String::new(),
+            priority_fee_tier,
+        };
+        instruction(accounts, data)
+    }
+
+    // Stand-in for the tracked swap: a plain system program transfer
+    fn transfer_ix(&self) -> Instruction {
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        Instruction {
+            program_id: system_program::ID,
+            accounts: vec![
+                AccountMeta::new(self.ctx.payer.pubkey(), true),
+                AccountMeta::new(Pubkey::new_unique(), false),
+            ],
+            data,
+        }
+    }
+
+    // get_summary's return data is only set on-chain, so compute it from the accounts
+    async fn summary(&mut self) -> RegistrySummary {
+        let registry = self.registry().await;
+        let catalog = self.catalog().await;
+        let stats = self.stats().await;
+        RegistrySummary::from_accounts(&registry, &catalog, &stats)
+    }
+
+    // The treasury is pre-seeded so set_report_fee's init_if_needed skips the CPI
+    async fn set_report_fee(&mut self, report_fee_lamports: u64) {
+        self.ctx.set_account(
+            &treasury_pda(),
+            &zeroed_account(Treasury::DISCRIMINATOR, Treasury::INIT_SPACE).into(),
+        );
+        let accounts = txguard::accounts::SetReportFee {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+            treasury: treasury_pda(),
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::SetReportFee {
+            _namespace: String::new(),
+            report_fee_lamports,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    fn withdraw_ix(&self, authority: Pubkey, recipient: Pubkey, amount: u64) -> Instruction {
+        let accounts = txguard::accounts::WithdrawTreasury {
+            authority,
+            config: config_pda(),
+            treasury: treasury_pda(),
+            recipient,
+        };
+        let data = txguard::instruction::WithdrawTreasury {
+            _namespace: String::new(),
+            amount,
+        };
+        instruction(accounts, data)
+    }
+
+    async fn lamports(&mut self, address: Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(address).await.unwrap()
+    }
+
+    async fn set_crank_config(&mut self, crank_interval_slots: u64, crank_reward_lamports: u64) {
+        let accounts = txguard::accounts::UpdateConfig {
+            authority: self.ctx.payer.pubkey(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::SetCrankConfig {
+            _namespace: String::new(),
+            crank_interval_slots,
+            crank_reward_lamports,
+        };
+        self.send(instruction(accounts, data)).await.unwrap();
+    }
+
+    async fn crank(&mut self, cranker: &Keypair) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::CrankAggregate {
+            cranker: cranker.pubkey(),
+            config: config_pda(),
+            registry: registry_pda(),
+            failure_catalog: catalog_pda(),
+            treasury: treasury_pda(),
+            daily_stats: Some(daily_pda()),
+        };
+        let data = txguard::instruction::CrankAggregate {
+            _namespace: String::new(),
+        };
+        self.send_as(instruction(accounts, data), cranker).await
+    }
+
+    async fn register_hourly(
+        &mut self,
+        success: bool,
+        failure_type: FailureType,
+        nonce: u64,
+    ) -> std::result::Result<(), TransactionError> {
+        let mut accounts = self.register_accounts();
+        accounts.hourly_stats = Some(hourly_pda());
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params: TxOutcomeParams {
+                success,
+                failure_type,
+                nonce,
+                ..Default::default()
+            },
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn register_seasonal(
+        &mut self,
+        params: TxOutcomeParams,
+    ) -> std::result::Result<(), TransactionError> {
+        let mut accounts = self.register_accounts();
+        accounts.seasonality_stats = Some(seasonality_pda());
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn register_latency(
+        &mut self,
+        params: TxOutcomeParams,
+    ) -> std::result::Result<(), TransactionError> {
+        let mut accounts = self.register_accounts();
+        accounts.latency_stats = Some(latency_pda());
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    // register_tx_outcome_with_metadata with every metadata-fed stats account supplied
+    async fn register_metadata(
+        &mut self,
+        params: TxOutcomeParams,
+        metadata: TxMetadata,
+    ) -> std::result::Result<(), TransactionError> {
+        let mut accounts = self.register_accounts();
+        accounts.compute_stats = Some(compute_pda());
+        accounts.tx_size_stats = Some(tx_size_pda());
+        accounts.instruction_stats = Some(instructions_pda());
+        accounts.blockhash_age_stats = Some(blockhash_age_pda());
+        accounts.route_stats = Some(routes_pda());
+        let data = txguard::instruction::RegisterTxOutcomeWithMetadata {
+            _namespace: String::new(),
+            params,
+            metadata,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn register_tier_daily(
+        &mut self,
+        tier_daily: Pubkey,
+        priority_fee_tier: u8,
+        nonce: u64,
+    ) -> std::result::Result<(), TransactionError> {
+        let mut accounts = self.register_accounts();
+        accounts.tier_daily = Some(tier_daily);
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params: TxOutcomeParams {
+                success: true,
+                priority_fee_tier,
+                nonce,
+                ..Default::default()
+            },
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn tier_trend(
+        &mut self,
+        tier: u8,
+        days: u8,
+        snapshots: &[Pubkey],
+    ) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::GetTierTrend {
+            priority_fee_stats: priority_pda(),
+        };
+        let data = txguard::instruction::GetTierTrend {
+            namespace: String::new(),
+            tier,
+            days,
+        };
+        let mut ix = instruction(accounts, data);
+        ix.accounts.extend(
+            snapshots
+                .iter()
+                .map(|address| AccountMeta::new_readonly(*address, false)),
+        );
+        self.send(ix).await
+    }
+
+    fn reset_seasonality_ix(&self, authority: Pubkey) -> Instruction {
+        let accounts = txguard::accounts::ResetSeasonality {
+            authority,
+            config: config_pda(),
+            seasonality_stats: seasonality_pda(),
+        };
+        let data = txguard::instruction::ResetSeasonality {
+            _namespace: String::new(),
+        };
+        instruction(accounts, data)
+    }
+
+    fn reset_failure_types_ix(&self, authority: Pubkey, types_to_reset: Vec<u8>) -> Instruction {
+        let accounts = txguard::accounts::ResetFailureTypes {
+            authority,
+            config: config_pda(),
+            failure_catalog: catalog_pda(),
+        };
+        let data = txguard::instruction::ResetFailureTypes {
+            namespace: String::new(),
+            types_to_reset,
+        };
+        instruction(accounts, data)
+    }
+
+    async fn register_daily(
+        &mut self,
+        params: TxOutcomeParams,
+    ) -> std::result::Result<(), TransactionError> {
+        let mut accounts = self.register_accounts();
+        accounts.daily_stats = Some(daily_pda());
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn record_failure(
+        &mut self,
+        failure_type: FailureType,
+    ) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::RecordFailure {
+            payer: self.ctx.payer.pubkey(),
+            failure_catalog: catalog_pda(),
+        };
+        let data = txguard::instruction::RecordFailure {
+            _namespace: String::new(),
+            failure_type,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn update_priority_fee(&mut self, tier: u8) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::UpdatePriorityFee {
+            payer: self.ctx.payer.pubkey(),
+            priority_fee_stats: priority_pda(),
+        };
+        let data = txguard::instruction::UpdatePriorityFee {
+            _namespace: String::new(),
+            tier,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn fetch<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self
+            .ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        T::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    // Overwrite a program account in place, keeping its allocated size
+    async fn store<T: AccountSerialize>(&mut self, address: Pubkey, value: &T) {
+        let mut account = self
+            .ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+        self.ctx.set_account(&address, &account.into());
+    }
+
+    // Config and catalog for a second namespace, without running initialize
+    async fn seed_namespace(&mut self, namespace: &str, authority: Pubkey) {
+        let config_address = pda(&[b"config", namespace.as_bytes()]);
+        let catalog_address = pda(&[b"catalog", namespace.as_bytes()]);
+        self.ctx.set_account(
+            &config_address,
+            &zeroed_account(GuardConfig::DISCRIMINATOR, GuardConfig::INIT_SPACE).into(),
+        );
+        self.ctx.set_account(
+            &catalog_address,
+            &zeroed_account(FailureCatalog::DISCRIMINATOR, FailureCatalog::INIT_SPACE).into(),
+        );
+
+        let mut config: GuardConfig = self.fetch(config_address).await;
+        config.authority = authority;
+        config.namespace = namespace.to_string();
+        self.store(config_address, &config).await;
+        let mut catalog: FailureCatalog = self.fetch(catalog_address).await;
+        catalog.reset();
+        self.store(catalog_address, &catalog).await;
+    }
+
+    async fn merge_into_parent(
+        &mut self,
+        child_namespace: &str,
+        zero_child: bool,
+    ) -> std::result::Result<(), TransactionError> {
+        let accounts = txguard::accounts::MergeIntoParent {
+            authority: self.ctx.payer.pubkey(),
+            child_config: pda(&[b"config", child_namespace.as_bytes()]),
+            parent_config: config_pda(),
+            child_catalog: pda(&[b"catalog", child_namespace.as_bytes()]),
+            parent_catalog: catalog_pda(),
+        };
+        let data = txguard::instruction::MergeIntoParent {
+            child_namespace: child_namespace.to_string(),
+            parent_namespace: String::new(),
+            zero_child,
+        };
+        self.send(instruction(accounts, data)).await
+    }
+
+    async fn registry(&mut self) -> TransactionRegistry {
+        self.fetch(registry_pda()).await
+    }
+
+    async fn catalog(&mut self) -> FailureCatalog {
+        self.fetch(catalog_pda()).await
+    }
+
+    async fn stats(&mut self) -> PriorityFeeStats {
+        self.fetch(priority_pda()).await
+    }
+}
+
+fn register_accounts_for(reporter: Pubkey) -> txguard::accounts::RegisterTxOutcome {
+    txguard::accounts::RegisterTxOutcome {
+        payer: reporter,
+        registry: registry_pda(),
+        failure_catalog: catalog_pda(),
+        priority_fee_stats: priority_pda(),
+        config: config_pda(),
+        registry_config: registry_config_pda(),
+        epoch_registry: None,
+        reporter_record: reporter_pda(&reporter),
+        treasury: None,
+        hourly_stats: None,
+        daily_stats: None,
+        seasonality_stats: None,
+        tier_daily: None,
+        compute_stats: None,
+        latency_stats: None,
+        tx_size_stats: None,
+        instruction_stats: None,
+        blockhash_age_stats: None,
+        route_stats: None,
+        tag_stats: None,
+        system_program: system_program::ID,
+    }
+}
+
+fn registry_config_ix(
+    authority: Pubkey,
+    max_tx_count: Option<u64>,
+    buffer_size: u16,
+    strict_failure_types: bool,
+    observer: Option<Pubkey>,
+) -> Instruction {
+    let accounts = txguard::accounts::UpdateRegistryConfig {
+        authority,
+        config: config_pda(),
+        registry: registry_pda(),
+        registry_config: registry_config_pda(),
+        system_program: system_program::ID,
+    };
+    let data = txguard::instruction::UpdateRegistryConfig {
+        _namespace: String::new(),
+        max_tx_count,
+        buffer_size,
+        strict_failure_types,
+        observer,
+    };
+    instruction(accounts, data)
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: txguard::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn assert_error(result: std::result::Result<(), TransactionError>, expected: TxGuardError) {
+    assert_error_at(result, 0, expected);
+}
+
+fn assert_error_at(
+    result: std::result::Result<(), TransactionError>,
+    index: u8,
+    expected: TxGuardError,
+) {
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionError::InstructionError(index, InstructionError::Custom(expected.into())),
+    );
+}
+
+#[tokio::test]
+async fn initialize_sets_up_empty_state() {
+    let mut env = TestEnv::uninitialized().await;
+    env.initialize().await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 0);
+    assert_eq!(registry.success_count, 0);
+    assert_eq!(registry.failure_count, 0);
+    assert_eq!(registry.cursor, 0);
+    assert_eq!(
+        registry.last_100_outcomes,
+        vec![txguard::OUTCOME_PENDING; 100]
+    );
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.slippage_exceeded, 0);
+    assert_eq!(catalog.other, 0);
+    assert_eq!(catalog.first_occurrence, vec![0; txguard::FAILURE_SLOTS]);
+
+    let stats = env.stats().await;
+    assert_eq!(stats.tiers, vec![0; 5]);
+
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert_eq!(config.authority, env.ctx.payer.pubkey());
+    assert!(!config.epoch_scoped);
+}
+
+#[tokio::test]
+async fn only_the_authority_can_reinitialize_the_default_namespace() {
+    let mut env = TestEnv::new().await;
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+
+    let outsider = Keypair::new();
+    assert_error(
+        env.send_as(initialize_ix(outsider.pubkey()), &outsider)
+            .await,
+        TxGuardError::Unauthorized,
+    );
+    assert_eq!(env.registry().await.tx_count, 1);
+
+    env.initialize().await.unwrap();
+    assert_eq!(env.registry().await.tx_count, 0);
+}
+
+fn program_data_pda() -> Pubkey {
+    bpf_loader_upgradeable::get_program_data_address(&txguard::ID)
+}
+
+// UpgradeableLoaderState::ProgramData as bincode lays it out: the variant, the
+// deployment slot and the optional upgrade authority
+fn program_data_account(upgrade_authority: Option<Pubkey>) -> SolanaAccount {
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    match upgrade_authority {
+        Some(authority) => {
+            data.push(1);
+            data.extend_from_slice(authority.as_ref());
+        }
+        None => data.push(0),
+    }
+    SolanaAccount {
+        lamports: 1_000_000_000,
+        data,
+        owner: bpf_loader_upgradeable::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+// Accounts as the first release laid them out, before any field was appended
+fn baseline_account(discriminator: &[u8], fields: &[u8]) -> SolanaAccount {
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(fields);
+    SolanaAccount {
+        lamports: 1_000_000_000,
+        data,
+        owner: txguard::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn migrate_registry_upgrades_baseline_accounts() {
+    let mut env = TestEnv::uninitialized().await;
+
+    // tx_count, success_count, failure_count, last_100_outcomes, cursor
+    let mut registry = Vec::new();
+    for count in [3u64, 2, 1] {
+        registry.extend_from_slice(&count.to_le_bytes());
+    }
+    registry.extend_from_slice(&100u32.to_le_bytes());
+    let mut outcomes = vec![txguard::OUTCOME_PENDING; 100];
+    outcomes[..3].copy_from_slice(&[1, 1, 0]);
+    registry.extend_from_slice(&outcomes);
+    registry.push(3);
+    // Six u32 failure counters, slippage_exceeded through other
+    let catalog: Vec<u8> = [0u32, 0, 1, 0, 0, 0]
+        .iter()
+        .flat_map(|count| count.to_le_bytes())
+        .collect();
+    let mut stats = 5u32.to_le_bytes().to_vec();
+    for count in [0u64, 1, 2, 0, 0] {
+        stats.extend_from_slice(&count.to_le_bytes());
+    }
+    for (address, discriminator, fields) in [
+        (registry_pda(), TransactionRegistry::DISCRIMINATOR, registry),
+        (catalog_pda(), FailureCatalog::DISCRIMINATOR, catalog),
+        (priority_pda(), PriorityFeeStats::DISCRIMINATOR, stats),
+    ] {
+        env.ctx
+            .set_account(&address, &baseline_account(discriminator, &fields).into());
+    }
+    assert!(env.register(true, FailureType::Slippage, 1).await.is_err());
+    let upgrade_authority = env.ctx.payer.pubkey();
+    env.ctx.set_account(
+        &program_data_pda(),
+        &program_data_account(Some(upgrade_authority)).into(),
+    );
+
+    let accounts = txguard::accounts::MigrateRegistry {
+        payer: env.ctx.payer.pubkey(),
+        registry: registry_pda(),
+        failure_catalog: catalog_pda(),
+        priority_fee_stats: priority_pda(),
+        config: config_pda(),
+        program_data: Some(program_data_pda()),
+        system_program: system_program::ID,
+    };
+    let data = txguard::instruction::MigrateRegistry {
+        namespace: String::new(),
+    };
+    let mut ix = instruction(accounts, data);
+    ix.accounts.push(AccountMeta::new(hourly_pda(), false));
+    env.send(ix.clone()).await.unwrap();
+    // Repeating it changes nothing
+    env.send(ix).await.unwrap();
+
+    let account = env
+        .ctx
+        .banks_client
+        .get_account(registry_pda())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), 8 + TransactionRegistry::INIT_SPACE);
+    let registry = env.registry().await;
+    assert_eq!(
+        (
+            registry.tx_count,
+            registry.success_count,
+            registry.failure_count
+        ),
+        (3, 2, 1)
+    );
+    assert_eq!(registry.last_100_outcomes, outcomes);
+    assert_eq!(registry.cursor, 3);
+    assert_eq!(registry.outcome_tiers, vec![txguard::TIER_UNKNOWN; 100]);
+    assert_eq!(registry.send_methods.len(), txguard::SEND_METHODS);
+    assert_eq!(
+        registry.blockhash_validity_window,
+        txguard::DEFAULT_BLOCKHASH_VALIDITY_WINDOW
+    );
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.mev_detected, 1);
+    assert_eq!(catalog.first_occurrence, vec![0; txguard::FAILURE_SLOTS]);
+    let stats = env.stats().await;
+    assert_eq!(stats.tiers, vec![0, 1, 2, 0, 0]);
+    assert_eq!(stats.tier_successes, vec![0; 5]);
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert_eq!(config.authority, env.ctx.payer.pubkey());
+
+    // Reports land on top of the migrated counts
+    env.register(false, FailureType::Mev, 1).await.unwrap();
+    let registry = env.registry().await;
+    assert_eq!((registry.tx_count, registry.failure_count), (4, 2));
+    assert_eq!(registry.last_100_outcomes[3], txguard::OUTCOME_FAILURE);
+    assert_eq!(registry.outcome_tiers[3], 1);
+    assert_eq!(env.catalog().await.mev_detected, 2);
+    assert_eq!(env.stats().await.tiers, vec![0, 2, 2, 0, 0]);
+}
+
+#[tokio::test]
+async fn only_the_upgrade_authority_can_claim_a_namespace_by_migrating() {
+    let mut env = TestEnv::uninitialized().await;
+    let upgrade_authority = env.ctx.payer.pubkey();
+    env.ctx.set_account(
+        &program_data_pda(),
+        &program_data_account(Some(upgrade_authority)).into(),
+    );
+    let migrate_ix = |payer: Pubkey, program_data: Option<Pubkey>| {
+        let accounts = txguard::accounts::MigrateRegistry {
+            payer,
+            registry: registry_pda(),
+            failure_catalog: catalog_pda(),
+            priority_fee_stats: priority_pda(),
+            config: config_pda(),
+            program_data,
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::MigrateRegistry {
+            namespace: String::new(),
+        };
+        instruction(accounts, data)
+    };
+
+    // Someone racing the upgrade can't take the namespace, with or without ProgramData
+    let racer = env.add_reporter();
+    for program_data in [Some(program_data_pda()), None] {
+        assert_error(
+            env.send_as(migrate_ix(racer.pubkey(), program_data), &racer)
+                .await,
+            TxGuardError::Unauthorized,
+        );
+    }
+    // Nor can anyone once the program is immutable
+    env.ctx
+        .set_account(&program_data_pda(), &program_data_account(None).into());
+    assert_error(
+        env.send(migrate_ix(upgrade_authority, Some(program_data_pda())))
+            .await,
+        TxGuardError::Unauthorized,
+    );
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert_eq!(config.authority, Pubkey::default());
+}
+
+#[tokio::test]
+async fn migrate_registry_rejects_unknown_accounts() {
+    let mut env = TestEnv::new().await;
+    let accounts = txguard::accounts::MigrateRegistry {
+        payer: env.ctx.payer.pubkey(),
+        registry: registry_pda(),
+        failure_catalog: catalog_pda(),
+        priority_fee_stats: priority_pda(),
+        config: config_pda(),
+        program_data: None,
+        system_program: system_program::ID,
+    };
+    let data = txguard::instruction::MigrateRegistry {
+        namespace: String::new(),
+    };
+    let mut ix = instruction(accounts, data);
+    ix.accounts.push(AccountMeta::new(
+        reporter_pda(&env.ctx.payer.pubkey()),
+        false,
+    ));
+    assert_error(env.send(ix).await, TxGuardError::InvalidMigrationAccount);
+}
+
+#[tokio::test]
+async fn register_success_updates_all_accounts() {
+    let mut env = TestEnv::new().await;
+    env.register(true, FailureType::Slippage, 2).await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 1);
+    assert_eq!(registry.success_count, 1);
+    assert_eq!(registry.failure_count, 0);
+    assert_eq!(registry.cursor, 1);
+    assert_eq!(registry.last_100_outcomes[0], txguard::OUTCOME_SUCCESS);
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.slippage_exceeded, 0);
+
+    let stats = env.stats().await;
+    assert_eq!(stats.tiers, vec![0, 0, 1, 0, 0]);
+    assert_eq!(stats.tier_successes, vec![0, 0, 1, 0, 0]);
+
+    let reporter: ReporterRecord = env.fetch(reporter_pda(&env.ctx.payer.pubkey())).await;
+    assert_eq!(reporter.reporter, env.ctx.payer.pubkey());
+    assert_eq!(reporter.successes_reported, 1);
+    assert_eq!(reporter.recent_outcomes[0], txguard::OUTCOME_SUCCESS);
+}
+
+#[tokio::test]
+async fn register_failure_updates_catalog() {
+    let mut env = TestEnv::new().await;
+    env.register(false, FailureType::Mev, 4).await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 1);
+    assert_eq!(registry.success_count, 0);
+    assert_eq!(registry.failure_count, 1);
+    assert_eq!(registry.last_100_outcomes[0], txguard::OUTCOME_FAILURE);
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.mev_detected, 1);
+    assert!(catalog.first_occurrence[2] > 0);
+    assert_eq!(catalog.first_occurrence[2], catalog.last_occurrence[2]);
+
+    let stats = env.stats().await;
+    assert_eq!(stats.tiers, vec![0, 0, 0, 0, 1]);
+    assert_eq!(stats.tier_successes, vec![0; 5]);
+}
+
+#[tokio::test]
+async fn register_rejects_invalid_tier() {
+    let mut env = TestEnv::new().await;
+    assert_error(
+        env.register(true, FailureType::Slippage, 5).await,
+        TxGuardError::InvalidPriorityFeeTier,
+    );
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 0);
+    assert_eq!(registry.cursor, 0);
+}
+
+#[tokio::test]
+async fn register_rejects_count_overflow() {
+    let mut env = TestEnv::new().await;
+
+    let mut registry = env.registry().await;
+    registry.tx_count = u64::MAX;
+    env.store(registry_pda(), &registry).await;
+
+    assert_error(
+        env.register(true, FailureType::Slippage, 0).await,
+        TxGuardError::CountOverflow,
+    );
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, u64::MAX);
+    assert_eq!(registry.success_count, 0);
+    assert_eq!(registry.cursor, 0);
+}
+
+#[tokio::test]
+async fn record_failure_rejects_counter_overflow() {
+    let mut env = TestEnv::new().await;
+
+    let mut catalog = env.catalog().await;
+    catalog.dropped_tx = u32::MAX;
+    env.store(catalog_pda(), &catalog).await;
+
+    assert_error(
+        env.record_failure(FailureType::Dropped).await,
+        TxGuardError::CountOverflow,
+    );
+    assert_eq!(env.catalog().await.dropped_tx, u32::MAX);
+}
+
+#[tokio::test]
+async fn circular_buffer_wraps_after_cursor_99() {
+    let mut env = TestEnv::new().await;
+
+    // Start the window at its last slot instead of sending 99 reports
+    let mut registry = env.registry().await;
+    registry.cursor = 99;
+    env.store(registry_pda(), &registry).await;
+
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+    let registry = env.registry().await;
+    assert_eq!(registry.cursor, 0);
+    assert_eq!(registry.last_100_outcomes[99], txguard::OUTCOME_SUCCESS);
+
+    env.register(false, FailureType::Liquidity, 0)
+        .await
+        .unwrap();
+    let registry = env.registry().await;
+    assert_eq!(registry.cursor, 1);
+    assert_eq!(registry.last_100_outcomes[0], txguard::OUTCOME_FAILURE);
+    assert_eq!(registry.last_100_outcomes.len(), 100);
+    assert_eq!(registry.tx_count, 2);
+}
+
+#[tokio::test]
+async fn circular_buffer_overwrites_oldest_entry_after_100_reports() {
+    let mut env = TestEnv::new().await;
+
+    for nonce in 0..101u64 {
+        let params = TxOutcomeParams {
+            success: nonce != 0,
+            nonce,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 101);
+    assert_eq!(registry.failure_count, 1);
+    assert_eq!(registry.cursor, 1);
+    // The failure at slot 0 has been overwritten by report #101
+    assert_eq!(
+        registry.last_100_outcomes,
+        vec![txguard::OUTCOME_SUCCESS; 100]
+    );
+}
+
+#[tokio::test]
+async fn record_failure_leaves_registry_untouched() {
+    let mut env = TestEnv::new().await;
+    env.record_failure(FailureType::InsufficientFunds)
+        .await
+        .unwrap();
+    env.record_failure(FailureType::Unassigned7).await.unwrap();
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.insufficient_funds, 1);
+    assert_eq!(catalog.other, 1);
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 0);
+    assert_eq!(registry.failure_count, 0);
+    assert_eq!(
+        registry.last_100_outcomes,
+        vec![txguard::OUTCOME_PENDING; 100]
+    );
+}
+
+#[tokio::test]
+async fn update_priority_fee_accepts_boundary_tiers() {
+    let mut env = TestEnv::new().await;
+    env.update_priority_fee(0).await.unwrap();
+    env.update_priority_fee(4).await.unwrap();
+
+    let stats = env.stats().await;
+    assert_eq!(stats.tiers, vec![1, 0, 0, 0, 1]);
+    assert_eq!(env.registry().await.tx_count, 0);
+}
+
+#[tokio::test]
+async fn update_priority_fee_rejects_tier_5() {
+    let mut env = TestEnv::new().await;
+    assert_error(
+        env.update_priority_fee(5).await,
+        TxGuardError::InvalidPriorityFeeTier,
+    );
+    assert_error(
+        env.update_priority_fee(u8::MAX).await,
+        TxGuardError::InvalidPriorityFeeTier,
+    );
+
+    assert_eq!(env.stats().await.tiers, vec![0; 5]);
+}
+
+#[tokio::test]
+async fn dual_report_updates_global_and_user_registries() {
+    let mut env = TestEnv::new().await;
+    let accounts = txguard::accounts::RegisterTxOutcomeDual {
+        base: env.register_accounts(),
+        user_registry: user_registry_pda(&env.ctx.payer.pubkey()),
+        payer: env.ctx.payer.pubkey(),
+        system_program: system_program::ID,
+    };
+    let data = txguard::instruction::RegisterTxOutcomeDual {
+        _namespace: String::new(),
+        params: TxOutcomeParams {
+            failure_type: FailureType::Mev,
+            priority_fee_tier: 3,
+            ..Default::default()
+        },
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 1);
+    assert_eq!(registry.failure_count, 1);
+
+    let user_registry: TransactionRegistry =
+        env.fetch(user_registry_pda(&env.ctx.payer.pubkey())).await;
+    assert_eq!(user_registry.tx_count, 1);
+    assert_eq!(user_registry.failure_count, 1);
+    assert_eq!(user_registry.last_100_outcomes.len(), 100);
+}
+
+#[tokio::test]
+async fn inline_success_records_after_target_instruction() {
+    let mut env = TestEnv::new().await;
+    let ixs = [
+        env.transfer_ix(),
+        env.inline_success_ix(system_program::ID, 3),
+    ];
+    env.send_all(&ixs).await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 1);
+    assert_eq!(registry.success_count, 1);
+    assert_eq!(env.stats().await.tier_successes, vec![0, 0, 0, 1, 0]);
+
+    let program_stats: ProgramStats = env.fetch(program_stats_pda(&system_program::ID)).await;
+    assert_eq!(program_stats.program_id, system_program::ID);
+    assert_eq!(program_stats.success_count, 1);
+    assert!(program_stats.last_success_slot > 0);
+}
+
+#[tokio::test]
+async fn inline_success_requires_earlier_target_instruction() {
+    let mut env = TestEnv::new().await;
+
+    let alone = [env.inline_success_ix(system_program::ID, 0)];
+    assert_error(
+        env.send_all(&alone).await,
+        TxGuardError::MissingTargetInstruction,
+    );
+
+    // The tracked instruction has to come first; a later one proves nothing yet
+    let reversed = [
+        env.inline_success_ix(system_program::ID, 0),
+        env.transfer_ix(),
+    ];
+    assert_error(
+        env.send_all(&reversed).await,
+        TxGuardError::MissingTargetInstruction,
+    );
+
+    assert_eq!(env.registry().await.tx_count, 0);
+}
+
+#[tokio::test]
+async fn inline_success_rejects_txguard_as_target() {
+    let mut env = TestEnv::new().await;
+    env.ctx.set_account(
+        &program_stats_pda(&txguard::ID),
+        &zeroed_account(ProgramStats::DISCRIMINATOR, ProgramStats::INIT_SPACE).into(),
+    );
+    let ixs = [env.transfer_ix(), env.inline_success_ix(txguard::ID, 0)];
+    assert_error_at(
+        env.send_all(&ixs).await,
+        1,
+        TxGuardError::InvalidTargetProgram,
+    );
+}
+
+#[tokio::test]
+async fn summary_of_empty_registry_is_zeroed() {
+    let mut env = TestEnv::new().await;
+    let summary = env.summary().await;
+
+    assert_eq!(summary.tx_count, 0);
+    assert_eq!(summary.success_rate_bps, 0);
+    assert_eq!(summary.dominant_failure_type, 0);
+    assert_eq!(summary.dominant_failure_count, 0);
+    assert_eq!(summary.optimal_tier, 0);
+    assert_eq!(summary.total_tier_activity, 0);
+}
+
+#[tokio::test]
+async fn summary_aggregates_all_three_accounts() {
+    let mut env = TestEnv::new().await;
+    // Tier 1: 2/2 succeed; tier 3: 1/2; MEV is the most common failure
+    for (nonce, (success, failure_type, tier)) in [
+        (true, FailureType::Slippage, 1),
+        (true, FailureType::Slippage, 1),
+        (true, FailureType::Slippage, 3),
+        (false, FailureType::Mev, 3),
+        (false, FailureType::Mev, 0),
+        (false, FailureType::Bridge, 0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let params = TxOutcomeParams {
+            success,
+            failure_type,
+            priority_fee_tier: tier,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let summary = env.summary().await;
+    assert_eq!(summary.tx_count, 6);
+    assert_eq!(summary.success_count, 3);
+    assert_eq!(summary.failure_count, 3);
+    assert_eq!(summary.cursor, 6);
+    assert_eq!(summary.success_rate_bps, 5_000);
+    assert_eq!(summary.dominant_failure_type, 2);
+    assert_eq!(summary.dominant_failure_count, 2);
+    assert_eq!(summary.optimal_tier, 1);
+    assert_eq!(summary.total_tier_activity, 6);
+}
+
+#[tokio::test]
+async fn cooldown_rejects_reports_until_exact_boundary_slot() {
+    let mut env = TestEnv::new().await;
+    env.set_report_cooldown(10).await;
+    let reporter = env.add_reporter();
+
+    env.register_as(&reporter, 0).await.unwrap();
+    let first = env.last_report_slot(reporter.pubkey()).await;
+
+    env.set_slot(first + 9).await;
+    assert_error(
+        env.register_as(&reporter, 1).await,
+        TxGuardError::RateLimited,
+    );
+
+    env.set_slot(first + 10).await;
+    env.register_as(&reporter, 2).await.unwrap();
+    assert_eq!(env.last_report_slot(reporter.pubkey()).await, first + 10);
+    assert_eq!(env.registry().await.tx_count, 2);
+}
+
+#[tokio::test]
+async fn cooldown_is_per_reporter() {
+    let mut env = TestEnv::new().await;
+    env.set_report_cooldown(10).await;
+    let first = env.add_reporter();
+    let second = env.add_reporter();
+
+    env.register_as(&first, 0).await.unwrap();
+    env.register_as(&second, 1).await.unwrap();
+    assert_error(env.register_as(&first, 2).await, TxGuardError::RateLimited);
+}
+
+#[tokio::test]
+async fn cooldown_exempts_authority_and_bulk_reporters() {
+    let mut env = TestEnv::new().await;
+    env.set_report_cooldown(1_000).await;
+
+    // ctx.payer initialized the namespace, so it is the authority
+    for nonce in 0..3 {
+        let params = TxOutcomeParams {
+            success: true,
+            nonce,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let bulk = env.add_reporter();
+    env.set_bulk_reporter(bulk.pubkey(), true).await;
+    for nonce in 3..6 {
+        env.register_as(&bulk, nonce).await.unwrap();
+    }
+
+    // Revoking the flag puts the reporter back under the cooldown
+    env.set_bulk_reporter(bulk.pubkey(), false).await;
+    assert_error(env.register_as(&bulk, 6).await, TxGuardError::RateLimited);
+    assert_eq!(env.registry().await.tx_count, 6);
+}
+
+#[tokio::test]
+async fn batches_check_the_cooldown_and_dedup_once() {
+    let mut env = TestEnv::new().await;
+    env.set_report_cooldown(10).await;
+    let reporter = env.add_reporter();
+    let batch = |hashes: &[u8]| {
+        let reports = hashes
+            .iter()
+            .map(|byte| TxOutcomeParams {
+                success: true,
+                tx_sig_hash: [*byte; 32],
+                ..Default::default()
+            })
+            .collect();
+        let data = txguard::instruction::RegisterTxOutcomesBatch {
+            _namespace: String::new(),
+            reports,
+        };
+        instruction(register_accounts_for(reporter.pubkey()), data)
+    };
+
+    // Three reports in one slot, under a 10-slot cooldown
+    env.send_as(batch(&[1, 2, 3]), &reporter).await.unwrap();
+    assert_eq!(env.registry().await.tx_count, 3);
+    let record: ReporterRecord = env.fetch(reporter_pda(&reporter.pubkey())).await;
+    assert_eq!(record.successes_reported, 3);
+    let first = env.last_report_slot(reporter.pubkey()).await;
+    assert_error(
+        env.send_as(batch(&[4]), &reporter).await,
+        TxGuardError::RateLimited,
+    );
+
+    // A signature already recorded or repeated in the batch refuses all of it
+    env.set_slot(first + 10).await;
+    for hashes in [&[4, 3][..], &[5, 5], &[]] {
+        let expected = if hashes.is_empty() {
+            TxGuardError::EmptyBatch
+        } else {
+            TxGuardError::DuplicateReport
+        };
+        assert_error(env.send_as(batch(hashes), &reporter).await, expected);
+    }
+    assert_eq!(env.registry().await.tx_count, 3);
+    env.send_as(batch(&[4, 5]), &reporter).await.unwrap();
+    assert_eq!(env.registry().await.tx_count, 5);
+}
+
+#[tokio::test]
+async fn deadline_failures_are_tracked_apart_from_dropped_tx() {
+    let mut env = TestEnv::new().await;
+    let params = TxOutcomeParams {
+        success: false,
+        failure_type: FailureType::Deadline,
+        slots_in_flight: 151,
+        ..Default::default()
+    };
+    env.register_v2(params).await.unwrap();
+    env.register(false, FailureType::Deadline, 0).await.unwrap();
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.deadline_exceeded, 2);
+    assert_eq!(catalog.dropped_tx, 0);
+    assert_eq!(catalog.other, 0);
+    assert!(catalog.first_occurrence[txguard::FAILURE_DEADLINE as usize] > 0);
+    assert_eq!(env.registry().await.blockhash_validity_window, 150);
+}
+
+#[tokio::test]
+async fn deadline_failure_must_outlast_validity_window() {
+    let mut env = TestEnv::new().await;
+    let deadline = |slots_in_flight, nonce| TxOutcomeParams {
+        success: false,
+        failure_type: FailureType::Deadline,
+        slots_in_flight,
+        nonce,
+        ..Default::default()
+    };
+
+    assert_error(
+        env.register_v2(deadline(149, 0)).await,
+        TxGuardError::DeadlineNotExceeded,
+    );
+    env.register_v2(deadline(150, 1)).await.unwrap();
+
+    // A shorter window admits earlier expiries
+    let accounts = txguard::accounts::SetBlockhashValidityWindow {
+        authority: env.ctx.payer.pubkey(),
+        config: config_pda(),
+        registry: registry_pda(),
+    };
+    let data = txguard::instruction::SetBlockhashValidityWindow {
+        _namespace: String::new(),
+        blockhash_validity_window: 60,
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+    env.register_v2(deadline(60, 2)).await.unwrap();
+
+    assert_eq!(env.catalog().await.deadline_exceeded, 2);
+}
+
+#[tokio::test]
+async fn slot_quota_caps_reports_until_slot_advances() {
+    let mut env = TestEnv::new().await;
+    let accounts = txguard::accounts::UpdateConfig {
+        authority: env.ctx.payer.pubkey(),
+        config: config_pda(),
+    };
+    let data = txguard::instruction::SetSlotQuota {
+        _namespace: String::new(),
+        max_reports_per_slot: 2,
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+    let reporter = env.add_reporter();
+    env.set_slot(slot).await;
+    env.register_as(&reporter, 0).await.unwrap();
+    env.set_slot(slot).await;
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+
+    // The cap spans all reporters, the authority included
+    env.set_slot(slot).await;
+    assert_error(
+        env.register(false, FailureType::Liquidity, 0).await,
+        TxGuardError::SlotQuotaExceeded,
+    );
+
+    env.set_slot(slot + 1).await;
+    env.register(false, FailureType::Liquidity, 0)
+        .await
+        .unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 3);
+    assert_eq!(registry.current_slot, slot + 1);
+    assert_eq!(registry.reports_this_slot, 1);
+}
+
+#[tokio::test]
+async fn slot_quota_of_zero_is_unlimited() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+    for nonce in 0..5 {
+        env.set_slot(slot).await;
+        let params = TxOutcomeParams {
+            success: true,
+            nonce,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 5);
+    assert_eq!(registry.reports_this_slot, 5);
+}
+
+#[tokio::test]
+async fn report_fee_requires_treasury_account() {
+    let mut env = TestEnv::new().await;
+    env.set_report_fee(5_000).await;
+
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert_eq!(config.report_fee_lamports, 5_000);
+    assert_error(
+        env.register(true, FailureType::Slippage, 0).await,
+        TxGuardError::MissingTreasury,
+    );
+}
+
+#[tokio::test]
+async fn withdraw_treasury_keeps_rent_reserve() {
+    let mut env = TestEnv::new().await;
+    env.set_report_fee(5_000).await;
+
+    let balance = env.lamports(treasury_pda()).await;
+    let rent: Rent = env.ctx.banks_client.get_sysvar().await.unwrap();
+    let available = balance - rent.minimum_balance(8 + Treasury::INIT_SPACE);
+    let recipient = Pubkey::new_unique();
+
+    let ix = env.withdraw_ix(env.ctx.payer.pubkey(), recipient, available + 1);
+    assert_error(env.send(ix).await, TxGuardError::InsufficientTreasury);
+
+    let ix = env.withdraw_ix(env.ctx.payer.pubkey(), recipient, available);
+    env.send(ix).await.unwrap();
+    assert_eq!(env.lamports(recipient).await, available);
+    assert_eq!(env.lamports(treasury_pda()).await, balance - available);
+}
+
+#[tokio::test]
+async fn withdraw_treasury_is_authority_only() {
+    let mut env = TestEnv::new().await;
+    env.set_report_fee(5_000).await;
+    let intruder = env.add_reporter();
+
+    let ix = env.withdraw_ix(intruder.pubkey(), intruder.pubkey(), 1);
+    assert_error(env.send_as(ix, &intruder).await, TxGuardError::Unauthorized);
+}
+
+#[tokio::test]
+async fn failure_pie_lists_largest_share_first() {
+    let mut env = TestEnv::new().await;
+    assert_eq!(
+        env.catalog().await.format_failure_pie(),
+        "no failures recorded"
+    );
+
+    for failure_type in [
+        FailureType::Slippage,
+        FailureType::Slippage,
+        FailureType::Mev,
+        FailureType::Dropped,
+    ] {
+        env.record_failure(failure_type).await.unwrap();
+    }
+
+    let pie = env.catalog().await.format_failure_pie();
+    assert_eq!(
+        pie,
+        "SLIP: ████░░░░ 50%  MEV: ██░░░░░░ 25%  DROP: ██░░░░░░ 25%"
+    );
+}
+
+#[tokio::test]
+async fn failure_pie_fits_fixed_buffer() {
+    let mut env = TestEnv::new().await;
+    for failure_type in [
+        FailureType::Slippage,
+        FailureType::Liquidity,
+        FailureType::Mev,
+        FailureType::Dropped,
+        FailureType::InsufficientFunds,
+        FailureType::Other,
+        FailureType::Bridge,
+        FailureType::Deadline,
+    ] {
+        env.record_failure(failure_type).await.unwrap();
+    }
+
+    let pie = env.catalog().await.format_failure_pie();
+    assert!(pie.len() <= txguard::MAX_PIE_LEN);
+    assert!(pie.starts_with("SLIP: █░░░░░░░ 12%"));
+}
+
+#[tokio::test]
+async fn crank_pays_bounty_once_per_interval() {
+    let mut env = TestEnv::new().await;
+    env.set_report_fee(0).await;
+    env.set_crank_config(50, 25_000).await;
+    let cranker = env.add_reporter();
+    let balance = env.lamports(cranker.pubkey()).await;
+
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+    env.set_slot(slot).await;
+    env.crank(&cranker).await.unwrap();
+    assert_eq!(env.lamports(cranker.pubkey()).await, balance + 25_000);
+
+    env.set_slot(slot + 49).await;
+    assert_error(env.crank(&cranker).await, TxGuardError::CrankTooEarly);
+
+    env.set_slot(slot + 50).await;
+    env.crank(&cranker).await.unwrap();
+
+    let treasury: Treasury = env.fetch(treasury_pda()).await;
+    assert_eq!(treasury.total_crank_rewards, 50_000);
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert_eq!(config.last_crank_slot, slot + 50);
+}
+
+#[tokio::test]
+async fn crank_fails_cleanly_when_treasury_is_short() {
+    let mut env = TestEnv::new().await;
+    env.set_report_fee(0).await;
+    env.set_crank_config(50, 2_000_000_000).await;
+    let cranker = env.add_reporter();
+
+    assert_error(
+        env.crank(&cranker).await,
+        TxGuardError::InsufficientTreasury,
+    );
+
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert_eq!(config.last_crank_slot, 0);
+    let treasury: Treasury = env.fetch(treasury_pda()).await;
+    assert_eq!(treasury.total_crank_rewards, 0);
+}
+
+#[tokio::test]
+async fn max_tx_per_slot_keeps_the_peak() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+    // Three reports in one slot, then one in the next
+    for (nonce, slot) in [(0, slot), (1, slot), (2, slot), (3, slot + 1)] {
+        env.set_slot(slot).await;
+        let params = TxOutcomeParams {
+            success: true,
+            nonce,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.current_slot, slot + 1);
+    assert_eq!(registry.reports_this_slot, 1);
+    assert_eq!(registry.max_tx_per_slot, 3);
+}
+
+#[tokio::test]
+async fn hourly_buckets_roll_over_across_clock_jumps() {
+    let mut env = TestEnv::new().await;
+    let start = 1_700_000_000 / 3600 * 3600; // top of an hour
+    let hour = txguard::current_hour(start);
+
+    env.set_time(start).await;
+    env.register_hourly(true, FailureType::Slippage, 0)
+        .await
+        .unwrap();
+    env.register_hourly(false, FailureType::Mev, 1)
+        .await
+        .unwrap();
+    env.register_hourly(false, FailureType::Mev, 2)
+        .await
+        .unwrap();
+    env.register_hourly(false, FailureType::Slippage, 3)
+        .await
+        .unwrap();
+
+    // Next hour lands in its own bucket
+    env.set_time(start + 3600).await;
+    env.register_hourly(false, FailureType::Dropped, 4)
+        .await
+        .unwrap();
+
+    let stats: HourlyStats = env.fetch(hourly_pda()).await;
+    let window = stats.last_24_hours(hour + 1);
+    assert_eq!(window.len(), 24);
+    let (previous, current) = (&window[22], &window[23]);
+    assert_eq!((previous.hour, current.hour), (hour, hour + 1));
+    assert_eq!((previous.success_count, previous.failure_count), (1, 3));
+    assert_eq!(previous.dominant_failure_type, 2);
+    assert_eq!((current.success_count, current.failure_count), (0, 1));
+    assert_eq!(current.dominant_failure_type, 3);
+
+    // 24 hours later the first bucket is reused and starts from zero
+    env.set_time(start + 24 * 3600).await;
+    env.register_hourly(true, FailureType::Slippage, 5)
+        .await
+        .unwrap();
+
+    let stats: HourlyStats = env.fetch(hourly_pda()).await;
+    let window = stats.last_24_hours(hour + 24);
+    let reused = &window[23];
+    assert_eq!(reused.hour, hour + 24);
+    assert_eq!((reused.success_count, reused.failure_count), (1, 0));
+    assert_eq!(reused.dominant_failure_type, 0);
+    // hour + 1 is still inside the window; hour itself has aged out
+    assert_eq!(window[0].hour, hour + 1);
+    assert_eq!(window[0].failure_count, 1);
+}
+
+#[tokio::test]
+async fn hourly_buckets_report_stale_hours_as_empty() {
+    let mut env = TestEnv::new().await;
+    let start = 1_700_000_000 / 3600 * 3600;
+    let hour = txguard::current_hour(start);
+
+    env.set_time(start).await;
+    env.register_hourly(false, FailureType::Liquidity, 0)
+        .await
+        .unwrap();
+
+    // Two days on, nothing has been written to the old bucket's slot since
+    let stats: HourlyStats = env.fetch(hourly_pda()).await;
+    let window = stats.last_24_hours(hour + 48);
+    assert!(window
+        .iter()
+        .all(|b| b.success_count == 0 && b.failure_count == 0));
+}
+
+#[tokio::test]
+async fn daily_ring_aggregates_and_wraps_after_30_days() {
+    let mut env = TestEnv::new().await;
+    let start = 1_700_000_000 / 86_400 * 86_400; // midnight UTC
+    let day = txguard::current_day(start);
+    let report = |success, failure_type, priority_fee_tier, nonce| TxOutcomeParams {
+        success,
+        failure_type,
+        priority_fee_tier,
+        nonce,
+        ..Default::default()
+    };
+
+    env.set_time(start).await;
+    env.register_daily(report(true, FailureType::Slippage, 4, 0))
+        .await
+        .unwrap();
+    env.register_daily(report(false, FailureType::Liquidity, 1, 1))
+        .await
+        .unwrap();
+    env.register_daily(report(false, FailureType::Liquidity, 2, 2))
+        .await
+        .unwrap();
+
+    let stats: DailyStats = env.fetch(daily_pda()).await;
+    let today = &stats.days[(day % 30) as usize];
+    assert_eq!(today.day_index, day);
+    assert_eq!(
+        (today.tx_count, today.success_count, today.failure_count),
+        (3, 1, 2)
+    );
+    assert_eq!(today.top_failure_type, 1);
+    assert_eq!(today.avg_tier, 233);
+
+    // Day + 30 maps onto the same slot and replaces it
+    env.set_time(start + 30 * 86_400).await;
+    env.register_daily(report(true, FailureType::Slippage, 0, 3))
+        .await
+        .unwrap();
+
+    let stats: DailyStats = env.fetch(daily_pda()).await;
+    let slot = &stats.days[(day % 30) as usize];
+    assert_eq!(slot.day_index, day + 30);
+    assert_eq!((slot.tx_count, slot.failure_count), (1, 0));
+}
+
+#[tokio::test]
+async fn crank_opens_quiet_days_in_daily_ring() {
+    let mut env = TestEnv::new().await;
+    env.set_report_fee(0).await;
+    env.set_crank_config(0, 0).await;
+    let cranker = env.add_reporter();
+    let start = 1_700_000_000 / 86_400 * 86_400;
+    let day = txguard::current_day(start);
+
+    env.set_time(start).await;
+    env.register_daily(TxOutcomeParams::default())
+        .await
+        .unwrap();
+
+    // Thirty days later the crank clears the stale slot before anyone reports
+    env.set_time(start + 30 * 86_400).await;
+    env.crank(&cranker).await.unwrap();
+
+    let stats: DailyStats = env.fetch(daily_pda()).await;
+    let slot = &stats.days[(day % 30) as usize];
+    assert_eq!(slot.day_index, day + 30);
+    assert_eq!(slot.tx_count, 0);
+}
+
+#[tokio::test]
+async fn locked_registry_rejects_mutations() {
+    let mut env = TestEnv::new().await;
+    let mut registry = env.registry().await;
+    registry.locked = true;
+    env.store(registry_pda(), &registry).await;
+
+    assert_error(
+        env.register(true, FailureType::Slippage, 0).await,
+        TxGuardError::ReentrancyDetected,
+    );
+    assert_error(env.initialize().await, TxGuardError::ReentrancyDetected);
+    assert_eq!(env.registry().await.tx_count, 0);
+}
+
+#[tokio::test]
+async fn register_releases_registry_lock() {
+    let mut env = TestEnv::new().await;
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+    env.register(false, FailureType::Liquidity, 0)
+        .await
+        .unwrap();
+
+    let registry = env.registry().await;
+    assert!(!registry.locked);
+    assert_eq!(registry.tx_count, 2);
+}
+
+#[tokio::test]
+async fn wasted_fees_charge_overpay_against_cheapest_matching_tier() {
+    let mut env = TestEnv::new().await;
+    let mut stats = env.stats().await;
+    // Tier rates: 0 = 50%, 1 = 90%, 2 = 95%, 3 = unused, 4 = 90%
+    stats.tiers = vec![10, 10, 20, 0, 10];
+    stats.tier_successes = vec![5, 9, 19, 0, 9];
+    env.store(priority_pda(), &stats).await;
+
+    let stats = env.stats().await;
+    let lamports = [100, 200, 300, 400, 500];
+    // Only tier 4 could have matched its rate at tier 1: (500 - 200) * 10
+    assert_eq!(stats.wasted_fee_lamports(&lamports), 3_000);
+
+    // Reversed prices never count as overpay
+    assert_eq!(stats.wasted_fee_lamports(&[500, 400, 300, 200, 100]), 0);
+}
+
+#[tokio::test]
+async fn sandwich_patterns_need_an_mev_failure_between_successes() {
+    let mut env = TestEnv::new().await;
+    // Two overlapping sandwiches, then a slippage failure between successes that isn't one
+    for (nonce, (success, failure_type)) in [
+        (true, FailureType::Slippage),
+        (false, FailureType::Mev),
+        (true, FailureType::Slippage),
+        (false, FailureType::Mev),
+        (true, FailureType::Slippage),
+        (false, FailureType::Slippage),
+        (true, FailureType::Slippage),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let params = TxOutcomeParams {
+            success,
+            failure_type,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(
+        registry.outcome_failure_types[..4],
+        [
+            txguard::NO_FAILURE_TYPE,
+            txguard::FAILURE_MEV,
+            txguard::NO_FAILURE_TYPE,
+            txguard::FAILURE_MEV
+        ]
+    );
+    assert_eq!(registry.sandwich_pattern_count, 2);
+    assert_eq!(registry.sandwich_patterns(), 2);
+
+    let accounts = txguard::accounts::DetectSandwichPatterns {
+        registry: registry_pda(),
+    };
+    let data = txguard::instruction::DetectSandwichPatterns {
+        _namespace: String::new(),
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    // A sandwich that wraps around the end of the ring still counts once it completes
+    let mut registry = env.registry().await;
+    registry.last_100_outcomes = vec![txguard::OUTCOME_FAILURE; 100];
+    registry.last_100_outcomes[98] = txguard::OUTCOME_SUCCESS;
+    registry.outcome_failure_types = vec![0; 100];
+    registry.outcome_failure_types[99] = txguard::FAILURE_MEV;
+    registry.cursor = 0;
+    env.store(registry_pda(), &registry).await;
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.sandwich_pattern_count, 3);
+    assert_eq!(registry.sandwich_patterns(), 1);
+}
+
+#[tokio::test]
+async fn tier_sparkline_buckets_a_tiers_reports_by_slot() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+    // Seven tier 2 reports over 30 slots, with tier 1 reports in between
+    for (nonce, (priority_fee_tier, at)) in [
+        (2, slot),
+        (2, slot + 2),
+        (1, slot + 4),
+        (2, slot + 10),
+        (2, slot + 11),
+        (2, slot + 12),
+        (1, slot + 20),
+        (2, slot + 25),
+        (2, slot + 29),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        env.set_slot(at).await;
+        let params = TxOutcomeParams {
+            priority_fee_tier,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.outcome_tiers[..3], [2, 2, 1]);
+    // The oldest tier 2 report is dropped to fill 3 buckets evenly, leaving slots
+    // +2 to +29 split into three 28 / 3 slot ranges
+    assert_eq!(registry.tier_sparkline(2, 3), vec![3, 1, 2]);
+    assert_eq!(registry.tier_sparkline(2, 7), vec![2, 0, 3, 0, 0, 1, 1]);
+    assert_eq!(registry.tier_sparkline(1, 2), vec![1, 1]);
+    // Fewer reports than buckets draw a flat line
+    assert_eq!(registry.tier_sparkline(1, 3), vec![0, 0, 0]);
+    assert_eq!(registry.tier_sparkline(4, 5), vec![0; 5]);
+
+    let sparkline = |tier: u8, buckets: u8| {
+        let accounts = txguard::accounts::GetTierSparkline {
+            registry: registry_pda(),
+        };
+        let data = txguard::instruction::GetTierSparkline {
+            _namespace: String::new(),
+            tier,
+            buckets,
+        };
+        instruction(accounts, data)
+    };
+    env.send(sparkline(2, 3)).await.unwrap();
+    let res = env.send(sparkline(2, 0)).await;
+    assert_error(res, TxGuardError::InvalidSparklineBuckets);
+    let res = env.send(sparkline(2, 101)).await;
+    assert_error(res, TxGuardError::InvalidSparklineBuckets);
+    let res = env.send(sparkline(5, 3)).await;
+    assert_error(res, TxGuardError::InvalidPriorityFeeTier);
+}
+
+#[tokio::test]
+async fn recent_rate_only_counts_entries_inside_the_horizon() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+    // Two old failures, then a success and a failure 100 slots later
+    for (nonce, (success, at)) in [
+        (false, slot),
+        (false, slot),
+        (true, slot + 100),
+        (false, slot + 100),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        env.set_slot(at).await;
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(
+        registry.outcome_slots[..4],
+        [slot, slot, slot + 100, slot + 100]
+    );
+
+    // The ring isn't full, but the first two entries are still past the horizon
+    let recent = registry.recent_rate(slot + 110, 50);
+    assert_eq!(recent.sample_count, 2);
+    assert_eq!(recent.success_rate_bps, 5_000);
+
+    let all = registry.recent_rate(slot + 110, 1_000);
+    assert_eq!(all.sample_count, 4);
+    assert_eq!(all.success_rate_bps, 2_500);
+
+    let none = registry.recent_rate(slot + 1_000, 50);
+    assert_eq!(none.sample_count, 0);
+    assert_eq!(none.success_rate_bps, 0);
+}
+
+#[tokio::test]
+async fn peak_hour_of_week_tracks_the_busiest_bucket() {
+    let mut env = TestEnv::new().await;
+    // Hour 10 of one week gets a report, hour 30 gets two across different weeks
+    let week = 168 * 3_600;
+    for (nonce, at) in [10 * 3_600, 30 * 3_600 + 59, week + 30 * 3_600]
+        .into_iter()
+        .enumerate()
+    {
+        env.set_time(at).await;
+        let params = TxOutcomeParams {
+            success: true,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.hourly_buckets.len(), txguard::HOURS_PER_WEEK);
+    assert_eq!(registry.hourly_buckets[10], 1);
+    assert_eq!(
+        registry.peak_hour_of_week(),
+        PeakHour {
+            hour_of_week: 30,
+            count: 2
+        }
+    );
+}
+
+#[tokio::test]
+async fn best_day_compares_weekday_success_rates() {
+    let mut env = TestEnv::new().await;
+    assert_eq!(txguard::day_of_week(0), 3); // 1970-01-01 was a Thursday
+                                            // Epoch day 4 (1970-01-05) was a Monday; Sunday's reports fall a week later
+    let day = 86_400;
+    let reports = [
+        (4 * day, true),
+        (4 * day + 3_600, false),
+        (5 * day + 100, true),
+        (10 * day, true),
+        (17 * day + day - 1, true),
+    ];
+    for (nonce, (at, success)) in reports.into_iter().enumerate() {
+        env.set_time(at).await;
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.day_of_week_success, vec![1, 1, 0, 0, 0, 0, 2]);
+    assert_eq!(registry.day_of_week_failure, vec![1, 0, 0, 0, 0, 0, 0]);
+    // Tuesday and Sunday both sit at 100%; the earlier day wins the tie
+    assert_eq!(
+        registry.best_day(),
+        BestDay {
+            day_of_week: 1,
+            success_rate_bps: 10_000,
+            sample_count: 1
+        }
+    );
+}
+
+#[tokio::test]
+async fn failure_gaps_are_bucketed_from_the_second_failure() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+
+    env.set_slot(slot).await;
+    env.record_failure(FailureType::Dropped).await.unwrap();
+    assert_eq!(env.catalog().await.failure_gap_histogram, [0; 5]);
+
+    // Gaps of 3, 20 and 1_000 slots; reported outcomes count like record_failure
+    env.set_slot(slot + 3).await;
+    env.record_failure(FailureType::Slippage).await.unwrap();
+    env.set_slot(slot + 23).await;
+    env.register(false, FailureType::Mev, 0).await.unwrap();
+    env.set_slot(slot + 1_023).await;
+    env.record_failure(FailureType::Dropped).await.unwrap();
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.last_failure_slot, slot + 1_023);
+    assert_eq!(catalog.failure_gap_histogram, [1, 1, 0, 0, 1]);
+}
+
+#[tokio::test]
+async fn first_report_of_an_epoch_snapshots_the_previous_one() {
+    let mut env = TestEnv::new().await;
+    let start = env.registry().await.last_seen_epoch;
+    env.register(false, FailureType::Dropped, 0).await.unwrap();
+
+    // The snapshot PDA is pre-seeded since the native processor can't CPI
+    let finished = snapshot_pda(start);
+    env.ctx.set_account(
+        &finished,
+        &zeroed_account(EpochSnapshot::DISCRIMINATOR, EpochSnapshot::INIT_SPACE).into(),
+    );
+    env.set_epoch(start + 1).await;
+    let params = TxOutcomeParams {
+        success: true,
+        nonce: 1,
+        ..Default::default()
+    };
+    env.register_with(params, &[finished]).await.unwrap();
+
+    let snapshot: EpochSnapshot = env.fetch(finished).await;
+    assert_eq!(snapshot.epoch, start);
+    assert_eq!(snapshot.tx_count, 1);
+    assert_eq!(snapshot.success_count, 0);
+    assert_eq!(snapshot.failure_count, 1);
+    assert_eq!(snapshot.failures_by_type[3], 1);
+
+    let registry = env.registry().await;
+    assert_eq!(registry.last_seen_epoch, start + 1);
+    assert_eq!(registry.tx_count, 2);
+}
+
+#[tokio::test]
+async fn rollover_without_the_snapshot_account_still_records() {
+    let mut env = TestEnv::new().await;
+    let start = env.registry().await.last_seen_epoch;
+    env.set_epoch(start + 2).await;
+
+    // An unrelated extra account doesn't count as the snapshot
+    let params = TxOutcomeParams {
+        success: true,
+        ..Default::default()
+    };
+    env.register_with(params, &[snapshot_pda(start + 1)])
+        .await
+        .unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.last_seen_epoch, start + 2);
+    assert_eq!(registry.tx_count, 1);
+    let missing = env
+        .ctx
+        .banks_client
+        .get_account(snapshot_pda(start))
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn merge_into_parent_adds_child_counts() {
+    let mut env = TestEnv::new().await;
+    let authority = env.ctx.payer.pubkey();
+    env.seed_namespace("pool-a", authority).await;
+    env.record_failure(FailureType::Slippage).await.unwrap();
+
+    let child_address = pda(&[b"catalog", b"pool-a"]);
+    let mut child: FailureCatalog = env.fetch(child_address).await;
+    child.slippage_exceeded = 2;
+    child.dropped_tx = 5;
+    child.failure_gap_histogram = [1, 0, 0, 3, 0];
+    env.store(child_address, &child).await;
+
+    env.merge_into_parent("pool-a", false).await.unwrap();
+    let parent = env.catalog().await;
+    assert_eq!(parent.slippage_exceeded, 3);
+    assert_eq!(parent.dropped_tx, 5);
+    assert_eq!(parent.failure_gap_histogram, [1, 0, 0, 3, 0]);
+    assert_eq!(parent.child_count, 1);
+
+    // Zeroing the child keeps a second merge from double counting
+    env.merge_into_parent("pool-a", true).await.unwrap();
+    let parent = env.catalog().await;
+    assert_eq!(parent.slippage_exceeded, 5);
+    assert_eq!(parent.child_count, 2);
+    let child: FailureCatalog = env.fetch(child_address).await;
+    assert_eq!(child.slippage_exceeded, 0);
+    assert_eq!(child.dropped_tx, 0);
+}
+
+#[tokio::test]
+async fn merge_into_parent_requires_a_shared_authority() {
+    let mut env = TestEnv::new().await;
+    env.seed_namespace("pool-b", Pubkey::new_unique()).await;
+
+    assert_error(
+        env.merge_into_parent("pool-b", false).await,
+        TxGuardError::Unauthorized,
+    );
+    assert_eq!(env.catalog().await.child_count, 0);
+}
+
+fn snapshot_with(failures_by_type: [u32; txguard::FAILURE_KINDS]) -> EpochSnapshot {
+    EpochSnapshot {
+        epoch: 0,
+        tx_count: 0,
+        success_count: 0,
+        failure_count: failures_by_type.iter().map(|c| *c as u64).sum(),
+        failures_by_type,
+        captured_slot: 0,
+    }
+}
+
+#[tokio::test]
+async fn detect_anomaly_flags_the_type_that_outgrew_the_baseline_mix() {
+    let mut env = TestEnv::new().await;
+    let mut catalog = env.catalog().await;
+    // Baseline split slippage/dropped evenly; since then 2 slippage vs 20 dropped
+    catalog.slippage_exceeded = 12;
+    catalog.dropped_tx = 30;
+    let baseline = snapshot_with([10, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // expected 11 each: z = (20 - 11) / sqrt(11) ~ 2.71
+    let report = catalog.detect_anomaly(&baseline, 20_000);
+    assert!(report.anomaly_detected);
+    assert_eq!(report.anomalous_type, 3);
+    assert_eq!(report.z_score_bps / 100, 271);
+    assert!(!catalog.detect_anomaly(&baseline, 30_000).anomaly_detected);
+
+    // A type missing from the baseline is measured against an expected count of 1
+    catalog.bridge_failure = 5;
+    let report = catalog.detect_anomaly(&baseline, 20_000);
+    assert_eq!(report.anomalous_type, txguard::FAILURE_BRIDGE);
+    assert_eq!(report.z_score_bps, 40_000);
+
+    // Nothing to compare against without baseline failures
+    assert_eq!(
+        catalog.detect_anomaly(&snapshot_with([0; txguard::FAILURE_KINDS]), 0),
+        AnomalyReport {
+            anomaly_detected: false,
+            anomalous_type: 0,
+            z_score_bps: 0
+        }
+    );
+}
+
+#[tokio::test]
+async fn detect_anomaly_reads_a_snapshot_of_the_same_namespace() {
+    let mut env = TestEnv::new().await;
+    let mut account = zeroed_account(EpochSnapshot::DISCRIMINATOR, EpochSnapshot::INIT_SPACE);
+    let mut data = Vec::new();
+    let mut snapshot = snapshot_with([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    snapshot.epoch = 7;
+    snapshot.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    env.ctx
+        .set_account(&snapshot_pda(7), &account.clone().into());
+    // Same contents at an address that isn't the epoch 7 PDA
+    let stray = Pubkey::new_unique();
+    env.ctx.set_account(&stray, &account.into());
+
+    let detect = |baseline_snapshot| {
+        let accounts = txguard::accounts::DetectAnomaly {
+            failure_catalog: catalog_pda(),
+            baseline_snapshot,
+        };
+        let data = txguard::instruction::DetectAnomaly {
+            _namespace: String::new(),
+            z_threshold_bps: 20_000,
+        };
+        instruction(accounts, data)
+    };
+    env.send(detect(snapshot_pda(7))).await.unwrap();
+    assert_eq!(
+        env.send(detect(stray)).await.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        ),
+    );
+}
+
+#[tokio::test]
+async fn seasonality_accumulates_by_hour_of_day_until_reset() {
+    let mut env = TestEnv::new().await;
+    // 03:xx on two different days, plus a partial fill at 05:xx that isn't counted
+    for (nonce, (at, success, partial_fill)) in [
+        (3 * 3_600, true, false),
+        (3 * 3_600 + 1_200, false, false),
+        (27 * 3_600, false, false),
+        (5 * 3_600, true, true),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        env.set_time(at).await;
+        let params = TxOutcomeParams {
+            success,
+            partial_fill,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_seasonal(params).await.unwrap();
+    }
+
+    let stats: SeasonalityStats = env.fetch(seasonality_pda()).await;
+    assert_eq!(stats.successes_by_hour[3], 1);
+    assert_eq!(stats.failures_by_hour[3], 2);
+    assert_eq!(stats.successes_by_hour.iter().sum::<u32>(), 1);
+    assert_eq!(stats.failures_by_hour.iter().sum::<u32>(), 2);
+
+    let outsider = env.add_reporter();
+    let ix = env.reset_seasonality_ix(outsider.pubkey());
+    assert_error(env.send_as(ix, &outsider).await, TxGuardError::Unauthorized);
+
+    let ix = env.reset_seasonality_ix(env.ctx.payer.pubkey());
+    env.send(ix).await.unwrap();
+    let stats: SeasonalityStats = env.fetch(seasonality_pda()).await;
+    assert_eq!(stats.successes_by_hour, [0; 24]);
+    assert_eq!(stats.failures_by_hour, [0; 24]);
+}
+
+#[tokio::test]
+async fn tier_daily_snapshot_counts_todays_tiers() {
+    let mut env = TestEnv::new().await;
+    let day = 20_000i64;
+    env.set_time(day * 86_400 + 600).await;
+    for address in [tier_daily_pda(day), tier_daily_pda(day - 1)] {
+        env.ctx.set_account(
+            &address,
+            &zeroed_account(
+                TierDailySnapshot::DISCRIMINATOR,
+                TierDailySnapshot::INIT_SPACE,
+            )
+            .into(),
+        );
+    }
+
+    for (nonce, tier) in [2, 2, 4].into_iter().enumerate() {
+        env.register_tier_daily(tier_daily_pda(day), tier, nonce as u64)
+            .await
+            .unwrap();
+    }
+    let snapshot: TierDailySnapshot = env.fetch(tier_daily_pda(day)).await;
+    assert_eq!(snapshot.day, day);
+    assert_eq!(snapshot.tier_counts, vec![0, 0, 2, 0, 1]);
+
+    // Yesterday's snapshot can't be passed off as today's
+    assert_error(
+        env.register_tier_daily(tier_daily_pda(day - 1), 0, 3).await,
+        TxGuardError::InvalidTierSnapshot,
+    );
+
+    env.tier_trend(2, 7, &[tier_daily_pda(day - 1), tier_daily_pda(day)])
+        .await
+        .unwrap();
+    assert_error(
+        env.tier_trend(2, 8, &[]).await,
+        TxGuardError::InvalidTrendWindow,
+    );
+    assert_error(
+        env.tier_trend(5, 7, &[]).await,
+        TxGuardError::InvalidPriorityFeeTier,
+    );
+}
+
+#[tokio::test]
+async fn report_cadence_tracks_gaps_and_flags_stale_data() {
+    let mut env = TestEnv::new().await;
+    env.set_freshness_bound(100).await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 5;
+
+    // Gaps of 0, 5 and 195 slots after the first report
+    for (nonce, at) in [slot, slot, slot + 5, slot + 200].into_iter().enumerate() {
+        env.set_slot(at).await;
+        let params = TxOutcomeParams {
+            success: nonce != 3,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.last_report_slot, slot + 200);
+    assert_eq!(registry.max_report_gap_slots, 195);
+    assert_eq!(registry.report_gap_histogram, [1, 1, 0, 1, 0]);
+
+    let mut config: GuardConfig = env.fetch(config_pda()).await;
+    let fresh = registry.health(slot + 300, &config);
+    assert_eq!(fresh.success_rate_bps, 7_500);
+    assert_eq!(fresh.slots_since_last_report, 100);
+    assert!(!fresh.stale);
+    assert!(registry.health(slot + 301, &config).stale);
+    config.max_report_staleness_slots = 0;
+    assert!(!registry.health(slot + 10_000, &config).stale);
+}
+
+#[tokio::test]
+async fn export_state_pages_through_accounts_at_capacity() {
+    let mut env = TestEnv::new().await;
+    let mut registry = env.registry().await;
+    registry.instruction_types = (1..=txguard::MAX_INSTRUCTION_TYPES as u8)
+        .map(|instruction_type| txguard::InstructionTypeStats {
+            instruction_type,
+            success: 1,
+            failure: 1,
+        })
+        .collect();
+    registry.seen_callers = (0..txguard::MAX_SEEN_CALLERS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    registry.caller_filter = [u64::MAX; 4];
+    registry.outcome_tiers = vec![0; 100];
+    registry.outcome_failure_types = vec![0; 100];
+    env.store(registry_pda(), &registry).await;
+    let mut catalog = env.catalog().await;
+    catalog.bridge_failure_by_chain = (0..10)
+        .map(|chain_id| txguard::ChainFailureCount { chain_id, count: 1 })
+        .collect();
+    env.store(catalog_pda(), &catalog).await;
+    let mut stats = env.stats().await;
+    stats.tier_names = vec![[b' '; 16]; 5];
+    stats.tier_successes = vec![0; 5];
+    stats.direct_update_counts = vec![0; 5];
+    stats.tier_avg_latency_slots = vec![0; 5];
+    stats.tier_latency_counts = vec![0; 5];
+    env.store(priority_pda(), &stats).await;
+
+    let export = |section, offset| {
+        let accounts = txguard::accounts::ExportState {
+            registry: registry_pda(),
+            failure_catalog: catalog_pda(),
+            priority_fee_stats: priority_pda(),
+        };
+        let data = txguard::instruction::ExportState {
+            _namespace: String::new(),
+            section,
+            offset,
+        };
+        instruction(accounts, data)
+    };
+    // Native tests can't read return data, so the pages are checked against export_page
+    // and the instruction only for which pages it serves
+    let registry = env.registry().await;
+    let catalog = env.catalog().await;
+    let stats = env.stats().await;
+    let encodings = [
+        registry.try_to_vec().unwrap(),
+        catalog.try_to_vec().unwrap(),
+        stats.try_to_vec().unwrap(),
+    ];
+    let catalog_len = encodings[1].len() as u32;
+    let sections = [
+        txguard::EXPORT_REGISTRY,
+        txguard::EXPORT_CATALOG,
+        txguard::EXPORT_PRIORITY_STATS,
+    ];
+    for (section, encoding) in sections.into_iter().zip(encodings) {
+        let page = |offset| match section {
+            txguard::EXPORT_REGISTRY => txguard::export_page(&registry, offset),
+            txguard::EXPORT_CATALOG => txguard::export_page(&catalog, offset),
+            _ => txguard::export_page(&stats, offset),
+        };
+        let mut exported = Vec::new();
+        loop {
+            let offset = exported.len() as u32;
+            env.send(export(section, offset)).await.unwrap();
+            let payload = page(offset).unwrap();
+            assert!(payload.len() <= txguard::MAX_EXPORT_LEN);
+            assert_eq!(payload[0], txguard::EXPORT_SCHEMA_VERSION);
+            let len = u32::from_le_bytes(payload[1..5].try_into().unwrap()) as usize;
+            assert_eq!(len, encoding.len());
+            exported.extend_from_slice(&payload[5..]);
+            if exported.len() >= len {
+                break;
+            }
+        }
+        // Nothing left out, unlike the v14 export
+        assert_eq!(exported, encoding);
+    }
+    // The registry alone takes several pages
+    assert!(registry.try_to_vec().unwrap().len() > 2 * txguard::MAX_EXPORT_PAGE);
+
+    let past_the_end = export(txguard::EXPORT_CATALOG, catalog_len + 1);
+    assert_error(
+        env.send(past_the_end).await,
+        TxGuardError::InvalidExportPage,
+    );
+    assert_error(
+        env.send(export(3, 0)).await,
+        TxGuardError::InvalidExportPage,
+    );
+}
+
+#[tokio::test]
+async fn wsol_failures_keep_the_latest_token_error_code() {
+    let mut env = TestEnv::new().await;
+    for (nonce, wsol_error_code) in [4u16, 11].into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            failure_type: FailureType::Wsol,
+            wsol_error_code,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.wrapped_sol_error, 2);
+    assert_eq!(catalog.wsol_error_code, 11);
+    assert_eq!(catalog.insufficient_funds, 0);
+    assert_eq!(catalog.other, 0);
+    assert!(catalog.format_failure_pie().starts_with("WSOL: "));
+}
+
+#[tokio::test]
+async fn declared_tx_slots_record_report_delays() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 100;
+    env.set_slot(slot).await;
+
+    // 30 slots late, undeclared, and reported in the slot it landed in
+    for (nonce, tx_slot) in [slot - 30, 0, slot].into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success: true,
+            tx_slot,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+    let future = TxOutcomeParams {
+        success: true,
+        tx_slot: slot + 1,
+        nonce: 3,
+        ..Default::default()
+    };
+    assert_error(env.register_v2(future).await, TxGuardError::InvalidTxSlot);
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 3);
+    assert_eq!(registry.report_delays[..3], [30, 0, 0]);
+    assert_eq!(registry.sum_report_delay_slots, 30);
+    assert_eq!(registry.max_report_delay_slots, 30);
+}
+
+#[tokio::test]
+async fn analytics_reads_enforce_min_tx_count() {
+    let mut env = TestEnv::new().await;
+    env.register(false, FailureType::Mev, 0).await.unwrap();
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+
+    let success_rate = |min_tx_count| {
+        let accounts = txguard::accounts::ComputeSuccessRate {
+            registry: registry_pda(),
+        };
+        let data = txguard::instruction::ComputeSuccessRate {
+            _namespace: String::new(),
+            min_tx_count,
+        };
+        instruction(accounts, data)
+    };
+    let dominant_failure = |min_tx_count| {
+        let accounts = txguard::accounts::ComputeDominantFailure {
+            registry: registry_pda(),
+            failure_catalog: catalog_pda(),
+        };
+        let data = txguard::instruction::ComputeDominantFailure {
+            _namespace: String::new(),
+            min_tx_count,
+        };
+        instruction(accounts, data)
+    };
+
+    // 0 disables the check and the boundary itself is enough
+    for min_tx_count in [0, 2] {
+        env.send(success_rate(min_tx_count)).await.unwrap();
+        env.send(dominant_failure(min_tx_count)).await.unwrap();
+    }
+    assert_error(
+        env.send(success_rate(3)).await,
+        TxGuardError::InsufficientData,
+    );
+    assert_error(
+        env.send(dominant_failure(100)).await,
+        TxGuardError::InsufficientData,
+    );
+
+    assert_eq!(env.registry().await.success_rate_bps(), 5_000);
+    assert_eq!(env.catalog().await.dominant_failure(), (2, 1));
+}
+
+#[tokio::test]
+async fn calibrate_tiers_spreads_usage_evenly() {
+    let mut env = TestEnv::new().await;
+    let payer = env.ctx.payer.pubkey();
+    let accounts = txguard::accounts::SetTierLamports {
+        authority: payer,
+        config: config_pda(),
+        tier_lamport_config: tier_lamports_pda(),
+        system_program: system_program::ID,
+    };
+    let data = txguard::instruction::SetTierLamports {
+        _namespace: String::new(),
+        tier_min_lamports: vec![0, 1_000, 2_000, 3_000, 4_000],
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    let calibrate = |authority: Pubkey, lamport_min: u64, lamport_max: u64| {
+        let accounts = txguard::accounts::CalibrateTiers {
+            authority,
+            config: config_pda(),
+            priority_fee_stats: priority_pda(),
+            tier_lamport_config: tier_lamports_pda(),
+        };
+        let data = txguard::instruction::CalibrateTiers {
+            namespace: String::new(),
+            lamport_min,
+            lamport_max,
+        };
+        instruction(accounts, data)
+    };
+    assert_error(
+        env.send(calibrate(payer, 0, 5_000)).await,
+        TxGuardError::InsufficientData,
+    );
+
+    // Four reports in the cheapest tier, one in the top tier
+    for tier in [0, 0, 0, 0, 4] {
+        env.register(true, FailureType::Slippage, tier)
+            .await
+            .unwrap();
+    }
+    let outsider = env.add_reporter();
+    assert_error(
+        env.send_as(calibrate(outsider.pubkey(), 0, 5_000), &outsider)
+            .await,
+        TxGuardError::Unauthorized,
+    );
+    assert_error(
+        env.send(calibrate(payer, 5_000, 5_000)).await,
+        TxGuardError::InvalidTierLamports,
+    );
+    env.send(calibrate(payer, 0, 5_000)).await.unwrap();
+
+    // Tier 0 held 80% of usage, so the first four fifths all fall inside its old range
+    let config: TierLamportConfig = env.fetch(tier_lamports_pda()).await;
+    assert_eq!(config.tier_min_lamports, [0, 250, 500, 750, 1_000]);
+    // Old boundaries outside the new range are clamped into it first
+    let stats = env.stats().await;
+    assert_eq!(
+        stats.calibrated_boundaries(&[0, 200, 300, 400, 500], 100, 600),
+        Some([100, 125, 150, 175, 200])
+    );
+}
+
+#[tokio::test]
+async fn fee_roi_compares_each_tier_with_the_one_below() {
+    let mut env = TestEnv::new().await;
+    let mut config: TierLamportConfig = env.fetch(tier_lamports_pda()).await;
+    config.tier_min_lamports = vec![0, 1_000, 3_000, 3_000, 10_000];
+    env.store(tier_lamports_pda(), &config).await;
+
+    // Tier 0: 50%, tier 1: 75%, tier 2: 100%, tier 3: 100%, tier 4: unused
+    let reports = [
+        (true, 0),
+        (false, 0),
+        (true, 1),
+        (true, 1),
+        (true, 1),
+        (false, 1),
+        (true, 2),
+        (true, 3),
+    ];
+    for (success, tier) in reports {
+        env.register(success, FailureType::Slippage, tier)
+            .await
+            .unwrap();
+    }
+    let accounts = txguard::accounts::ComputeFeeRoi {
+        priority_fee_stats: priority_pda(),
+        tier_lamport_config: tier_lamports_pda(),
+    };
+    let data = txguard::instruction::ComputeFeeRoi {
+        _namespace: String::new(),
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    let roi = env.stats().await.fee_roi(&[0, 1_000, 3_000, 3_000, 10_000]);
+    let summary: Vec<(u8, i16, u64, i64)> = roi
+        .iter()
+        .map(|entry| {
+            (
+                entry.tier,
+                entry.marginal_success_gain_bps,
+                entry.marginal_lamport_cost,
+                entry.gain_bps_per_million_lamports,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, 2_500, 1_000, 2_500_000),
+            (2, 2_500, 2_000, 1_250_000),
+            (3, 0, 0, 0)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn project_next_failure_picks_the_largest_share() {
+    let mut env = TestEnv::new().await;
+    let project = |recent_context: u8| {
+        let accounts = txguard::accounts::ProjectNextFailure {
+            failure_catalog: catalog_pda(),
+        };
+        let data = txguard::instruction::ProjectNextFailure {
+            _namespace: String::new(),
+            recent_context,
+        };
+        instruction(accounts, data)
+    };
+    assert_error(env.send(project(0)).await, TxGuardError::InsufficientData);
+    assert_eq!(env.catalog().await.project_next_failure(), None);
+
+    for (nonce, failure_type) in [
+        FailureType::Slippage,
+        FailureType::Dropped,
+        FailureType::Dropped,
+        FailureType::Mev,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let params = TxOutcomeParams {
+            failure_type,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+    for recent_context in [
+        txguard::RECENT_CONTEXT_NORMAL,
+        txguard::RECENT_CONTEXT_CONGESTED,
+        txguard::RECENT_CONTEXT_VOLATILE,
+    ] {
+        env.send(project(recent_context)).await.unwrap();
+    }
+    assert_error(
+        env.send(project(3)).await,
+        TxGuardError::InvalidRecentContext,
+    );
+    assert_eq!(env.catalog().await.project_next_failure(), Some(3));
+}
+
+#[tokio::test]
+async fn unique_callers_are_counted_once() {
+    let mut env = TestEnv::new().await;
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+    env.register(false, FailureType::Liquidity, 0)
+        .await
+        .unwrap();
+    let reporter = env.add_reporter();
+    env.register_as(&reporter, 0).await.unwrap();
+
+    let mut registry = env.registry().await;
+    assert_eq!(registry.unique_caller_count, 2);
+    assert_eq!(
+        registry.seen_callers,
+        vec![env.ctx.payer.pubkey(), reporter.pubkey()]
+    );
+
+    // Past the exact list, repeat callers are caught by the filter instead
+    while registry.seen_callers.len() < txguard::MAX_SEEN_CALLERS {
+        registry.record_caller(&Pubkey::new_unique());
+    }
+    let late_caller = Pubkey::new_unique();
+    registry.record_caller(&late_caller);
+    registry.record_caller(&late_caller);
+    assert_eq!(
+        registry.unique_caller_count,
+        txguard::MAX_SEEN_CALLERS as u32 + 1
+    );
+    assert_eq!(registry.seen_callers.len(), txguard::MAX_SEEN_CALLERS);
+
+    // Merging replays the source's exact list, so shared callers count once
+    let mut merged = TransactionRegistry::default();
+    merged.record_caller(&reporter.pubkey());
+    merged.merge_from(&registry).unwrap();
+    assert_eq!(merged.unique_caller_count, registry.unique_caller_count);
+    assert_eq!(merged.caller_filter, registry.caller_filter);
+}
+
+#[tokio::test]
+async fn accepted_disputes_amend_the_outcome() {
+    let mut env = TestEnv::new().await;
+    for (tier, success) in [true, false, true].into_iter().enumerate() {
+        env.register(success, FailureType::Mev, tier as u8)
+            .await
+            .unwrap();
+    }
+
+    env.open_dispute(1, true).await.unwrap();
+    let dispute: Dispute = env.fetch(dispute_pda(1, &env.ctx.payer.pubkey())).await;
+    assert_eq!(dispute.disputer, env.ctx.payer.pubkey());
+    assert!(dispute.claimed_success);
+    // The cursor is at 3, so entry 1 is written again 99 reports from now
+    assert_eq!(dispute.overwrite_tx_count, 3 + 99);
+
+    // Same outcome as recorded, and an entry that was never written
+    assert_error(
+        env.open_dispute(0, true).await,
+        TxGuardError::InvalidDispute,
+    );
+    assert_error(
+        env.open_dispute(5, false).await,
+        TxGuardError::InvalidDispute,
+    );
+
+    env.resolve_dispute(1, true).await.unwrap();
+    let registry = env.registry().await;
+    assert_eq!((registry.success_count, registry.failure_count), (3, 0));
+    assert_eq!(registry.last_100_outcomes[1], txguard::OUTCOME_SUCCESS);
+    assert!(env
+        .ctx
+        .banks_client
+        .get_account(dispute_pda(1, &env.ctx.payer.pubkey()))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn each_disputer_contests_an_entry_separately() {
+    let mut env = TestEnv::new().await;
+    env.register(false, FailureType::Mev, 0).await.unwrap();
+
+    // An open dispute doesn't lock anyone else out of the entry
+    env.open_dispute(0, true).await.unwrap();
+    let other = env.add_reporter();
+    let ix = env.open_dispute_ix(other.pubkey(), 0, true);
+    env.send_as(ix, &other).await.unwrap();
+    let dispute: Dispute = env.fetch(dispute_pda(0, &other.pubkey())).await;
+    assert_eq!(dispute.disputer, other.pubkey());
+
+    // Once one is accepted the entry already says what the other claims
+    env.resolve_dispute(0, true).await.unwrap();
+    assert_error(
+        env.resolve_dispute_of(other.pubkey(), 0, true).await,
+        TxGuardError::InvalidDispute,
+    );
+    let registry = env.registry().await;
+    assert_eq!((registry.success_count, registry.failure_count), (1, 0));
+}
+
+#[tokio::test]
+async fn archive_registry_moves_the_counters_and_keeps_the_window() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 100;
+    env.set_slot(slot).await;
+    for (nonce, success) in [true, false, true].into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            fee_lamports: 5_000,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+    env.open_dispute(1, true).await.unwrap();
+    let window = env.registry().await.last_100_outcomes;
+
+    // Not after the last report, and not in the future
+    for archive_before_slot in [slot, slot + 1] {
+        assert_error(
+            env.archive_registry(archive_before_slot).await,
+            TxGuardError::InvalidArchiveSlot,
+        );
+    }
+
+    env.set_slot(slot + 10).await;
+    env.archive_registry(slot + 5).await.unwrap();
+    let archive: ArchiveRegistry = env.fetch(archive_pda(0)).await;
+    assert_eq!(archive.registry, registry_pda());
+    assert_eq!(
+        (
+            archive.session_id,
+            archive.since_slot,
+            archive.archive_before_slot
+        ),
+        (0, 0, slot + 5)
+    );
+    assert_eq!(archive.archived_slot, slot + 10);
+    assert_eq!(
+        (
+            archive.tx_count,
+            archive.success_count,
+            archive.failure_count
+        ),
+        (3, 2, 1)
+    );
+    assert_eq!(archive.total_fee_lamports, 15_000);
+    assert_eq!(
+        archive.day_of_week_success.iter().sum::<u32>()
+            + archive.day_of_week_failure.iter().sum::<u32>(),
+        3
+    );
+
+    let registry = env.registry().await;
+    assert_eq!(
+        (
+            registry.tx_count,
+            registry.success_count,
+            registry.failure_count
+        ),
+        (0, 0, 0)
+    );
+    assert_eq!(registry.total_fee_lamports, 0);
+    assert_eq!(registry.day_of_week_success, vec![0; 7]);
+    assert_eq!(
+        (registry.session_id, registry.archived_before_slot),
+        (1, slot + 5)
+    );
+    assert_eq!(registry.last_100_outcomes, window);
+    assert_eq!(registry.last_report_slot, slot);
+    // Amending the entry would move counts the registry no longer holds
+    assert_error(
+        env.resolve_dispute(1, true).await,
+        TxGuardError::DisputeEntryArchived,
+    );
+
+    // Nothing reported since, but the boundary can't move backwards
+    assert_error(
+        env.archive_registry(slot + 4).await,
+        TxGuardError::InvalidArchiveSlot,
+    );
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+    env.set_slot(slot + 20).await;
+    env.archive_registry(slot + 15).await.unwrap();
+    let archive: ArchiveRegistry = env.fetch(archive_pda(1)).await;
+    assert_eq!(
+        (archive.session_id, archive.since_slot, archive.tx_count),
+        (1, slot + 5, 1)
+    );
+    assert_eq!(env.registry().await.session_id, 2);
+}
+
+#[tokio::test]
+async fn archive_registry_restarts_the_catalog_rates() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 100;
+    env.set_slot(slot).await;
+    for outcome in [
+        Outcome::Failure,
+        Outcome::Failure,
+        Outcome::Partial,
+        Outcome::Partial,
+    ] {
+        env.register_outcome(outcome, FailureType::Mev, 0)
+            .await
+            .unwrap();
+    }
+    assert_eq!(env.catalog().await.partial_fill_rate_bps, 5_000);
+
+    env.set_slot(slot + 10).await;
+    env.archive_registry(slot + 5).await.unwrap();
+    let archive: ArchiveRegistry = env.fetch(archive_pda(0)).await;
+    assert_eq!(archive.failures_by_type[2], 2);
+    assert_eq!(archive.failures_by_type.iter().sum::<u32>(), 2);
+    assert_eq!(archive.partial_fill, 2);
+    let catalog = env.catalog().await;
+    assert_eq!((catalog.mev_detected, catalog.partial_fill), (0, 0));
+    assert_eq!(catalog.partial_fill_rate_bps, 0);
+
+    // Rates are against the restarted tx_count, not inflated by archived counts
+    env.register_outcome(Outcome::Partial, FailureType::Mev, 0)
+        .await
+        .unwrap();
+    env.register(false, FailureType::Mev, 0).await.unwrap();
+    let catalog = env.catalog().await;
+    let registry = env.registry().await;
+    assert_eq!(catalog.partial_fill_rate_bps, 5_000);
+    assert_eq!(catalog.mev_detected as u64, registry.failure_count);
+    assert_eq!(registry.tx_count, 2);
+}
+
+#[tokio::test]
+async fn rejected_disputes_forfeit_the_bond() {
+    let mut env = TestEnv::new().await;
+    env.register(false, FailureType::Mev, 0).await.unwrap();
+    env.ctx.set_account(
+        &treasury_pda(),
+        &zeroed_account(Treasury::DISCRIMINATOR, Treasury::INIT_SPACE).into(),
+    );
+    env.open_dispute(0, true).await.unwrap();
+    // Escrowing takes a system transfer, which the native runner can't CPI into
+    let mut account = env
+        .ctx
+        .banks_client
+        .get_account(dispute_pda(0, &env.ctx.payer.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let mut dispute: Dispute = env.fetch(dispute_pda(0, &env.ctx.payer.pubkey())).await;
+    dispute.bond_lamports = 5_000;
+    let mut data = Vec::new();
+    dispute.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    account.lamports += 5_000;
+    env.ctx
+        .set_account(&dispute_pda(0, &env.ctx.payer.pubkey()), &account.into());
+
+    // Overwritten entries can't be amended any more
+    let mut registry = env.registry().await;
+    registry.tx_count = dispute.overwrite_tx_count;
+    env.store(registry_pda(), &registry).await;
+    assert_error(
+        env.resolve_dispute(0, true).await,
+        TxGuardError::DisputeEntryOverwritten,
+    );
+
+    let treasury_before = env.lamports(treasury_pda()).await;
+    env.resolve_dispute(0, false).await.unwrap();
+    assert_eq!(env.lamports(treasury_pda()).await, treasury_before + 5_000);
+    let treasury: Treasury = env.fetch(treasury_pda()).await;
+    assert_eq!(treasury.total_fees_collected, 5_000);
+    assert_eq!(env.registry().await.failure_count, 1);
+}
+
+#[tokio::test]
+async fn reputation_rises_per_report_and_drops_when_flagged() {
+    let mut env = TestEnv::new().await;
+    let payer = env.ctx.payer.pubkey();
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 10;
+    env.set_slot(slot).await;
+    for nonce in 0..3 {
+        env.register(nonce != 1, FailureType::Mev, nonce as u8)
+            .await
+            .unwrap();
+    }
+    let record: ReporterRecord = env.fetch(reporter_pda(&payer)).await;
+    let expected = txguard::REPUTATION_BASELINE + 3 * txguard::DEFAULT_REPUTATION_INCREMENT;
+    assert_eq!(record.reputation, expected);
+
+    env.flag_report(payer, 1).await.unwrap();
+    let record: ReporterRecord = env.fetch(reporter_pda(&payer)).await;
+    assert_eq!(
+        record.reputation,
+        expected - txguard::DEFAULT_REPUTATION_PENALTY
+    );
+    assert_eq!(record.flagged_outcomes, 0b10);
+    assert_error(
+        env.flag_report(payer, 1).await,
+        TxGuardError::ReportAlreadyFlagged,
+    );
+    // Entry 3 hasn't been written yet, and the ring only has 32 entries
+    for window_index in [3, 32] {
+        assert_error(
+            env.flag_report(payer, window_index).await,
+            TxGuardError::InvalidWindowIndex,
+        );
+    }
+}
+
+#[tokio::test]
+async fn reputation_decays_towards_the_baseline() {
+    let mut env = TestEnv::new().await;
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+    let mut record: ReporterRecord = env.fetch(reporter_pda(&env.ctx.payer.pubkey())).await;
+    record.reputation = 7_000;
+    record.reputation_slot = 1_000;
+
+    assert_eq!(record.current_reputation(1_000, 100), 7_000);
+    assert_eq!(record.current_reputation(1_100, 100), 6_000);
+    // Linear within a half-life
+    assert_eq!(record.current_reputation(1_150, 100), 5_750);
+    assert_eq!(record.current_reputation(1_200, 100), 5_500);
+    assert_eq!(record.current_reputation(1_000_000, 100), 5_000);
+    // No half-life, no decay
+    assert_eq!(record.current_reputation(1_000_000, 0), 7_000);
+
+    // Low reputations recover the same way
+    record.reputation = 3_000;
+    assert_eq!(record.current_reputation(1_100, 100), 4_000);
+    record.adjust_reputation(-5_000, 1_100, 100);
+    assert_eq!((record.reputation, record.reputation_slot), (0, 1_100));
+}
+
+#[tokio::test]
+async fn reporters_earn_points_up_to_the_slot_cap() {
+    let mut env = TestEnv::new().await;
+    let payer = env.ctx.payer.pubkey();
+    // Rewards are off until the authority prices reports
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+    let record: ReporterRecord = env.fetch(reporter_pda(&payer)).await;
+    assert_eq!(record.points, 0);
+
+    env.set_reward_points(10, 4, 25).await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 10;
+    env.set_slot(slot).await;
+    env.register(true, FailureType::Slippage, 1).await.unwrap();
+    env.register(false, FailureType::Mev, 1).await.unwrap();
+    env.register(true, FailureType::Slippage, 2).await.unwrap();
+    // Only 1 point of the cap is left in this slot
+    env.register(true, FailureType::Slippage, 3).await.unwrap();
+    let record: ReporterRecord = env.fetch(reporter_pda(&payer)).await;
+    assert_eq!(record.points, 25);
+
+    env.set_points_multiplier(2).await;
+    env.set_slot(slot + 1).await;
+    env.register(false, FailureType::Mev, 0).await.unwrap();
+    let mut record: ReporterRecord = env.fetch(reporter_pda(&payer)).await;
+    assert_eq!(record.points, 33);
+    assert_eq!(record.points_this_slot, 8);
+
+    record.debit_points(30).unwrap();
+    assert_eq!(record.points, 3);
+    let insufficient: anchor_lang::error::Error = TxGuardError::InsufficientPoints.into();
+    assert_eq!(record.debit_points(4).unwrap_err(), insufficient);
+}
+
+// The mint_to CPI itself can't run under the native processor; tests/txguard.ts
+// covers a full claim against the token program on localnet
+#[tokio::test]
+async fn claim_rewards_checks_run_before_minting() {
+    let mut env = TestEnv::new().await;
+    let payer = env.ctx.payer.pubkey();
+    let mint = Pubkey::new_unique();
+    let token_account = txguard::associated_token_address(&payer, &mint);
+    env.ctx
+        .set_account(&mint, &spl_mint_account(&reward_authority_pda(), 0).into());
+    env.set_reward_points(10, 4, 0).await;
+    env.register(true, FailureType::Slippage, 0).await.unwrap();
+    // Inert until a mint is configured
+    assert_error(
+        env.claim_rewards(mint, token_account).await,
+        TxGuardError::RewardsDisabled,
+    );
+
+    // The program has to be able to mint it
+    let foreign_mint = Pubkey::new_unique();
+    env.ctx.set_account(
+        &foreign_mint,
+        &spl_mint_account(&Pubkey::new_unique(), 0).into(),
+    );
+    assert_error(
+        env.set_reward_mint(foreign_mint, 100).await,
+        TxGuardError::InvalidRewardMint,
+    );
+    env.set_reward_mint(mint, 100).await.unwrap();
+    assert_error(
+        env.claim_rewards(mint, Pubkey::new_unique()).await,
+        TxGuardError::InvalidRewardAccount,
+    );
+
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 200;
+    env.set_slot(slot).await;
+    let mut record: ReporterRecord = env.fetch(reporter_pda(&payer)).await;
+    record.last_claim_slot = slot - 99;
+    env.store(reporter_pda(&payer), &record).await;
+    assert_error(
+        env.claim_rewards(mint, token_account).await,
+        TxGuardError::ClaimTooEarly,
+    );
+    record.last_claim_slot = slot - 100;
+    record.points = 0;
+    env.store(reporter_pda(&payer), &record).await;
+    assert_error(
+        env.claim_rewards(mint, token_account).await,
+        TxGuardError::InsufficientPoints,
+    );
+
+    env.set_reward_mint(Pubkey::default(), 0).await.unwrap();
+    assert_error(
+        env.claim_rewards(mint, token_account).await,
+        TxGuardError::RewardsDisabled,
+    );
+}
+
+#[tokio::test]
+async fn report_age_limit_boundaries() {
+    let mut env = TestEnv::new().await;
+    env.set_max_report_age(100).await;
+    let mut config: GuardConfig = env.fetch(config_pda()).await;
+    let slot = 1_000;
+    let stale: anchor_lang::error::Error = TxGuardError::StaleReport.into();
+
+    // Exactly max_report_age_slots old is still accepted
+    config.check_report_age(slot - 100, slot).unwrap();
+    assert_eq!(
+        config.check_report_age(slot - 101, slot).unwrap_err(),
+        stale
+    );
+    // Undeclared and future slots aren't aged
+    config.check_report_age(0, slot).unwrap();
+    config.check_report_age(slot + 1, slot).unwrap();
+
+    config.max_report_age_slots = 0;
+    config.check_report_age(1, slot).unwrap();
+}
+
+#[tokio::test]
+async fn stale_reports_are_rejected_unless_backfill_reporter() {
+    let mut env = TestEnv::new().await;
+    env.set_max_report_age(100).await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 500;
+    env.set_slot(slot).await;
+    let report = |tx_slot, nonce| TxOutcomeParams {
+        success: true,
+        tx_slot,
+        nonce,
+        ..Default::default()
+    };
+
+    env.register_v2(report(slot - 100, 0)).await.unwrap();
+    // The authority isn't exempt until it is flagged
+    assert_error(
+        env.register_v2(report(slot - 101, 1)).await,
+        TxGuardError::StaleReport,
+    );
+
+    let payer = env.ctx.payer.pubkey();
+    env.set_backfill_reporter(payer, true).await;
+    env.register_v2(report(slot - 400, 2)).await.unwrap();
+    assert_eq!(env.registry().await.tx_count, 2);
+}
+
+#[tokio::test]
+async fn direct_tier_updates_are_counted_apart_from_reports() {
+    let mut env = TestEnv::new().await;
+    env.update_priority_fee(2).await.unwrap();
+    env.register(true, FailureType::Slippage, 2).await.unwrap();
+    env.register(false, FailureType::Liquidity, 3)
+        .await
+        .unwrap();
+
+    let stats = env.stats().await;
+    assert_eq!(stats.tiers, vec![0, 0, 2, 1, 0]);
+    assert_eq!(stats.direct_update_counts, vec![0, 0, 1, 0, 0]);
+}
+
+#[tokio::test]
+async fn metadata_reports_feed_dedup_and_report_delay() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 100;
+    env.set_slot(slot).await;
+    let metadata = TxMetadata {
+        tx_sig_hash: [7; 32],
+        tx_slot: slot - 12,
+        compute_units_consumed: 180_000,
+        cu_limit: 200_000,
+        ..Default::default()
+    };
+    assert_eq!(metadata.compute_units(), Some(180_000));
+    assert_eq!(metadata.priority_fee(), None);
+    assert_eq!(metadata.retries(), None);
+
+    let report = |nonce| TxOutcomeParams {
+        success: true,
+        nonce,
+        ..Default::default()
+    };
+    env.register_metadata(report(0), metadata.clone())
+        .await
+        .unwrap();
+    assert_error(
+        env.register_metadata(report(1), metadata).await,
+        TxGuardError::DuplicateReport,
+    );
+
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 1);
+    assert_eq!(registry.report_delays[0], 12);
+    assert_eq!(registry.max_report_delay_slots, 12);
+}
+
+#[tokio::test]
+async fn compute_stats_split_by_outcome_and_skip_unknown_units() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, 120_000, 200_000),
+        (true, 80_000, TxMetadata::UNKNOWN_COMPUTE_UNITS),
+        (false, 196_000, 200_000),
+        (false, 190_000, 200_000),
+        // No CU data: must not drag min down to 0 or max up to u32::MAX
+        (false, TxMetadata::UNKNOWN_COMPUTE_UNITS, 200_000),
+    ];
+    for (nonce, (success, compute_units_consumed, cu_limit)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            failure_type: if success {
+                FailureType::Slippage
+            } else {
+                FailureType::Liquidity
+            },
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            compute_units_consumed,
+            cu_limit,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+
+    let stats: ComputeStats = env.fetch(compute_pda()).await;
+    let successes = stats.success_compute;
+    assert_eq!((successes.count, successes.sum), (2, 200_000));
+    assert_eq!((successes.min, successes.max), (80_000, 120_000));
+    let failures = stats.failure_compute;
+    assert_eq!((failures.count, failures.sum), (2, 386_000));
+    assert_eq!((failures.min, failures.max), (190_000, 196_000));
+    // 196k of 200k is 98%; 190k is exactly 95%
+    assert_eq!(stats.near_cu_limit_count, 2);
+    assert_eq!(env.registry().await.tx_count, 5);
+}
+
+#[tokio::test]
+async fn frozen_token_failures_keep_the_latest_mint() {
+    let mut env = TestEnv::new().await;
+    let usdc = Pubkey::new_unique();
+    let eurc = Pubkey::new_unique();
+    for (nonce, frozen_mint) in [usdc, eurc].into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            failure_type: FailureType::TokenFrozen,
+            frozen_mint,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.token_frozen, 2);
+    assert_eq!(catalog.frozen_mint, eurc);
+    assert_eq!(catalog.other, 0);
+    assert_eq!(catalog.dominant_failure(), (txguard::FAILURE_FROZEN, 2));
+    assert!(catalog.format_failure_pie().starts_with("FRZN: "));
+}
+
+#[tokio::test]
+async fn confirmation_latency_is_bucketed_per_tier() {
+    let mut env = TestEnv::new().await;
+    // (tier, submitted, confirmed): latencies 1, 4, 33 and 12; the last report is undeclared
+    let reports = [
+        (4, 100, 101),
+        (4, 100, 104),
+        (0, 100, 133),
+        (0, 200, 212),
+        (0, 0, 150),
+    ];
+    for (nonce, (tier, submitted_slot, confirmed_slot)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success: true,
+            priority_fee_tier: tier,
+            submitted_slot,
+            confirmed_slot,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_latency(params).await.unwrap();
+    }
+
+    let stats: LatencyStats = env.fetch(latency_pda()).await;
+    assert_eq!(stats.histogram, [1, 1, 0, 1, 0, 1]);
+    assert_eq!(stats.histogram_by_tier[4], [1, 1, 0, 0, 0, 0]);
+    assert_eq!(stats.histogram_by_tier[0], [0, 0, 0, 1, 0, 1]);
+    assert_eq!((stats.latency_sum, stats.latency_count), (50, 4));
+
+    for (nonce, (submitted_slot, confirmed_slot)) in
+        [(100, 99), (100, 10_101)].into_iter().enumerate()
+    {
+        let params = TxOutcomeParams {
+            success: true,
+            submitted_slot,
+            confirmed_slot,
+            nonce: 10 + nonce as u64,
+            ..Default::default()
+        };
+        assert_error(
+            env.register_latency(params).await,
+            TxGuardError::InvalidConfirmationSlots,
+        );
+    }
+    // Bogus slots are rejected even when the latency account isn't supplied
+    let params = TxOutcomeParams {
+        submitted_slot: 100,
+        confirmed_slot: 99,
+        ..Default::default()
+    };
+    assert_error(
+        env.register_v2(params).await,
+        TxGuardError::InvalidConfirmationSlots,
+    );
+    assert_eq!(env.registry().await.tx_count, 5);
+}
+
+// Two-pass mean and sample variance in floating point
+fn reference_fee_stats(fees: &[u64]) -> (f64, f64) {
+    let n = fees.len() as f64;
+    let mean = fees.iter().map(|fee| *fee as f64).sum::<f64>() / n;
+    let squares: f64 = fees.iter().map(|fee| (*fee as f64 - mean).powi(2)).sum();
+    (mean, squares / (n - 1.0))
+}
+
+#[test]
+fn fee_variance_tracks_a_floating_point_reference() {
+    // A fixed linear congruential sequence, so failures reproduce
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = |range: u64| {
+        seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (seed >> 16) % range
+    };
+    // Typical priority fees, a bimodal market with rare spikes, and fees in the 2^50s
+    let typical: Vec<u64> = (0..300).map(|_| 5_000 + next(200_000)).collect();
+    let spiky: Vec<u64> = (0..400)
+        .map(|i| match i % 50 {
+            0 => 50_000_000 + next(10_000_000),
+            n if n % 2 == 0 => 1_000 + next(100),
+            _ => 100_000 + next(5_000),
+        })
+        .collect();
+    let huge: Vec<u64> = (0..250).map(|_| (1 << 50) + next(1 << 52)).collect();
+
+    for fees in [typical, spiky, huge] {
+        let mut stats = PriorityFeeStats::default();
+        for fee in &fees {
+            stats.record_fee(*fee);
+        }
+        let (mean, variance) = reference_fee_stats(&fees);
+        assert_eq!(stats.fee_sample_count, fees.len() as u64);
+        assert!((stats.fee_mean_lamports() as f64 - mean).abs() <= 1.0 + mean * 1e-12);
+        let error = (stats.fee_variance() as f64 - variance).abs() / variance;
+        assert!(error < 1e-9, "relative error {}", error);
+    }
+
+    // The widest spread a u64 fee allows still fits: (2^64 - 1)^2 / 2, rounded down
+    let mut stats = PriorityFeeStats::default();
+    stats.record_fee(0);
+    stats.record_fee(u64::MAX);
+    assert_eq!(stats.fee_variance(), (u64::MAX as u128).pow(2) / 2);
+    assert_eq!(stats.fee_mean_lamports(), (u64::MAX / 2) + 1);
+
+    // A single sample or a constant fee has no spread
+    let mut stats = PriorityFeeStats::default();
+    stats.record_fee(7_000);
+    assert_eq!(
+        (stats.fee_mean_lamports(), stats.fee_variance()),
+        (7_000, 0)
+    );
+    stats.record_fee(7_000);
+    assert_eq!(
+        (stats.fee_mean_lamports(), stats.fee_variance()),
+        (7_000, 0)
+    );
+}
+
+#[tokio::test]
+async fn successful_reports_with_a_fee_feed_the_fee_variance() {
+    let mut env = TestEnv::new().await;
+    // (success, fee_lamports): the failure and the undeclared fee stay out
+    let reports = [
+        (true, 2_000),
+        (true, 4_000),
+        (false, 1_000_000),
+        (true, 0),
+        (true, 9_000),
+    ];
+    for (nonce, (success, fee_lamports)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            fee_lamports,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    // 2000, 4000 and 9000: mean 5000, sample variance (9 + 1 + 16) * 10^6 / 2
+    let mut stats = env.stats().await;
+    assert_eq!(stats.fee_sample_count, 3);
+    assert_eq!(stats.fee_mean_lamports(), 5_000);
+    assert_eq!(stats.fee_variance(), 13_000_000);
+
+    let accounts = txguard::accounts::GetStats {
+        registry: registry_pda(),
+        hourly_stats: hourly_pda(),
+        failure_catalog: catalog_pda(),
+        compute_stats: compute_pda(),
+        tx_size_stats: tx_size_pda(),
+        instruction_stats: instructions_pda(),
+        blockhash_age_stats: blockhash_age_pda(),
+        route_stats: routes_pda(),
+        priority_fee_stats: priority_pda(),
+    };
+    let data = txguard::instruction::GetStats {
+        _namespace: String::new(),
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    stats.reset();
+    assert_eq!(
+        (stats.fee_sample_count, stats.fee_mean_fp, stats.fee_m2),
+        (0, 0, 0)
+    );
+}
+
+#[tokio::test]
+async fn recommend_tier_for_deadline_picks_the_cheapest_tier_fast_enough() {
+    let mut env = TestEnv::new().await;
+    let recommend = |deadline_slots| {
+        let accounts = txguard::accounts::RecommendTierForDeadline {
+            priority_fee_stats: priority_pda(),
+            latency_stats: latency_pda(),
+        };
+        let data = txguard::instruction::RecommendTierForDeadline {
+            _namespace: String::new(),
+            deadline_slots,
+        };
+        instruction(accounts, data)
+    };
+    assert_error(
+        env.send(recommend(10)).await,
+        TxGuardError::InsufficientData,
+    );
+
+    // (tier, latency): means of 33 (32.5 rounded), 5 and 2 (1.5 rounded)
+    let reports = [(0, 30), (0, 35), (2, 4), (2, 8), (2, 3), (4, 1), (4, 2)];
+    for (nonce, (tier, latency)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success: true,
+            priority_fee_tier: tier,
+            submitted_slot: 100,
+            confirmed_slot: 100 + latency,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_latency(params).await.unwrap();
+    }
+    // Reports without the latency account don't count
+    env.register(true, FailureType::Slippage, 1).await.unwrap();
+
+    let stats = env.stats().await;
+    assert_eq!(stats.tier_avg_latency_slots, vec![33, 0, 5, 0, 2]);
+    assert_eq!(stats.tier_latency_counts, vec![2, 0, 3, 0, 2]);
+    env.send(recommend(10)).await.unwrap();
+
+    let latency: LatencyStats = env.fetch(latency_pda()).await;
+    let recommendation = |deadline_slots| {
+        let recommendation = stats
+            .recommend_for_deadline(&latency, deadline_slots)
+            .unwrap();
+        (recommendation.tier, recommendation.confidence_bps)
+    };
+    // Tier 2's 3, 4 and 8 all fit; at 5 slots the 8 doesn't
+    assert_eq!(recommendation(10), (2, 10_000));
+    assert_eq!(recommendation(5), (2, 6_666));
+    // No tier's mean fits, so the fastest one, where only the 1 fits
+    assert_eq!(recommendation(1), (4, 5_000));
+    // The 35 falls in the open-ended bucket, which only the maximum latency covers
+    assert_eq!(recommendation(40), (0, 5_000));
+    assert_eq!(recommendation(10_000), (0, 10_000));
+}
+
+#[tokio::test]
+async fn tx_size_buckets_track_success_rates_up_to_the_packet_limit() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, 399),
+        (true, 400),
+        (false, 699),
+        (false, 999),
+        (true, 1_000),
+        (false, 1_232),
+        (false, 1_232),
+        (false, TxMetadata::UNKNOWN_TX_SIZE),
+    ];
+    for (nonce, (success, tx_size_bytes)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            tx_size_bytes,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+    let oversized = TxMetadata {
+        tx_size_bytes: 1_233,
+        ..Default::default()
+    };
+    assert_error(
+        env.register_metadata(TxOutcomeParams::default(), oversized)
+            .await,
+        TxGuardError::InvalidTxSize,
+    );
+
+    let stats: TxSizeStats = env.fetch(tx_size_pda()).await;
+    assert_eq!(stats.successes_by_size, [1, 1, 0, 1]);
+    assert_eq!(stats.failures_by_size, [0, 1, 1, 2]);
+    let rates: Vec<u16> = stats
+        .buckets()
+        .iter()
+        .map(|bucket| bucket.success_rate_bps)
+        .collect();
+    assert_eq!(rates, [10_000, 5_000, 0, 3_333]);
+    assert_eq!(env.registry().await.tx_count, 8);
+}
+
+#[tokio::test]
+async fn stale_oracle_cache_failures_get_their_own_bucket() {
+    let mut env = TestEnv::new().await;
+    for nonce in 0..3 {
+        let params = TxOutcomeParams {
+            failure_type: FailureType::StaleOracleUpdate,
+            nonce,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.stale_oracle_update, 3);
+    assert_eq!(catalog.total_oracle_failures(), 3);
+    assert_eq!(catalog.other, 0);
+    assert!(catalog.format_failure_pie().starts_with("ORCL: "));
+}
+
+#[tokio::test]
+async fn signature_verify_failures_mark_the_precompile_as_used() {
+    let mut env = TestEnv::new().await;
+    let precompile_usage = |authority: Pubkey, used_ed25519_precompile: bool| {
+        let accounts = txguard::accounts::SetPrecompileUsage {
+            authority,
+            config: config_pda(),
+            registry: registry_pda(),
+        };
+        let data = txguard::instruction::SetPrecompileUsage {
+            _namespace: String::new(),
+            used_ed25519_precompile,
+        };
+        instruction(accounts, data)
+    };
+    let outsider = env.add_reporter();
+    assert_error(
+        env.send_as(precompile_usage(outsider.pubkey(), true), &outsider)
+            .await,
+        TxGuardError::Unauthorized,
+    );
+    env.send(precompile_usage(env.ctx.payer.pubkey(), true))
+        .await
+        .unwrap();
+    assert!(env.registry().await.used_ed25519_precompile);
+    env.send(precompile_usage(env.ctx.payer.pubkey(), false))
+        .await
+        .unwrap();
+
+    for nonce in 0..2 {
+        let params = TxOutcomeParams {
+            failure_type: FailureType::SignatureVerifyFailure,
+            nonce,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.signature_verify_failure, 2);
+    assert_eq!(catalog.other, 0);
+    assert!(catalog.format_failure_pie().starts_with("SIGV: "));
+    assert!(env.registry().await.used_ed25519_precompile);
+}
+
+#[tokio::test]
+async fn version_mismatch_failures_keep_the_latest_version_pair() {
+    let mut env = TestEnv::new().await;
+    for (nonce, (expected_version, actual_version)) in [(2u8, 1u8), (3, 4)].into_iter().enumerate()
+    {
+        let params = TxOutcomeParams {
+            failure_type: FailureType::VersionMismatch,
+            expected_version,
+            actual_version,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.version_mismatch, 2);
+    assert_eq!((catalog.expected_version, catalog.actual_version), (3, 4));
+    assert_eq!(catalog.other, 0);
+    assert!(catalog.format_failure_pie().starts_with("VERS: "));
+
+    let ix = env.reset_failure_types_ix(
+        env.ctx.payer.pubkey(),
+        vec![txguard::FAILURE_VERSION_MISMATCH],
+    );
+    env.send(ix).await.unwrap();
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.version_mismatch, 0);
+    assert_eq!((catalog.expected_version, catalog.actual_version), (0, 0));
+}
+
+#[tokio::test]
+async fn blockhash_not_found_is_kept_apart_from_expiry_and_drops() {
+    let mut env = TestEnv::new().await;
+    for nonce in 0..2 {
+        let params = TxOutcomeParams {
+            failure_type: FailureType::BlockhashNotFound,
+            nonce,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.blockhash_not_found, 2);
+    assert_eq!(
+        (catalog.deadline_exceeded, catalog.dropped_tx, catalog.other),
+        (0, 0, 0)
+    );
+    assert!(catalog.format_failure_pie().starts_with("BHNF: "));
+}
+
+#[tokio::test]
+async fn cpi_depth_failures_and_the_deepest_successful_depth() {
+    let mut env = TestEnv::new().await;
+    let report = |success, cpi_depth, nonce| TxOutcomeParams {
+        success,
+        failure_type: if success {
+            FailureType::Slippage
+        } else {
+            FailureType::CpiDepthExceeded
+        },
+        cpi_depth,
+        nonce,
+        ..Default::default()
+    };
+    env.register_v2(report(true, 3, 0)).await.unwrap();
+    env.register_v2(report(true, 1, 1)).await.unwrap();
+    // Only successes move the high-water mark
+    env.register_v2(report(false, 4, 2)).await.unwrap();
+    assert_error(
+        env.register_v2(report(true, 5, 3)).await,
+        TxGuardError::InvalidCpiDepth,
+    );
+
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.cpi_depth_exceeded, 1);
+    assert_eq!(catalog.max_observed_cpi_depth, 3);
+    assert_eq!(catalog.other, 0);
+    assert!(catalog.format_failure_pie().starts_with("CPID: "));
+}
+
+#[tokio::test]
+async fn nonce_failures_record_the_latest_slot() {
+    let mut env = TestEnv::new().await;
+    let slot = env.ctx.banks_client.get_root_slot().await.unwrap() + 50;
+    for (nonce, at) in [slot, slot + 7].into_iter().enumerate() {
+        env.set_slot(at).await;
+        let params = TxOutcomeParams {
+            failure_type: FailureType::NonceAccountExpired,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let mut catalog = env.catalog().await;
+    assert_eq!(catalog.nonce_account_expired, 2);
+    assert_eq!(catalog.last_nonce_failure_slot, slot + 7);
+    assert!(catalog.format_failure_pie().starts_with("NONC: "));
+    catalog.reset_type(txguard::FAILURE_NONCE_EXPIRED);
+    assert_eq!(
+        (
+            catalog.nonce_account_expired,
+            catalog.last_nonce_failure_slot
+        ),
+        (0, 0)
+    );
+}
+
+#[tokio::test]
+async fn gas_price_spikes_keep_the_tier_that_fell_short() {
+    let mut env = TestEnv::new().await;
+    for (nonce, (success, tier)) in [(false, 1), (false, 3), (true, 4)].into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            failure_type: FailureType::GasPriceSpike,
+            priority_fee_tier: tier,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    // The success at tier 4 leaves the spike record alone
+    let mut catalog = env.catalog().await;
+    assert_eq!(catalog.gas_price_spike, 2);
+    assert_eq!(catalog.last_spike_fee_tier, 3);
+    assert!(catalog.format_failure_pie().starts_with("SPIK: "));
+    catalog.reset_type(txguard::FAILURE_GAS_SPIKE);
+    assert_eq!(
+        (catalog.gas_price_spike, catalog.last_spike_fee_tier),
+        (0, 0)
+    );
+}
+
+#[tokio::test]
+async fn account_data_too_small_keeps_the_latest_sizes() {
+    let mut env = TestEnv::new().await;
+    for (nonce, (required_space, available_space)) in
+        [(165, 82), (512, 256)].into_iter().enumerate()
+    {
+        let params = TxOutcomeParams {
+            success: false,
+            failure_type: FailureType::AccountDataTooSmall,
+            required_space,
+            available_space,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let mut catalog = env.catalog().await;
+    assert_eq!(catalog.account_data_too_small, 2);
+    assert_eq!(
+        (catalog.last_required_space, catalog.last_available_space),
+        (512, 256)
+    );
+    assert!(catalog.format_failure_pie().starts_with("SIZE: "));
+    catalog.reset_type(txguard::FAILURE_DATA_TOO_SMALL);
+    assert_eq!(
+        (
+            catalog.account_data_too_small,
+            catalog.last_required_space,
+            catalog.last_available_space
+        ),
+        (0, 0, 0)
+    );
+}
+
+#[tokio::test]
+async fn registry_config_defaults_are_written_by_the_first_report() {
+    let mut env = TestEnv::new().await;
+    env.register(true, FailureType::Slippage, 1).await.unwrap();
+
+    let registry_config: RegistryConfig = env.fetch(registry_config_pda()).await;
+    assert_eq!(registry_config.authority, env.ctx.payer.pubkey());
+    assert_eq!(registry_config.max_tx_count, None);
+    assert_eq!(registry_config.buffer_size, 100);
+    assert!(!registry_config.strict_failure_types);
+    assert_eq!(registry_config.observer, None);
+    assert_eq!(registry_config.version, txguard::REGISTRY_CONFIG_VERSION);
+
+    // Unassigned codes still fold into other by default
+    env.register(false, FailureType::Unassigned9, 1)
+        .await
+        .unwrap();
+    assert_eq!(env.catalog().await.other, 1);
+}
+
+#[tokio::test]
+async fn registry_config_caps_the_registry_and_its_window() {
+    let mut env = TestEnv::new().await;
+    let authority = env.ctx.payer.pubkey();
+    let outsider = env.add_reporter();
+    let result = env
+        .send_as(
+            registry_config_ix(outsider.pubkey(), None, 100, false, None),
+            &outsider,
+        )
+        .await;
+    assert_error(result, TxGuardError::Unauthorized);
+    for buffer_size in [0, 101] {
+        let result = env
+            .send(registry_config_ix(
+                authority,
+                None,
+                buffer_size,
+                false,
+                None,
+            ))
+            .await;
+        assert_error(result, TxGuardError::InvalidBufferSize);
+    }
+
+    env.send(registry_config_ix(authority, Some(3), 2, true, None))
+        .await
+        .unwrap();
+    env.register(true, FailureType::Slippage, 1).await.unwrap();
+    let result = env.register(false, FailureType::Unassigned9, 1).await;
+    assert_error(result, TxGuardError::InvalidFailureType);
+    env.register(true, FailureType::Slippage, 1).await.unwrap();
+    env.register(false, FailureType::Slippage, 1).await.unwrap();
+    let result = env.register(true, FailureType::Slippage, 1).await;
+    assert_error(result, TxGuardError::RegistryFull);
+
+    // Three reports counted, but the window only holds the newest two
+    let registry = env.registry().await;
+    assert_eq!(registry.tx_count, 3);
+    assert_eq!(
+        registry.chronological_outcomes(),
+        [txguard::OUTCOME_SUCCESS, txguard::OUTCOME_FAILURE]
+    );
+}
+
+#[tokio::test]
+async fn registry_config_observer_is_the_only_other_reporter() {
+    let mut env = TestEnv::new().await;
+    let authority = env.ctx.payer.pubkey();
+    let observer = env.add_reporter();
+    let bot = env.add_reporter();
+    env.send(registry_config_ix(
+        authority,
+        None,
+        100,
+        false,
+        Some(observer.pubkey()),
+    ))
+    .await
+    .unwrap();
+
+    assert_error(env.register_as(&bot, 1).await, TxGuardError::NotObserver);
+    env.register_as(&observer, 2).await.unwrap();
+    env.register(true, FailureType::Slippage, 1).await.unwrap();
+    assert_eq!(env.registry().await.tx_count, 2);
+}
+
+#[tokio::test]
+async fn program_not_executable_is_its_own_failure_type() {
+    let mut env = TestEnv::new().await;
+    let params = TxOutcomeParams {
+        success: false,
+        failure_type: FailureType::ProgramNotExecutable,
+        ..Default::default()
+    };
+    env.register_v2(params).await.unwrap();
+
+    let mut catalog = env.catalog().await;
+    assert_eq!((catalog.program_not_executable, catalog.other), (1, 0));
+    assert!(catalog
+        .counts_by_type()
+        .contains(&(txguard::FAILURE_NOT_EXECUTABLE, 1)));
+    assert!(catalog.format_failure_pie().starts_with("NEXE: "));
+    catalog.reset_type(txguard::FAILURE_NOT_EXECUTABLE);
+    assert_eq!(catalog.program_not_executable, 0);
+}
+
+#[tokio::test]
+async fn wrong_token_program_keeps_the_latest_program_pair() {
+    let mut env = TestEnv::new().await;
+    let token_2022 = pubkey!("TokenzQdBNbLqP5VEhdkAS6EHFLC1tL5F6P3GgHPe9i");
+    // Each way round during a migration, the latest one kept
+    let mixups = [
+        (txguard::TOKEN_PROGRAM_ID, token_2022),
+        (token_2022, txguard::TOKEN_PROGRAM_ID),
+    ];
+    for (nonce, (expected_program, actual_program)) in mixups.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success: false,
+            failure_type: FailureType::WrongTokenProgram,
+            expected_program,
+            actual_program,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+    // Other failure types leave the pair alone
+    let params = TxOutcomeParams {
+        success: false,
+        failure_type: FailureType::ProgramNotExecutable,
+        expected_program: Pubkey::new_unique(),
+        nonce: 2,
+        ..Default::default()
+    };
+    env.register_v2(params).await.unwrap();
+
+    let mut catalog = env.catalog().await;
+    assert_eq!((catalog.wrong_token_program, catalog.other), (2, 0));
+    assert_eq!(
+        (catalog.last_expected_program, catalog.last_actual_program),
+        (token_2022, txguard::TOKEN_PROGRAM_ID)
+    );
+    assert!(catalog
+        .counts_by_type()
+        .contains(&(txguard::FAILURE_WRONG_TOKEN_PROGRAM, 2)));
+    assert!(catalog.format_failure_pie().starts_with("TOKP: "));
+    catalog.reset_type(txguard::FAILURE_WRONG_TOKEN_PROGRAM);
+    assert_eq!(
+        (
+            catalog.wrong_token_program,
+            catalog.last_expected_program,
+            catalog.last_actual_program
+        ),
+        (0, Pubkey::default(), Pubkey::default())
+    );
+}
+
+#[tokio::test]
+async fn outcome_and_failure_type_arguments_keep_their_u8_encoding() {
+    let mut env = TestEnv::new().await;
+    let data = |outcome, failure_type| {
+        txguard::instruction::RegisterTxOutcome {
+            _namespace: String::new(),
+            outcome,
+            failure_type,
+            priority_fee_tier: 2,
+        }
+        .data()
+    };
+    // After the discriminator and the empty namespace's length, the bytes a
+    // bool and two u8s took
+    assert_eq!(
+        data(Outcome::Success, FailureType::Bridge)[12..],
+        [txguard::OUTCOME_SUCCESS, txguard::FAILURE_BRIDGE, 2]
+    );
+    assert_eq!(u8::from(FailureType::Unassigned6), 6);
+    assert_eq!(
+        FailureType::try_from(txguard::FAILURE_NOT_EXECUTABLE),
+        Ok(FailureType::ProgramNotExecutable)
+    );
+    assert_eq!(FailureType::try_from(27), Err(27));
+    assert_eq!(
+        Outcome::try_from(txguard::OUTCOME_PARTIAL),
+        Ok(Outcome::Partial)
+    );
+    assert_eq!(Outcome::try_from(4), Err(4));
+
+    // A partial outcome is a partial fill; pending is never reported
+    env.register_outcome(Outcome::Partial, FailureType::Slippage, 2)
+        .await
+        .unwrap();
+    let registry = env.registry().await;
+    assert_eq!(registry.last_100_outcomes[0], txguard::OUTCOME_PARTIAL);
+    assert_eq!(env.catalog().await.partial_fill, 1);
+    let result = env
+        .register_outcome(Outcome::Pending, FailureType::Slippage, 2)
+        .await;
+    assert_error(result, TxGuardError::InvalidOutcome);
+
+    // Codes past the last variant no longer decode
+    let accounts = txguard::accounts::RecordFailure {
+        payer: env.ctx.payer.pubkey(),
+        failure_catalog: catalog_pda(),
+    };
+    let data = txguard::instruction::RecordFailure {
+        _namespace: String::new(),
+        failure_type: FailureType::Slippage,
+    };
+    let mut ix = instruction(accounts, data);
+    *ix.data.last_mut().unwrap() = 27;
+    assert_eq!(
+        env.send(ix).await.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                anchor_lang::error::ErrorCode::InstructionDidNotDeserialize.into()
+            )
+        ),
+    );
+}
+
+#[tokio::test]
+async fn tx_versions_are_counted_with_unknown_formats_kept_apart() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, TxMetadata::TX_VERSION_LEGACY),
+        (false, TxMetadata::TX_VERSION_LEGACY),
+        (true, TxMetadata::TX_VERSION_V0),
+        (true, TxMetadata::TX_VERSION_V0),
+        (false, TxMetadata::TX_VERSION_V0),
+        // A format newer than this program, and a reporter that didn't say
+        (false, 2),
+        (true, TxMetadata::UNKNOWN_TX_VERSION),
+    ];
+    for (nonce, (success, tx_version)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            tx_version,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.version_successes, [1, 2, 1]);
+    assert_eq!(registry.version_failures, [1, 1, 1]);
+    assert_eq!(registry.tx_count, 7);
+}
+
+#[tokio::test]
+async fn lookup_table_usage_splits_failure_rates() {
+    let mut env = TestEnv::new().await;
+    let unknown = TxMetadata::UNKNOWN_LOOKUPS;
+    // (success, tx_version, tables, looked-up accounts)
+    let reports = [
+        (true, TxMetadata::TX_VERSION_LEGACY, 0, 0),
+        (true, TxMetadata::TX_VERSION_V0, 0, 0),
+        (true, TxMetadata::TX_VERSION_V0, 1, 12),
+        (false, TxMetadata::TX_VERSION_V0, 2, 40),
+        (false, TxMetadata::TX_VERSION_V0, 3, 64),
+        (false, TxMetadata::TX_VERSION_V0, unknown, unknown),
+    ];
+    for (nonce, (success, tx_version, tables, accounts)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            tx_version,
+            num_lookup_tables: tables,
+            num_looked_up_accounts: accounts,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+
+    // Legacy transactions can't use tables, and accounts need a table to come from
+    for (nonce, (tx_version, tables, accounts)) in [
+        (TxMetadata::TX_VERSION_LEGACY, 1, 5),
+        (TxMetadata::TX_VERSION_V0, 0, 5),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let params = TxOutcomeParams {
+            success: true,
+            nonce: 10 + nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            tx_version,
+            num_lookup_tables: tables,
+            num_looked_up_accounts: accounts,
+            ..Default::default()
+        };
+        assert_error(
+            env.register_metadata(params, metadata).await,
+            TxGuardError::InvalidLookupUsage,
+        );
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.lookup_reports, [2, 3]);
+    assert_eq!(registry.lookup_failures, [0, 2]);
+    assert_eq!(registry.lookup_failure_rates_bps(), [0, 6_666]);
+    assert_eq!(registry.max_looked_up_accounts, 64);
+    assert_eq!(registry.tx_count, 6);
+}
+
+#[tokio::test]
+async fn buffer_utilization_counts_filled_and_pending_entries() {
+    let mut env = TestEnv::new().await;
+    let utilization_ix = || {
+        let accounts = txguard::accounts::GetBufferUtilization {
+            registry: registry_pda(),
+        };
+        let data = txguard::instruction::GetBufferUtilization {
+            _namespace: String::new(),
+        };
+        instruction(accounts, data)
+    };
+    assert_error(env.send(utilization_ix()).await, TxGuardError::BufferEmpty);
+
+    env.register(true, FailureType::Slippage, 1).await.unwrap();
+    env.register(false, FailureType::Mev, 1).await.unwrap();
+    env.register(true, FailureType::Slippage, 2).await.unwrap();
+    env.send(utilization_ix()).await.unwrap();
+    assert_eq!(
+        env.registry().await.buffer_utilization(),
+        BufferUtilization {
+            non_pending_count: 3,
+            pending_count: 97,
+            buffer_len: 100,
+        }
+    );
+}
+
+#[tokio::test]
+async fn instruction_types_are_tracked_up_to_capacity() {
+    let mut env = TestEnv::new().await;
+    // Swaps (1) succeed twice and fail once; a partial fill and untyped reports are skipped
+    let reports = [
+        (1, true, false),
+        (1, false, false),
+        (1, true, false),
+        (2, true, true),
+        (0, false, false),
+    ];
+    for (nonce, (instruction_type, success, partial_fill)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            partial_fill,
+            instruction_type,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+    let accounts = txguard::accounts::GetInstructionStats {
+        registry: registry_pda(),
+    };
+    let data = txguard::instruction::GetInstructionStats {
+        _namespace: String::new(),
+        instruction_type: 1,
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.instruction_types.len(), 1);
+    assert_eq!(
+        registry.instruction_totals(1),
+        InstructionTypeTotals {
+            success: 2,
+            failure: 1,
+        }
+    );
+    assert_eq!(
+        registry.instruction_totals(2),
+        InstructionTypeTotals {
+            success: 0,
+            failure: 0,
+        }
+    );
+
+    // Once every slot is taken, new types are dropped but known ones keep counting
+    for instruction_type in 2..=txguard::MAX_INSTRUCTION_TYPES as u8 + 1 {
+        let params = TxOutcomeParams {
+            instruction_type,
+            nonce: env.registry().await.tx_count,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+    let registry = env.registry().await;
+    assert_eq!(
+        registry.instruction_types.len(),
+        txguard::MAX_INSTRUCTION_TYPES
+    );
+    assert_eq!(
+        registry
+            .instruction_totals(txguard::MAX_INSTRUCTION_TYPES as u8)
+            .failure,
+        1
+    );
+    assert_eq!(
+        registry
+            .instruction_totals(txguard::MAX_INSTRUCTION_TYPES as u8 + 1)
+            .failure,
+        0
+    );
+}
+
+#[tokio::test]
+async fn landing_must_agree_with_success_and_feeds_the_landed_error_rate() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, txguard::LANDING_SUCCEEDED),
+        (false, txguard::LANDING_ERRORED),
+        (false, txguard::LANDING_NEVER),
+        (false, txguard::LANDING_ERRORED),
+        (true, txguard::LANDING_UNKNOWN),
+    ];
+    for (nonce, (success, landing)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            landing,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    for (success, landing) in [
+        (true, txguard::LANDING_ERRORED),
+        (false, txguard::LANDING_SUCCEEDED),
+    ] {
+        let params = TxOutcomeParams {
+            success,
+            landing,
+            nonce: 10,
+            ..Default::default()
+        };
+        assert_error(
+            env.register_v2(params).await,
+            TxGuardError::ContradictoryReport,
+        );
+    }
+    let params = TxOutcomeParams {
+        landing: 3,
+        nonce: 10,
+        ..Default::default()
+    };
+    assert_error(env.register_v2(params).await, TxGuardError::InvalidLanding);
+
+    let registry = env.registry().await;
+    assert_eq!(registry.landing_counts, [1, 2, 1]);
+    assert_eq!(registry.landed_error_rate_bps(), 5_000);
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert_eq!(registry.health(0, &config).landed_error_rate_bps, 5_000);
+}
+
+#[tokio::test]
+async fn reset_failure_types_zeroes_only_the_listed_types() {
+    let mut env = TestEnv::new().await;
+    env.set_time(1_700_000_000).await;
+    for failure_type in [
+        FailureType::Slippage,
+        FailureType::Mev,
+        FailureType::Mev,
+        FailureType::TokenFrozen,
+    ] {
+        let params = TxOutcomeParams {
+            failure_type,
+            frozen_mint: Pubkey::new_unique(),
+            nonce: env.registry().await.tx_count,
+            ..Default::default()
+        };
+        env.register_v2(params).await.unwrap();
+    }
+
+    let outsider = env.add_reporter();
+    let ix = env.reset_failure_types_ix(outsider.pubkey(), vec![2]);
+    assert_error(env.send_as(ix, &outsider).await, TxGuardError::Unauthorized);
+    // 6 isn't a failure bucket, and an empty list resets nothing
+    for types in [vec![2, 6], vec![]] {
+        let ix = env.reset_failure_types_ix(env.ctx.payer.pubkey(), types);
+        assert_error(env.send(ix).await, TxGuardError::InvalidFailureType);
+    }
+
+    let ix = env.reset_failure_types_ix(env.ctx.payer.pubkey(), vec![2, txguard::FAILURE_FROZEN]);
+    env.send(ix).await.unwrap();
+    let catalog = env.catalog().await;
+    assert_eq!(catalog.mev_detected, 0);
+    assert_eq!(catalog.first_occurrence[2], 0);
+    assert_eq!(catalog.token_frozen, 0);
+    assert_eq!(catalog.frozen_mint, Pubkey::default());
+    assert_eq!(catalog.slippage_exceeded, 1);
+    assert_eq!(catalog.first_occurrence[0], 1_700_000_000);
+    assert_eq!(catalog.catalog_reset_count, 1);
+}
+
+#[tokio::test]
+async fn instruction_count_buckets_track_the_largest_transaction() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, 1),
+        (true, 3),
+        (false, 4),
+        (true, 6),
+        (false, 12),
+        (false, 13),
+        (false, 40),
+        (true, TxMetadata::UNKNOWN_INSTRUCTIONS),
+    ];
+    for (nonce, (success, num_instructions)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            num_instructions,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+    let empty = TxMetadata {
+        num_instructions: 0,
+        ..Default::default()
+    };
+    assert_error(
+        env.register_metadata(TxOutcomeParams::default(), empty)
+            .await,
+        TxGuardError::InvalidInstructionCount,
+    );
+
+    let stats: InstructionStats = env.fetch(instructions_pda()).await;
+    assert_eq!(stats.successes_by_count, [1, 1, 1, 0, 0]);
+    assert_eq!(stats.failures_by_count, [0, 0, 1, 1, 2]);
+    assert_eq!(stats.max_instructions, 40);
+    assert_eq!(stats.buckets()[2].success_rate_bps, 5_000);
+}
+
+#[tokio::test]
+async fn blockhash_age_buckets_split_outcomes_and_count_stale_failures() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, 0),
+        (true, 10),
+        (false, 11),
+        (true, 45),
+        (false, 90),
+        (false, 120),
+        (false, 121),
+        (true, 150),
+        (false, TxMetadata::UNKNOWN_BLOCKHASH_AGE),
+    ];
+    for (nonce, (success, blockhash_age_slots)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            blockhash_age_slots,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+
+    let stats: BlockhashAgeStats = env.fetch(blockhash_age_pda()).await;
+    assert_eq!(stats.successes_by_age, [2, 0, 1, 0, 1]);
+    assert_eq!(stats.failures_by_age, [0, 1, 0, 1, 2]);
+    // 121 counts; 120 and the successful 150 don't
+    assert_eq!(stats.stale_failures, 1);
+    assert_eq!(stats.buckets()[4].success_rate_bps, 3_333);
+}
+
+#[tokio::test]
+async fn route_hops_bucket_swaps_and_their_slippage_failures() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, 1, FailureType::Slippage),
+        (false, 2, FailureType::Liquidity),
+        (false, 3, FailureType::Slippage),
+        (true, 3, FailureType::Slippage),
+        (false, 5, FailureType::Slippage),
+        (false, 9, FailureType::Dropped),
+        // Not a swap
+        (false, TxMetadata::NOT_A_SWAP, FailureType::Slippage),
+    ];
+    for (nonce, (success, route_hops, failure_type)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            failure_type,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            route_hops,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+
+    let stats: RouteStats = env.fetch(routes_pda()).await;
+    assert_eq!(stats.successes_by_hops, [1, 0, 1, 0]);
+    assert_eq!(stats.failures_by_hops, [0, 1, 1, 2]);
+    assert_eq!(stats.slippage_failures_by_hops, [0, 0, 1, 1]);
+    assert_eq!(stats.buckets()[2].success_rate_bps, 5_000);
+}
+
+#[tokio::test]
+async fn compare_to_snapshot_diffs_counts_and_per_report_rates() {
+    let mut env = TestEnv::new().await;
+    // Up to the snapshot: 100 reports, 20% slippage, 10% MEV, 5% dropped
+    let mut snapshot =
+        snapshot_with([20, 0, 10, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    snapshot.epoch = 3;
+    snapshot.tx_count = 100;
+    // 100 reports since: 5 slippage (5%), 15 MEV (15%), 5 dropped (still 5%)
+    let mut registry = env.registry().await;
+    registry.tx_count = 200;
+    env.store(registry_pda(), &registry).await;
+    let mut catalog = env.catalog().await;
+    catalog.slippage_exceeded = 25;
+    catalog.mev_detected = 25;
+    catalog.dropped_tx = 10;
+    env.store(catalog_pda(), &catalog).await;
+
+    let diff = catalog.compare_to_snapshot(&registry, &snapshot);
+    assert_eq!(
+        (
+            diff.delta_slippage,
+            diff.delta_mev,
+            diff.delta_dropped,
+            diff.delta_other
+        ),
+        (5, 15, 5, 0)
+    );
+    assert_eq!(diff.improved_types, 0b000001);
+    assert_eq!(diff.regressed_types, 0b000100);
+
+    // A selective reset can leave the catalog below the snapshot
+    catalog.slippage_exceeded = 0;
+    assert_eq!(
+        catalog
+            .compare_to_snapshot(&registry, &snapshot)
+            .delta_slippage,
+        -20
+    );
+
+    let mut account = zeroed_account(EpochSnapshot::DISCRIMINATOR, EpochSnapshot::INIT_SPACE);
+    let mut data = Vec::new();
+    snapshot.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    env.ctx.set_account(&snapshot_pda(3), &account.into());
+    let accounts = txguard::accounts::CompareToSnapshot {
+        registry: registry_pda(),
+        failure_catalog: catalog_pda(),
+        snapshot: snapshot_pda(3),
+    };
+    let data = txguard::instruction::CompareToSnapshot {
+        _namespace: String::new(),
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+}
+
+#[tokio::test]
+async fn signer_counts_split_outcomes_into_one_two_and_more() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, 1),
+        (true, 1),
+        (false, 2),
+        (true, 3),
+        (false, 12),
+        (false, TxMetadata::UNKNOWN_SIGNERS),
+    ];
+    for (nonce, (success, num_signers)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            num_signers,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+    for num_signers in [0, 13] {
+        let metadata = TxMetadata {
+            num_signers,
+            ..Default::default()
+        };
+        assert_error(
+            env.register_metadata(TxOutcomeParams::default(), metadata)
+                .await,
+            TxGuardError::InvalidSignerCount,
+        );
+    }
+
+    let registry = env.registry().await;
+    assert_eq!(registry.signer_successes, [2, 0, 1]);
+    assert_eq!(registry.signer_failures, [0, 1, 1]);
+    let rates: Vec<u16> = registry
+        .signer_buckets()
+        .iter()
+        .map(|bucket| bucket.success_rate_bps)
+        .collect();
+    assert_eq!(rates, [10_000, 0, 5_000]);
+}
+
+#[tokio::test]
+async fn send_methods_keep_their_own_rates_and_dominant_failures() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, TxMetadata::SEND_METHOD_RPC, FailureType::Slippage),
+        (false, TxMetadata::SEND_METHOD_RPC, FailureType::Dropped),
+        (false, TxMetadata::SEND_METHOD_RPC, FailureType::Dropped),
+        (false, TxMetadata::SEND_METHOD_RPC, FailureType::Slippage),
+        (true, TxMetadata::SEND_METHOD_JITO, FailureType::Slippage),
+        (false, TxMetadata::SEND_METHOD_JITO, FailureType::Mev),
+        (true, TxMetadata::SEND_METHOD_RELAY, FailureType::Slippage),
+        // Out of range methods count as unknown
+        (false, 9, FailureType::Deadline),
+    ];
+    for (nonce, (success, send_method, failure_type)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            failure_type,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            send_method,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+
+    let registry = env.registry().await;
+    let rates: Vec<u16> = registry
+        .send_method_buckets()
+        .iter()
+        .map(|bucket| bucket.success_rate_bps)
+        .collect();
+    assert_eq!(rates, [2_500, 5_000, 10_000, 0]);
+    assert_eq!(
+        registry.send_method_dominant_failures(),
+        [3, 2, 0, txguard::FAILURE_DEADLINE]
+    );
+}
+
+#[tokio::test]
+async fn tags_are_counted_per_bit() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, 0b01),
+        (false, 0b11),
+        (true, 0b10),
+        (true, 1 << 15),
+        (true, 0),
+    ];
+    for (nonce, (success, tags)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            tags,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let mut accounts = env.register_accounts();
+        accounts.tag_stats = Some(tags_pda());
+        let data = txguard::instruction::RegisterTxOutcomeV2 {
+            _namespace: String::new(),
+            params,
+        };
+        env.send(instruction(accounts, data)).await.unwrap();
+    }
+    let accounts = txguard::accounts::GetTagStats {
+        tag_stats: tags_pda(),
+    };
+    let data = txguard::instruction::GetTagStats {
+        _namespace: String::new(),
+    };
+    env.send(instruction(accounts, data)).await.unwrap();
+
+    let stats: TagStats = env.fetch(tags_pda()).await;
+    assert_eq!(stats.successes_by_tag[..2], [1, 1]);
+    assert_eq!(stats.failures_by_tag[..2], [1, 1]);
+    assert_eq!(stats.successes_by_tag[15], 1);
+    let rates = stats.success_rates_bps();
+    assert_eq!(rates[..3], [5_000, 5_000, 0]);
+    assert_eq!(rates[15], 10_000);
+}
+
+#[tokio::test]
+async fn preflight_simulation_is_tabulated_against_outcomes() {
+    let mut env = TestEnv::new().await;
+    let reports = [
+        (true, true),
+        (true, true),
+        (false, true),
+        (false, false),
+        (true, false),
+    ];
+    for (nonce, (success, was_simulated)) in reports.into_iter().enumerate() {
+        let params = TxOutcomeParams {
+            success,
+            nonce: nonce as u64,
+            ..Default::default()
+        };
+        let metadata = TxMetadata {
+            was_simulated,
+            ..Default::default()
+        };
+        env.register_metadata(params, metadata).await.unwrap();
+    }
+    // Reports without metadata aren't tabulated
+    env.register(false, FailureType::Slippage, 0).await.unwrap();
+
+    let registry = env.registry().await;
+    assert_eq!(registry.simulation_successes, [1, 2]);
+    assert_eq!(registry.simulation_failures, [1, 1]);
+}
+
+#[tokio::test]
+async fn catalog_snapshots_are_written_to_the_callers_account() {
+    let mut env = TestEnv::new().await;
+    let mut catalog = env.catalog().await;
+    catalog.mev_detected = 3;
+    catalog.slippage_exceeded = 1;
+    env.store(catalog_pda(), &catalog).await;
+
+    // A fresh system account is allocated and assigned through CPI on its first write,
+    // which the native processor can't run; start from one TxGuard already took over
+    let raw = |owner: Pubkey, data: Vec<u8>| {
+        SolanaAccount {
+            lamports: 1_000_000_000,
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into()
+    };
+    let space = 8 + txguard::CatalogBorshSnapshot::INIT_SPACE;
+    let dest = Keypair::new();
+    env.ctx
+        .set_account(&dest.pubkey(), &raw(txguard::ID, vec![0; space]));
+    let write = |writer: Pubkey, dest: Pubkey| {
+        let accounts = txguard::accounts::WriteCatalogSnapshot {
+            writer,
+            failure_catalog: catalog_pda(),
+            dest,
+            system_program: system_program::ID,
+        };
+        let data = txguard::instruction::WriteCatalogSnapshotToAccount {
+            _namespace: String::new(),
+        };
+        instruction(accounts, data)
+    };
+    let payer = env.ctx.payer.pubkey();
+    env.send_as(write(payer, dest.pubkey()), &dest)
+        .await
+        .unwrap();
+
+    let snapshot: txguard::CatalogBorshSnapshot = env.fetch(dest.pubkey()).await;
+    assert_eq!(snapshot.writer, payer);
+    assert_eq!(snapshot.catalog, catalog_pda());
+    assert_eq!(snapshot.failures_by_type[0], 1);
+    assert_eq!(snapshot.failures_by_type[2], 3);
+    assert_eq!(snapshot.total_failures, 4);
+
+    // The writer can refresh it; anyone else is refused
+    catalog.mev_detected = 5;
+    env.store(catalog_pda(), &catalog).await;
+    env.send_as(write(payer, dest.pubkey()), &dest)
+        .await
+        .unwrap();
+    let snapshot: txguard::CatalogBorshSnapshot = env.fetch(dest.pubkey()).await;
+    assert_eq!(snapshot.total_failures, 6);
+    let other = env.add_reporter();
+    assert_error(
+        env.send_signed(write(other.pubkey(), dest.pubkey()), &[&other, &dest])
+            .await,
+        TxGuardError::InvalidSnapshotAccount,
+    );
+
+    // Nor can it write over other TxGuard data, another program's account, or a short one
+    let mut registry_data = TransactionRegistry::DISCRIMINATOR.to_vec();
+    registry_data.resize(space, 0);
+    let cases = [
+        raw(txguard::ID, registry_data),
+        raw(txguard::TOKEN_PROGRAM_ID, vec![0; space]),
+        raw(txguard::ID, vec![0; 16]),
+    ];
+    for account in cases {
+        let dest = Keypair::new();
+        env.ctx.set_account(&dest.pubkey(), &account);
+        assert_error(
+            env.send_as(write(payer, dest.pubkey()), &dest).await,
+            TxGuardError::InvalidSnapshotAccount,
+        );
+    }
+}
+
+#[test]
+fn wilson_lower_bound_matches_reference_values() {
+    // Floors of (p + z²/2n - z·sqrt(p(1 - p)/n + z²/4n²)) / (1 + z²/n) at z = 1.96
+    for (successes, total, bound) in [
+        (19, 20, 7_638),
+        (19_000, 20_000, 9_468),
+        (50, 100, 4_038),
+        (1, 3, 614),
+        (10, 10, 7_224),
+        (1, 1, 2_065),
+        (0, 10, 0),
+        (0, 0, 0),
+    ] {
+        assert_eq!(
+            txguard::wilson_lower_bound_bps(successes, total),
+            bound,
+            "{}/{}",
+            successes,
+            total
+        );
+    }
+    // The full u64 range doesn't overflow, and the bound closes in on the rate
+    assert_eq!(txguard::wilson_lower_bound_bps(u64::MAX, u64::MAX), 9_999);
+    assert_eq!(
+        txguard::wilson_lower_bound_bps(u64::MAX / 2, u64::MAX),
+        4_999
+    );
+    assert_eq!(txguard::wilson_lower_bound_bps(1, u64::MAX), 0);
+}
+
+#[tokio::test]
+async fn check_health_can_judge_the_wilson_lower_bound() {
+    let mut env = TestEnv::new().await;
+    let mut registry = env.registry().await;
+    registry.tx_count = 20;
+    registry.success_count = 19;
+    registry.failure_count = 1;
+    env.store(registry_pda(), &registry).await;
+
+    let check_health = || {
+        let accounts = txguard::accounts::CheckHealth {
+            registry: registry_pda(),
+            config: config_pda(),
+        };
+        let data = txguard::instruction::CheckHealth {
+            _namespace: String::new(),
+        };
+        instruction(accounts, data)
+    };
+    let health = |config: &GuardConfig| registry.health(0, config);
+
+    // No threshold by default
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    let status = health(&config);
+    assert_eq!(status.success_rate_bps, 9_500);
+    assert_eq!(status.success_rate_lower_bound_bps, 7_638);
+    assert!(!status.below_min_success_rate);
+
+    // 95% clears 90% as a point estimate, but not over only 20 reports
+    env.set_health_threshold(9_000, false).await.unwrap();
+    env.send(check_health()).await.unwrap();
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert!(!health(&config).below_min_success_rate);
+    env.set_health_threshold(9_000, true).await.unwrap();
+    env.send(check_health()).await.unwrap();
+    let config: GuardConfig = env.fetch(config_pda()).await;
+    assert!(config.health_uses_lower_bound);
+    assert!(health(&config).below_min_success_rate);
+
+    // The same rate over 20,000 reports does
+    registry.tx_count = 20_000;
+    registry.success_count = 19_000;
+    assert_eq!(registry.success_rate_lower_bound_bps(), 9_468);
+    assert!(!registry.health(0, &config).below_min_success_rate);
+
+    assert_error(
+        env.set_health_threshold(10_001, true).await,
+        TxGuardError::InvalidSuccessRateThreshold,
+    );
+}
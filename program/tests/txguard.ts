@@ -3,6 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { Txguard } from "../target/types/txguard";
 import { Keypair } from "@solana/web3.js";
 import { expect } from "chai";
+import { createHash, randomBytes } from "crypto";
 
 describe("txguard", () => {
   // Configure the client to use the local cluster.
@@ -12,13 +13,50 @@ describe("txguard", () => {
 
   const program = anchor.workspace.txguard as Program<Txguard>;
 
+  // Seeds and limits as the program publishes them in its IDL
+  const idlConstant = (name: string) =>
+    program.rawIdl.constants.find((constant) => constant.name === name).value;
+  const REGISTRY_SEED = Buffer.from(JSON.parse(idlConstant("REGISTRY_SEED")));
+  const CATALOG_SEED = Buffer.from(JSON.parse(idlConstant("CATALOG_SEED")));
+  const PRIORITY_SEED = Buffer.from(JSON.parse(idlConstant("PRIORITY_SEED")));
+  const WINDOW_SIZE = Number(idlConstant("WINDOW_SIZE"));
+
+  // TxOutcomeParams with every optional field zeroed
+  const outcomeParams = (overrides: Record<string, unknown> = {}) => ({
+    success: true,
+    failureType: { slippage: {} },
+    priorityFeeTier: 0,
+    feeLamports: new anchor.BN(0),
+    valueLamports: new anchor.BN(0),
+    mevScore: 0,
+    nonce: new anchor.BN(0),
+    bridgeChainId: 0,
+    partialFill: false,
+    txSigHash: new Array(32).fill(0),
+    slotsInFlight: new anchor.BN(0),
+    wsolErrorCode: 0,
+    txSlot: new anchor.BN(0),
+    frozenMint: anchor.web3.PublicKey.default,
+    submittedSlot: new anchor.BN(0),
+    confirmedSlot: new anchor.BN(0),
+    instructionType: 0,
+    landing: 255, // LANDING_UNKNOWN
+    expectedVersion: 0,
+    actualVersion: 0,
+    tags: 0,
+    cpiDepth: 0,
+    requiredSpace: 0,
+    availableSpace: 0,
+    ...overrides,
+  });
+
   it("Initialize PDAs for registry, catalog, and priority stats", async () => {
-    const tx = await program.methods.initialize().rpc();
+    const tx = await program.methods.initialize("").rpc();
     console.log("Initialize transaction signature:", tx);
 
     // Fetch the registry account
     const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("registry")],
+      [REGISTRY_SEED],
       program.programId
     );
 
@@ -29,18 +67,18 @@ describe("txguard", () => {
     expect(registry.successCount.toNumber()).to.equal(0);
     expect(registry.failureCount.toNumber()).to.equal(0);
     expect(registry.cursor).to.equal(0);
-    expect(registry.last100Outcomes.length).to.equal(100);
+    expect(registry.last100Outcomes.length).to.equal(WINDOW_SIZE);
   });
 
   it("Register successful transaction", async () => {
     const tx = await program.methods
-      .registerTxOutcome(true, 0, 2)
+      .registerTxOutcome("", { success: {} }, { slippage: {} }, 2)
       .rpc();
     console.log("Register success transaction signature:", tx);
 
     // Fetch the registry
     const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("registry")],
+      [REGISTRY_SEED],
       program.programId
     );
 
@@ -54,18 +92,18 @@ describe("txguard", () => {
 
   it("Register slippage failure transaction", async () => {
     const tx = await program.methods
-      .registerTxOutcome(false, 0, 1)
+      .registerTxOutcome("", { failure: {} }, { slippage: {} }, 1)
       .rpc();
     console.log("Register slippage failure transaction signature:", tx);
 
     // Fetch the registry and catalog
     const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("registry")],
+      [REGISTRY_SEED],
       program.programId
     );
     
     const [catalogPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("catalog")],
+      [CATALOG_SEED],
       program.programId
     );
 
@@ -81,12 +119,12 @@ describe("txguard", () => {
 
   it("Record failure directly", async () => {
     const tx = await program.methods
-      .recordFailure(4) // insufficient_funds
+      .recordFailure("", { insufficientFunds: {} }) // insufficient_funds
       .rpc();
     console.log("Record failure transaction signature:", tx);
 
     const [catalogPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("catalog")],
+      [CATALOG_SEED],
       program.programId
     );
 
@@ -96,14 +134,33 @@ describe("txguard", () => {
     expect(catalog.insufficientFunds).to.equal(1);
   });
 
+  it("Timestamp the first and last occurrence of a failure type", async () => {
+    const [catalogPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [CATALOG_SEED],
+      program.programId
+    );
+    const first = await program.account.failureCatalog.fetch(catalogPda);
+    expect(first.firstOccurrence[4].toNumber()).to.be.greaterThan(0);
+    expect(first.firstOccurrence[4].toNumber()).to.equal(first.lastOccurrence[4].toNumber());
+
+    await new Promise((resolve) => setTimeout(resolve, 1500));
+    await program.methods.recordFailure("", { insufficientFunds: {} }).rpc();
+
+    const second = await program.account.failureCatalog.fetch(catalogPda);
+    expect(second.firstOccurrence[4].toNumber()).to.equal(first.firstOccurrence[4].toNumber());
+    expect(second.lastOccurrence[4].toNumber()).to.be.greaterThanOrEqual(first.lastOccurrence[4].toNumber());
+    // Unmapped codes are stamped in the `other` slot
+    expect(second.firstOccurrence[9].toNumber()).to.equal(0);
+  });
+
   it("Update priority fee tier", async () => {
     const tx = await program.methods
-      .updatePriorityFee(3)
+      .updatePriorityFee("", 3)
       .rpc();
     console.log("Update priority fee transaction signature:", tx);
 
     const [priorityPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("priority")],
+      [PRIORITY_SEED],
       program.programId
     );
 
@@ -114,21 +171,548 @@ describe("txguard", () => {
     expect(stats.tiers[3].toNumber()).to.equal(1);
   });
 
+  it("Register transaction outcome via v2 params", async () => {
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const before = await program.account.transactionRegistry.fetch(registryPda);
+
+    await program.methods
+      .registerTxOutcomeV2("", outcomeParams({
+        priorityFeeTier: 2,
+        feeLamports: new anchor.BN(5000),
+        valueLamports: new anchor.BN(1_000_000),
+        nonce: new anchor.BN(1),
+      }))
+      .rpc();
+
+    const registry = await program.account.transactionRegistry.fetch(registryPda);
+
+    expect(registry.txCount.toNumber()).to.equal(before.txCount.toNumber() + 1);
+    expect(registry.successCount.toNumber()).to.equal(before.successCount.toNumber() + 1);
+    expect(registry.totalFeeLamports.toNumber()).to.equal(before.totalFeeLamports.toNumber() + 5000);
+    expect(registry.totalValueLamports.toNumber()).to.equal(before.totalValueLamports.toNumber() + 1_000_000);
+  });
+
+  it("Return the new tx_count as the report's reference ID", async () => {
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const before = await program.account.transactionRegistry.fetch(registryPda);
+
+    const txId = await program.methods
+      .registerTxOutcomeV2("", outcomeParams({ nonce: new anchor.BN(Date.now()) }))
+      .view();
+
+    expect(txId.toNumber()).to.equal(before.txCount.toNumber() + 1);
+  });
+
+  it("Record bridge failures by destination chain", async () => {
+    const [catalogPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [CATALOG_SEED],
+      program.programId
+    );
+
+    for (const chainId of [2, 2, 30]) {
+      await program.methods
+        .registerTxOutcomeV2("", outcomeParams({
+          success: false,
+          failureType: { bridge: {} },
+          priorityFeeTier: 1,
+          bridgeChainId: chainId,
+        }))
+        .rpc();
+    }
+
+    const catalog = await program.account.failureCatalog.fetch(catalogPda);
+
+    expect(catalog.bridgeFailure).to.equal(3);
+    expect(catalog.bridgeChainId).to.equal(30);
+    expect(catalog.bridgeFailureByChain).to.deep.equal([
+      { chainId: 2, count: 2 },
+      { chainId: 30, count: 1 },
+    ]);
+  });
+
+  it("Record a partial fill as its own outcome", async () => {
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const [catalogPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [CATALOG_SEED],
+      program.programId
+    );
+    const before = await program.account.transactionRegistry.fetch(registryPda);
+
+    await program.methods
+      .registerTxOutcomeV2("", outcomeParams({ priorityFeeTier: 2, partialFill: true }))
+      .rpc();
+
+    const registry = await program.account.transactionRegistry.fetch(registryPda);
+    const catalog = await program.account.failureCatalog.fetch(catalogPda);
+
+    expect(registry.txCount.toNumber()).to.equal(before.txCount.toNumber() + 1);
+    expect(registry.successCount.toNumber()).to.equal(before.successCount.toNumber());
+    expect(registry.failureCount.toNumber()).to.equal(before.failureCount.toNumber());
+    expect(registry.last100Outcomes[before.cursor]).to.equal(3);
+    expect(catalog.partialFill).to.equal(1);
+    expect(catalog.partialFillRateBps).to.equal(
+      Math.floor(10_000 / registry.txCount.toNumber())
+    );
+  });
+
+  it("Route outcomes to the current epoch registry in epoch mode", async () => {
+    const { epoch } = await provider.connection.getEpochInfo();
+    const epochBytes = Buffer.alloc(8);
+    epochBytes.writeBigUInt64LE(BigInt(epoch));
+    const [epochRegistryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED, epochBytes],
+      program.programId
+    );
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const globalBefore = await program.account.transactionRegistry.fetch(registryPda);
+
+    await program.methods.setEpochMode("", true).rpc();
+    await program.methods
+      .registerTxOutcome("", { success: {} }, { slippage: {} }, 1)
+      .accounts({ epochRegistry: epochRegistryPda })
+      .rpc();
+    await program.methods.setEpochMode("", false).rpc();
+
+    const epochRegistry = await program.account.transactionRegistry.fetch(epochRegistryPda);
+    const globalAfter = await program.account.transactionRegistry.fetch(registryPda);
+
+    expect(epochRegistry.txCount.toNumber()).to.be.greaterThanOrEqual(1);
+    expect(epochRegistry.last100Outcomes.length).to.equal(WINDOW_SIZE);
+    expect(globalAfter.txCount.toNumber()).to.equal(globalBefore.txCount.toNumber());
+  });
+
+  it("Attribute reports to the signing reporter", async () => {
+    const [reporterPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reporter"), payer.publicKey.toBuffer()],
+      program.programId
+    );
+    const before = await program.account.reporterRecord.fetch(reporterPda);
+
+    await program.methods.registerTxOutcome("", { failure: {} }, { dropped: {} }, 0).rpc();
+
+    const record = await program.account.reporterRecord.fetch(reporterPda);
+    expect(record.reporter.toBase58()).to.equal(payer.publicKey.toBase58());
+    expect(record.failuresReported.toNumber()).to.equal(before.failuresReported.toNumber() + 1);
+    expect(record.lastReportSlot.toNumber()).to.be.greaterThan(0);
+
+    const stats = await program.methods.getReporterStats("", payer.publicKey).view();
+    expect(stats.successesReported.toNumber()).to.equal(record.successesReported.toNumber());
+    expect(stats.failuresReported.toNumber()).to.equal(record.failuresReported.toNumber());
+    // min_reputation defaults to 0, so everyone is trusted until the authority raises it
+    expect(stats.reputation).to.be.greaterThan(0);
+    expect(stats.trusted).to.equal(true);
+  });
+
+  it("Close a reporter record", async () => {
+    const reporter = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(reporter.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig);
+
+    const [reporterPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reporter"), reporter.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .registerTxOutcome("", { success: {} }, { slippage: {} }, 1)
+      .accounts({ payer: reporter.publicKey })
+      .signers([reporter])
+      .rpc();
+    expect((await program.account.reporterRecord.fetch(reporterPda)).successesReported.toNumber()).to.equal(1);
+
+    await program.methods
+      .closeReporterRecord("")
+      .accounts({ reporter: reporter.publicKey })
+      .signers([reporter])
+      .rpc();
+    expect(await program.account.reporterRecord.fetchNullable(reporterPda)).to.be.null;
+  });
+
+  it("Label priority fee tiers", async () => {
+    const label = (name: string) => Array.from(Buffer.from(name.padEnd(16, " ")));
+    const names = ["ultra-low", "low", "medium", "high", "ultra-high"].map(label);
+
+    await program.methods.setTierNames("", names).rpc();
+
+    const tiers = await program.methods.getTierStats("").view();
+    expect(tiers.length).to.equal(5);
+    expect(Buffer.from(tiers[3].name).toString().trim()).to.equal("high");
+  });
+
+  it("Reject tier names from a non-authority", async () => {
+    const intruder = Keypair.generate();
+    const names = [Array.from(Buffer.from("hijacked".padEnd(16, " ")))];
+
+    try {
+      await program.methods
+        .setTierNames("", names)
+        .accounts({ authority: intruder.publicKey })
+        .signers([intruder])
+        .rpc();
+      expect.fail("non-authority should not set tier names");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("Apply one report to both the global and the user registry", async () => {
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const [userRegistryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("user_registry"), Buffer.from(""), payer.publicKey.toBuffer()],
+      program.programId
+    );
+    const globalBefore = await program.account.transactionRegistry.fetch(registryPda);
+    const userBefore = await program.account.transactionRegistry.fetchNullable(userRegistryPda);
+
+    await program.methods
+      .registerTxOutcomeDual("", outcomeParams({ success: false, failureType: { mev: {} }, priorityFeeTier: 3 }))
+      .accounts({ base: { payer: payer.publicKey }, payer: payer.publicKey })
+      .rpc();
+
+    const globalAfter = await program.account.transactionRegistry.fetch(registryPda);
+    const userAfter = await program.account.transactionRegistry.fetch(userRegistryPda);
+
+    expect(globalAfter.txCount.toNumber()).to.equal(globalBefore.txCount.toNumber() + 1);
+    expect(globalAfter.failureCount.toNumber()).to.equal(globalBefore.failureCount.toNumber() + 1);
+    expect(userAfter.txCount.toNumber()).to.equal((userBefore?.txCount.toNumber() ?? 0) + 1);
+    expect(userAfter.failureCount.toNumber()).to.equal((userBefore?.failureCount.toNumber() ?? 0) + 1);
+    expect(userAfter.last100Outcomes.length).to.equal(WINDOW_SIZE);
+  });
+
+  it("Leave both registries untouched when the user registry can't be funded", async () => {
+    const broke = Keypair.generate();
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const before = await program.account.transactionRegistry.fetch(registryPda);
+
+    try {
+      await program.methods
+        .registerTxOutcomeDual("", outcomeParams())
+        .accounts({ base: { payer: broke.publicKey }, payer: broke.publicKey })
+        .signers([broke])
+        .rpc();
+      expect.fail("unfunded payer should not create the user registry");
+    } catch (err) {
+      expect(String(err)).to.not.match(/should not create/);
+    }
+
+    const after = await program.account.transactionRegistry.fetch(registryPda);
+    expect(after.txCount.toNumber()).to.equal(before.txCount.toNumber());
+  });
+
+  it("Record a success inline after the tracked instruction", async () => {
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const [programStatsPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("program_stats"), Buffer.from(""), anchor.web3.SystemProgram.programId.toBuffer()],
+      program.programId
+    );
+    const before = await program.account.transactionRegistry.fetch(registryPda);
+
+    // A system transfer stands in for the swap being tracked
+    const swap = anchor.web3.SystemProgram.transfer({
+      fromPubkey: payer.publicKey,
+      toPubkey: Keypair.generate().publicKey,
+      lamports: 1_000_000,
+    });
+    await program.methods
+      .recordInlineSuccess("", 2)
+      .accounts({
+        base: { payer: payer.publicKey },
+        targetProgram: anchor.web3.SystemProgram.programId,
+        payer: payer.publicKey,
+      })
+      .preInstructions([swap])
+      .rpc();
+
+    const after = await program.account.transactionRegistry.fetch(registryPda);
+    const programStats = await program.account.programStats.fetch(programStatsPda);
+    expect(after.successCount.toNumber()).to.equal(before.successCount.toNumber() + 1);
+    expect(programStats.programId.toBase58()).to.equal(anchor.web3.SystemProgram.programId.toBase58());
+    expect(programStats.successCount.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("Reject an inline success without the tracked instruction", async () => {
+    try {
+      await program.methods
+        .recordInlineSuccess("", 0)
+        .accounts({
+          base: { payer: payer.publicKey },
+          targetProgram: anchor.web3.SystemProgram.programId,
+          payer: payer.publicKey,
+        })
+        .rpc();
+      expect.fail("expected MissingTargetInstruction");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MissingTargetInstruction");
+    }
+  });
+
+  it("Transfer a user registry to a new wallet", async () => {
+    const newOwner = Keypair.generate();
+    const userRegistryFor = (owner: anchor.web3.PublicKey) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("user_registry"), Buffer.from(""), owner.toBuffer()],
+        program.programId
+      )[0];
+    const before = await program.account.transactionRegistry.fetch(userRegistryFor(payer.publicKey));
+
+    let event = null;
+    const listener = program.addEventListener("userRegistryTransferred", (e) => {
+      event = e;
+    });
+    await program.methods.transferUserRegistry("", newOwner.publicKey).rpc();
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await program.removeEventListener(listener);
+
+    const moved = await program.account.transactionRegistry.fetch(userRegistryFor(newOwner.publicKey));
+    expect(moved.txCount.toNumber()).to.equal(before.txCount.toNumber());
+    expect(moved.last100Outcomes).to.deep.equal(before.last100Outcomes);
+    expect(await program.account.transactionRegistry.fetchNullable(userRegistryFor(payer.publicKey))).to.be.null;
+    expect(event.oldOwner.toBase58()).to.equal(payer.publicKey.toBase58());
+    expect(event.newOwner.toBase58()).to.equal(newOwner.publicKey.toBase58());
+  });
+
+  it("Refuse to transfer onto an existing user registry", async () => {
+    const other = Keypair.generate();
+    // Give the payer a fresh user registry, then try to land it on the same wallet twice
+    await program.methods
+      .registerTxOutcomeDual("", outcomeParams())
+      .accounts({ base: { payer: payer.publicKey }, payer: payer.publicKey })
+      .rpc();
+    await program.methods.transferUserRegistry("", other.publicKey).rpc();
+    await program.methods
+      .registerTxOutcomeDual("", outcomeParams())
+      .accounts({ base: { payer: payer.publicKey }, payer: payer.publicKey })
+      .rpc();
+
+    try {
+      await program.methods.transferUserRegistry("", other.publicKey).rpc();
+      expect.fail("transfer onto an existing registry should fail");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("DestinationRegistryExists");
+    }
+  });
+
+  it("Summarize registry, catalog and stats in one call", async () => {
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const registry = await program.account.transactionRegistry.fetch(registryPda);
+
+    const summary = await program.methods.getSummary("").view();
+
+    expect(summary.txCount.toNumber()).to.equal(registry.txCount.toNumber());
+    expect(summary.successCount.toNumber()).to.equal(registry.successCount.toNumber());
+    expect(summary.cursor).to.equal(registry.cursor);
+    expect(summary.successRateBps).to.equal(
+      Math.floor((registry.successCount.toNumber() * 10_000) / registry.txCount.toNumber())
+    );
+    expect(summary.optimalTier).to.be.lessThan(5);
+  });
+
+  it("Export registry, catalog and stats as a versioned payload", async () => {
+    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [REGISTRY_SEED],
+      program.programId
+    );
+    const registry = await program.account.transactionRegistry.fetch(registryPda);
+
+    const payload = Buffer.from(await program.methods.exportState("").view());
+
+    // [schema version] ++ borsh(registry) ++ borsh(catalog) ++ borsh(stats)
+    expect(payload[0]).to.equal(14);
+    expect(payload.readBigUInt64LE(1)).to.equal(BigInt(registry.txCount.toString()));
+    expect(payload.readBigUInt64LE(9)).to.equal(BigInt(registry.successCount.toString()));
+  });
+
+  describe("duplicate reports", () => {
+    const sigHash = () => Array.from(createHash("sha256").update(randomBytes(64)).digest());
+    const duplicated = sigHash();
+
+    it("Reject a signature already in the dedup window", async () => {
+      await program.methods.registerTxOutcomeV2("", outcomeParams({ txSigHash: duplicated })).rpc();
+
+      try {
+        await program.methods.registerTxOutcomeV2("", outcomeParams({ txSigHash: duplicated })).rpc();
+        expect.fail("duplicate signature should be rejected");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("DuplicateReport");
+      }
+    });
+
+    it("Accept the signature again once it has been evicted", async () => {
+      // 64 fresh signatures push the duplicated one out of the ring
+      for (let i = 0; i < 64; i++) {
+        await program.methods.registerTxOutcomeV2("", outcomeParams({ txSigHash: sigHash() })).rpc();
+      }
+
+      await program.methods.registerTxOutcomeV2("", outcomeParams({ txSigHash: duplicated })).rpc();
+    });
+  });
+
+  it("Recommend a lamport fee from historical tier success rates", async () => {
+    const lamports = [0, 1_000, 5_000, 20_000, 100_000].map((l) => new anchor.BN(l));
+    await program.methods.setTierLamports("", lamports).rpc();
+
+    await program.methods.registerTxOutcome("", { success: {} }, { slippage: {} }, 1).rpc();
+    const easy = await program.methods.recommendFeeLamports("", 0).view();
+    expect(easy.toNumber()).to.be.lessThanOrEqual(1_000);
+
+    // No tier can beat 100%, so the top tier is the fallback
+    const impossible = await program.methods.recommendFeeLamports("", 10_001).view();
+    expect(impossible.toNumber()).to.equal(100_000);
+  });
+
+  it("Reject a decreasing tier lamport mapping", async () => {
+    const lamports = [5_000, 1_000, 0, 0, 0].map((l) => new anchor.BN(l));
+    try {
+      await program.methods.setTierLamports("", lamports).rpc();
+      expect.fail("decreasing mapping should be rejected");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidTierLamports");
+    }
+  });
+
+  it("Charge a report fee into the treasury and withdraw it", async () => {
+    const [treasuryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      program.programId
+    );
+    await program.methods.setReportFee("", new anchor.BN(10_000)).rpc();
+    const before = await program.account.treasury.fetch(treasuryPda);
+
+    await program.methods
+      .registerTxOutcomeV2("", outcomeParams({ nonce: new anchor.BN(Date.now()) }))
+      .accounts({ treasury: treasuryPda })
+      .rpc();
+
+    const after = await program.account.treasury.fetch(treasuryPda);
+    expect(after.totalFeesCollected.toNumber()).to.equal(before.totalFeesCollected.toNumber() + 10_000);
+
+    // A fresh recipient would need rent-exempt funding, so pay the authority back instead
+    const treasuryBalance = await provider.connection.getBalance(treasuryPda);
+    await program.methods
+      .withdrawTreasury("", new anchor.BN(10_000))
+      .accounts({ recipient: payer.publicKey })
+      .rpc();
+    expect(await provider.connection.getBalance(treasuryPda)).to.equal(treasuryBalance - 10_000);
+
+    // Later tests report without a treasury account
+    await program.methods.setReportFee("", new anchor.BN(0)).rpc();
+  });
+
+  it("Mint reward tokens for a reporter's points", async () => {
+    const tokenProgram = new anchor.web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    const associatedTokenProgram = new anchor.web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+    const [mintAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_authority"), Buffer.from("")],
+      program.programId
+    );
+    const [reporterPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reporter"), Buffer.from(""), payer.publicKey.toBuffer()],
+      program.programId
+    );
+    const mint = Keypair.generate();
+    const [tokenAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [payer.publicKey.toBuffer(), tokenProgram.toBuffer(), mint.publicKey.toBuffer()],
+      associatedTokenProgram
+    );
+
+    // Built by hand rather than pulling in @solana/spl-token: InitializeMint2 (tag 20,
+    // 0 decimals, no freeze authority), then the token account via CreateIdempotent (tag 1)
+    const createMint = anchor.web3.SystemProgram.createAccount({
+      fromPubkey: payer.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: 82,
+      lamports: await provider.connection.getMinimumBalanceForRentExemption(82),
+      programId: tokenProgram,
+    });
+    const initializeMint = new anchor.web3.TransactionInstruction({
+      programId: tokenProgram,
+      keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+      data: Buffer.concat([Buffer.from([20, 0]), mintAuthority.toBuffer(), Buffer.from([0])]),
+    });
+    const createTokenAccount = new anchor.web3.TransactionInstruction({
+      programId: associatedTokenProgram,
+      keys: [
+        { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+        { pubkey: tokenAccount, isSigner: false, isWritable: true },
+        { pubkey: payer.publicKey, isSigner: false, isWritable: false },
+        { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+        { pubkey: anchor.web3.SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: tokenProgram, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.from([1]),
+    });
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(createMint, initializeMint, createTokenAccount),
+      [mint]
+    );
+
+    await program.methods.setRewardPoints("", new anchor.BN(10), new anchor.BN(4), new anchor.BN(0)).rpc();
+    await program.methods
+      .registerTxOutcomeV2("", outcomeParams({ nonce: new anchor.BN(Date.now()) }))
+      .rpc();
+    await program.methods
+      .setRewardMint("", mint.publicKey, new anchor.BN(0))
+      .accounts({ rewardMint: mint.publicKey })
+      .rpc();
+
+    const { points } = await program.account.reporterRecord.fetch(reporterPda);
+    expect(points.toNumber()).to.be.greaterThanOrEqual(10);
+    await program.methods
+      .claimRewards("")
+      .accounts({ rewardMint: mint.publicKey, reporterTokenAccount: tokenAccount, tokenProgram })
+      .rpc();
+
+    const balance = await provider.connection.getTokenAccountBalance(tokenAccount);
+    expect(balance.value.amount).to.equal(points.toString());
+    expect((await program.account.reporterRecord.fetch(reporterPda)).points.toNumber()).to.equal(0);
+
+    // Later tests run without rewards
+    await program.methods.setRewardPoints("", new anchor.BN(0), new anchor.BN(0), new anchor.BN(0)).rpc();
+    await program.methods
+      .setRewardMint("", anchor.web3.PublicKey.default, new anchor.BN(0))
+      .accounts({ rewardMint: anchor.web3.PublicKey.default })
+      .rpc();
+  });
+
   it("Verify multiple transactions update counts correctly", async () => {
     // Register multiple successful transactions with different priority tiers
-    await program.methods.registerTxOutcome(true, 0, 0).rpc();
-    await program.methods.registerTxOutcome(true, 0, 1).rpc();
-    await program.methods.registerTxOutcome(true, 0, 2).rpc();
-    await program.methods.registerTxOutcome(false, 1, 3).rpc(); // liquidity failure
-    await program.methods.registerTxOutcome(true, 0, 4).rpc();
+    await program.methods.registerTxOutcome("", { success: {} }, { slippage: {} }, 0).rpc();
+    await program.methods.registerTxOutcome("", { success: {} }, { slippage: {} }, 1).rpc();
+    await program.methods.registerTxOutcome("", { success: {} }, { slippage: {} }, 2).rpc();
+    await program.methods.registerTxOutcome("", { failure: {} }, { liquidity: {} }, 3).rpc(); // liquidity failure
+    await program.methods.registerTxOutcome("", { success: {} }, { slippage: {} }, 4).rpc();
 
     const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("registry")],
+      [REGISTRY_SEED],
       program.programId
     );
     
     const [catalogPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("catalog")],
+      [CATALOG_SEED],
       program.programId
     );
 
@@ -142,4 +726,97 @@ describe("txguard", () => {
     
     console.log("✓ Multiple transactions verified");
   });
+
+  describe("namespaces", () => {
+    const registryFor = (namespace: string) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [REGISTRY_SEED, Buffer.from(namespace)],
+        program.programId
+      )[0];
+
+    it("Map the empty namespace to the original seeds", async () => {
+      const [legacyRegistry] = anchor.web3.PublicKey.findProgramAddressSync(
+        [REGISTRY_SEED],
+        program.programId
+      );
+      expect(registryFor("").toBase58()).to.equal(legacyRegistry.toBase58());
+    });
+
+    it("Evolve two namespaces independently", async () => {
+      await program.methods.initialize("team-alpha").rpc();
+      await program.methods.initialize("team-beta").rpc();
+
+      await program.methods.registerTxOutcome("team-alpha", { success: {} }, { slippage: {} }, 1).rpc();
+      await program.methods.registerTxOutcome("team-alpha", { success: {} }, { slippage: {} }, 2).rpc();
+      await program.methods.registerTxOutcome("team-beta", { failure: {} }, { mev: {} }, 4).rpc();
+
+      const alpha = await program.account.transactionRegistry.fetch(registryFor("team-alpha"));
+      const beta = await program.account.transactionRegistry.fetch(registryFor("team-beta"));
+
+      expect(alpha.txCount.toNumber()).to.equal(2);
+      expect(alpha.successCount.toNumber()).to.equal(2);
+      expect(beta.txCount.toNumber()).to.equal(1);
+      expect(beta.failureCount.toNumber()).to.equal(1);
+    });
+
+    it("Reject creating a namespace twice", async () => {
+      try {
+        await program.methods.initialize("team-alpha").rpc();
+        expect.fail("re-initializing a namespace should fail");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NamespaceExists");
+      }
+    });
+
+    it("Merge one namespace's registry into another and close the source", async () => {
+      const alphaBefore = await program.account.transactionRegistry.fetch(registryFor("team-alpha"));
+      const betaBefore = await program.account.transactionRegistry.fetch(registryFor("team-beta"));
+
+      await program.methods
+        .mergeRegistries("team-alpha", "team-beta", null, null, true)
+        .accounts({
+          source: registryFor("team-alpha"),
+          destination: registryFor("team-beta"),
+          rentRecipient: payer.publicKey,
+        })
+        .rpc();
+
+      const beta = await program.account.transactionRegistry.fetch(registryFor("team-beta"));
+      expect(beta.txCount.toNumber()).to.equal(
+        alphaBefore.txCount.toNumber() + betaBefore.txCount.toNumber()
+      );
+      expect(beta.successCount.toNumber()).to.equal(
+        alphaBefore.successCount.toNumber() + betaBefore.successCount.toNumber()
+      );
+      // Source entries come first, the destination's own entries are the newest
+      expect(beta.last100Outcomes.slice(0, 3)).to.deep.equal([1, 1, 0]);
+      expect(beta.cursor).to.equal(3);
+      expect(await program.account.transactionRegistry.fetchNullable(registryFor("team-alpha"))).to.be.null;
+    });
+
+    it("Reject merging a registry into itself", async () => {
+      try {
+        await program.methods
+          .mergeRegistries("team-beta", "team-beta", null, null, false)
+          .accounts({
+            source: registryFor("team-beta"),
+            destination: registryFor("team-beta"),
+            rentRecipient: null,
+          })
+          .rpc();
+        expect.fail("self-merge should be rejected");
+      } catch (err) {
+        expect(String(err)).to.match(/SelfMerge|ConstraintDuplicateMutableAccount/);
+      }
+    });
+
+    it("Reject namespaces longer than 32 bytes", async () => {
+      try {
+        await program.methods.initialize("x".repeat(33)).rpc();
+        expect.fail("oversized namespace should fail");
+      } catch (err) {
+        expect(String(err)).to.match(/NamespaceTooLong|Max seed length exceeded/);
+      }
+    });
+  });
 });